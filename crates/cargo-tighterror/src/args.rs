@@ -1,5 +1,6 @@
-use clap::Parser;
+use clap::{builder::TypedValueParser, Parser};
 use std::path::PathBuf;
+use tighterror_build::{Edition, OutputMode, SpecLang};
 
 #[derive(Parser)]
 #[command(name = "cargo")]
@@ -11,25 +12,232 @@ enum CargoCli {
 #[derive(clap::Args, Debug)]
 #[command(author, version, about, long_about = None)]
 pub struct Args {
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// The specification file path
     #[arg(short, long, value_name = "PATH")]
     pub spec: Option<PathBuf>,
 
+    /// The specification markup language
+    #[arg(long, value_name = "LANG")]
+    pub lang: Option<LangArg>,
+
     /// The output path
-    #[arg(short, long, value_name = "PATH")]
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        env = OUTPUT_ENV_VAR,
+        hide_env_values = true,
+        value_parser = clap::builder::OsStringValueParser::new().map(PathBuf::from)
+    )]
     pub output: Option<PathBuf>,
 
     /// Include a unit-test in the generated code
-    #[arg(short, long)]
-    pub test: bool,
+    #[arg(
+        short,
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub test: Option<bool>,
+
+    /// Generate code for a no_std environment, overriding the specification
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub no_std: Option<bool>,
+
+    /// Start the generated files with a generated-by comment including the spec hash
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub header: Option<bool>,
 
     /// Do not overwrite the output file if data is unchanged
-    #[arg(short, long)]
-    pub update: bool,
+    #[arg(
+        short,
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub update: Option<bool>,
+
+    /// Print a unified diff of the changes instead of writing the output
+    #[arg(long)]
+    pub diff: bool,
+
+    /// Print the bit layout of the error kinds instead of writing the output
+    #[arg(long, conflicts_with = "diff")]
+    pub print_layout: bool,
 
     /// Write modules in separate files
-    #[arg(short = 'S', long)]
-    pub separate_files: bool,
+    #[arg(
+        short = 'S',
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub separate_files: Option<bool>,
+
+    /// The module file path pattern in separate-files mode, e.g., gen_{module}.rs
+    #[arg(long, value_name = "PATTERN")]
+    pub output_pattern: Option<String>,
+
+    /// The extension of the output files in an output directory, e.g., gen.rs
+    #[arg(long, value_name = "EXT")]
+    pub output_ext: Option<String>,
+
+    /// Name the file after the single module when the output is a directory
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub module_filename: Option<bool>,
+
+    /// Write the private tables of every module to a <module>_tables.rs file
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub split_tables: Option<bool>,
+
+    /// Report specification warnings as errors
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub strict: Option<bool>,
+
+    /// Exit with a failure status if any warning was logged
+    #[arg(long)]
+    pub fail_on_warnings: bool,
+
+    /// Reject documentation strings containing tab characters
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub strict_docs: Option<bool>,
+
+    /// Require an explicit display string of every error
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub require_display: Option<bool>,
+
+    /// Ignore unknown specification attributes with a warning
+    #[arg(
+        long,
+        value_name = "BOOL",
+        num_args = 0..=1,
+        require_equals = true,
+        default_missing_value = "true",
+        hide_possible_values = true
+    )]
+    pub lenient: Option<bool>,
+
+    /// The output file writing mode
+    #[arg(long, value_name = "MODE")]
+    pub output_mode: Option<OutputModeArg>,
+
+    /// The Rust edition used to format the generated code
+    #[arg(long, value_name = "EDITION")]
+    pub edition: Option<EditionArg>,
+
+    /// The rustfmt configuration file used to format the generated code
+    #[arg(long, value_name = "PATH")]
+    pub rustfmt_config: Option<PathBuf>,
+
+    /// Write a JSON catalog of the errors to the given path
+    #[arg(long, value_name = "PATH")]
+    pub catalog: Option<PathBuf>,
+
+    /// The number of threads generating the modules in parallel
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u16).range(1..))]
+    pub threads: Option<u16>,
+}
+
+/// The environment variable overriding the specification output path
+pub const OUTPUT_ENV_VAR: &str = "TIGHTERROR_OUTPUT";
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum OutputModeArg {
+    /// Overwrite the output file
+    Overwrite,
+    /// Regenerate the region between markers and preserve the rest
+    Append,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum LangArg {
+    Yaml,
+    Toml,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy, Debug)]
+pub enum EditionArg {
+    #[value(name = "2015")]
+    Edition2015,
+    #[value(name = "2018")]
+    Edition2018,
+    #[value(name = "2021")]
+    Edition2021,
+    #[value(name = "2024")]
+    Edition2024,
+}
+
+#[derive(clap::Subcommand, Debug)]
+pub enum Command {
+    /// Print a build script that generates the code from the specification file
+    EmitBuildRs,
+    /// Print the error kinds and their values without generating code
+    List {
+        /// Print the listing in the JSON format of the catalog
+        #[arg(long)]
+        json: bool,
+    },
 }
 
 impl Args {
@@ -38,24 +246,108 @@ impl Args {
         args
     }
 
-    #[inline]
-    fn bool_to_opt(v: bool) -> Option<bool> {
-        if v {
-            Some(v)
-        } else {
-            None
-        }
+    /// Returns the output path of the command line, or of the
+    /// `TIGHTERROR_OUTPUT` environment variable.
+    ///
+    /// `None` means the output path of the specification file is used.
+    /// An empty path, e.g., of an empty environment variable, is ignored.
+    pub fn output(&self) -> Option<PathBuf> {
+        self.output.clone().filter(|p| !p.as_os_str().is_empty())
+    }
+
+    pub fn lang(&self) -> Option<SpecLang> {
+        self.lang.map(|l| match l {
+            LangArg::Yaml => SpecLang::Yaml,
+            LangArg::Toml => SpecLang::Toml,
+        })
     }
 
-    pub fn test(&self) -> Option<bool> {
-        Self::bool_to_opt(self.test)
+    pub fn output_mode(&self) -> Option<OutputMode> {
+        self.output_mode.map(|m| match m {
+            OutputModeArg::Overwrite => OutputMode::Overwrite,
+            OutputModeArg::Append => OutputMode::Append,
+        })
     }
 
-    pub fn update(&self) -> Option<bool> {
-        Self::bool_to_opt(self.update)
+    pub fn edition(&self) -> Option<Edition> {
+        self.edition.map(|e| match e {
+            EditionArg::Edition2015 => Edition::Edition2015,
+            EditionArg::Edition2018 => Edition::Edition2018,
+            EditionArg::Edition2021 => Edition::Edition2021,
+            EditionArg::Edition2024 => Edition::Edition2024,
+        })
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_args() {
+        let CargoCli::Tighterror(args) =
+            CargoCli::try_parse_from(["cargo", "tighterror", "-o", "cli.rs"]).unwrap();
+        assert_eq!(args.output, Some(PathBuf::from("cli.rs")));
+        assert_eq!(args.output(), Some(PathBuf::from("cli.rs")));
+
+        let CargoCli::Tighterror(args) = CargoCli::try_parse_from(["cargo", "tighterror"]).unwrap();
+        assert_eq!(args.output, None);
+    }
+
+    #[test]
+    fn test_list_args() {
+        let CargoCli::Tighterror(args) =
+            CargoCli::try_parse_from(["cargo", "tighterror", "list"]).unwrap();
+        assert!(matches!(args.command, Some(Command::List { json: false })));
+
+        let CargoCli::Tighterror(args) = CargoCli::try_parse_from([
+            "cargo",
+            "tighterror",
+            "-s",
+            "errors.yaml",
+            "list",
+            "--json",
+        ])
+        .unwrap();
+        assert!(matches!(args.command, Some(Command::List { json: true })));
+        assert_eq!(args.spec, Some(PathBuf::from("errors.yaml")));
+    }
+
+    #[test]
+    fn test_threads_args() {
+        let CargoCli::Tighterror(args) =
+            CargoCli::try_parse_from(["cargo", "tighterror", "--threads", "4"]).unwrap();
+        assert_eq!(args.threads, Some(4));
+        assert!(CargoCli::try_parse_from(["cargo", "tighterror", "--threads", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_print_layout_args() {
+        let CargoCli::Tighterror(args) =
+            CargoCli::try_parse_from(["cargo", "tighterror", "--print-layout"]).unwrap();
+        assert!(args.print_layout);
+        assert!(
+            CargoCli::try_parse_from(["cargo", "tighterror", "--print-layout", "--diff"]).is_err()
+        );
+    }
+
+    #[test]
+    fn test_bool_args() {
+        for (argv, res) in [
+            (&["--test"][..], Some(true)),
+            (&["--test=true"][..], Some(true)),
+            (&["--test=false"][..], Some(false)),
+            (&["-t"][..], Some(true)),
+            (&[][..], None),
+        ] {
+            let CargoCli::Tighterror(args) =
+                CargoCli::try_parse_from(["cargo", "tighterror"].iter().chain(argv)).unwrap();
+            assert_eq!(args.test, res, "{argv:?}");
+        }
 
-    pub fn separate_files(&self) -> Option<bool> {
-        Self::bool_to_opt(self.separate_files)
+        let CargoCli::Tighterror(args) =
+            CargoCli::try_parse_from(["cargo", "tighterror", "--no-std=false"]).unwrap();
+        assert_eq!(args.no_std, Some(false));
+        assert!(CargoCli::try_parse_from(["cargo", "tighterror", "--no-std=maybe"]).is_err());
     }
 }
@@ -0,0 +1,67 @@
+use log::error;
+use std::{path::Path, process::exit};
+
+const DEFAULT_SPEC_PATHS: [&str; 3] = [
+    tighterror_build::DEFAULT_SPEC_PATH_YAML,
+    tighterror_build::DEFAULT_SPEC_PATH_YML,
+    tighterror_build::DEFAULT_SPEC_PATH_TOML,
+];
+
+pub fn emit_build_rs_main(spec: Option<&Path>) {
+    let mut default_paths = DEFAULT_SPEC_PATHS.iter().map(Path::new);
+    let Some(spec) = spec.or_else(|| default_paths.find(|p| p.is_file())) else {
+        error!("specification file couldn't be found: use --spec to define its path");
+        exit(1);
+    };
+    if spec.as_os_str() == "-" {
+        error!("a build script cannot read the specification from stdin");
+        exit(1);
+    }
+    print!("{}", build_rs(spec));
+}
+
+fn build_rs(spec: &Path) -> String {
+    let spec = format!("{:?}", spec.display().to_string());
+    format!(
+        r#"// build.rs
+use tighterror_build::CodegenOptions;
+
+const SPEC: &str = {spec};
+
+fn main() {{
+    println!("cargo:rerun-if-changed={{SPEC}}");
+    let out_dir = std::env::var("OUT_DIR").expect("OUT_DIR isn't defined");
+    let out_path = format!("{{out_dir}}/errors.rs");
+    if let Err(e) = CodegenOptions::new().spec(SPEC).output(out_path).codegen() {{
+        panic!("tighterror-build failed: {{e}}; out_dir: {{out_dir}}");
+    }}
+}}
+
+// Include the generated code somewhere in the crate, e.g., `lib.rs`:
+//
+// pub mod errors {{
+//     include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+// }}
+"#
+    )
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+
+    #[test]
+    fn test_build_rs() {
+        let s = build_rs(Path::new("errors/tighterror.yaml"));
+        assert!(s.contains("const SPEC: &str = \"errors/tighterror.yaml\";"));
+        assert!(s.contains("println!(\"cargo:rerun-if-changed={SPEC}\");"));
+        assert!(s.contains("std::env::var(\"OUT_DIR\")"));
+        assert!(s.contains(".spec(SPEC)"));
+    }
+
+    #[test]
+    fn test_build_rs_escapes_spec_path() {
+        let s = build_rs(Path::new("my \"errors\".toml"));
+        assert!(s.contains(r#"const SPEC: &str = "my \"errors\".toml";"#));
+    }
+}
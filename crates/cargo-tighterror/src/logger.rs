@@ -0,0 +1,59 @@
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static WARNINGS: AtomicUsize = AtomicUsize::new(0);
+
+/// A logger that counts the warnings and forwards the records to the
+/// `RUST_LOG`-configured pretty logger.
+///
+/// Warnings are counted even if they are filtered out by `RUST_LOG`.
+struct CountingLogger<L> {
+    inner: L,
+}
+
+impl<L: Log> Log for CountingLogger<L> {
+    fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+        metadata.level() <= Level::Warn || self.inner.enabled(metadata)
+    }
+
+    fn log(&self, record: &Record<'_>) {
+        if record.level() == Level::Warn {
+            WARNINGS.fetch_add(1, Ordering::Relaxed);
+        }
+        if self.inner.enabled(record.metadata()) {
+            self.inner.log(record);
+        }
+    }
+
+    fn flush(&self) {
+        self.inner.flush();
+    }
+}
+
+/// Initializes the global logger.
+///
+/// If `count_warnings` is set the warnings are counted, see [warnings], and
+/// are displayed unless `RUST_LOG` says otherwise.
+pub fn init(count_warnings: bool) {
+    let mut builder = pretty_env_logger::formatted_builder();
+    if count_warnings {
+        builder.filter_level(LevelFilter::Warn);
+    }
+    if let Ok(s) = std::env::var("RUST_LOG") {
+        builder.parse_filters(&s);
+    }
+    if !count_warnings {
+        builder.init();
+        return;
+    }
+    let inner = builder.build();
+    let max_level = inner.filter().max(LevelFilter::Warn);
+    log::set_boxed_logger(Box::new(CountingLogger { inner }))
+        .expect("the logger must be initialized once");
+    log::set_max_level(max_level);
+}
+
+/// Returns the number of warnings logged so far.
+pub fn warnings() -> usize {
+    WARNINGS.load(Ordering::Relaxed)
+}
@@ -1,31 +1,155 @@
 #![deny(warnings)]
 #![forbid(unsafe_code)]
 
-use log::error;
+use log::{error, info};
 use std::process::exit;
 use tighterror_build::CodegenOptions;
 
 mod args;
 use args::*;
+mod build_rs;
+mod logger;
 
 fn codegen_main(args: Args) {
-    let test = args.test();
-    let update = args.update();
-    let separate_files = args.separate_files();
-    if let Err(e) = CodegenOptions::new()
-        .spec_option(args.spec)
-        .output_option(args.output)
+    let fail_on_warnings = args.fail_on_warnings;
+    // a specification read from stdin can't be read twice
+    let spec_from_stdin = args.spec.as_ref().is_some_and(|p| p.as_os_str() == "-");
+    let lang = args.lang();
+    let test = args.test;
+    let update = args.update;
+    let header = args.header;
+    let no_std = args.no_std;
+    let separate_files = args.separate_files;
+    let module_filename = args.module_filename;
+    let split_tables = args.split_tables;
+    let strict = args.strict;
+    let strict_docs = args.strict_docs;
+    let require_display = args.require_display;
+    let lenient = args.lenient;
+    let output_mode = args.output_mode();
+    let edition = args.edition();
+    let output = args.output();
+    let mut opts = CodegenOptions::new();
+    opts.spec_option(args.spec)
+        .spec_lang(lang)
+        .output_option(output)
         .test(test)
         .update(update)
+        .header(header)
+        .no_std(no_std)
         .separate_files(separate_files)
-        .codegen()
-    {
-        error!("{e}");
-        exit(1);
+        .module_filename(module_filename)
+        .output_pattern(args.output_pattern)
+        .output_ext(args.output_ext)
+        .split_tables(split_tables)
+        .strict(strict)
+        .strict_docs(strict_docs)
+        .require_display(require_display)
+        .lenient(lenient)
+        .output_mode(output_mode)
+        .edition(edition)
+        .rustfmt_config_option(args.rustfmt_config)
+        .catalog_output_option(args.catalog)
+        .threads(args.threads.map(usize::from));
+
+    if args.diff {
+        diff_main(&opts);
+        return;
+    }
+
+    if args.print_layout {
+        layout_main(&opts);
+        return;
+    }
+
+    if fail_on_warnings && !spec_from_stdin {
+        // generate without writing first, so nothing is written on warnings
+        if let Err(e) = opts.codegen_diff() {
+            error!("{e}");
+            exit(1);
+        }
+        check_warnings();
+    }
+
+    match opts.codegen() {
+        Ok(report) => {
+            for p in report.written() {
+                info!("written: {}", p.display());
+            }
+            for p in report.unchanged() {
+                info!("unchanged: {}", p.display());
+            }
+        }
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
+    }
+}
+
+fn diff_main(opts: &CodegenOptions) {
+    match opts.codegen_diff() {
+        Ok(diff) => print!("{diff}"),
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
+    }
+}
+
+fn layout_main(opts: &CodegenOptions) {
+    match opts.layout() {
+        Ok(layout) => print!("{layout}"),
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
+    }
+}
+
+fn list_main(args: Args, json: bool) {
+    let lang = args.lang();
+    let strict = args.strict;
+    let strict_docs = args.strict_docs;
+    let require_display = args.require_display;
+    let lenient = args.lenient;
+    let mut opts = CodegenOptions::new();
+    opts.spec_option(args.spec)
+        .spec_lang(lang)
+        .strict(strict)
+        .strict_docs(strict_docs)
+        .require_display(require_display)
+        .lenient(lenient);
+
+    match opts.list(json) {
+        Ok(list) => print!("{list}"),
+        Err(e) => {
+            error!("{e}");
+            exit(1);
+        }
     }
 }
 
 fn main() {
-    pretty_env_logger::init();
-    codegen_main(Args::parse_args())
+    let args = Args::parse_args();
+    let fail_on_warnings = args.fail_on_warnings;
+    logger::init(fail_on_warnings);
+    match args.command {
+        Some(Command::EmitBuildRs) => build_rs::emit_build_rs_main(args.spec.as_deref()),
+        Some(Command::List { json }) => list_main(args, json),
+        None => codegen_main(args),
+    }
+
+    if fail_on_warnings {
+        check_warnings();
+    }
+}
+
+/// Exits with a failure status if any warning was logged.
+fn check_warnings() {
+    let n_warnings = logger::warnings();
+    if n_warnings > 0 {
+        error!("failing on {n_warnings} warning(s)");
+        exit(1);
+    }
 }
@@ -0,0 +1,61 @@
+use std::{fs, path::Path, process::Command};
+
+const SPEC: &str = "errors:\n  - BAD_FILE: Bad input.\n  - BAD_ARG: Bad input.\n";
+
+fn run(dir: &Path, args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_cargo-tighterror"))
+        .current_dir(dir)
+        .env_remove("RUST_LOG")
+        .arg("tighterror")
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn test_fail_on_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("tighterror.yaml"), SPEC).unwrap();
+
+    let out = run(dir.path(), &["-o", "errors.rs"]);
+    assert!(out.status.success());
+
+    let out = run(dir.path(), &["-o", "errors.rs", "--fail-on-warnings"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(stderr.contains("share a display string"), "{stderr}");
+    assert!(stderr.contains("failing on 1 warning(s)"), "{stderr}");
+
+    fs::remove_file(dir.path().join("errors.rs")).unwrap();
+    let out = run(dir.path(), &["-o", "errors.rs", "--fail-on-warnings"]);
+    assert!(!out.status.success());
+    assert!(!dir.path().join("errors.rs").exists());
+}
+
+#[test]
+fn test_fail_on_warnings_no_warnings() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("tighterror.yaml"), "errors: [BAD_FILE]").unwrap();
+
+    let out = run(dir.path(), &["-o", "errors.rs", "--fail-on-warnings"]);
+    assert!(out.status.success());
+}
+
+#[test]
+fn test_fail_on_warnings_mirror_categories() {
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "categories:\n  - name: Reader\n    errors: [BAD_FILE, TIMEOUT]\n  - name: Writer\n    errors: [TIMEOUT, BAD_FILE]\n";
+    fs::write(dir.path().join("tighterror.yaml"), spec).unwrap();
+
+    let out = run(dir.path(), &["-o", "errors.rs", "--fail-on-warnings"]);
+    assert!(!out.status.success());
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert!(
+        stderr.contains(
+            "categories 'Reader' and 'Writer' in module 'errors' define the same error names"
+        ),
+        "{stderr}"
+    );
+    assert!(stderr.contains("failing on 1 warning(s)"), "{stderr}");
+    assert!(!dir.path().join("errors.rs").exists());
+}
@@ -0,0 +1,45 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+const SPEC: &str = "---\nerrors:\n  - BAD_FILE: The file is bad.\n";
+const STD_ERROR_IMPL: &str = "impl std::error::Error for Error";
+
+fn cargo_tighterror(args: &[&str], target_os: Option<&str>) -> String {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_cargo-tighterror"));
+    cmd.arg("tighterror")
+        .args(["--spec", "-", "--lang", "yaml"])
+        .args(args)
+        .env_remove("CARGO_CFG_TARGET_OS")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+    if let Some(os) = target_os {
+        cmd.env("CARGO_CFG_TARGET_OS", os);
+    }
+    let mut child = cmd.spawn().unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(SPEC.as_bytes())
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert!(out.status.success());
+    String::from_utf8(out.stdout).unwrap()
+}
+
+#[test]
+fn test_no_std_target_detection() {
+    assert!(cargo_tighterror(&[], None).contains(STD_ERROR_IMPL));
+    assert!(cargo_tighterror(&[], Some("linux")).contains(STD_ERROR_IMPL));
+    assert!(!cargo_tighterror(&[], Some("none")).contains(STD_ERROR_IMPL));
+}
+
+#[test]
+fn test_no_std_arg_overrides_target_detection() {
+    assert!(cargo_tighterror(&["--no-std=false"], Some("none")).contains(STD_ERROR_IMPL));
+    assert!(!cargo_tighterror(&["--no-std"], None).contains(STD_ERROR_IMPL));
+    assert!(!cargo_tighterror(&["--no-std=true"], Some("linux")).contains(STD_ERROR_IMPL));
+}
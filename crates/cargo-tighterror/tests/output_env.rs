@@ -0,0 +1,43 @@
+use std::{fs, path::Path, process::Command};
+
+const SPEC: &str = "errors:\n  - BAD_FILE: The file is bad.\n";
+
+fn run(dir: &Path, env: Option<&str>, args: &[&str]) -> std::process::Output {
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_cargo-tighterror"));
+    cmd.current_dir(dir)
+        .env_remove("TIGHTERROR_OUTPUT")
+        .arg("tighterror")
+        .args(args);
+    if let Some(v) = env {
+        cmd.env("TIGHTERROR_OUTPUT", v);
+    }
+    let out = cmd.output().unwrap();
+    assert!(
+        out.status.success(),
+        "{}",
+        String::from_utf8_lossy(&out.stderr)
+    );
+    out
+}
+
+#[test]
+fn test_output_env() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("tighterror.yaml"), SPEC).unwrap();
+
+    run(dir.path(), Some("env.rs"), &[]);
+    assert!(dir.path().join("env.rs").exists());
+
+    run(dir.path(), Some("env2.rs"), &["-o", "cli.rs"]);
+    assert!(dir.path().join("cli.rs").exists());
+    assert!(!dir.path().join("env2.rs").exists());
+}
+
+#[test]
+fn test_output_env_empty() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("tighterror.yaml"), SPEC).unwrap();
+
+    let out = run(dir.path(), Some(""), &[]);
+    assert!(String::from_utf8_lossy(&out.stdout).contains("BAD_FILE"));
+}
@@ -0,0 +1,41 @@
+use std::{
+    io::Write,
+    process::{Command, Stdio},
+};
+
+fn cargo_tighterror(args: &[&str], stdin: &str) -> std::process::Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_cargo-tighterror"))
+        .arg("tighterror")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    // the process may exit without reading stdin, e.g., on bad arguments
+    child.stdin.take().unwrap().write_all(stdin.as_bytes()).ok();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn test_spec_from_stdin() {
+    let yaml = "---\nerrors:\n  - BAD_FILE: The file is bad.\n";
+    let out = cargo_tighterror(&["--spec", "-", "--lang", "yaml"], yaml);
+    assert!(out.status.success());
+    let code = String::from_utf8(out.stdout).unwrap();
+    assert!(code.contains("pub struct Error"));
+    assert!(code.contains("BAD_FILE"));
+
+    let toml = "errors = [{ name = \"BAD_FILE\", display = \"The file is bad.\" }]\n";
+    let out = cargo_tighterror(&["--spec", "-", "--lang", "toml"], toml);
+    assert!(out.status.success());
+    let code = String::from_utf8(out.stdout).unwrap();
+    assert!(code.contains("BAD_FILE"));
+}
+
+#[test]
+fn test_spec_from_stdin_requires_lang() {
+    let out = cargo_tighterror(&["--spec", "-"], "---\nerrors:\n  - BAD_FILE\n");
+    assert!(!out.status.success());
+    assert!(out.stdout.is_empty());
+}
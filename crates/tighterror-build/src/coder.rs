@@ -1,19 +1,35 @@
 use crate::{
     errors::{
-        kind::coder::{FAILED_TO_READ_OUTPUT_FILE, FAILED_TO_WRITE_OUTPUT_FILE},
+        kind::coder::{
+            BAD_OUTPUT_MARKERS, CATEGORY_BITS_ADDED, FAILED_TO_READ_OUTPUT_FILE,
+            FAILED_TO_WRITE_OUTPUT_FILE,
+        },
+        kind::parser::NAME_COLLISION,
         TbError,
     },
     parser,
-    spec::definitions::STDOUT_PATH,
+    spec::{
+        definitions::{
+            DEFAULT_LENIENT, DEFAULT_REQUIRE_DISPLAY, DEFAULT_STRICT, DEFAULT_STRICT_DOCS,
+            STDOUT_PATH,
+        },
+        ModuleSpec, Spec,
+    },
 };
 use log::error;
+use similar::TextDiff;
 use std::{
-    fs::File,
+    collections::{BTreeMap, BTreeSet},
+    env,
+    ffi::OsStr,
+    fs::{self, File},
     io::{self, Read, Write},
-    path::Path,
+    path::{Path, PathBuf},
 };
 
+mod catalog;
 mod formatter;
+use formatter::RustfmtOptions;
 mod frozen_options;
 pub(crate) use frozen_options::*;
 mod generator;
@@ -21,16 +37,29 @@ use generator::ModuleCode;
 pub(crate) mod idents;
 mod options;
 pub use options::*;
+mod report;
+pub use report::*;
+#[cfg(feature = "serde")]
+mod resolved_spec;
+#[cfg(feature = "serde")]
+pub use resolved_spec::*;
 
 const TMP_FILE_PFX: &str = "tighterror.";
 const TMP_FILE_SFX: &str = ".rs";
-const RUST_FILE_EXTENSION: &str = "rs";
 const ALL_MODULES: &str = "*";
+const BEGIN_MARKER: &str = "// tighterror:begin";
+const END_MARKER: &str = "// tighterror:end";
+const CAT_BITS_MARKER: &str = "// tighterror:cat_bits";
+const DIFF_NULL_PATH: &str = "/dev/null";
+const TARGET_OS_ENV_VAR: &str = "CARGO_CFG_TARGET_OS";
+const NO_STD_TARGET_OS: &str = "none";
 
 /// Generates Rust source code from a specification file.
 ///
 /// See [CodegenOptions] for more information about function parameters.
 ///
+/// Returns a [CodegenReport] listing the written and unchanged files.
+///
 /// # Examples
 ///
 /// This example shows how the [codegen] function may be called directly.
@@ -48,46 +77,403 @@ const ALL_MODULES: &str = "*";
 /// # }
 /// # foo().unwrap();
 /// ```
-pub fn codegen(opts: &CodegenOptions) -> Result<(), TbError> {
-    let spec = parser::parse(opts.spec.as_deref())?;
-    debug_assert!(!spec.modules.is_empty());
+pub fn codegen(opts: &CodegenOptions) -> Result<CodegenReport, TbError> {
+    let (frozen, spec, modules) = generate(opts)?;
 
-    let frozen = FrozenOptions::new(opts, &spec)?;
-    let modules = generator::spec_to_rust(&frozen, &spec)?;
-
-    match frozen.output {
-        p if p.as_os_str() == STDOUT_PATH => {
+    let mut report = match frozen.output.as_os_str() {
+        p if p == STDOUT_PATH => {
             debug_assert_eq!(modules.len(), 1);
             let code = modules[0].code.as_bytes();
             if let Err(e) = io::stdout().lock().write_all(code) {
                 error!("failed to write to stdout: {e}");
-                FAILED_TO_WRITE_OUTPUT_FILE.into()
-            } else {
-                Ok(())
+                return FAILED_TO_WRITE_OUTPUT_FILE.into();
             }
+            CodegenReport::default()
         }
-        _ if frozen.update => update_modules(&frozen, &modules),
-        _ => write_modules(&frozen, &modules),
+        _ if frozen.output_mode == OutputMode::Append => append_modules(&frozen, &modules)?,
+        _ if frozen.update => update_modules(&frozen, &modules)?,
+        _ => write_modules(&frozen, &modules)?,
+    };
+
+    if let Some(ref path) = frozen.catalog_output {
+        let catalog = generator::spec_to_catalog(&spec)?;
+        let written = catalog::write_catalog(&catalog, path, frozen.update)?;
+        report.add(path, written);
     }
+
+    Ok(report)
 }
 
-fn write_modules(frozen: &FrozenOptions, modules: &[ModuleCode]) -> Result<(), TbError> {
+/// Validates a specification file without generating code.
+///
+/// The specification is parsed and checked exactly as in [codegen],
+/// including the *strict* and *strict docs* modes, but no output is written.
+/// This is useful to check a specification quickly, e.g., in an editor
+/// integration.
+///
+/// Only the specification related options of [CodegenOptions] are used,
+/// while the output related ones are ignored. The options are needed
+/// because they locate the specification file and its `doc_file` files,
+/// and set the *lenient*, *strict* and *strict docs* modes. See
+/// [validate_str] to validate a specification held in memory.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, validate};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// validate(&opts)?;
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn validate(opts: &CodegenOptions) -> Result<(), TbError> {
+    let spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    check_spec(&spec, opts)?;
+    generator::validate(&spec)
+}
+
+/// Validates a specification held in memory without generating code.
+///
+/// The specification is parsed and checked as in [validate] with the
+/// default options, but without touching the filesystem. Therefore,
+/// the `doc_file` attributes are checked but the files aren't read.
+/// This is useful to check an unsaved specification, e.g., in an editor
+/// integration.
+///
+/// # Examples
+///
+/// ```rust
+/// # use tighterror_build::{errors::TbError, validate_str, SpecLang};
+/// # pub fn foo() -> Result<(), TbError> {
+/// validate_str("errors: [BAD_FILE, BAD_ARG]", SpecLang::Yaml)?;
+/// assert!(validate_str("errors: [BAD_FILE, BAD_FILE]", SpecLang::Yaml).is_err());
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn validate_str(spec: &str, lang: SpecLang) -> Result<(), TbError> {
+    let spec = parser::parse_str(spec, lang, DEFAULT_LENIENT)?;
+    check_spec(&spec, &CodegenOptions::new())?;
+    generator::validate(&spec)
+}
+
+/// Describes the layout of the generated error kinds without generating code.
+///
+/// For every module the description lists the underlying Rust type,
+/// the number of kind, category and variant bits, and the number of
+/// variants of every category. This is useful to debug the binary
+/// representation of the error kinds. The same description is logged at
+/// *info* level during code generation.
+///
+/// Only the specification related options of [CodegenOptions] are used,
+/// while the output related ones are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, codegen_layout};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// print!("{}", codegen_layout(&opts)?);
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn codegen_layout(opts: &CodegenOptions) -> Result<String, TbError> {
+    let spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    check_spec(&spec, opts)?;
+    generator::spec_to_layout(&spec)
+}
+
+/// Computes the values of the error kinds without generating code.
+///
+/// Returns a map from module name to a map from the category-qualified
+/// error kind name, e.g., `GENERAL::BAD_FILE`, to the error kind value.
+/// The values are assigned exactly as in the generated code, i.e., every
+/// value equals the one returned by `ErrorKind::value` of the respective
+/// error kind constant. This is useful for tooling that needs to know
+/// the values, e.g., to migrate persisted error codes.
+///
+/// Only the specification related options of [CodegenOptions] are used,
+/// while the output related ones are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, codegen_kind_values};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// let values = codegen_kind_values(&opts)?;
+/// println!("{}", values["errors"]["GENERAL::BAD_FILE"]);
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn codegen_kind_values(
+    opts: &CodegenOptions,
+) -> Result<BTreeMap<String, BTreeMap<String, u64>>, TbError> {
+    let spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    check_spec(&spec, opts)?;
+    generator::spec_to_kind_values(&spec)
+}
+
+/// Lists the error kinds without generating code.
+///
+/// Returns a table of the category name, the error kind name, the error
+/// kind value and the display string of every error kind, in the order of
+/// the values. The values are assigned exactly as in the generated code.
+/// If `json` is `true` the listing is returned in the JSON format of the
+/// catalog instead, see [CodegenOptions::catalog_output].
+///
+/// Only the specification related options of [CodegenOptions] are used,
+/// while the output related ones are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, codegen_list};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// print!("{}", codegen_list(&opts, false)?);
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn codegen_list(opts: &CodegenOptions, json: bool) -> Result<String, TbError> {
+    let spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    check_spec(&spec, opts)?;
+    let catalog = generator::spec_to_catalog(&spec)?;
+    Ok(if json {
+        catalog.to_json()
+    } else {
+        catalog.to_table()
+    })
+}
+
+/// Parses a specification file and resolves the default values.
+///
+/// The specification is parsed and checked exactly as in [codegen].
+/// The returned [ResolvedSpec] can be serialized, e.g., into JSON, by
+/// build scripts that emit their own artifacts.
+///
+/// Only the specification related options of [CodegenOptions] are used,
+/// while the output related ones are ignored.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, parse_spec};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// let spec = parse_spec(&opts)?;
+/// println!("{}", serde_json::to_string_pretty(&spec).unwrap());
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+#[cfg(feature = "serde")]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub fn parse_spec(opts: &CodegenOptions) -> Result<ResolvedSpec, TbError> {
+    let mut spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    check_spec(&spec, opts)?;
+    generator::validate(&spec)?;
+    resolve_no_std(opts, &mut spec);
+    Ok(ResolvedSpec::new(spec))
+}
+
+/// Generates Rust source code and returns a unified diff against the
+/// existing output instead of writing it.
+///
+/// The generated code is formatted and, in *append* output mode, spliced
+/// into the existing file exactly as in [codegen], so the diff shows the
+/// changes [codegen] would make. In *separate files* mode a diff is produced
+/// per file. A missing output file is compared to `/dev/null`.
+///
+/// Returns an empty string if the output is up to date.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use tighterror_build::{CodegenOptions, errors::TbError, codegen_diff};
+/// # pub fn foo() -> Result<(), TbError> {
+/// let mut opts = CodegenOptions::new();
+/// opts.spec("tighterror.yaml".to_owned());
+/// print!("{}", codegen_diff(&opts)?);
+/// # Ok(())
+/// # }
+/// # foo().unwrap();
+/// ```
+pub fn codegen_diff(opts: &CodegenOptions) -> Result<String, TbError> {
+    let (frozen, _, modules) = generate(opts)?;
+    let append = frozen.output_mode == OutputMode::Append;
+    let files: Vec<(PathBuf, &str, bool)> = if frozen.separate_files {
+        modules
+            .iter()
+            .flat_map(|m| {
+                let tables = m
+                    .tables
+                    .as_deref()
+                    .map(|t| (frozen.tables_path(&m.name), t, false));
+                [(frozen.module_path(&m.name), m.code.as_str(), append)]
+                    .into_iter()
+                    .chain(tables)
+            })
+            .collect()
+    } else {
+        debug_assert_eq!(modules.len(), 1);
+        vec![(frozen.output.clone(), modules[0].code.as_str(), append)]
+    };
+
+    let mut diff = String::new();
+    for (path, code, splice) in files {
+        let exists = path.is_file();
+        let existing_data = if exists {
+            read_code(&path)?
+        } else {
+            String::new()
+        };
+        // the nearest existing directory is used to discover rustfmt.toml
+        let dir = output_dir(&path)
+            .ancestors()
+            .find(|d| d.is_dir())
+            .map_or_else(env::temp_dir, Path::to_path_buf);
+        let code = format_code(code, &dir, &frozen.rustfmt)?;
+        let new_data = if splice {
+            splice_code(&existing_data, &code, &path)?
+        } else {
+            code
+        };
+        if new_data != existing_data {
+            let new_header = path.display().to_string();
+            let old_header = if exists {
+                new_header.as_str()
+            } else {
+                DIFF_NULL_PATH
+            };
+            let text_diff = TextDiff::from_lines(&existing_data, &new_data);
+            diff.push_str(
+                &text_diff
+                    .unified_diff()
+                    .header(old_header, &new_header)
+                    .to_string(),
+            );
+        }
+    }
+    Ok(diff)
+}
+
+fn generate(opts: &CodegenOptions) -> Result<(FrozenOptions, Spec, Vec<ModuleCode>), TbError> {
+    let mut spec = parser::parse(
+        opts.spec.as_deref(),
+        opts.spec_lang,
+        opts.lenient.unwrap_or(DEFAULT_LENIENT),
+    )?;
+    debug_assert!(!spec.modules.is_empty());
+    resolve_no_std(opts, &mut spec);
+
+    let frozen = FrozenOptions::new(opts, &spec)?;
+    check_spec(&spec, opts)?;
+    if frozen.split_tables {
+        check_tables_paths(&frozen, &spec)?;
+    }
+    let modules = generator::spec_to_rust(&frozen, &spec)?;
+
+    if frozen.output.as_os_str() != STDOUT_PATH {
+        check_category_bits(&frozen, &spec)?;
+    }
+
+    Ok((frozen, spec, modules))
+}
+
+fn resolve_no_std(opts: &CodegenOptions, spec: &mut Spec) {
+    spec.main.no_std = opts
+        .no_std
+        .or(spec.main.no_std)
+        .or_else(|| detect_no_std(env::var_os(TARGET_OS_ENV_VAR).as_deref()));
+}
+
+/// Detects a `no_std` target using the value of `CARGO_CFG_TARGET_OS`.
+///
+/// Cargo sets the variable when running a build script. Targets without an
+/// operating system, e.g., `thumbv7em-none-eabihf`, have the value `none`
+/// and lack the `std` crate. Returns `None` if a `no_std` target isn't
+/// detected, so the default applies.
+fn detect_no_std(target_os: Option<&OsStr>) -> Option<bool> {
+    if target_os? == NO_STD_TARGET_OS {
+        log::debug!("detected a no_std target: {TARGET_OS_ENV_VAR}={NO_STD_TARGET_OS}");
+        Some(true)
+    } else {
+        None
+    }
+}
+
+fn check_spec(spec: &Spec, opts: &CodegenOptions) -> Result<(), TbError> {
+    parser::check_warnings(spec, opts.strict.unwrap_or(DEFAULT_STRICT))?;
+    if opts.strict_docs.unwrap_or(DEFAULT_STRICT_DOCS) {
+        parser::check_docs(spec)?;
+    }
+    if opts
+        .require_display
+        .or(spec.main.require_display)
+        .unwrap_or(DEFAULT_REQUIRE_DISPLAY)
+    {
+        parser::check_displays(spec)?;
+    }
+    Ok(())
+}
+
+fn write_modules(frozen: &FrozenOptions, modules: &[ModuleCode]) -> Result<CodegenReport, TbError> {
+    let mut report = CodegenReport::default();
     if frozen.separate_files {
-        let dir = frozen.output.as_path();
         for m in modules {
-            let mut path = dir.join(&m.name);
-            path.set_extension(RUST_FILE_EXTENSION);
-            write_code(&m.code, &path)?;
+            let path = frozen.module_path(&m.name);
+            create_output_dir(&path)?;
+            write_code(&m.code, &path, &frozen.rustfmt)?;
+            report.add(&path, true);
+            if let Some(ref tables) = m.tables {
+                let path = frozen.tables_path(&m.name);
+                write_code(tables, &path, &frozen.rustfmt)?;
+                report.add(&path, true);
+            }
         }
     } else {
         debug_assert_eq!(modules.len(), 1);
-        write_code(&modules[0].code, frozen.output.as_path())?;
+        let path = frozen.output.as_path();
+        write_code(&modules[0].code, path, &frozen.rustfmt)?;
+        report.add(path, true);
     }
 
-    Ok(())
+    Ok(report)
 }
 
-fn write_code(code: &str, path: &Path) -> Result<(), TbError> {
+fn write_code(code: &str, path: &Path, fmt: &RustfmtOptions) -> Result<(), TbError> {
     let file = match File::options()
         .write(true)
         .create(true)
@@ -101,17 +487,26 @@ fn write_code(code: &str, path: &Path) -> Result<(), TbError> {
         }
     };
 
-    write_and_format(code, path, file)
+    write_and_format(code, path, file, fmt)
 }
 
-fn write_and_format(code: &str, path: &Path, mut file: File) -> Result<(), TbError> {
-    if let Err(e) = file.write_all(code.as_bytes()) {
+fn write_and_format(
+    code: &str,
+    path: &Path,
+    file: File,
+    fmt: &RustfmtOptions,
+) -> Result<(), TbError> {
+    write_data(code, path, file)?;
+    formatter::rustfmt(path, fmt).ok();
+    Ok(())
+}
+
+fn write_data(data: &str, path: &Path, mut file: File) -> Result<(), TbError> {
+    if let Err(e) = file.write_all(data.as_bytes()) {
         error!("failed to write to the output file {:?}: {e}", path);
         return FAILED_TO_WRITE_OUTPUT_FILE.into();
     }
     file.flush().ok();
-    drop(file);
-    formatter::rustfmt(path).ok();
     Ok(())
 }
 
@@ -133,49 +528,50 @@ fn read_code(path: &Path) -> Result<String, TbError> {
     Ok(data)
 }
 
-fn update_modules(frozen: &FrozenOptions, modules: &[ModuleCode]) -> Result<(), TbError> {
+fn update_modules(
+    frozen: &FrozenOptions,
+    modules: &[ModuleCode],
+) -> Result<CodegenReport, TbError> {
+    let mut report = CodegenReport::default();
     if frozen.separate_files {
-        let dir = frozen.output.as_path();
         for m in modules {
-            let mut path = dir.join(&m.name);
-            path.set_extension(RUST_FILE_EXTENSION);
-            update_module(&m.code, &path)?;
+            let path = frozen.module_path(&m.name);
+            create_output_dir(&path)?;
+            let written = update_module(&m.code, &path, &frozen.rustfmt)?;
+            report.add(&path, written);
+            if let Some(ref tables) = m.tables {
+                let path = frozen.tables_path(&m.name);
+                let written = update_module(tables, &path, &frozen.rustfmt)?;
+                report.add(&path, written);
+            }
         }
     } else {
         debug_assert_eq!(modules.len(), 1);
-        update_module(&modules[0].code, frozen.output.as_path())?;
+        let path = frozen.output.as_path();
+        let written = update_module(&modules[0].code, path, &frozen.rustfmt)?;
+        report.add(path, written);
     }
 
-    Ok(())
+    Ok(report)
 }
 
-fn update_module(code: &str, path: &Path) -> Result<(), TbError> {
+/// Returns `true` if the file was written, `false` if it was left unchanged.
+fn update_module(code: &str, path: &Path, fmt: &RustfmtOptions) -> Result<bool, TbError> {
     if !path.exists() {
-        return write_code(code, path);
+        return write_code(code, path, fmt).map(|_| true);
     }
 
     let existing_data = read_code(path)?;
 
-    let dir = match path.parent() {
-        Some(p) if !p.as_os_str().is_empty() => p,
-        _ => Path::new("."),
-    };
-
-    let tmp_file = tempfile::Builder::new()
-        .prefix(TMP_FILE_PFX)
-        .suffix(TMP_FILE_SFX)
-        .tempfile_in(dir)
-        .map_err(|e| {
-            error!("failed to create a temporary file [dir={:?}]: {e}", dir);
-            TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
-        })?;
+    let dir = output_dir(path);
+    let tmp_file = tmp_file_in(dir)?;
 
     let (tmp_file, tmp_path) = tmp_file.keep().map_err(|e| {
         error!("failed to keep the temporary file: {e}");
         TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
     })?;
 
-    write_and_format(code, &tmp_path, tmp_file)?;
+    write_and_format(code, &tmp_path, tmp_file, fmt)?;
 
     let new_data = read_code(&tmp_path)?;
 
@@ -186,11 +582,269 @@ fn update_module(code: &str, path: &Path) -> Result<(), TbError> {
                 tmp_path, path
             );
             TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
-        })
+        })?;
+        Ok(true)
     } else {
         std::fs::remove_file(&tmp_path).map_err(|e| {
             error!("failed to unlink temporary file {:?}: {e}", tmp_path);
             TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
+        })?;
+        Ok(false)
+    }
+}
+
+fn output_dir(path: &Path) -> &Path {
+    match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    }
+}
+
+/// Creates the missing parent directories of a module file, e.g.,
+/// when a module is written to a subdirectory in *separate files* mode.
+fn create_output_dir(path: &Path) -> Result<(), TbError> {
+    let dir = output_dir(path);
+    if dir.is_dir() {
+        return Ok(());
+    }
+    fs::create_dir_all(dir).map_err(|e| {
+        error!("failed to create the output directory {:?}: {e}", dir);
+        TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
+    })
+}
+
+fn tmp_file_in(dir: &Path) -> Result<tempfile::NamedTempFile, TbError> {
+    tempfile::Builder::new()
+        .prefix(TMP_FILE_PFX)
+        .suffix(TMP_FILE_SFX)
+        .tempfile_in(dir)
+        .map_err(|e| {
+            error!("failed to create a temporary file [dir={:?}]: {e}", dir);
+            TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
+        })
+}
+
+fn append_modules(
+    frozen: &FrozenOptions,
+    modules: &[ModuleCode],
+) -> Result<CodegenReport, TbError> {
+    let mut report = CodegenReport::default();
+    if frozen.separate_files {
+        for m in modules {
+            let path = frozen.module_path(&m.name);
+            create_output_dir(&path)?;
+            let written = append_module(&m.code, &path, &frozen.rustfmt)?;
+            report.add(&path, written);
+            // the tables file is generated wholesale, so it's only updated
+            if let Some(ref tables) = m.tables {
+                let path = frozen.tables_path(&m.name);
+                let written = update_module(tables, &path, &frozen.rustfmt)?;
+                report.add(&path, written);
+            }
+        }
+    } else {
+        debug_assert_eq!(modules.len(), 1);
+        let path = frozen.output.as_path();
+        let written = append_module(&modules[0].code, path, &frozen.rustfmt)?;
+        report.add(path, written);
+    }
+
+    Ok(report)
+}
+
+/// Returns `true` if the file was written, `false` if it was left unchanged.
+fn append_module(code: &str, path: &Path, fmt: &RustfmtOptions) -> Result<bool, TbError> {
+    let existing_data = if path.exists() {
+        read_code(path)?
+    } else {
+        String::new()
+    };
+
+    // format the generated code alone to leave hand-written code untouched
+    let code = format_code(code, output_dir(path), fmt)?;
+
+    let new_data = splice_code(&existing_data, &code, path)?;
+    if new_data == existing_data {
+        return Ok(false);
+    }
+
+    let file = match File::options()
+        .write(true)
+        .create(true)
+        .truncate(true)
+        .open(path)
+    {
+        Ok(f) => f,
+        Err(e) => {
+            error!("failed to open the output file {:?}: {e}", path);
+            return FAILED_TO_WRITE_OUTPUT_FILE.into();
+        }
+    };
+    write_data(&new_data, path, file)?;
+    Ok(true)
+}
+
+/// Formats the code in a temporary file under `dir` and returns the result.
+fn format_code(code: &str, dir: &Path, fmt: &RustfmtOptions) -> Result<String, TbError> {
+    let tmp_file = tmp_file_in(dir)?;
+    write_and_format(
+        code,
+        tmp_file.path(),
+        tmp_file.reopen().map_err(|e| {
+            error!(
+                "failed to open the temporary file {:?}: {e}",
+                tmp_file.path()
+            );
+            TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
+        })?,
+        fmt,
+    )?;
+    read_code(tmp_file.path())
+}
+
+/// Checks that no tables file overwrites a module file, e.g., the tables
+/// file of module `errors` and the file of module `errors_tables`.
+fn check_tables_paths(frozen: &FrozenOptions, spec: &Spec) -> Result<(), TbError> {
+    let module_paths: BTreeSet<PathBuf> = spec
+        .modules
+        .iter()
+        .map(|m| frozen.module_path(m.name()))
+        .collect();
+    for m in &spec.modules {
+        let path = frozen.tables_path(m.name());
+        if module_paths.contains(&path) {
+            error!(
+                "the tables file of module '{}' is the file of another module: {path:?}",
+                m.name()
+            );
+            return NAME_COLLISION.into();
+        }
+    }
+    Ok(())
+}
+
+/// Checks whether a module gains category bits compared to the existing output.
+///
+/// A module with a single category has no category bits. Adding a second
+/// category adds them, which changes the error kind layout and may widen the
+/// underlying Rust type. This is reported as a warning to make it visible.
+///
+/// The category bits of the existing output are read from the records the
+/// generated code starts with, see [CAT_BITS_MARKER].
+fn check_category_bits(frozen: &FrozenOptions, spec: &Spec) -> Result<(), TbError> {
+    if frozen.separate_files {
+        for m in &spec.modules {
+            let path = frozen.module_path(m.name());
+            check_file_category_bits(&path, spec, std::slice::from_ref(m), frozen.strict)?;
+        }
+        Ok(())
+    } else {
+        check_file_category_bits(&frozen.output, spec, &spec.modules, frozen.strict)
+    }
+}
+
+fn check_file_category_bits(
+    path: &Path,
+    spec: &Spec,
+    module_specs: &[ModuleSpec],
+    strict: bool,
+) -> Result<(), TbError> {
+    if !path.is_file() {
+        return Ok(());
+    }
+    let code = read_code(path)?;
+    let old_bits = recorded_category_bits(&code);
+    for m in module_specs {
+        let Some(&old) = old_bits.get(m.name()) else {
+            continue;
+        };
+        if old == 0 && generator::category_bits(spec, m)? > 0 {
+            parser::warn(
+                strict,
+                CATEGORY_BITS_ADDED,
+                format_args!(
+                    "module '{}' has multiple categories now, and its error kind layout changes: {path:?}",
+                    m.name()
+                ),
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// Returns the category bits recorded in the generated code, by module name.
+fn recorded_category_bits(code: &str) -> BTreeMap<&str, usize> {
+    code.lines()
+        .filter_map(|l| {
+            let (name, bits) = l
+                .trim()
+                .strip_prefix(CAT_BITS_MARKER)?
+                .trim()
+                .split_once(' ')?;
+            Some((name, bits.parse().ok()?))
         })
+        .collect()
+}
+
+/// Replaces the region between the code-generation markers with `code`.
+///
+/// If the markers are not found the region is appended to `data`.
+fn splice_code(data: &str, code: &str, path: &Path) -> Result<String, TbError> {
+    let mut begin = None;
+    let mut end = None;
+    let mut offset = 0;
+    for line in data.split_inclusive('\n') {
+        let marker = line.trim();
+        if marker == BEGIN_MARKER {
+            if begin.is_some() {
+                error!("output file contains multiple `{BEGIN_MARKER}` markers: {path:?}");
+                return BAD_OUTPUT_MARKERS.into();
+            }
+            begin = Some(offset + line.len());
+        } else if marker == END_MARKER {
+            if end.is_some() {
+                error!("output file contains multiple `{END_MARKER}` markers: {path:?}");
+                return BAD_OUTPUT_MARKERS.into();
+            }
+            end = Some(offset);
+        }
+        offset += line.len();
+    }
+
+    let mut ans = String::with_capacity(data.len() + code.len());
+    match (begin, end) {
+        (Some(b), Some(e)) if b <= e => {
+            ans.push_str(&data[..b]);
+            if !data[..b].ends_with('\n') {
+                ans.push('\n');
+            }
+            ans.push_str(code);
+            ans.push_str(&data[e..]);
+        }
+        (None, None) => {
+            ans.push_str(data);
+            if !data.is_empty() {
+                if !data.ends_with('\n') {
+                    ans.push('\n');
+                }
+                ans.push('\n');
+            }
+            ans.push_str(BEGIN_MARKER);
+            ans.push('\n');
+            ans.push_str(code);
+            ans.push_str(END_MARKER);
+            ans.push('\n');
+        }
+        _ => {
+            error!(
+                "output file must contain both `{BEGIN_MARKER}` and `{END_MARKER}` markers, \
+                in this order: {path:?}"
+            );
+            return BAD_OUTPUT_MARKERS.into();
+        }
     }
+    Ok(ans)
 }
+
+#[cfg(all(test, feature = "yaml"))]
+mod test_coder;
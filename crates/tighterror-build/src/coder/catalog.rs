@@ -0,0 +1,217 @@
+use crate::{
+    coder::read_code,
+    errors::{kind::coder::FAILED_TO_WRITE_OUTPUT_FILE, TbError},
+};
+use log::error;
+use std::{fmt::Write, fs, path::Path};
+
+const TABLE_HEADER: [&str; 4] = ["category", "kind", "value", "display"];
+
+/// A machine-readable description of the errors of a specification.
+#[derive(Debug)]
+pub struct Catalog {
+    pub modules: Vec<ModuleCatalog>,
+}
+
+#[derive(Debug)]
+pub struct ModuleCatalog {
+    pub name: String,
+    pub doc: String,
+    pub categories: Vec<CategoryCatalog>,
+}
+
+#[derive(Debug)]
+pub struct CategoryCatalog {
+    pub name: String,
+    pub doc: String,
+    pub value: u64,
+    pub kinds: Vec<KindCatalog>,
+}
+
+#[derive(Debug)]
+pub struct KindCatalog {
+    pub name: String,
+    pub display: String,
+    pub doc: String,
+    pub category: String,
+    pub subcategory: Option<String>,
+    pub value: u64,
+}
+
+impl Catalog {
+    /// Formats the catalog as pretty-printed JSON.
+    pub fn to_json(&self) -> String {
+        let modules = self.modules.iter().map(|m| {
+            let categories = m.categories.iter().map(|c| {
+                let kinds = c.kinds.iter().map(|k| {
+                    let mut fields = vec![
+                        ("name", Json::Str(&k.name)),
+                        ("display", Json::Str(&k.display)),
+                        ("doc", Json::Str(&k.doc)),
+                        ("category", Json::Str(&k.category)),
+                    ];
+                    if let Some(ref s) = k.subcategory {
+                        fields.push(("subcategory", Json::Str(s)));
+                    }
+                    fields.push(("value", Json::Num(k.value)));
+                    Json::Obj(fields)
+                });
+                Json::Obj(vec![
+                    ("name", Json::Str(&c.name)),
+                    ("doc", Json::Str(&c.doc)),
+                    ("value", Json::Num(c.value)),
+                    ("kinds", Json::Arr(kinds.collect())),
+                ])
+            });
+            Json::Obj(vec![
+                ("name", Json::Str(&m.name)),
+                ("doc", Json::Str(&m.doc)),
+                ("categories", Json::Arr(categories.collect())),
+            ])
+        });
+        let mut json = String::new();
+        Json::Obj(vec![("modules", Json::Arr(modules.collect()))]).write(&mut json, 0);
+        json.push('\n');
+        json
+    }
+
+    /// Formats the error kinds as a table of the category name, the kind
+    /// name, the value and the display string.
+    ///
+    /// If there are several modules every module table is preceded by
+    /// a line with the module name.
+    pub fn to_table(&self) -> String {
+        let mut table = String::new();
+        for m in &self.modules {
+            if self.modules.len() > 1 {
+                table.push_str(&format!("module {}:\n", m.name));
+            }
+            let rows: Vec<[String; 4]> = m
+                .categories
+                .iter()
+                .flat_map(|c| c.kinds.iter())
+                .map(|k| {
+                    let name = match &k.subcategory {
+                        Some(s) => format!("{s}::{}", k.name),
+                        None => k.name.clone(),
+                    };
+                    [
+                        k.category.clone(),
+                        name,
+                        k.value.to_string(),
+                        k.display.clone(),
+                    ]
+                })
+                .collect();
+            let header = TABLE_HEADER.map(str::to_owned);
+            let mut widths = [0; 4];
+            for row in std::iter::once(&header).chain(&rows) {
+                for (w, cell) in widths.iter_mut().zip(row) {
+                    *w = (*w).max(cell.chars().count());
+                }
+            }
+            for row in std::iter::once(&header).chain(&rows) {
+                let line = format!(
+                    "{:w0$} | {:w1$} | {:>w2$} | {}",
+                    row[0],
+                    row[1],
+                    row[2],
+                    row[3],
+                    w0 = widths[0],
+                    w1 = widths[1],
+                    w2 = widths[2],
+                );
+                table.push_str(line.trim_end());
+                table.push('\n');
+            }
+        }
+        table
+    }
+}
+
+/// A JSON value of the catalog.
+enum Json<'a> {
+    Str(&'a str),
+    Num(u64),
+    Arr(Vec<Json<'a>>),
+    Obj(Vec<(&'a str, Json<'a>)>),
+}
+
+impl Json<'_> {
+    /// Writes the value indented by two spaces per nesting level.
+    fn write(&self, out: &mut String, level: usize) {
+        let indent = |out: &mut String, level: usize| {
+            out.extend(std::iter::repeat("  ").take(level));
+        };
+        match self {
+            Self::Str(s) => write_json_str(out, s),
+            Self::Num(n) => write!(out, "{n}").expect("writing to a String doesn't fail"),
+            Self::Arr(items) if items.is_empty() => out.push_str("[]"),
+            Self::Arr(items) => {
+                out.push_str("[\n");
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    indent(out, level + 1);
+                    item.write(out, level + 1);
+                }
+                out.push('\n');
+                indent(out, level);
+                out.push(']');
+            }
+            Self::Obj(fields) if fields.is_empty() => out.push_str("{}"),
+            Self::Obj(fields) => {
+                out.push_str("{\n");
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    if i > 0 {
+                        out.push_str(",\n");
+                    }
+                    indent(out, level + 1);
+                    write_json_str(out, key);
+                    out.push_str(": ");
+                    value.write(out, level + 1);
+                }
+                out.push('\n');
+                indent(out, level);
+                out.push('}');
+            }
+        }
+    }
+}
+
+fn write_json_str(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{8}' => out.push_str("\\b"),
+            '\u{c}' => out.push_str("\\f"),
+            c if c < ' ' => {
+                write!(out, "\\u{:04x}", c as u32).expect("writing to a String doesn't fail")
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Writes the catalog in JSON format.
+///
+/// Returns `true` if the file was written, `false` if it was left unchanged
+/// in *update* mode.
+pub fn write_catalog(catalog: &Catalog, path: &Path, update: bool) -> Result<bool, TbError> {
+    let json = catalog.to_json();
+    if update && path.is_file() && read_code(path)? == json {
+        return Ok(false);
+    }
+    fs::write(path, json).map_err(|e| {
+        error!("failed to write the catalog file {:?}: {e}", path);
+        TbError::from(FAILED_TO_WRITE_OUTPUT_FILE)
+    })?;
+    Ok(true)
+}
@@ -1,11 +1,27 @@
-use crate::errors::{
-    kind::coder::{FAILED_TO_PARSE_TOKENS, RUSTFMT_FAILED, RUSTFMT_NOT_FOUND},
-    TbError,
+use crate::{
+    coder::Edition,
+    errors::{
+        kind::coder::{FAILED_TO_PARSE_TOKENS, RUSTFMT_FAILED, RUSTFMT_NOT_FOUND},
+        TbError,
+    },
 };
 use log::{error, info, warn};
 use proc_macro2::TokenStream;
 use regex::RegexSet;
-use std::{ffi::OsStr, io::ErrorKind, process::Command};
+use std::{
+    io::ErrorKind,
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// Settings of the `rustfmt` invocation.
+#[derive(Debug, Clone)]
+pub struct RustfmtOptions {
+    /// The edition passed with `--edition`
+    pub(crate) edition: Edition,
+    /// An explicit configuration file passed with `--config-path`
+    pub(crate) config_path: Option<PathBuf>,
+}
 
 pub fn pretty(tokens: TokenStream) -> Result<String, TbError> {
     let tokens_str = tokens.to_string();
@@ -38,6 +54,7 @@ fn add_newlines(file: String) -> String {
     .unwrap();
     let rg_comment = RegexSet::new([r"^[[:space:]]*///", r"^[[:space:]]*/\*\*"]).unwrap();
     let rg_inner_comment = RegexSet::new([r"^[[:space:]]*//!"]).unwrap();
+    let rg_comment_end = RegexSet::new([r"^[[:space:]]*\*/$"]).unwrap();
     let mut ans = String::with_capacity(file.capacity());
     let mut last_line_is_comment = false;
     let mut last_line_is_inner_comment = false;
@@ -61,16 +78,32 @@ fn add_newlines(file: String) -> String {
 
         last_line_is_comment = is_comment;
         last_line_is_inner_comment = is_inner_comment;
-        last_line_prefix = prefix;
+        // a doc comment must not be separated from the item it documents
+        last_line_prefix = prefix || is_comment || rg_comment_end.is_match(line);
     }
     ans
 }
 
-pub fn rustfmt(path: impl AsRef<OsStr>) -> Result<(), TbError> {
-    let result = Command::new("rustfmt")
-        .args(["--edition", "2021"])
-        .arg(path)
-        .status();
+/// Formats a file in place.
+///
+/// `rustfmt` is run from the directory of the file, so a project
+/// `rustfmt.toml` is discovered regardless of the current working directory,
+/// unless an explicit configuration file is set.
+pub fn rustfmt(path: &Path, opts: &RustfmtOptions) -> Result<(), TbError> {
+    let mut cmd = Command::new("rustfmt");
+    cmd.args(["--edition", opts.edition.as_str()]);
+    if let Some(ref config_path) = opts.config_path {
+        cmd.arg("--config-path").arg(config_path);
+    }
+    match (path.parent(), path.file_name()) {
+        (Some(dir), Some(name)) if !dir.as_os_str().is_empty() => {
+            cmd.current_dir(dir).arg(name);
+        }
+        _ => {
+            cmd.arg(path);
+        }
+    }
+    let result = cmd.status();
     match result {
         Ok(exit_status) => {
             if !exit_status.success() {
@@ -1,35 +1,133 @@
 use crate::{
-    coder::CodegenOptions,
-    errors::{kind::coder::OUTPUT_PATH_NOT_DIRECTORY, TbError},
-    spec::{definitions::*, Spec},
+    coder::{formatter::RustfmtOptions, CodegenOptions, OutputMode},
+    errors::{
+        kind::coder::{OUTPUT_PATH_NOT_DIRECTORY, RUSTFMT_CONFIG_NOT_FOUND},
+        TbError,
+    },
+    parser::{check_output_ext, check_output_pattern, check_tests},
+    spec::{definitions::*, test_selector_matches, Spec},
 };
-use std::path::PathBuf;
+use std::{fs, path::PathBuf};
 
 #[derive(Debug)]
 pub struct FrozenOptions {
     pub(crate) output: PathBuf,
     pub(crate) test: bool,
+    pub(crate) tests: Option<Vec<String>>,
     pub(crate) update: bool,
+    pub(crate) header: bool,
     pub(crate) separate_files: bool,
+    pub(crate) output_pattern: String,
+    pub(crate) output_ext: String,
+    pub(crate) split_tables: bool,
+    pub(crate) strict: bool,
+    pub(crate) output_mode: OutputMode,
+    pub(crate) rustfmt: RustfmtOptions,
+    pub(crate) catalog_output: Option<PathBuf>,
+    #[cfg(feature = "parallel")]
+    pub(crate) threads: Option<usize>,
 }
 
 impl FrozenOptions {
     pub fn new(opts: &CodegenOptions, spec: &Spec) -> Result<Self, TbError> {
-        let op = Self::output_path(opts, spec)?;
+        let output_ext = opts.output_ext.as_deref().unwrap_or(DEFAULT_OUTPUT_EXT);
+        check_output_ext(output_ext)?;
+        let op = Self::output_path(opts, spec, output_ext)?;
+        let output_pattern = opts
+            .output_pattern
+            .clone()
+            .or_else(|| spec.main.output_pattern.clone())
+            .unwrap_or_else(|| format!("{OUTPUT_PATTERN_PLACEHOLDER}.{output_ext}"));
+        check_output_pattern(&output_pattern)?;
+        let tests = opts.tests.as_ref().or(spec.main.tests.as_ref()).cloned();
+        if let Some(ref t) = tests {
+            check_tests(t)?;
+        }
+        let split_tables = op.separate_files
+            && opts
+                .split_tables
+                .or(spec.main.split_tables)
+                .unwrap_or(DEFAULT_SPLIT_TABLES);
         Ok(Self {
             output: op.path,
-            test: opts.test.unwrap_or(DEFAULT_TEST),
+            test: opts.test.or(spec.main.test).unwrap_or(DEFAULT_TEST),
+            tests,
             update: opts.update.unwrap_or(DEFAULT_UPDATE_MODE),
+            header: opts.header.unwrap_or(DEFAULT_HEADER),
             separate_files: op.separate_files,
+            output_pattern,
+            output_ext: output_ext.to_owned(),
+            split_tables,
+            strict: opts.strict.unwrap_or(DEFAULT_STRICT),
+            output_mode: op.output_mode,
+            rustfmt: RustfmtOptions {
+                edition: opts.edition.unwrap_or(DEFAULT_EDITION),
+                config_path: Self::rustfmt_config_path(opts)?,
+            },
+            catalog_output: opts.catalog_output.clone(),
+            // zero threads stands for the global thread pool
+            #[cfg(feature = "parallel")]
+            threads: opts.threads.filter(|&n| n != 0),
+        })
+    }
+
+    /// Checks if a generated unit-test is included in the test selection.
+    pub fn test_selected(&self, test_name: &str) -> bool {
+        self.tests.as_ref().map_or(true, |tests| {
+            tests.iter().any(|t| test_selector_matches(t, test_name))
+        })
+    }
+
+    /// Returns the path of a module file in *separate files* mode.
+    pub fn module_path(&self, module_name: &str) -> PathBuf {
+        let filename = self
+            .output_pattern
+            .replace(OUTPUT_PATTERN_PLACEHOLDER, module_name);
+        self.output.join(filename)
+    }
+
+    /// Returns the file name of the private tables of a module in
+    /// *separate files* mode.
+    pub fn tables_filename(&self, module_name: &str) -> String {
+        format!("{module_name}{TABLES_FILE_SUFFIX}.{}", self.output_ext)
+    }
+
+    /// Returns the path of the private tables file of a module.
+    ///
+    /// The file is a sibling of the module file, so the module can
+    /// `include!` it by the file name.
+    pub fn tables_path(&self, module_name: &str) -> PathBuf {
+        self.module_path(module_name)
+            .with_file_name(self.tables_filename(module_name))
+    }
+
+    /// Returns the absolute path of the `rustfmt` configuration file,
+    /// because `rustfmt` runs from the output directory.
+    fn rustfmt_config_path(opts: &CodegenOptions) -> Result<Option<PathBuf>, TbError> {
+        let Some(ref p) = opts.rustfmt_config else {
+            return Ok(None);
+        };
+        if !p.is_file() {
+            log::error!("rustfmt configuration file isn't found: {p:?}");
+            return RUSTFMT_CONFIG_NOT_FOUND.into();
+        }
+        fs::canonicalize(p).map(Some).map_err(|e| {
+            log::error!("failed to resolve the rustfmt configuration path {p:?}: {e}");
+            TbError::from(RUSTFMT_CONFIG_NOT_FOUND)
         })
     }
 
-    fn output_path(opts: &CodegenOptions, spec: &Spec) -> Result<OutputPath, TbError> {
+    fn output_path(
+        opts: &CodegenOptions,
+        spec: &Spec,
+        output_ext: &str,
+    ) -> Result<OutputPath, TbError> {
         let output = spec.main.output(&spec.path, opts.output.as_deref())?;
         if output.as_os_str() == STDOUT_PATH {
             return Ok(OutputPath {
                 path: output,
                 separate_files: false,
+                output_mode: OutputMode::Overwrite,
             });
         }
 
@@ -42,7 +140,12 @@ impl FrozenOptions {
         }
 
         let op = if is_dir && !separate_files {
-            output.join(IMPLICIT_FILENAME)
+            let module_filename = opts.module_filename.unwrap_or(DEFAULT_MODULE_FILENAME);
+            let stem = match spec.modules.as_slice() {
+                [m] if module_filename => m.name(),
+                _ => IMPLICIT_FILE_STEM,
+            };
+            output.join(format!("{stem}.{output_ext}"))
         } else {
             output
         };
@@ -50,6 +153,7 @@ impl FrozenOptions {
         Ok(OutputPath {
             path: op,
             separate_files,
+            output_mode: opts.output_mode.unwrap_or(DEFAULT_OUTPUT_MODE),
         })
     }
 }
@@ -58,4 +162,5 @@ impl FrozenOptions {
 struct OutputPath {
     path: PathBuf,
     separate_files: bool,
+    output_mode: OutputMode,
 }
@@ -1,12 +1,21 @@
+#[cfg(feature = "parallel")]
+use crate::errors::kind::coder::{FAILED_TO_BUILD_THREAD_POOL, FAILED_TO_PARSE_TOKENS};
 use crate::{
-    coder::{formatter::pretty, FrozenOptions, ALL_MODULES},
+    coder::{
+        catalog::Catalog, formatter::pretty, FrozenOptions, OutputMode, ALL_MODULES,
+        CAT_BITS_MARKER,
+    },
     errors::TbError,
-    spec::Spec,
+    spec::{definitions::STDIN_PATH, ModuleSpec, Spec},
 };
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
+#[cfg(feature = "parallel")]
+use std::str::FromStr;
+use std::{collections::BTreeMap, fmt::Write};
 
 mod bits;
+use bits::Bits;
 mod helpers;
 mod module;
 mod repr_type;
@@ -19,6 +28,8 @@ pub struct ModuleCode {
     pub(crate) name: String,
     /// The module code
     pub(crate) code: String,
+    /// The code of the private tables, if written to a separate file
+    pub(crate) tables: Option<String>,
 }
 
 struct RustGenerator<'a> {
@@ -32,46 +43,183 @@ impl<'a> RustGenerator<'a> {
     }
 
     fn rust(&self) -> Result<Vec<ModuleCode>, TbError> {
-        let mut ret = Vec::new();
-        let mut ts = TokenStream::default();
-        for m in &self.spec.modules {
-            let mod_doc = self.opts.separate_files || self.spec.modules.len() == 1;
-            let tokens = ModuleGenerator::new(self.opts, self.spec, m, mod_doc)?.rust()?;
-            if self.spec.modules.len() > 1 && !self.opts.separate_files {
-                let module_name = format_ident!("{}", m.name());
-                let module_doc = helpers::doc_tokens(m.doc());
-                ts = quote! {
-                    #ts
-                    #module_doc
-                    pub mod #module_name {
-                        #tokens
-                    }
-                };
-            } else {
-                ret.push(ModuleCode {
-                    name: m.name().into(),
-                    code: pretty(tokens)?,
-                });
-            }
+        let header = self.header();
+        if self.spec.modules.len() == 1 || self.opts.separate_files {
+            return self.map_modules(|m| self.module_code(m, &header));
         }
-        if ret.is_empty() {
-            let name = if self.spec.modules.len() > 1 {
-                ALL_MODULES.to_owned()
-            } else {
-                self.spec
-                    .modules
-                    .first()
-                    .expect("at least one module is expected to exist at this point")
-                    .name()
-                    .to_owned()
-            };
-            ret.push(ModuleCode {
-                name,
-                code: pretty(ts)?,
+        let record = self.cat_bits_record(&self.spec.modules)?;
+        Ok(vec![ModuleCode {
+            name: ALL_MODULES.to_owned(),
+            code: format!("{header}{record}{}", pretty(self.combined_tokens()?)?),
+            tables: None,
+        }])
+    }
+
+    /// Generates the formatted code of a module file, along with the
+    /// formatted code of its tables file if the tables are split.
+    fn module_code(&self, m: &ModuleSpec, header: &str) -> Result<ModuleCode, TbError> {
+        let mod_doc = self.opts.output_mode != OutputMode::Append;
+        let generator = ModuleGenerator::new(self.opts, self.spec, m, mod_doc)?;
+        let tables = if self.opts.split_tables {
+            Some(format!("{header}{}", pretty(generator.tables())?))
+        } else {
+            None
+        };
+        let record = self.cat_bits_record(std::slice::from_ref(m))?;
+        Ok(ModuleCode {
+            name: m.name().into(),
+            code: format!("{header}{record}{}", pretty(generator.rust()?)?),
+            tables,
+        })
+    }
+
+    /// Generates the tokens of all modules of a combined file.
+    ///
+    /// Tokens can't be sent across threads, so the modules generated in
+    /// parallel are converted to strings and parsed back.
+    fn combined_tokens(&self) -> Result<TokenStream, TbError> {
+        #[cfg(feature = "parallel")]
+        if self.parallel() {
+            let code: String = self
+                .map_modules(|m| Ok(self.combined_module_tokens(m)?.to_string()))?
+                .concat();
+            return TokenStream::from_str(&code).map_err(|e| {
+                log::error!("failed to parse the generated modules: {e}");
+                TbError::from(FAILED_TO_PARSE_TOKENS)
             });
         }
-        Ok(ret)
+        self.spec
+            .modules
+            .iter()
+            .map(|m| self.combined_module_tokens(m))
+            .collect()
+    }
+
+    /// Generates the tokens of a module of a combined file, wrapped in a
+    /// `pub mod`.
+    fn combined_module_tokens(&self, m: &ModuleSpec) -> Result<TokenStream, TbError> {
+        let generator = ModuleGenerator::new(self.opts, self.spec, m, false)?;
+        let tokens = generator.rust()?;
+        let module_name = format_ident!("{}", m.name());
+        let module_doc = helpers::doc_tokens(m.doc());
+        let module_allows = helpers::allow_tokens(&m.allows);
+        let module_cfg = helpers::cfg_feature_tokens(m.feature.as_deref(), m.doc_cfg());
+        Ok(quote! {
+            #module_doc
+            #module_cfg
+            #module_allows
+            pub mod #module_name {
+                #tokens
+            }
+        })
+    }
+
+    /// Checks if the modules are generated in parallel.
+    #[cfg(feature = "parallel")]
+    fn parallel(&self) -> bool {
+        self.spec.modules.len() > 1 && self.opts.threads != Some(1)
+    }
+
+    /// Maps the modules in the specification order.
+    ///
+    /// With the `parallel` feature the modules are mapped in parallel,
+    /// unless a single thread is requested.
+    fn map_modules<T, F>(&self, f: F) -> Result<Vec<T>, TbError>
+    where
+        T: Send,
+        F: Fn(&ModuleSpec) -> Result<T, TbError> + Sync,
+    {
+        #[cfg(feature = "parallel")]
+        if self.parallel() {
+            use rayon::prelude::*;
+            let map = || self.spec.modules.par_iter().map(&f).collect();
+            return match self.opts.threads {
+                Some(n) => rayon::ThreadPoolBuilder::new()
+                    .num_threads(n)
+                    .build()
+                    .map_err(|e| {
+                        log::error!("failed to build a thread pool of {n} threads: {e}");
+                        TbError::from(FAILED_TO_BUILD_THREAD_POOL)
+                    })?
+                    .install(map),
+                None => map(),
+            };
+        }
+        self.spec.modules.iter().map(f).collect()
+    }
+
+    /// Records the number of category bits of the modules.
+    ///
+    /// The records are compared with the specification on the next code
+    /// generation, to report modules gaining category bits.
+    fn cat_bits_record(&self, modules: &[ModuleSpec]) -> Result<String, TbError> {
+        let mut record = String::new();
+        for m in modules {
+            let bits = Bits::calculate(self.spec, m)?;
+            writeln!(record, "{CAT_BITS_MARKER} {} {}", m.name(), bits.category)
+                .expect("writing to a String doesn't fail");
+        }
+        record.push('\n');
+        Ok(record)
+    }
+
+    fn header(&self) -> String {
+        if !self.opts.header {
+            return String::new();
+        }
+        // the file name only, so the header doesn't depend on the checkout path
+        let spec_path = if self.spec.path.as_os_str() == STDIN_PATH {
+            "stdin".into()
+        } else {
+            self.spec
+                .path
+                .file_name()
+                .unwrap_or(self.spec.path.as_os_str())
+                .to_string_lossy()
+        };
+        format!(
+            "// @generated by tighterror-build v{} from {} (sha256: {})\n\n",
+            env!("CARGO_PKG_VERSION"),
+            spec_path,
+            self.spec.hash
+        )
+    }
+}
+
+pub fn validate(spec: &Spec) -> Result<(), TbError> {
+    for m in &spec.modules {
+        ModuleGenerator::validate(spec, m)?;
     }
+    Ok(())
+}
+
+pub fn category_bits(spec: &Spec, m: &ModuleSpec) -> Result<usize, TbError> {
+    Ok(Bits::calculate(spec, m)?.category)
+}
+
+pub fn spec_to_catalog(spec: &Spec) -> Result<Catalog, TbError> {
+    let modules = spec
+        .modules
+        .iter()
+        .map(|m| ModuleGenerator::catalog(spec, m))
+        .collect::<Result<_, _>>()?;
+    Ok(Catalog { modules })
+}
+
+pub fn spec_to_kind_values(
+    spec: &Spec,
+) -> Result<BTreeMap<String, BTreeMap<String, u64>>, TbError> {
+    spec.modules
+        .iter()
+        .map(|m| Ok((m.name().to_owned(), ModuleGenerator::kind_values(spec, m)?)))
+        .collect()
+}
+
+pub fn spec_to_layout(spec: &Spec) -> Result<String, TbError> {
+    spec.modules
+        .iter()
+        .map(|m| ModuleGenerator::layout(spec, m))
+        .collect()
 }
 
 pub fn spec_to_rust(opts: &FrozenOptions, spec: &Spec) -> Result<Vec<ModuleCode>, TbError> {
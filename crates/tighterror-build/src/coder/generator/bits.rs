@@ -61,6 +61,15 @@ impl Bits {
     }
 }
 
+/// Returns the value of an error kind, given the index of its category
+/// and its index within the category, i.e., its variant.
+///
+/// This mirrors `ErrorKind::new` of the generated code, which computes
+/// the values of the error kind constants.
+pub fn kind_value(cat_idx: usize, var_idx: usize, bits: &Bits) -> u64 {
+    ((cat_idx as u64) << bits.variant) | var_idx as u64
+}
+
 fn calc_n_category_bits(n_categories: usize) -> Result<usize, TbError> {
     match n_categories {
         0 => {
@@ -81,11 +90,17 @@ fn calc_n_variant_bits(module: &ModuleSpec) -> Result<usize, TbError> {
         }
     };
 
+    if let Some(c) = module.categories.iter().find(|c| c.errors.is_empty()) {
+        log::error!(
+            "at least one error must be defined in category: {}::{}",
+            module.name(),
+            c.name
+        );
+        return ERROR_REQUIRED.into();
+    }
+
     match n {
-        0 => {
-            log::error!("at least one error must be defined");
-            ERROR_REQUIRED.into()
-        }
+        0 => unreachable!("empty categories are rejected above"),
         1 => Ok(1),
         n => calc_n_bits(n, "errors in largest category"),
     }
@@ -99,3 +114,64 @@ fn calc_n_bits(n: usize, name: &str) -> Result<usize, TbError> {
         TOO_MANY_BITS.into()
     }
 }
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::{
+        parser::testing::log_init,
+        spec::{CategorySpec, ErrorSpec},
+    };
+
+    fn cat(name: &str, errors: &[&str]) -> CategorySpec {
+        CategorySpec {
+            name: name.into(),
+            errors: errors
+                .iter()
+                .map(|e| ErrorSpec {
+                    name: (*e).into(),
+                    ..Default::default()
+                })
+                .collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_empty_category() {
+        log_init();
+        for categories in [
+            vec![cat("Empty", &[])],
+            vec![cat("Cat1", &["ERR1", "ERR2"]), cat("Empty", &[])],
+            vec![cat("Empty", &[]), cat("Cat2", &["ERR1"])],
+        ] {
+            let module = ModuleSpec {
+                categories,
+                ..Default::default()
+            };
+            assert_eq!(
+                Bits::calculate(&Spec::default(), &module)
+                    .unwrap_err()
+                    .kind(),
+                ERROR_REQUIRED
+            );
+        }
+
+        assert_eq!(
+            Bits::calculate(&Spec::default(), &ModuleSpec::default())
+                .unwrap_err()
+                .kind(),
+            CATEGORY_REQUIRED
+        );
+
+        let module = ModuleSpec {
+            categories: vec![
+                cat("Cat1", &["ERR1", "ERR2", "ERR3"]),
+                cat("Cat2", &["ERR1"]),
+            ],
+            ..Default::default()
+        };
+        let bits = Bits::calculate(&Spec::default(), &module).unwrap();
+        assert_eq!((bits.category, bits.variant), (1, 2));
+    }
+}
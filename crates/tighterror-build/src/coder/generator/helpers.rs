@@ -1,6 +1,10 @@
-use crate::coder::idents;
+use crate::{
+    coder::idents,
+    spec::{CategorySpec, DisplayCase, ErrorSpec, GroupSpec},
+};
 use proc_macro2::{Ident, TokenStream};
 use quote::{format_ident, quote};
+use std::str::FromStr;
 
 fn _handle_multiline_doc(doc: &str) -> String {
     let n_lines = doc.lines().count();
@@ -46,10 +50,115 @@ pub fn outer_doc_tokens(doc: &str) -> TokenStream {
     _doc_tokens(doc, OUTER)
 }
 
+fn _allow_tokens(lints: &[String], outer: bool) -> TokenStream {
+    if lints.is_empty() {
+        return TokenStream::default();
+    }
+    let lints = lints
+        .iter()
+        .map(|l| TokenStream::from_str(l).expect("lint names are validated by the parser"));
+    if outer {
+        quote! {
+            #![allow(#(#lints),*)]
+        }
+    } else {
+        quote! {
+            #[allow(#(#lints),*)]
+        }
+    }
+}
+
+pub fn allow_tokens(lints: &[String]) -> TokenStream {
+    const OUTER: bool = false;
+    _allow_tokens(lints, OUTER)
+}
+
+pub fn outer_allow_tokens(lints: &[String]) -> TokenStream {
+    const OUTER: bool = true;
+    _allow_tokens(lints, OUTER)
+}
+
+/// Gates an item on a crate feature, and optionally marks the requirement
+/// in the documentation built by docs.rs.
+fn _cfg_feature_tokens(feature: Option<&str>, doc_cfg: bool, outer: bool) -> TokenStream {
+    match (feature, doc_cfg, outer) {
+        (None, _, _) => TokenStream::default(),
+        (Some(f), false, true) => quote! {
+            #![cfg(feature = #f)]
+        },
+        (Some(f), false, false) => quote! {
+            #[cfg(feature = #f)]
+        },
+        (Some(f), true, true) => quote! {
+            #![cfg(feature = #f)]
+            #![cfg_attr(docsrs, doc(cfg(feature = #f)))]
+        },
+        (Some(f), true, false) => quote! {
+            #[cfg(feature = #f)]
+            #[cfg_attr(docsrs, doc(cfg(feature = #f)))]
+        },
+    }
+}
+
+pub fn cfg_feature_tokens(feature: Option<&str>, doc_cfg: bool) -> TokenStream {
+    const OUTER: bool = false;
+    _cfg_feature_tokens(feature, doc_cfg, OUTER)
+}
+
+pub fn outer_cfg_feature_tokens(feature: Option<&str>, doc_cfg: bool) -> TokenStream {
+    const OUTER: bool = true;
+    _cfg_feature_tokens(feature, doc_cfg, OUTER)
+}
+
 pub fn category_names_mod_ident() -> Ident {
     format_ident!("{}", idents::CATEGORY_NAMES_MOD)
 }
 
+/// Returns the error kind name qualified by the category name,
+/// e.g., `CATEGORY::NAME`.
+pub fn err_kind_qualified_name(c: &CategorySpec, e: &ErrorSpec) -> String {
+    format!("{}::{}", c.ident_name(), e.name)
+}
+
+/// Converts the casing of a display string.
+///
+/// [DisplayCase::Lower] keeps display strings starting with an acronym,
+/// i.e., with a first word having more than one uppercase letter,
+/// e.g., `IO`, `I/O` or `IPv6`.
+pub fn convert_display_case(display: &str, case: DisplayCase) -> String {
+    let mut chars = display.chars();
+    let Some(first) = chars.next() else {
+        return String::new();
+    };
+    let rest = chars.as_str();
+    match case {
+        DisplayCase::Sentence => first.to_uppercase().chain(rest.chars()).collect(),
+        DisplayCase::Lower if !starts_with_acronym(display) => {
+            first.to_lowercase().chain(rest.chars()).collect()
+        }
+        _ => display.to_owned(),
+    }
+}
+
+fn starts_with_acronym(display: &str) -> bool {
+    display
+        .split_whitespace()
+        .next()
+        .is_some_and(|w| w.chars().filter(|c| c.is_uppercase()).count() > 1)
+}
+
+pub fn category_all_ident() -> Ident {
+    format_ident!("{}", idents::CATEGORY_ALL)
+}
+
+pub fn kind_variants_ident() -> Ident {
+    format_ident!("{}", idents::KIND_VARIANTS)
+}
+
+pub fn by_category_mod_ident() -> Ident {
+    format_ident!("{}", idents::BY_CATEGORY_MOD)
+}
+
 pub fn error_names_mod_ident() -> Ident {
     format_ident!("{}", idents::ERROR_NAMES_MOD)
 }
@@ -58,20 +167,28 @@ pub fn error_displays_mod_ident() -> Ident {
     format_ident!("{}", idents::ERROR_DISPLAYS_MOD)
 }
 
-pub fn private_mod_ident() -> Ident {
-    format_ident!("{}", idents::PRIVATE_MOD)
+pub fn error_docs_mod_ident() -> Ident {
+    format_ident!("{}", idents::ERROR_DOCS_MOD)
 }
 
-pub fn error_kinds_mod_ident() -> Ident {
-    format_ident!("{}", idents::ERROR_KINDS_MOD)
+pub fn prelude_mod_ident() -> Ident {
+    format_ident!("{}", idents::PRELUDE_MOD)
 }
 
-pub fn tests_mod_ident() -> Ident {
-    format_ident!("{}", idents::TESTS_MOD)
+pub fn catalog_ident() -> Ident {
+    format_ident!("{}", idents::CATALOG)
+}
+
+pub fn message_fn_ident() -> Ident {
+    format_ident!("{}", idents::MESSAGE_FN)
+}
+
+pub fn private_mod_ident() -> Ident {
+    format_ident!("{}", idents::PRIVATE_MOD)
 }
 
-pub fn categories_mod_ident() -> Ident {
-    format_ident!("{}", idents::CATEGORY_CONSTS_MOD)
+pub fn tests_mod_ident() -> Ident {
+    format_ident!("{}", idents::TESTS_MOD)
 }
 
 pub fn variants_mod_ident() -> Ident {
@@ -81,3 +198,7 @@ pub fn variants_mod_ident() -> Ident {
 pub fn types_mod_ident() -> Ident {
     format_ident!("{}", idents::TYPES_MOD)
 }
+
+pub fn group_table_ident(g: &GroupSpec) -> Ident {
+    format_ident!("GROUP_{}", g.name.to_uppercase())
+}
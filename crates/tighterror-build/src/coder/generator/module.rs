@@ -1,17 +1,31 @@
 use crate::{
-    coder::generator::{bits::Bits, helpers::*, repr_type::ReprType},
-    errors::TbError,
-    spec::{CategorySpec, ErrorSpec, ModuleSpec, Spec},
+    coder::{
+        catalog::{CategoryCatalog, KindCatalog, ModuleCatalog},
+        generator::{
+            bits::{kind_value, Bits},
+            helpers::*,
+            repr_type::ReprType,
+        },
+    },
+    errors::{
+        kind::coder::{BAD_INDEX, TOO_MANY_BITS, VALUE_WIDTH_TOO_NARROW},
+        TbError,
+    },
+    spec::{
+        definitions::GENERATED_TESTS, CategorySpec, DisplayCase, DisplayCodeRadix, ErrorSpec,
+        ModuleSpec, OrderBy, SerdeRepr, Spec, GROUP_REF_SEPARATOR,
+    },
     FrozenOptions,
 };
 use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote};
-use std::{num::TryFromIntError, str::FromStr};
+use std::{borrow::Cow, collections::BTreeMap, num::TryFromIntError, str::FromStr};
 
 pub struct ModuleGenerator<'a> {
     opts: &'a FrozenOptions,
     spec: &'a Spec,
-    module: &'a ModuleSpec,
+    /// the module with categories ordered by their pinned indices
+    module: Cow<'a, ModuleSpec>,
     /// add a module doc string to the generated code
     mod_doc: bool,
     /// bit calculations' results
@@ -25,33 +39,303 @@ impl<'a> ModuleGenerator<'a> {
         module: &'a ModuleSpec,
         mod_doc: bool,
     ) -> Result<ModuleGenerator<'a>, TbError> {
+        let bits = Bits::calculate(spec, module)?;
+        Self::check_value_widths(module, &bits)?;
+        Self::category_set_repr_type(module)?;
+        let module = Self::apply_display_case(Self::pin_indices(module)?);
+        for line in Self::layout_text(&module, &bits).lines() {
+            log::info!("{line}");
+        }
         Ok(Self {
             opts,
             spec,
             module,
             mod_doc,
-            bits: Bits::calculate(spec, module)?,
+            bits,
+        })
+    }
+
+    /// Converts the casing of the display strings as set by the
+    /// `display_case` module attribute.
+    ///
+    /// The conversion is done only for the generated code, i.e.,
+    /// the specification keeps the display strings as written.
+    fn apply_display_case<'m>(mut module: Cow<'m, ModuleSpec>) -> Cow<'m, ModuleSpec> {
+        let case = module.display_case();
+        if case == DisplayCase::None || module.display_is_message_id() {
+            return module;
+        }
+        for c in &mut module.to_mut().categories {
+            for e in &mut c.errors {
+                if let Some(ref mut display) = e.display {
+                    *display = convert_display_case(display, case);
+                }
+            }
+        }
+        module
+    }
+
+    /// Runs the module checks of [new](Self::new) without generating code.
+    pub fn validate(spec: &Spec, module: &ModuleSpec) -> Result<(), TbError> {
+        Self::pin_indices(module)?;
+        let bits = Bits::calculate(spec, module)?;
+        Self::check_value_widths(module, &bits)?;
+        Self::category_set_repr_type(module)?;
+        Ok(())
+    }
+
+    /// Describes the module in the catalog, with the values assigned
+    /// exactly as in the generated code.
+    pub fn catalog(spec: &Spec, module: &ModuleSpec) -> Result<ModuleCatalog, TbError> {
+        let bits = Bits::calculate(spec, module)?;
+        let module = Self::apply_display_case(Self::pin_indices(module)?);
+        let categories = module
+            .categories
+            .iter()
+            .enumerate()
+            .map(|(i, c)| {
+                let kinds = c
+                    .errors
+                    .iter()
+                    .enumerate()
+                    .map(|(j, e)| KindCatalog {
+                        name: e.name.clone(),
+                        display: e.display.as_deref().unwrap_or(&e.name).to_owned(),
+                        doc: module.err_kind_const_doc(c, e).to_owned(),
+                        category: c.name.clone(),
+                        subcategory: e.subcategory.clone(),
+                        value: kind_value(i, j, &bits),
+                    })
+                    .collect();
+                CategoryCatalog {
+                    name: c.name.clone(),
+                    doc: module.cat_const_doc(c).to_owned(),
+                    value: i as u64,
+                    kinds,
+                }
+            })
+            .collect();
+        Ok(ModuleCatalog {
+            name: module.name().to_owned(),
+            doc: module.doc().to_owned(),
+            categories,
         })
     }
 
+    /// Returns the values of the module's error kinds, keyed by the
+    /// category-qualified error kind name, exactly as returned by
+    /// `ErrorKind::value` in the generated code.
+    pub fn kind_values(spec: &Spec, module: &ModuleSpec) -> Result<BTreeMap<String, u64>, TbError> {
+        let bits = &Bits::calculate(spec, module)?;
+        let module = Self::pin_indices(module)?;
+        Ok(module
+            .categories
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                c.errors
+                    .iter()
+                    .enumerate()
+                    .map(move |(j, e)| (err_kind_qualified_name(c, e), kind_value(i, j, bits)))
+            })
+            .collect())
+    }
+
+    /// Describes the module layout, i.e., the underlying Rust type,
+    /// the bit widths and the number of variants per category, exactly
+    /// as in the generated code.
+    pub fn layout(spec: &Spec, module: &ModuleSpec) -> Result<String, TbError> {
+        let bits = Bits::calculate(spec, module)?;
+        let module = Self::pin_indices(module)?;
+        Ok(Self::layout_text(&module, &bits))
+    }
+
+    fn layout_text(module: &ModuleSpec, bits: &Bits) -> String {
+        let mut text = format!(
+            "module {}: repr={} KIND_BITS={} CAT_BITS={} VAR_BITS={}\n",
+            module.name(),
+            bits.repr_type.name(),
+            bits.kind,
+            bits.category,
+            bits.variant
+        );
+        for (i, c) in module.categories.iter().enumerate() {
+            text.push_str(&format!(
+                "  category {}: index={i} variants={}\n",
+                c.name,
+                c.errors.len()
+            ));
+            for s in &c.subcategories {
+                text.push_str(&format!(
+                    "    subcategory {}: variants={}\n",
+                    s.name,
+                    c.subcategory_errors(s).count()
+                ));
+            }
+        }
+        text
+    }
+
+    fn check_value_widths(module: &ModuleSpec, bits: &Bits) -> Result<(), TbError> {
+        let repr_bits = bits.repr_type.bits();
+        match module.value_widths.iter().find(|w| **w < repr_bits) {
+            Some(w) => {
+                log::error!(
+                    "value width u{w} is narrower than the underlying Rust type {}: module {}",
+                    bits.repr_type.name(),
+                    module.name()
+                );
+                VALUE_WIDTH_TOO_NARROW.into()
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the underlying Rust type of the category set, one bit per
+    /// category, or `None` if category sets are disabled.
+    fn category_set_repr_type(module: &ModuleSpec) -> Result<Option<ReprType>, TbError> {
+        if !module.category_sets() {
+            return Ok(None);
+        }
+        let n_categories = module.categories.len();
+        match ReprType::from_n_bits(n_categories) {
+            Ok(r) => Ok(Some(r)),
+            Err(_) => {
+                log::error!(
+                    "category set of {n_categories} categories exceeds the largest supported type {}: module {}",
+                    ReprType::largest_type_name(),
+                    module.name()
+                );
+                TOO_MANY_BITS.into()
+            }
+        }
+    }
+
+    /// Orders the categories, and the errors in every category, by their
+    /// pinned indices.
+    fn pin_indices(module: &ModuleSpec) -> Result<Cow<'_, ModuleSpec>, TbError> {
+        let module_owner = format!("module {}", module.name());
+        let pinned_categories = Self::pin(
+            &module.categories,
+            |c| c.index,
+            |c| &c.name,
+            "categories",
+            &module_owner,
+        )?;
+        let mut pinned = pinned_categories.is_some();
+        let mut categories = pinned_categories.unwrap_or_else(|| module.categories.clone());
+        for c in &mut categories {
+            let category_owner = format!("category {}", c.name);
+            if !c.subcategories.is_empty() && c.errors.iter().any(|e| e.index.is_some()) {
+                // the sub-categories must occupy contiguous ranges of variants
+                log::error!(
+                    "`index` isn't supported in a category with subcategories: {category_owner}"
+                );
+                return BAD_INDEX.into();
+            }
+            if let Some(errors) = Self::pin(
+                &c.errors,
+                |e| e.index,
+                |e| &e.name,
+                "errors",
+                &category_owner,
+            )? {
+                c.errors = errors;
+                pinned = true;
+            }
+        }
+
+        if pinned {
+            Ok(Cow::Owned(ModuleSpec {
+                categories,
+                ..module.clone()
+            }))
+        } else {
+            Ok(Cow::Borrowed(module))
+        }
+    }
+
+    /// Orders the items by their pinned indices.
+    ///
+    /// Returns `None` if none of the items is pinned.
+    fn pin<T: Clone>(
+        items: &[T],
+        index: impl Fn(&T) -> Option<usize>,
+        name: impl Fn(&T) -> &str,
+        what: &str,
+        owner: &str,
+    ) -> Result<Option<Vec<T>>, TbError> {
+        let n_pinned = items.iter().filter(|i| index(i).is_some()).count();
+        if n_pinned == 0 {
+            return Ok(None);
+        }
+
+        let n_items = items.len();
+        if n_pinned != n_items {
+            log::error!("either all or none of the {what} must define `index`: {owner}");
+            return BAD_INDEX.into();
+        }
+
+        let mut pinned = vec![None; n_items];
+        for item in items {
+            match index(item).and_then(|i| pinned.get_mut(i)) {
+                Some(slot @ None) => *slot = Some(item.clone()),
+                Some(Some(_)) => {
+                    log::error!("`index` must be unique in {owner}: {}", name(item));
+                    return BAD_INDEX.into();
+                }
+                None => {
+                    log::error!(
+                        "`index` must be less than the number of {what} {n_items} in {owner}: {}",
+                        name(item)
+                    );
+                    return BAD_INDEX.into();
+                }
+            }
+        }
+        Ok(Some(pinned.into_iter().flatten().collect()))
+    }
+
     pub fn rust(&self) -> Result<TokenStream, TbError> {
         let module_doc = self.module_doc_tokens();
+        let module_allows = self.module_allow_tokens();
+        let module_cfg = self.module_cfg_tokens();
         let private_modules = self.private_modules_tokens();
         let category_tokens = self.category_tokens();
+        let category_default_tokens = self.category_default_tokens();
+        let category_set_tokens = self.category_set_tokens();
+        let category_kinds_tokens = self.category_kinds_tokens();
         let error_kind_tokens = self.error_kind_tokens();
+        let proptest_tokens = self.proptest_tokens();
         let error_tokens = self.error_tokens();
+        let bail_macro = self.bail_macro_tokens();
+        let bridge_enum = self.bridge_enum_tokens();
+        let map_by_name = self.map_by_name_tokens();
         let category_constants = self.category_constants_tokens();
         let error_kind_constants = self.error_kind_constants_tokens();
+        let prelude = self.prelude_tokens();
+        let catalog = self.catalog_tokens();
         let variants_module = self.variants_module_tokens();
         let test = self.test_tokens();
         Ok(quote! {
             #module_doc
+            #module_cfg
+            #module_allows
             #category_tokens
+            #category_default_tokens
+            #category_set_tokens
+            #category_kinds_tokens
             #error_kind_tokens
+            #proptest_tokens
             #error_tokens
+            #bail_macro
+            #bridge_enum
+            #map_by_name
             #private_modules
             #category_constants
             #error_kind_constants
+            #prelude
+            #catalog
             #variants_module
             #test
         })
@@ -60,14 +344,46 @@ impl<'a> ModuleGenerator<'a> {
     fn private_modules_tokens(&self) -> TokenStream {
         let constants_tokens = self.private_constants_tokens();
         let types = self.private_types();
+        let private_mod = private_mod_ident();
+        let message_fn = self.message_fn_tokens();
+
+        let tables = if self.opts.split_tables {
+            let filename = self.opts.tables_filename(self.module.name());
+            quote! { include!(#filename); }
+        } else {
+            self.tables()
+        };
+
+        quote! {
+            #tables
+            mod #private_mod {
+                #constants_tokens
+                #types
+            }
+            #message_fn
+        }
+    }
+
+    /// Returns the private name, display and doc tables of the module.
+    pub fn tables(&self) -> TokenStream {
         let category_names = self.private_category_names();
         let error_names = self.private_error_names();
         let error_display = self.private_error_display();
+        let error_docs = if self.module.catalog() {
+            let error_docs = self.private_error_docs();
+            let error_docs_mod = error_docs_mod_ident();
+            quote! {
+                mod #error_docs_mod {
+                    #error_docs
+                }
+            }
+        } else {
+            TokenStream::default()
+        };
 
         let category_names_mod = category_names_mod_ident();
         let error_names_mod = error_names_mod_ident();
         let error_displays_mod = error_displays_mod_ident();
-        let private_mod = private_mod_ident();
 
         quote! {
             mod #category_names_mod {
@@ -79,13 +395,69 @@ impl<'a> ModuleGenerator<'a> {
             mod #error_displays_mod {
                 #error_display
             }
-            mod #private_mod {
-                #constants_tokens
-                #types
+            #error_docs
+        }
+    }
+
+    fn runtime_path_tokens(&self) -> TokenStream {
+        TokenStream::from_str(self.module.runtime_path())
+            .expect("runtime path is validated by the parser")
+    }
+
+    fn message_fn_tokens(&self) -> TokenStream {
+        let Some(resolver) = self.module.display_resolver() else {
+            return TokenStream::default();
+        };
+        let resolver = TokenStream::from_str(resolver)
+            .expect("display resolver path is validated by the parser");
+        let message_fn = message_fn_ident();
+        quote! {
+            #[inline]
+            fn #message_fn(id: &'static str) -> impl core::fmt::Display {
+                #resolver(id)
             }
         }
     }
 
+    fn message_doc_tokens(&self, what: &str) -> TokenStream {
+        let doc = match self.module.display_resolver() {
+            Some(resolver) => format!(
+                " Returns the message id of the {what}, resolved by `{resolver}` on display."
+            ),
+            None => format!(" Returns the error message, i.e., the display string of the {what}."),
+        };
+        quote! { #[doc = #doc] }
+    }
+
+    fn display_message_tokens(&self, message: TokenStream) -> TokenStream {
+        if self.module.display_resolver().is_some() {
+            let message_fn = message_fn_ident();
+            quote! { core::fmt::Display::fmt(&#message_fn(#message), f) }
+        } else {
+            quote! { f.pad(#message) }
+        }
+    }
+
+    /// Appends the error kind value to the display tokens if
+    /// `display_with_code` is enabled.
+    ///
+    /// Used with `display_resolver` only, because the resolved message
+    /// isn't known in advance. Otherwise, the precomputed `C` tables are
+    /// displayed, so padding applies to the whole string.
+    fn display_with_code_tokens(&self, display: TokenStream, value: TokenStream) -> TokenStream {
+        if !self.module.display_with_code() {
+            return display;
+        }
+        let fmt = match self.module.display_code_radix() {
+            DisplayCodeRadix::Hex => " [{:#x}]",
+            DisplayCodeRadix::Decimal => " [{}]",
+        };
+        quote! {
+            #display?;
+            write!(f, #fmt, #value)
+        }
+    }
+
     fn private_constants_tokens(&self) -> TokenStream {
         let repr_type = self.bits.repr_type.ident();
         let n_kind_bits = Literal::usize_unsuffixed(self.bits.kind);
@@ -116,6 +488,11 @@ impl<'a> ModuleGenerator<'a> {
                 pub const VAR_BITS: usize = #n_variant_bits;
             }
         };
+        let all_table = self.private_all_table_tokens();
+        let cat_offsets_table = self.private_cat_offsets_table_tokens();
+        let kind_to_cat_table = self.private_kind_to_cat_table_tokens();
+        let group_tables = self.private_group_tables_tokens();
+        let names_table = self.private_names_table_tokens();
 
         quote! {
             pub type R = #repr_type;
@@ -127,11 +504,100 @@ impl<'a> ModuleGenerator<'a> {
                 #(#variant_maxes_iter),*
             ];
             #optional_tokens
+            #all_table
+            #cat_offsets_table
+            #kind_to_cat_table
+            #group_tables
+            #names_table
             const _: () = assert!(KIND_BITS <= R::BITS as usize);
             const _: () = assert!(CAT_BITS <= usize::BITS as usize); // for casting to usize
         }
     }
 
+    fn needs_all_table(&self) -> bool {
+        self.module.arbitrary() || self.module.proptest() || self.module.kind_iter()
+    }
+
+    fn private_all_table_tokens(&self) -> TokenStream {
+        if !self.needs_all_table() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = &self.error_kinds_mod_ident();
+        let n_errors = self.n_errors_literal();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().map(move |e| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                quote! { super::#err_kinds_mod::#err_const }
+            })
+        });
+        quote! {
+            pub static ALL: [super::#err_kind_name; #n_errors] = [
+                #(#iter),*
+            ];
+        }
+    }
+
+    fn private_cat_offsets_table_tokens(&self) -> TokenStream {
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        let mut offset = 0;
+        let offsets_iter = self.module.categories.iter().map(|c| {
+            let lit = Literal::usize_unsuffixed(offset);
+            offset += c.errors.len();
+            lit
+        });
+        quote! {
+            pub static CAT_OFFSETS: [usize; #n_categories] = [
+                #(#offsets_iter),*
+            ];
+        }
+    }
+
+    fn private_kind_to_cat_table_tokens(&self) -> TokenStream {
+        if !self.module.kind_to_cat() {
+            return TokenStream::default();
+        }
+        let n_errors = self.n_errors_literal();
+        let kind_to_cat_iter = self
+            .module
+            .categories
+            .iter()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                let cat = self.usize_to_repr_type_literal(i).unwrap();
+                std::iter::repeat(cat).take(c.errors.len())
+            });
+        quote! {
+            pub static KIND_TO_CAT: [R; #n_errors] = [
+                #(#kind_to_cat_iter),*
+            ];
+        }
+    }
+
+    fn private_group_tables_tokens(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = &self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.groups.iter().map(|g| {
+            let table = group_table_ident(g);
+            let members = self.module.group_members(g);
+            let n_members = Literal::usize_unsuffixed(members.len());
+            let members_iter = members.into_iter().map(|(c, e)| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                quote! { super::#err_kinds_mod::#err_const }
+            });
+            quote! {
+                pub static #table: [super::#err_kind_name; #n_members] = [
+                    #(#members_iter),*
+                ];
+            }
+        });
+        quote! {
+            #(#iter)*
+        }
+    }
+
     fn private_types(&self) -> TokenStream {
         quote! {
             pub(super) struct Ident<'a>(pub(super) &'a str);
@@ -178,12 +644,46 @@ impl<'a> ModuleGenerator<'a> {
             let cat_mod_ident = format_ident!("{}", c.module_name());
             quote! { &#cat_mod_ident::A }
         });
+        let qualified_iter = self.module.categories.iter().map(|c| {
+            let names_iter = c.errors.iter().map(|e| err_kind_qualified_name(c, e));
+            quote! { &[#(#names_iter),*] }
+        });
         let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        let with_code = self.private_with_code_tokens(|e| e.name.as_str());
         quote! {
             #(#cat_iter)*
             pub static A: [&[&str]; #n_categories] = [
                 #(#cat_arr_iter),*
             ];
+            pub static Q: [&[&str]; #n_categories] = [
+                #(#qualified_iter),*
+            ];
+            #with_code
+        }
+    }
+
+    /// Returns the table of strings with the error kind value appended,
+    /// if `display_with_code` is enabled.
+    ///
+    /// The `Display` implementations pad the whole string, so it
+    /// must be complete in advance.
+    fn private_with_code_tokens<'s>(&'s self, s: impl Fn(&'s ErrorSpec) -> &'s str) -> TokenStream {
+        if !self.module.display_with_code() {
+            return TokenStream::default();
+        }
+        let iter = self.module.categories.iter().enumerate().map(|(i, c)| {
+            let strs = c
+                .errors
+                .iter()
+                .enumerate()
+                .map(|(j, e)| format!("{}{}", s(e), self.display_code_suffix(i, j)));
+            quote! { &[#(#strs),*] }
+        });
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            pub static C: [&[&str]; #n_categories] = [
+                #(#iter),*
+            ];
         }
     }
 
@@ -223,11 +723,17 @@ impl<'a> ModuleGenerator<'a> {
             quote! { &#cat_mod_ident::A }
         });
         let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        let with_code = if self.module.display_resolver().is_none() {
+            self.private_with_code_tokens(|e| e.display.as_deref().unwrap_or(e.name.as_str()))
+        } else {
+            TokenStream::default()
+        };
         quote! {
             #(#cat_iter)*
             pub static A: [&[&str]; #n_categories] = [
                 #(#cat_arr_iter),*
             ];
+            #with_code
         }
     }
 
@@ -243,7 +749,7 @@ impl<'a> ModuleGenerator<'a> {
                 }
             } else {
                 quote! {
-                    const #const_ident: &str = #error_names_mod::#cat_mod_ident::#const_ident
+                    pub(crate) const #const_ident: &str = #error_names_mod::#cat_mod_ident::#const_ident
                 }
             }
         });
@@ -272,12 +778,38 @@ impl<'a> ModuleGenerator<'a> {
         }
     }
 
+    fn private_error_docs(&self) -> TokenStream {
+        let cat_iter = self.module.categories.iter().map(|c| {
+            let cat_mod_ident = format_ident!("{}", c.module_name());
+            let const_iter = c.errors.iter().map(|e| {
+                let const_ident = format_ident!("{}", e.name);
+                let doc = e.doc.as_deref().unwrap_or_default();
+                quote! {
+                    pub(crate) const #const_ident: &str = #doc
+                }
+            });
+            quote! {
+                pub(crate) mod #cat_mod_ident {
+                    #(#const_iter);* ;
+                }
+            }
+        });
+        quote! {
+            #(#cat_iter)*
+        }
+    }
+
     fn category_tokens(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
         let err_cat_name = self.err_cat_name_ident();
         let err_cat_name_str = self.module.err_cat_name();
         let err_cat_doc = doc_tokens(self.module.err_cat_doc());
+        let err_cat_kinds_name = self.err_cat_kinds_name_ident();
         let category_names_mod = category_names_mod_ident();
         let private_mod = private_mod_ident();
+        let value_widths =
+            self.value_widths_tokens("error category value, i.e., its index in the module,");
+        let category_max_comparison = self.category_max_comparison();
         quote! {
             #err_cat_doc
             #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
@@ -295,9 +827,55 @@ impl<'a> ModuleGenerator<'a> {
                 pub fn name(&self) -> &'static str {
                     #category_names_mod::A[self.0 as usize]
                 }
+
+                #[doc = " An alias of [name](Self::name)."]
+                #[inline]
+                pub fn as_str(&self) -> &'static str {
+                    self.name()
+                }
+
+                #[doc = " Returns the number of error kinds in the error category."]
+                #[inline]
+                pub fn kind_count(&self) -> usize {
+                    #private_mod::VAR_MAXES[self.0 as usize] as usize + 1
+                }
+
+                #[doc = " Returns an iterator over the error kinds of the error category."]
+                #[inline]
+                pub fn kinds(&self) -> #err_cat_kinds_name {
+                    #err_cat_kinds_name {
+                        cat: *self,
+                        next: 0,
+                        end: self.kind_count(),
+                    }
+                }
+
+                #value_widths
+
+                #[doc = " Creates an error category from a raw value of the underlying Rust type."]
+                #[doc = ""]
+                #[doc = " Returns `None` if the value doesn't denote an error category."]
+                #[inline]
+                pub fn from_value(value: #private_mod::R) -> Option<Self> {
+                    if value #category_max_comparison #private_mod::CAT_MAX {
+                        Some(Self::new(value))
+                    } else {
+                        None
+                    }
+                }
+
+                #[doc = " Creates an error category from its name."]
+                #[doc = ""]
+                #[doc = " Returns `None` if the name doesn't denote an error category."]
+                pub fn from_name(name: &str) -> Option<Self> {
+                    #category_names_mod::A
+                        .iter()
+                        .position(|n| *n == name)
+                        .map(|i| Self::new(i as #private_mod::R))
+                }
             }
 
-            impl tighterror::Category for #err_cat_name {
+            impl #rt::Category for #err_cat_name {
                 type R = #private_mod::R;
                 const BITS: usize = #private_mod::CAT_BITS;
 
@@ -305,6 +883,11 @@ impl<'a> ModuleGenerator<'a> {
                 fn name(&self) -> &'static str {
                     self.name()
                 }
+
+                #[inline]
+                fn from_value(value: Self::R) -> Option<Self> {
+                    Self::from_value(value)
+                }
             }
 
             impl core::fmt::Display for #err_cat_name {
@@ -322,55 +905,276 @@ impl<'a> ModuleGenerator<'a> {
                         .finish()
                 }
             }
+
+            impl core::str::FromStr for #err_cat_name {
+                type Err = #rt::ParseKindError;
+
+                #[inline]
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::from_name(s).ok_or(#rt::ParseKindError)
+                }
+            }
+
+            impl<'a> core::convert::TryFrom<&'a str> for #err_cat_name {
+                type Error = #rt::ParseKindError;
+
+                #[inline]
+                fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                    Self::from_name(s).ok_or(#rt::ParseKindError)
+                }
+            }
         }
     }
 
-    fn error_kind_tokens(&self) -> TokenStream {
-        let err_name = self.err_name_ident();
-        let err_kind_name = self.err_kind_name_ident();
-        let err_kind_name_str = self.module.err_kind_name();
+    fn category_default_tokens(&self) -> TokenStream {
+        if !self.module.category_default() {
+            return TokenStream::default();
+        }
         let err_cat_name = self.err_cat_name_ident();
-        let private_mod = private_mod_ident();
-        let error_names_mod = error_names_mod_ident();
-        let error_displays_mod = error_displays_mod_ident();
-        let err_kind_doc = doc_tokens(self.module.err_kind_doc());
-        let category_max_comparison = self.category_max_comparison();
-        let result_from_err_kind = if self.module.result_from_err_kind() {
-            quote! {
-                impl<T> core::convert::From<#err_kind_name> for Result<T, #err_name> {
-                    #[inline]
-                    fn from(v: #err_kind_name) -> Self {
-                        Err(v.into())
-                    }
+        let categories_mod = self.categories_mod_ident();
+        let cat_ident = format_ident!("{}", self.module.categories[0].ident_name());
+        quote! {
+            impl core::default::Default for #err_cat_name {
+                #[inline]
+                fn default() -> Self {
+                    #categories_mod::#cat_ident
                 }
             }
-        } else {
-            TokenStream::default()
-        };
+        }
+    }
 
-        let err_kind_new_tokens = if self.bits.category == 0 {
-            quote! { assert!(cat.0 == #private_mod::CAT_MAX); Self(variant) }
-        } else {
-            quote! { Self(cat.0 << #private_mod::VAR_BITS | variant) }
+    fn category_set_tokens(&self) -> TokenStream {
+        let Some(repr_type) = Self::category_set_repr_type(&self.module)
+            .expect("category set type is validated in new")
+        else {
+            return TokenStream::default();
         };
+        let err_cat_name = self.err_cat_name_ident();
+        let err_cat_set_name = self.err_cat_set_name_ident();
+        let private_mod = private_mod_ident();
+        let repr = repr_type.ident();
+        let one = TokenStream::from_str(&format!("1{}", repr_type.name())).unwrap();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            #[doc = " A set of error categories."]
+            #[doc = ""]
+            #[doc = " Built by combining error categories with the `|` operator."]
+            #[derive(Copy, Clone, Default, Eq, PartialEq, Hash)]
+            #[repr(transparent)]
+            pub struct #err_cat_set_name(#repr);
 
-        let cat_value_tokens = if self.bits.category == 0 {
-            quote! { #private_mod::CAT_MAX }
-        } else {
-            quote! { (self.0 & #private_mod::CAT_MASK) >> #private_mod::VAR_BITS }
-        };
+            impl #err_cat_set_name {
+                #[doc = " Creates an empty set."]
+                #[inline]
+                pub const fn new() -> Self {
+                    Self(0)
+                }
 
-        let from_value_tokens = if self.bits.category == 0 {
-            quote! {
-                if value <= #private_mod::VAR_MAXES[0] {
-                    Some(Self::new(#err_cat_name::new(0), value))
-                } else {
-                    None
+                #[inline]
+                fn bit(cat: #err_cat_name) -> #repr {
+                    #one << cat.0
                 }
-            }
-        } else {
-            quote! {
-                let cat = (value & #private_mod::CAT_MASK) >> #private_mod::VAR_BITS;
+
+                #[doc = " Checks if the set contains the error category."]
+                #[inline]
+                pub fn contains(&self, cat: #err_cat_name) -> bool {
+                    self.0 & Self::bit(cat) != 0
+                }
+
+                #[doc = " Checks if the set contains no error categories."]
+                #[inline]
+                pub fn is_empty(&self) -> bool {
+                    self.0 == 0
+                }
+            }
+
+            impl core::convert::From<#err_cat_name> for #err_cat_set_name {
+                #[inline]
+                fn from(cat: #err_cat_name) -> Self {
+                    Self(Self::bit(cat))
+                }
+            }
+
+            impl core::ops::BitOr for #err_cat_name {
+                type Output = #err_cat_set_name;
+
+                #[inline]
+                fn bitor(self, rhs: Self) -> Self::Output {
+                    #err_cat_set_name::from(self) | rhs
+                }
+            }
+
+            impl core::ops::BitOr<#err_cat_name> for #err_cat_set_name {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, rhs: #err_cat_name) -> Self::Output {
+                    Self(self.0 | Self::bit(rhs))
+                }
+            }
+
+            impl core::ops::BitOr for #err_cat_set_name {
+                type Output = Self;
+
+                #[inline]
+                fn bitor(self, rhs: Self) -> Self::Output {
+                    Self(self.0 | rhs.0)
+                }
+            }
+
+            impl core::fmt::Debug for #err_cat_set_name {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_set()
+                        .entries(
+                            (0..#n_categories)
+                                .map(|i| #err_cat_name::new(i as #private_mod::R))
+                                .filter(|c| self.contains(*c)),
+                        )
+                        .finish()
+                }
+            }
+        }
+    }
+
+    fn category_kinds_tokens(&self) -> TokenStream {
+        let err_cat_name = self.err_cat_name_ident();
+        let err_cat_kinds_name = self.err_cat_kinds_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let private_mod = private_mod_ident();
+        let returned_by = format!(" Returned by [{0}::kinds].", self.module.err_cat_name());
+        quote! {
+            #[doc = " An iterator over the error kinds of an error category."]
+            #[doc = ""]
+            #[doc = #returned_by]
+            #[derive(Clone, Debug)]
+            pub struct #err_cat_kinds_name {
+                cat: #err_cat_name,
+                next: usize,
+                end: usize,
+            }
+
+            impl Iterator for #err_cat_kinds_name {
+                type Item = #err_kind_name;
+
+                #[inline]
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.next == self.end {
+                        return None;
+                    }
+                    let variant = self.next as #private_mod::R;
+                    self.next += 1;
+                    Some(#err_kind_name::new(self.cat, variant))
+                }
+
+                #[inline]
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    let n = self.end - self.next;
+                    (n, Some(n))
+                }
+            }
+
+            impl DoubleEndedIterator for #err_cat_kinds_name {
+                #[inline]
+                fn next_back(&mut self) -> Option<Self::Item> {
+                    if self.next == self.end {
+                        return None;
+                    }
+                    self.end -= 1;
+                    Some(#err_kind_name::new(self.cat, self.end as #private_mod::R))
+                }
+            }
+
+            impl ExactSizeIterator for #err_cat_kinds_name {}
+
+            impl core::iter::FusedIterator for #err_cat_kinds_name {}
+
+            impl IntoIterator for #err_cat_name {
+                type Item = #err_kind_name;
+                type IntoIter = #err_cat_kinds_name;
+
+                #[inline]
+                fn into_iter(self) -> Self::IntoIter {
+                    self.kinds()
+                }
+            }
+        }
+    }
+
+    fn error_kind_tokens(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_name = self.err_name_ident();
+        let message_doc = self.message_doc_tokens("error");
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kind_name_str = self.module.err_kind_name();
+        let err_cat_name = self.err_cat_name_ident();
+        let private_mod = private_mod_ident();
+        let error_names_mod = error_names_mod_ident();
+        let error_displays_mod = error_displays_mod_ident();
+        let err_kind_doc = doc_tokens(self.module.err_kind_doc());
+        let category_max_comparison = self.category_max_comparison();
+        let track_caller = self.track_caller_tokens();
+        let arbitrary = self.arbitrary_tokens();
+        let serde = self.serde_tokens();
+        let assoc_consts = self.err_kind_assoc_consts_tokens();
+        let (ord_derive, ord_impl) = self.err_kind_ord_tokens();
+        let category_index = self.err_kind_category_index_tokens();
+        let from_value_unchecked = self.err_kind_from_value_unchecked_tokens();
+        let default_impl = self.err_kind_default_tokens();
+        let groups = self.err_kind_groups_tokens();
+        let kind_iter = self.err_kind_iter_tokens();
+        let (from_name, from_str_impl) = self.err_kind_from_str_tokens();
+        let error_fn = self.err_kind_error_fn_tokens();
+        let display_name = if self.module.display_with_code() {
+            quote! {
+                f.pad(#error_names_mod::C[self.category_value() as usize][self.variant() as usize])
+            }
+        } else {
+            quote! { f.pad(self.name()) }
+        };
+        let debug_val = if self.module.debug_stable() {
+            TokenStream::default()
+        } else {
+            quote! { .field("val", &self.0) }
+        };
+        let result_from_err_kind = if self.module.result_from_err_kind() {
+            quote! {
+                impl<T> core::convert::From<#err_kind_name> for Result<T, #err_name> {
+                    #[inline]
+                    #track_caller
+                    fn from(v: #err_kind_name) -> Self {
+                        Err(v.into())
+                    }
+                }
+            }
+        } else {
+            TokenStream::default()
+        };
+
+        let err_kind_new_tokens = if self.bits.category == 0 {
+            quote! { assert!(cat.0 == #private_mod::CAT_MAX); Self(variant) }
+        } else {
+            quote! { Self(cat.0 << #private_mod::VAR_BITS | variant) }
+        };
+
+        let value_widths = self.value_widths_tokens("error kind value");
+
+        let cat_value_tokens = if self.bits.category == 0 {
+            quote! { #private_mod::CAT_MAX }
+        } else {
+            quote! { (self.0 & #private_mod::CAT_MASK) >> #private_mod::VAR_BITS }
+        };
+
+        let from_value_tokens = if self.bits.category == 0 {
+            quote! {
+                if value <= #private_mod::VAR_MAXES[0] {
+                    Some(Self::new(#err_cat_name::new(0), value))
+                } else {
+                    None
+                }
+            }
+        } else {
+            quote! {
+                let cat = (value & #private_mod::CAT_MASK) >> #private_mod::VAR_BITS;
                 let variant = value & #private_mod::VAR_MASK;
                 if cat #category_max_comparison #private_mod::CAT_MAX && variant <= #private_mod::VAR_MAXES[cat as usize] {
                     Some(Self::new(#err_cat_name::new(cat), variant))
@@ -382,7 +1186,14 @@ impl<'a> ModuleGenerator<'a> {
 
         quote! {
             #err_kind_doc
-            #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            #[doc = ""]
+            #[doc = " The numeric value of an error kind isn't stable. It depends on the"]
+            #[doc = " position of the error kind in the specification, so it may change"]
+            #[doc = " when the specification changes. The conversions from and to the value,"]
+            #[doc = " e.g., [from_value](Self::from_value) and [value](Self::value), are"]
+            #[doc = " consistent within the same generated code only. Use the error kind"]
+            #[doc = " constants or names to identify error kinds across versions."]
+            #[derive(Copy, Clone, Eq, PartialEq, #ord_derive)]
             #[repr(transparent)]
             pub struct #err_kind_name(#private_mod::R);
 
@@ -392,30 +1203,62 @@ impl<'a> ModuleGenerator<'a> {
                 }
 
                 #[inline]
-                fn category_value(&self) -> #private_mod::R {
+                const fn category_value(&self) -> #private_mod::R {
                     #cat_value_tokens
                 }
 
+                #[doc = " Returns the error category."]
                 #[inline]
-                fn variant_value(&self) -> #private_mod::R {
-                    self.0 & #private_mod::VAR_MASK
+                pub const fn category(&self) -> #err_cat_name {
+                    #err_cat_name::new(self.category_value())
                 }
 
-                #[doc = " Returns the error category."]
+                #[doc = " Returns `true` if the error kind belongs to the error category."]
+                #[doc = ""]
+                #[doc = " Unlike comparing the [category](Self::category), this can be used"]
+                #[doc = " in const contexts."]
                 #[inline]
-                pub fn category(&self) -> #err_cat_name {
-                    #err_cat_name::new(self.category_value())
+                pub const fn is_in(&self, cat: #err_cat_name) -> bool {
+                    self.category_value() == cat.0
+                }
+
+                #error_fn
+
+                #[doc = " Returns the error kind index, i.e., its position among all error kinds"]
+                #[doc = " of the module, in the range `0..N`, where `N` is the number of error kinds."]
+                #[doc = ""]
+                #[doc = " Unlike the [value](Self::value), the indices are dense and don't depend"]
+                #[doc = " on the bit layout of the error kinds, so they can index arrays sized"]
+                #[doc = " by the number of error kinds."]
+                #[inline]
+                pub fn index(&self) -> usize {
+                    #private_mod::CAT_OFFSETS[self.category_value() as usize] + self.variant() as usize
                 }
 
+                #category_index
+
                 #[doc = " Returns the error kind name."]
                 #[inline]
                 pub fn name(&self) -> &'static str {
-                    #error_names_mod::A[self.category_value() as usize][self.variant_value() as usize]
+                    #error_names_mod::A[self.category_value() as usize][self.variant() as usize]
                 }
 
+                #[doc = " An alias of [name](Self::name)."]
                 #[inline]
-                fn display(&self) -> &'static str {
-                    #error_displays_mod::A[self.category_value() as usize][self.variant_value() as usize]
+                pub fn as_str(&self) -> &'static str {
+                    self.name()
+                }
+
+                #[doc = " Returns the error kind name qualified by the category name, e.g., `CATEGORY::NAME`."]
+                #[inline]
+                pub fn qualified_name(&self) -> &'static str {
+                    #error_names_mod::Q[self.category_value() as usize][self.variant() as usize]
+                }
+
+                #message_doc
+                #[inline]
+                pub fn message(&self) -> &'static str {
+                    #error_displays_mod::A[self.category_value() as usize][self.variant() as usize]
                 }
 
                 #[doc = " Returns the error kind value as the underlying Rust type."]
@@ -424,14 +1267,30 @@ impl<'a> ModuleGenerator<'a> {
                     self.0
                 }
 
+                #value_widths
+
+                #[doc = " Returns the error kind variant, i.e., its index within the error category."]
+                #[inline]
+                pub const fn variant(&self) -> #private_mod::R {
+                    self.0 & #private_mod::VAR_MASK
+                }
+
                 #[doc = " Creates an error kind from a raw value of the underlying Rust type."]
                 #[inline]
                 pub fn from_value(value: #private_mod::R) -> Option<Self> {
                     #from_value_tokens
                 }
+
+                #from_value_unchecked
+
+                #from_name
+
+                #kind_iter
+
+                #groups
             }
 
-            impl tighterror::Kind for #err_kind_name {
+            impl #rt::Kind for #err_kind_name {
                 type R = #private_mod::R;
                 type Category = #err_cat_name;
 
@@ -452,6 +1311,11 @@ impl<'a> ModuleGenerator<'a> {
                     self.value()
                 }
 
+                #[inline]
+                fn variant(&self) -> Self::R {
+                    self.variant()
+                }
+
                 #[inline]
                 fn from_value(value: Self::R) -> Option<Self> {
                     Self::from_value(value)
@@ -461,7 +1325,18 @@ impl<'a> ModuleGenerator<'a> {
             impl core::fmt::Display for #err_kind_name {
                 #[inline]
                 fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.pad(self.name())
+                    #display_name
+                }
+            }
+
+            impl core::hash::Hash for #err_kind_name {
+                #[doc = " Hashes the error kind value, consistently with `PartialEq`."]
+                #[doc = ""]
+                #[doc = " The hash is the hash of [value](Self::value), so it doesn't depend"]
+                #[doc = " on the environment, e.g., `std` or `no_std`, but on the hasher only."]
+                #[inline]
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.value().hash(state);
                 }
             }
 
@@ -471,716 +1346,2805 @@ impl<'a> ModuleGenerator<'a> {
                     f.debug_struct(#err_kind_name_str)
                         .field("cat", &#private_mod::Ident(self.category().name()))
                         .field("var", &#private_mod::Ident(self.name()))
-                        .field("val", &self.0)
+                        #debug_val
                         .finish()
                 }
             }
 
+            impl core::convert::From<#err_kind_name> for #private_mod::R {
+                #[inline]
+                fn from(kind: #err_kind_name) -> Self {
+                    kind.value()
+                }
+            }
+
+            impl core::convert::TryFrom<#private_mod::R> for #err_kind_name {
+                type Error = #rt::InvalidValueError<#private_mod::R>;
+
+                #[inline]
+                fn try_from(value: #private_mod::R) -> Result<Self, Self::Error> {
+                    Self::from_value(value).ok_or(#rt::InvalidValueError::new(value))
+                }
+            }
+
+            #from_str_impl
+            #ord_impl
+            #default_impl
             #result_from_err_kind
+            #arbitrary
+            #serde
+            #assoc_consts
         }
     }
 
-    fn error_tokens(&self) -> TokenStream {
-        let err_name = self.err_name_ident();
+    fn err_kind_assoc_consts_tokens(&self) -> TokenStream {
+        if !self.module.assoc_consts() {
+            return TokenStream::default();
+        }
         let err_kind_name = self.err_kind_name_ident();
-        let err_cat_name = self.err_cat_name_ident();
-        let err_doc = doc_tokens(self.module.err_doc());
-        let private_mod = private_mod_ident();
-        let result_from_err = if self.module.result_from_err() {
-            quote! {
-                impl<T> core::convert::From<#err_name> for core::result::Result<T, #err_name> {
-                    #[inline]
-                    fn from(err: #err_name) -> Self {
-                        Err(err)
-                    }
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let ident = format_ident!("{}", self.module.err_kind_assoc_const_name(c, e));
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                let doc = doc_tokens(self.module.err_kind_const_doc(c, e));
+                quote! {
+                    #doc
+                    pub const #ident: Self = #err_kinds_mod::#err_const;
                 }
-            }
-        } else {
-            TokenStream::default()
-        };
-        let error_trait = if self.module.error_trait(self.spec.main.no_std) {
+            });
             quote! {
-                impl std::error::Error for #err_name {}
+                #(#err_iter)*
             }
-        } else {
-            TokenStream::default()
-        };
+        });
         quote! {
-            #err_doc
-            #[derive(Debug)]
-            #[repr(transparent)]
-            pub struct #err_name(#err_kind_name);
+            impl #err_kind_name {
+                #(#iter)*
+            }
+        }
+    }
 
-            impl #err_name {
-                #[doc = " Returns the error kind."]
+    fn err_kind_default_tokens(&self) -> TokenStream {
+        let Some((c, e)) = self.module.default_error() else {
+            return TokenStream::default();
+        };
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_const = self.err_const_tokens(c, e, !self.module.flat_kinds());
+        let track_caller = self.track_caller_tokens();
+        quote! {
+            impl core::default::Default for #err_kind_name {
                 #[inline]
-                pub fn kind(&self) -> #err_kind_name {
-                    self.0
+                fn default() -> Self {
+                    #err_kinds_mod::#err_const
                 }
+            }
 
-                #[doc = " Returns the error origin location."]
+            impl core::default::Default for #err_name {
                 #[inline]
-                pub fn location(&self) -> tighterror::Location {
-                    tighterror::Location::undefined()
+                #track_caller
+                fn default() -> Self {
+                    #err_kind_name::default().into()
                 }
             }
+        }
+    }
 
-            impl tighterror::Error for #err_name {
-                type R = #private_mod::R;
-                type Category = #err_cat_name;
-                type Kind = #err_kind_name;
+    /// Returns the names an error kind can be parsed from.
+    ///
+    /// A category-qualified name, e.g., `Parser::BAD_TOKEN`, and the
+    /// qualified name, e.g., `PARSER::BAD_TOKEN`, are always accepted.
+    /// A plain name is accepted only if it is unique in the module.
+    fn err_kind_parse_names(&self, c: &CategorySpec, e: &ErrorSpec) -> Vec<String> {
+        let mut names = vec![format!("{}{}{}", c.name, GROUP_REF_SEPARATOR, e.name)];
+        let qualified_name = err_kind_qualified_name(c, e);
+        if names[0] != qualified_name {
+            names.push(qualified_name);
+        }
+        if self
+            .module
+            .errors_iter()
+            .filter(|o| o.name == e.name)
+            .count()
+            == 1
+        {
+            names.insert(0, e.name.clone());
+        }
+        names
+    }
 
-                #[inline]
-                fn kind(&self) -> Self::Kind {
-                    self.kind()
-                }
+    fn private_names_table_tokens(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = &self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let entries = self
+            .module
+            .categories
+            .iter()
+            .flat_map(|c| c.errors.iter().map(move |e| (c, e)))
+            .flat_map(|(c, e)| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                self.err_kind_parse_names(c, e)
+                    .into_iter()
+                    .map(move |n| quote! { (#n, super::#err_kinds_mod::#err_const) })
+            })
+            .collect::<Vec<_>>();
+        let n_entries = Literal::usize_unsuffixed(entries.len());
+        quote! {
+            pub static NAMES: [(&str, super::#err_kind_name); #n_entries] = [
+                #(#entries),*
+            ];
+        }
+    }
 
-                #[inline]
-                fn location(&self) -> tighterror::Location {
-                    self.location()
-                }
+    fn err_kind_from_str_tokens(&self) -> (TokenStream, TokenStream) {
+        let rt = self.runtime_path_tokens();
+        let err_kind_name = self.err_kind_name_ident();
+        let private_mod = private_mod_ident();
+        let from_name = quote! {
+            #[doc = " Creates an error kind from its name."]
+            #[doc = ""]
+            #[doc = " A name qualified by the category name, e.g., `General::BAD_FILE`,"]
+            #[doc = " or by the category constant name as returned by [qualified_name](Self::qualified_name),"]
+            #[doc = " e.g., `GENERAL::BAD_FILE`, is always accepted. A plain name is accepted"]
+            #[doc = " only if it is unique in the module."]
+            #[doc = ""]
+            #[doc = " Returns `None` if the name doesn't denote an error kind."]
+            pub fn from_name(name: &str) -> Option<Self> {
+                #private_mod::NAMES
+                    .iter()
+                    .find(|(n, _)| *n == name)
+                    .map(|(_, k)| *k)
             }
+        };
+        let from_str_impl = quote! {
+            impl core::str::FromStr for #err_kind_name {
+                type Err = #rt::ParseKindError;
 
-            impl core::convert::From<#err_kind_name> for #err_name {
                 #[inline]
-                fn from(kind: #err_kind_name) -> Self {
-                    Self(kind)
+                fn from_str(s: &str) -> Result<Self, Self::Err> {
+                    Self::from_name(s).ok_or(#rt::ParseKindError)
                 }
             }
 
-            impl core::fmt::Display for #err_name {
-                #[inline]
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.pad(self.kind().display())
-                }
-            }
+            impl<'a> core::convert::TryFrom<&'a str> for #err_kind_name {
+                type Error = #rt::ParseKindError;
 
-            impl core::cmp::PartialEq for #err_name {
-                #[doc = " Checks equality based on the error kind only."]
                 #[inline]
-                fn eq(&self, other: &#err_name) -> bool {
-                    self.0 == other.0
+                fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+                    Self::from_name(s).ok_or(#rt::ParseKindError)
                 }
             }
-
-            #result_from_err
-            #error_trait
-        }
+        };
+        (from_name, from_str_impl)
     }
 
-    fn error_kind_constants_tokens(&self) -> TokenStream {
-        let err_kinds_mod = error_kinds_mod_ident();
-        let err_kind_name = self.err_kind_name_ident();
-        let mut tokens = TokenStream::default();
-        for c in &self.module.categories {
-            let cat_tokens = self.error_kind_category_constants_tokens(c);
-            if self.module.flat_kinds() {
-                tokens = quote! {
-                    #tokens
-                    #cat_tokens
-                };
-            } else {
-                let cat_mod_ident = format_ident!("{}", c.module_name());
-                let cat_mod_doc = doc_tokens(&format!("{} category error kind constants.", c.name));
-                tokens = quote! {
-                    #tokens
+    fn err_kind_iter_tokens(&self) -> TokenStream {
+        if !self.module.kind_iter() {
+            return TokenStream::default();
+        }
+        let private_mod = private_mod_ident();
+        let variants = kind_variants_ident();
+        let names_iter = self.module.errors_iter().map(|e| e.name.as_str());
+        quote! {
+            #[doc = " The names of all error kinds, in the order of [iter](Self::iter)."]
+            #[doc = ""]
+            #[doc = " A name may repeat if errors in different categories share it."]
+            pub const #variants: &'static [&'static str] = &[
+                #(#names_iter),*
+            ];
 
-                    #cat_mod_doc
-                    pub mod #cat_mod_ident {
-                        use super::c;
-                        use super::EK;
-                        #cat_tokens
-                    }
-                };
+            #[doc = " Returns an iterator over all error kinds, ordered by value."]
+            #[inline]
+            pub fn iter() -> core::iter::Copied<core::slice::Iter<'static, Self>> {
+                #private_mod::ALL.iter().copied()
             }
         }
+    }
 
-        let categories_mod = categories_mod_ident();
-        quote! {
-            #[doc = " Error kind constants."]
-            pub mod #err_kinds_mod {
-                use super::#err_kind_name as EK;
-                use super::#categories_mod as c;
-                #tokens
+    fn err_kind_groups_tokens(&self) -> TokenStream {
+        let private_mod = private_mod_ident();
+        let iter = self.module.groups.iter().map(|g| {
+            let table = group_table_ident(g);
+            let method = format_ident!("{}", g.method_name());
+            let doc = doc_tokens(&format!(
+                "Returns `true` if the error kind belongs to the `{}` group.",
+                g.name
+            ));
+            quote! {
+                #doc
+                #[inline]
+                pub fn #method(&self) -> bool {
+                    #private_mod::#table.contains(self)
+                }
             }
+        });
+        quote! {
+            #(#iter)*
         }
     }
 
-    fn error_kind_category_constants_tokens(&self, c: &CategorySpec) -> TokenStream {
-        let mut tokens = TokenStream::default();
-        for (i, e) in c.errors.iter().enumerate() {
-            let cat_ident = format_ident!("{}", c.ident_name());
-            let err_value = self.usize_to_repr_type_literal(i).unwrap();
-            let err_ident = format_ident!("{}", e.name);
-            let err_doc = doc_tokens(self.module.err_kind_const_doc(c, e));
-            tokens = quote! {
-                #tokens
-
-                #err_doc
-                pub const #err_ident: EK = EK::new(c::#cat_ident, #err_value);
-            };
+    fn err_kind_from_value_unchecked_tokens(&self) -> TokenStream {
+        if !self.module.allow_unsafe() {
+            return TokenStream::default();
+        }
+        let private_mod = private_mod_ident();
+        let doc = doc_tokens(
+            "Creates an error kind from a raw value of the underlying Rust type without checking its validity.\n\n# Safety\n\nThe value must be a valid error kind value, e.g., returned by [value](Self::value).\nUsing an invalid value is undefined behavior.",
+        );
+        quote! {
+            #doc
+            #[inline]
+            pub const unsafe fn from_value_unchecked(value: #private_mod::R) -> Self {
+                Self(value)
+            }
         }
-        tokens
     }
 
-    fn category_constants_tokens(&self) -> TokenStream {
-        let err_cat_name = self.err_cat_name_ident();
-        let mut tokens = quote! {};
-        for (i, c) in self.module.categories.iter().enumerate() {
-            let cat_value = self.usize_to_repr_type_literal(i).unwrap();
-            let cat_name_upper_snake = format_ident!("{}", c.ident_name());
-            let const_doc = doc_tokens(self.module.cat_const_doc(c));
-            let single = quote! {
-                #const_doc
-                pub const #cat_name_upper_snake: C = C::new(#cat_value);
-            };
-            tokens = quote! {
-                #tokens
-                #single
-            };
+    fn err_kind_category_index_tokens(&self) -> TokenStream {
+        if !self.module.kind_to_cat() {
+            return TokenStream::default();
         }
-
-        let categories_mod = categories_mod_ident();
+        let private_mod = private_mod_ident();
         quote! {
-            #[doc = " Error category constants."]
-            pub mod #categories_mod {
-                use super::#err_cat_name as C;
-                #tokens
+            #[doc = " Returns the error category index using a lookup table."]
+            #[inline]
+            pub fn category_index(&self) -> usize {
+                #private_mod::KIND_TO_CAT[self.index()] as usize
             }
         }
     }
 
-    fn needs_variants_module(&self) -> bool {
-        self.module.has_variant_types()
+    fn err_kind_ord_tokens(&self) -> (TokenStream, TokenStream) {
+        match self.module.order_by() {
+            OrderBy::Value => (quote! { Ord, PartialOrd, }, TokenStream::default()),
+            OrderBy::Name => {
+                let err_kind_name = self.err_kind_name_ident();
+                let ord_impl = quote! {
+                    impl core::cmp::PartialOrd for #err_kind_name {
+                        #[inline]
+                        fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+                            Some(self.cmp(other))
+                        }
+                    }
+
+                    impl core::cmp::Ord for #err_kind_name {
+                        #[inline]
+                        fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+                            self.name()
+                                .cmp(other.name())
+                                .then_with(|| self.0.cmp(&other.0))
+                        }
+                    }
+                };
+                (TokenStream::default(), ord_impl)
+            }
+        }
     }
 
-    fn variants_module_tokens(&self) -> TokenStream {
-        if !self.needs_variants_module() {
+    fn arbitrary_tokens(&self) -> TokenStream {
+        if !self.module.arbitrary() {
             return TokenStream::default();
         }
-
-        let variant_types_mod_tokens = self.variant_types_module_tokens();
-        let variants_mod = variants_mod_ident();
+        let cfg = cfg_feature_tokens(Some("arbitrary"), self.module.doc_cfg());
+        let err_kind_name = self.err_kind_name_ident();
+        let private_mod = private_mod_ident();
         quote! {
-            #[doc = " Error variant types and constants."]
-            pub mod #variants_mod {
-                #variant_types_mod_tokens
+            #cfg
+            impl<'a> arbitrary::Arbitrary<'a> for #err_kind_name {
+                #[inline]
+                fn arbitrary(u: &mut arbitrary::Unstructured<'a>) -> arbitrary::Result<Self> {
+                    u.choose(&#private_mod::ALL).copied()
+                }
             }
         }
     }
 
-    fn variant_types_module_tokens(&self) -> TokenStream {
-        let mut tokens = TokenStream::default();
-
-        if !self.module.has_variant_types() {
-            return tokens;
+    fn proptest_tokens(&self) -> TokenStream {
+        if !self.module.proptest() {
+            return TokenStream::default();
         }
-
-        let kinds_mod = error_kinds_mod_ident();
-        let private_mod = private_mod_ident();
-        let category_names_mod = category_names_mod_ident();
-        let categories_mod = categories_mod_ident();
-        let display_mod = error_displays_mod_ident();
-        let error_names_mod = error_names_mod_ident();
+        let cfg = cfg_feature_tokens(Some("proptest"), self.module.doc_cfg());
         let err_kind_name = self.err_kind_name_ident();
-        let err_name = self.err_name_ident();
-        let cat_name = self.err_cat_name_ident();
-        let use_display_mod = if self.module.has_display_variant_types() {
-            quote! { #display_mod, }
-        } else {
-            TokenStream::default()
-        };
-        let use_tokens = quote! {
-            super::{
-                #use_display_mod #private_mod, #category_names_mod,
-                #error_names_mod,
-                #categories_mod, #kinds_mod, #err_kind_name,
-                #err_name, #cat_name
+        let proptest_fn = format_ident!("{}", self.module.proptest_fn_name());
+        let private_mod = private_mod_ident();
+        quote! {
+            #[doc = " Returns a `proptest` strategy choosing uniformly among all error kinds."]
+            #cfg
+            #[inline]
+            pub fn #proptest_fn() -> proptest::sample::Select<#err_kind_name> {
+                proptest::sample::select(&#private_mod::ALL[..])
             }
-        };
-        if self.module.flat_kinds() {
-            tokens = quote! { use super::#use_tokens; };
-        }
 
-        for c in &self.module.categories {
-            if !self.module.cat_has_variant_types(c) {
-                continue;
+            #cfg
+            impl proptest::arbitrary::Arbitrary for #err_kind_name {
+                type Parameters = ();
+                type Strategy = proptest::sample::Select<Self>;
+
+                #[inline]
+                fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+                    #proptest_fn()
+                }
             }
+        }
+    }
 
-            let cvt = self.category_variant_types_tokens(c);
-            if self.module.flat_kinds() {
-                tokens = quote! {
-                    #tokens
-                    #cvt
-                };
-            } else {
-                let cat_mod_ident = format_ident!("{}", c.module_name());
-                let cat_mod_doc = doc_tokens(&format!("{} category error variant types.", c.name));
+    fn serde_tokens(&self) -> TokenStream {
+        if !self.module.serde() {
+            return TokenStream::default();
+        }
+        let cfg = cfg_feature_tokens(Some("serde"), self.module.doc_cfg());
+        let err_kind_name = self.err_kind_name_ident();
+        let private_mod = private_mod_ident();
+        let (serialize, deserialize) = match self.module.serde_repr() {
+            SerdeRepr::Name => (
+                quote! {
+                    serializer.serialize_str(self.qualified_name())
+                },
+                quote! {
+                    struct Visitor;
 
-                tokens = quote! {
-                    #tokens
+                    impl serde::de::Visitor<'_> for Visitor {
+                        type Value = #err_kind_name;
 
-                    #cat_mod_doc
-                    pub mod #cat_mod_ident {
-                        use super::super::#use_tokens;
-                        #cvt
+                        fn expecting(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                            f.write_str("an error kind name")
+                        }
+
+                        fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                            #err_kind_name::from_name(v)
+                                .ok_or_else(|| E::invalid_value(serde::de::Unexpected::Str(v), &self))
+                        }
                     }
-                };
-            }
-        }
 
-        let types_mod = types_mod_ident();
+                    deserializer.deserialize_str(Visitor)
+                },
+            ),
+            SerdeRepr::Value => (
+                quote! {
+                    serde::Serialize::serialize(&self.value(), serializer)
+                },
+                quote! {
+                    let value = <#private_mod::R as serde::Deserialize>::deserialize(deserializer)?;
+                    Self::from_value(value).ok_or_else(|| {
+                        serde::de::Error::invalid_value(
+                            serde::de::Unexpected::Unsigned(value.into()),
+                            &"an error kind value",
+                        )
+                    })
+                },
+            ),
+        };
         quote! {
-            #[doc = " Error variant types."]
-            pub mod #types_mod {
-                #tokens
+            #cfg
+            impl serde::Serialize for #err_kind_name {
+                fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    #serialize
+                }
             }
-        }
-    }
-
-    fn category_variant_types_tokens(&self, c: &CategorySpec) -> TokenStream {
-        let mut tokens = TokenStream::default();
 
-        for e in &c.errors {
-            if !self.module.err_has_variant_type(c, e) {
-                continue;
+            #cfg
+            impl<'de> serde::Deserialize<'de> for #err_kind_name {
+                fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    #deserialize
+                }
             }
-            let evt = self.error_variant_type_tokens(c, e);
-            tokens = quote! {
-                #tokens
-                #evt
-            };
         }
-
-        tokens
     }
 
-    fn error_variant_type_tokens(&self, c: &CategorySpec, e: &ErrorSpec) -> TokenStream {
-        let display_mod = error_displays_mod_ident();
-        let kinds_mod = error_kinds_mod_ident();
+    fn error_tokens(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_cat_name = self.err_cat_name_ident();
+        let err_doc = doc_tokens(self.module.err_doc());
+        let message_doc = self.message_doc_tokens("error kind");
+        let display_message = if self.module.display_with_code()
+            && self.module.display_resolver().is_none()
+        {
+            let error_displays_mod = error_displays_mod_ident();
+            quote! {
+                f.pad(#error_displays_mod::C[self.0.category_value() as usize][self.0.variant() as usize])
+            }
+        } else {
+            let display_message = self.display_message_tokens(quote! { self.message() });
+            self.display_with_code_tokens(display_message, quote! { self.0.value() })
+        };
         let private_mod = private_mod_ident();
-        let category_names_mod = category_names_mod_ident();
-        let categories_mod = categories_mod_ident();
-        let error_names_mod = error_names_mod_ident();
-        let cat_const_ident = format_ident!("{}", c.ident_name());
-        let err_kind_name_ident = self.err_kind_name_ident();
-        let err_name_ident = self.err_name_ident();
-        let cat_mod = format_ident!("{}", c.module_name());
-        let cat_name_ident = self.err_cat_name_ident();
-        let err_ident = format_ident!("{}", e.name);
-        let err_kind_const_ident = format_ident!("{}", e.name);
-        let var_type_name = e.variant_type_name();
-        let var_type_ident = format_ident!("{}", var_type_name);
-        let err_doc = doc_tokens(self.module.err_kind_const_doc(c, e));
-        let display = if e.display.is_some() {
-            quote! { #display_mod::#cat_mod::#err_ident }
+        let result_from_err = if self.module.result_from_err() {
+            quote! {
+                impl<T> core::convert::From<#err_name> for core::result::Result<T, #err_name> {
+                    #[inline]
+                    fn from(err: #err_name) -> Self {
+                        Err(err)
+                    }
+                }
+            }
         } else {
-            quote! { <Self as tighterror::VariantType>::NAME }
+            TokenStream::default()
         };
         let error_trait = if self.module.error_trait(self.spec.main.no_std) {
-            quote! { impl std::error::Error for #var_type_ident {} }
+            let error_trait_path = self.error_trait_path();
+            quote! {
+                impl #error_trait_path for #err_name {}
+            }
+        } else {
+            TokenStream::default()
+        };
+        let boxed_error = self.boxed_error_tokens();
+        let track_caller = self.track_caller_tokens();
+        let from_kind_at = if self.module.location() {
+            quote! {
+                #[doc = " Creates an error of the kind at the location."]
+                #[inline]
+                pub const fn from_kind_at(kind: #err_kind_name, location: #rt::Location) -> Self {
+                    Self(kind, location)
+                }
+            }
         } else {
             TokenStream::default()
         };
-        let err_kind_tokens = if self.module.flat_kinds() {
-            quote! { #kinds_mod::#err_kind_const_ident }
+        let (err_struct, location_fn, from_kind_fn, debug_impl) = if self.module.location() {
+            let err_name_str = self.module.err_name();
+            (
+                quote! {
+                    pub struct #err_name(#err_kind_name, #rt::Location);
+                },
+                quote! { self.1 },
+                quote! {
+                    #[inline]
+                    #[track_caller]
+                    fn from(kind: #err_kind_name) -> Self {
+                        Self(kind, #rt::Location::caller())
+                    }
+                },
+                quote! {
+                    impl core::fmt::Debug for #err_name {
+                        #[inline]
+                        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                            f.debug_struct(#err_name_str)
+                                .field("kind", &self.0)
+                                .field("location", &format_args!("{}", self.1))
+                                .finish()
+                        }
+                    }
+                },
+            )
         } else {
-            quote! { #kinds_mod::#cat_mod::#err_kind_const_ident }
+            (
+                quote! {
+                    #[derive(Debug)]
+                    #[repr(transparent)]
+                    pub struct #err_name(#err_kind_name);
+                },
+                quote! { #rt::Location::undefined() },
+                quote! {
+                    #[inline]
+                    fn from(kind: #err_kind_name) -> Self {
+                        Self(kind)
+                    }
+                },
+                TokenStream::default(),
+            )
         };
         quote! {
             #err_doc
-            #[derive(Clone, Copy)]
-            #[non_exhaustive]
-            pub struct #var_type_ident;
+            #err_struct
 
-            impl #var_type_ident {
-                #[doc = " Returns the struct name."]
+            impl #err_name {
+                #[doc = " Returns the error kind."]
                 #[inline]
-                pub fn name(&self) -> &'static str {
-                    <#var_type_ident as tighterror::VariantType>::NAME
+                pub fn kind(&self) -> #err_kind_name {
+                    self.0
                 }
 
-                #[doc = " Returns the error kind constant."]
+                #[doc = " Returns the error origin location."]
                 #[inline]
-                pub fn kind(&self) -> #err_kind_name_ident {
-                    <#var_type_ident as tighterror::VariantType>::KIND
+                pub fn location(&self) -> #rt::Location {
+                    #location_fn
                 }
 
-                #[doc = " Returns the error category constant."]
+                #message_doc
                 #[inline]
-                pub fn category(&self) -> #cat_name_ident {
-                    <#var_type_ident as tighterror::VariantType>::CATEGORY
+                pub fn message(&self) -> &'static str {
+                    self.0.message()
                 }
-            }
 
-            impl core::fmt::Display for #var_type_ident {
-                #[inline]
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.pad(#display)
-                }
+                #from_kind_at
             }
 
-            impl core::fmt::Debug for #var_type_ident {
+            impl #rt::Error for #err_name {
+                type R = #private_mod::R;
+                type Category = #err_cat_name;
+                type Kind = #err_kind_name;
+
                 #[inline]
-                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-                    f.debug_struct(#var_type_name)
-                        .field("cat", &#private_mod::Ident(#category_names_mod::#cat_const_ident))
-                        .field("var", &#private_mod::Ident(#error_names_mod::#cat_mod::#err_ident))
-                        .finish()
+                fn kind(&self) -> Self::Kind {
+                    self.kind()
+                }
+
+                #[inline]
+                fn location(&self) -> #rt::Location {
+                    self.location()
                 }
             }
 
-            impl tighterror::VariantType for #var_type_ident {
-                type R = #private_mod::R;
-                type Category = #cat_name_ident;
-                type Kind = #err_kind_name_ident;
+            impl core::convert::From<#err_kind_name> for #err_name {
+                #from_kind_fn
+            }
 
-                const CATEGORY: Self::Category = #categories_mod::#cat_const_ident;
-                const KIND: Self::Kind = #err_kind_tokens;
-                const NAME: &'static str = #var_type_name;
+            impl core::convert::From<&#err_kind_name> for #err_name {
+                #[inline]
+                #track_caller
+                fn from(kind: &#err_kind_name) -> Self {
+                    Self::from(*kind)
+                }
             }
 
-            impl core::convert::From<#var_type_ident> for #err_kind_name_ident {
+            impl core::convert::From<#err_name> for #private_mod::R {
                 #[inline]
-                fn from(_: #var_type_ident) -> #err_kind_name_ident {
-                    <#var_type_ident as tighterror::VariantType>::KIND
+                fn from(err: #err_name) -> Self {
+                    err.kind().value()
                 }
             }
 
-            impl core::convert::From<#var_type_ident> for #err_name_ident {
+            #debug_impl
+
+            impl core::fmt::Display for #err_name {
                 #[inline]
-                fn from(_: #var_type_ident) -> Self {
-                    <#var_type_ident as tighterror::VariantType>::KIND.into()
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #display_message
                 }
             }
 
-            impl<T> core::convert::From<#var_type_ident> for core::result::Result<T, #err_name_ident> {
+            impl core::cmp::PartialEq for #err_name {
+                #[doc = " Checks equality based on the error kind only."]
                 #[inline]
-                fn from(_: #var_type_ident) -> Self {
-                    <#var_type_ident as tighterror::VariantType>::KIND.into()
+                fn eq(&self, other: &#err_name) -> bool {
+                    self.0 == other.0
                 }
             }
 
-            impl<T> core::convert::From<#var_type_ident> for core::result::Result<T, #var_type_ident> {
+            impl core::cmp::Eq for #err_name {}
+
+            impl core::hash::Hash for #err_name {
+                #[doc = " Hashes the error kind only, consistently with `PartialEq`."]
                 #[inline]
-                fn from(v: #var_type_ident) -> Self {
-                    Err(v)
+                fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+                    self.0.hash(state);
                 }
             }
 
+            #result_from_err
             #error_trait
+            #boxed_error
         }
     }
 
-    fn test_tokens(&self) -> TokenStream {
-        let tests_mod = tests_mod_ident();
-        if self.opts.test {
-            let test_tokens = self.test_tokens_impl();
-            quote! {
-                #[cfg(test)]
-                mod #tests_mod {
-                    use super::*;
-                    #test_tokens
+    fn boxed_error_tokens(&self) -> TokenStream {
+        if self.spec.main.no_std() || !self.module.error_trait(self.spec.main.no_std) {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let track_caller = self.track_caller_tokens();
+        quote! {
+            impl core::convert::From<#err_kind_name>
+                for std::boxed::Box<dyn std::error::Error + Send + Sync>
+            {
+                #[inline]
+                #track_caller
+                fn from(kind: #err_kind_name) -> Self {
+                    std::boxed::Box::new(#err_name::from(kind))
                 }
             }
+        }
+    }
+
+    fn error_trait_path(&self) -> TokenStream {
+        if self.module.core_error() {
+            quote! { core::error::Error }
         } else {
-            TokenStream::default()
+            quote! { std::error::Error }
         }
     }
 
-    fn test_tokens_impl(&self) -> TokenStream {
-        let ut_category_name = self.ut_category_name_tokens();
-        let ut_category_display = self.ut_category_display();
-        let ut_category_uniqueness = self.ut_category_uniqueness();
-        let ut_category_values = self.ut_category_values();
-        let ut_err_kind_name = self.ut_err_kind_name();
-        let ut_err_kind_display = self.ut_err_kind_display();
-        let ut_err_kind_uniqueness = self.ut_err_kind_uniqueness();
-        let ut_err_kind_value_uniqueness = self.ut_err_kind_value_uniqueness();
-        let ut_err_kind_category = self.ut_err_kind_category();
-        let ut_err_kind_from_value = self.ut_err_kind_from_value();
-        let ut_err_display = self.ut_err_display();
-        let ut_variant_types_display = self.ut_variant_types_display();
-        let ut_variant_types_to_kind = self.ut_variant_types_to_kind();
-        let ut_variant_types_to_error = self.ut_variant_types_to_error();
-        let ut_variant_types_to_result = self.ut_variant_types_to_result();
-
-        quote! {
-            #ut_category_name
-            #ut_category_display
-            #ut_category_uniqueness
-            #ut_category_values
-            #ut_err_kind_name
-            #ut_err_kind_display
-            #ut_err_kind_uniqueness
-            #ut_err_kind_value_uniqueness
-            #ut_err_kind_category
-            #ut_err_kind_from_value
-            #ut_err_display
-            #ut_variant_types_display
-            #ut_variant_types_to_kind
-            #ut_variant_types_to_error
-            #ut_variant_types_to_result
+    fn bail_macro_tokens(&self) -> TokenStream {
+        if !self.module.location() {
+            return TokenStream::default();
+        }
+        let mod_name = format_ident!("{}", self.module.name());
+        let err_name = self.err_name_ident();
+        let rt = self.runtime_path_tokens();
+        let err_path = format!("crate::{}::{}", self.module.name(), self.module.err_name());
+        let doc = format!(
+            " Expands to `return Err({err_path}::from_kind_at(kind, Location::caller()))`,"
+        );
+        quote! {
+            #[doc = " Returns early with an error, capturing the macro invocation location."]
+            #[doc = ""]
+            #[doc = #doc]
+            #[doc = " hence the module must be declared at the crate root."]
+            #[allow(unused_macros)]
+            macro_rules! bail {
+                ($kind:expr $(,)?) => {
+                    return ::core::result::Result::Err($crate::#mod_name::#err_name::from_kind_at(
+                        $kind,
+                        #rt::Location::caller(),
+                    ))
+                };
+            }
+
+            #[allow(unused_imports)]
+            pub(crate) use bail;
         }
     }
 
-    fn ut_category_name_tokens(&self) -> TokenStream {
-        let categories_mod = categories_mod_ident();
-        let check_cat_name_iter = self.module.categories.iter().map(|c| {
-            let ident_name = c.ident_name();
-            let ident = format_ident!("{}", ident_name);
+    fn bridge_enum_tokens(&self) -> TokenStream {
+        let Some(b) = &self.module.bridge_enum else {
+            return TokenStream::default();
+        };
+        let path =
+            TokenStream::from_str(&b.path).expect("bridge enum path is validated by the parser");
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let arms = self
+            .module
+            .bridge_enum_variants(b)
+            .into_iter()
+            .map(|(c, e, v)| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                let variant = format_ident!("{}", v);
+                quote! { #err_kinds_mod::#err_const => Self::#variant }
+            });
+        quote! {
+            impl core::convert::From<#err_kind_name> for #path {
+                #[inline]
+                fn from(kind: #err_kind_name) -> Self {
+                    match kind {
+                        #(#arms,)*
+                        _ => unreachable!("error kinds are exhaustively mapped"),
+                    }
+                }
+            }
+
+            impl core::convert::From<#err_name> for #path {
+                #[inline]
+                fn from(err: #err_name) -> Self {
+                    Self::from(err.kind())
+                }
+            }
+        }
+    }
+
+    fn map_by_name_target(&self, name: &str) -> &ModuleSpec {
+        self.spec
+            .modules
+            .iter()
+            .find(|m| m.name() == name)
+            .expect("map_by_name target is validated by the parser")
+    }
+
+    /// Returns the paths of every error kind constant and of its same-named
+    /// constant in the `target` module. The latter is relative to the parent
+    /// module.
+    fn map_by_name_consts(&self, target: &ModuleSpec) -> Vec<(TokenStream, TokenStream)> {
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let target_mod = format_ident!("{}", target.name());
+        let target_kinds_mod = format_ident!("{}", target.kinds_mod_name());
+        self.module
+            .map_by_name_errors(target)
+            .into_iter()
+            .map(|(c, e, tc, te)| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                let target_const = self.err_const_tokens(tc, te, !target.flat_kinds());
+                (
+                    quote! { #err_kinds_mod::#err_const },
+                    quote! { #target_mod::#target_kinds_mod::#target_const },
+                )
+            })
+            .collect()
+    }
+
+    fn map_by_name_tokens(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let impls = self.module.map_by_name.iter().map(|t| {
+            let target = self.map_by_name_target(t);
+            let target_mod = format_ident!("{}", target.name());
+            let target_err_name = format_ident!("{}", target.err_name());
+            let target_err_kind_name = format_ident!("{}", target.err_kind_name());
+            let cfg = cfg_feature_tokens(target.feature.as_deref(), self.module.doc_cfg());
+            let track_caller = if target.location() {
+                quote! { #[track_caller] }
+            } else {
+                TokenStream::default()
+            };
+            let arms = self
+                .map_by_name_consts(target)
+                .into_iter()
+                .map(|(err_const, target_const)| quote! { #err_const => super::#target_const });
             quote! {
-                assert_eq!(#ident.name(), #ident_name);
-                assert_eq!(tighterror::Category::name(&#ident), #ident_name)
+                #cfg
+                impl core::convert::From<#err_kind_name> for super::#target_mod::#target_err_kind_name {
+                    #[inline]
+                    fn from(kind: #err_kind_name) -> Self {
+                        match kind {
+                            #(#arms,)*
+                            _ => unreachable!("error kinds are exhaustively mapped"),
+                        }
+                    }
+                }
+
+                #cfg
+                impl core::convert::From<#err_name> for super::#target_mod::#target_err_name {
+                    #[inline]
+                    #track_caller
+                    fn from(err: #err_name) -> Self {
+                        super::#target_mod::#target_err_kind_name::from(err.kind()).into()
+                    }
+                }
             }
         });
         quote! {
-            #[test]
-            fn test_category_name() {
-                use #categories_mod::*;
-                #(#check_cat_name_iter);*
+            #(#impls)*
+        }
+    }
+
+    fn error_kind_constants_tokens(&self) -> TokenStream {
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let mut tokens = TokenStream::default();
+        for c in &self.module.categories {
+            let cat_tokens = self.error_kind_category_constants_tokens(c);
+            if self.module.flat_kinds() {
+                tokens = quote! {
+                    #tokens
+                    #cat_tokens
+                };
+            } else {
+                let cat_mod_ident = format_ident!("{}", c.module_name());
+                let cat_mod_doc = doc_tokens(&format!("{} category error kind constants.", c.name));
+                let sub_tokens = self.error_kind_subcategory_modules_tokens(c);
+                let all_ident = category_all_ident();
+                let all_tokens = self.error_kind_category_slice_tokens(c, &all_ident, false);
+                tokens = quote! {
+                    #tokens
+
+                    #cat_mod_doc
+                    pub mod #cat_mod_ident {
+                        use super::c;
+                        use super::EK;
+                        #cat_tokens
+                        #sub_tokens
+                        #all_tokens
+                    }
+                };
+            }
+        }
+
+        if self.module.flat_kinds() {
+            let by_category_mod = by_category_mod_ident();
+            let iter = self.module.categories.iter().map(|c| {
+                let cat_ident = format_ident!("{}", c.ident_name());
+                self.error_kind_category_slice_tokens(c, &cat_ident, true)
+            });
+            tokens = quote! {
+                #tokens
+
+                #[doc = " Error kinds of every category."]
+                pub mod #by_category_mod {
+                    use super::EK;
+                    #(#iter)*
+                }
+            };
+        }
+
+        let categories_mod = self.categories_mod_ident();
+        quote! {
+            #[doc = " Error kind constants."]
+            pub mod #err_kinds_mod {
+                use super::#err_kind_name as EK;
+                use super::#categories_mod as c;
+                #tokens
             }
         }
     }
 
-    fn ut_category_display(&self) -> TokenStream {
-        if self.spec.main.no_std() {
-            return TokenStream::default();
+    /// Returns a static array of the category error kinds, ordered by value.
+    ///
+    /// The error kind constants are referred to relative to the parent
+    /// module if `in_parent` is set.
+    fn error_kind_category_slice_tokens(
+        &self,
+        c: &CategorySpec,
+        ident: &Ident,
+        in_parent: bool,
+    ) -> TokenStream {
+        let n_errors = Literal::usize_unsuffixed(c.errors.len());
+        let doc = doc_tokens(&format!(
+            "All error kinds of the {} category, ordered by value.",
+            c.name
+        ));
+        let iter = c.errors.iter().map(|e| {
+            let err_ident = format_ident!("{}", e.name);
+            match e.subcategory_module_name() {
+                _ if in_parent => quote! { super::#err_ident },
+                Some(sub_mod) => {
+                    let sub_mod_ident = format_ident!("{}", sub_mod);
+                    quote! { #sub_mod_ident::#err_ident }
+                }
+                None => quote! { #err_ident },
+            }
+        });
+        quote! {
+            #doc
+            pub static #ident: [EK; #n_errors] = [
+                #(#iter),*
+            ];
         }
-        let categories_mod = categories_mod_ident();
-        let check_cat_display_iter = self.module.categories.iter().map(|c| {
-            let ident_name = c.ident_name();
-            let ident = format_ident!("{}", ident_name);
+    }
+
+    /// Returns the constants of the category errors, with the errors of
+    /// sub-categories included only if `flat_kinds` is enabled.
+    fn error_kind_category_constants_tokens(&self, c: &CategorySpec) -> TokenStream {
+        let flat_kinds = self.module.flat_kinds();
+        self.error_kind_constants_filtered_tokens(c, |e| flat_kinds || e.subcategory.is_none())
+    }
+
+    fn error_kind_subcategory_modules_tokens(&self, c: &CategorySpec) -> TokenStream {
+        let iter = c.subcategories.iter().map(|s| {
+            let sub_mod_ident = format_ident!("{}", s.module_name());
+            let sub_mod_doc = match s.doc.as_deref() {
+                Some(doc) => doc_tokens(doc),
+                None => doc_tokens(&format!("{} subcategory error kind constants.", s.name)),
+            };
+            let sub_tokens = self.error_kind_constants_filtered_tokens(c, |e| {
+                e.subcategory.as_deref() == Some(s.name.as_str())
+            });
             quote! {
-                assert_eq!(format!("{}", #ident), #ident_name);
+                #sub_mod_doc
+                pub mod #sub_mod_ident {
+                    use super::c;
+                    use super::EK;
+                    #sub_tokens
+                }
             }
         });
         quote! {
-            #[test]
-            fn test_category_display() {
-                use #categories_mod::*;
-                #(#check_cat_display_iter)*
+            #(#iter)*
+        }
+    }
+
+    fn error_kind_constants_filtered_tokens(
+        &self,
+        c: &CategorySpec,
+        filter: impl Fn(&ErrorSpec) -> bool,
+    ) -> TokenStream {
+        let mut tokens = TokenStream::default();
+        for (i, e) in c.errors.iter().enumerate().filter(|(_, e)| filter(e)) {
+            let cat_ident = format_ident!("{}", c.ident_name());
+            let err_value = self.usize_to_repr_type_literal(i).unwrap();
+            let err_ident = format_ident!("{}", e.name);
+            let err_doc = doc_tokens(self.module.err_kind_const_doc(c, e));
+            tokens = quote! {
+                #tokens
+
+                #err_doc
+                pub const #err_ident: EK = EK::new(c::#cat_ident, #err_value);
+            };
+        }
+        tokens
+    }
+
+    fn prelude_tokens(&self) -> TokenStream {
+        if !self.module.prelude() {
+            return TokenStream::default();
+        }
+        let prelude_mod = prelude_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors
+                .iter()
+                .map(move |e| self.err_const_tokens(c, e, add_cat_mod))
+        });
+        quote! {
+            #[doc = " All error kind constants of the module, for glob import."]
+            pub mod #prelude_mod {
+                pub use super::#err_kinds_mod::{#(#iter),*};
             }
         }
     }
 
-    fn ut_category_uniqueness(&self) -> TokenStream {
-        if self.spec.main.no_std() {
+    fn catalog_tokens(&self) -> TokenStream {
+        if !self.module.catalog() {
             return TokenStream::default();
         }
+        let catalog = catalog_ident();
+        let error_names_mod = &error_names_mod_ident();
+        let error_displays_mod = &error_displays_mod_ident();
+        let error_docs_mod = &error_docs_mod_ident();
+        let n_errors = self.n_errors_literal();
+        let flat_kinds = self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            let cat_mod_ident = format_ident!("{}", c.module_name());
+            c.errors.iter().map(move |e| {
+                let const_ident = format_ident!("{}", e.name);
+                let name = if flat_kinds {
+                    quote! { #error_names_mod::#cat_mod_ident::#const_ident }
+                } else {
+                    let qualified_name = err_kind_qualified_name(c, e);
+                    quote! { #qualified_name }
+                };
+                quote! {
+                    (
+                        #name,
+                        #error_displays_mod::#cat_mod_ident::#const_ident,
+                        #error_docs_mod::#cat_mod_ident::#const_ident,
+                    )
+                }
+            })
+        });
+        quote! {
+            /// The `(name, display, doc)` tuples of the error kinds, in the
+            /// order of their values.
+            ///
+            /// The name is qualified by the category name unless `flat_kinds`
+            /// is enabled. The doc is empty if the error has no documentation.
+            pub static #catalog: [(&str, &str, &str); #n_errors] = [
+                #(#iter),*
+            ];
+        }
+    }
+
+    fn category_constants_tokens(&self) -> TokenStream {
         let err_cat_name = self.err_cat_name_ident();
-        let categories_mod = categories_mod_ident();
-        let cat_arr = self.ut_cat_arr();
-        let n_categories = self.n_categories_literal();
+        let mut tokens = quote! {};
+        for (i, c) in self.module.categories.iter().enumerate() {
+            let cat_value = self.usize_to_repr_type_literal(i).unwrap();
+            let cat_name_upper_snake = format_ident!("{}", c.ident_name());
+            let const_doc = doc_tokens(self.module.cat_const_doc(c));
+            let single = quote! {
+                #const_doc
+                pub const #cat_name_upper_snake: C = C::new(#cat_value);
+            };
+            tokens = quote! {
+                #tokens
+                #single
+            };
+        }
+
+        let categories_mod = self.categories_mod_ident();
         quote! {
-            #[test]
-            fn test_category_uniqueness() {
-                use #categories_mod::*;
-                use std::collections::HashSet;
+            #[doc = " Error category constants."]
+            pub mod #categories_mod {
+                use super::#err_cat_name as C;
+                #tokens
+            }
+        }
+    }
+
+    fn needs_variants_module(&self) -> bool {
+        self.module.has_variant_types()
+    }
+
+    fn variants_module_tokens(&self) -> TokenStream {
+        if !self.needs_variants_module() {
+            return TokenStream::default();
+        }
+
+        let variant_types_mod_tokens = self.variant_types_module_tokens();
+        let variants_mod = variants_mod_ident();
+        quote! {
+            #[doc = " Error variant types and constants."]
+            pub mod #variants_mod {
+                #variant_types_mod_tokens
+            }
+        }
+    }
+
+    fn variant_types_module_tokens(&self) -> TokenStream {
+        let mut tokens = TokenStream::default();
+
+        if !self.module.has_variant_types() {
+            return tokens;
+        }
+
+        let kinds_mod = self.error_kinds_mod_ident();
+        let private_mod = private_mod_ident();
+        let category_names_mod = category_names_mod_ident();
+        let categories_mod = self.categories_mod_ident();
+        let display_mod = error_displays_mod_ident();
+        let error_names_mod = error_names_mod_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_name = self.err_name_ident();
+        let cat_name = self.err_cat_name_ident();
+        let use_display_mod = if self.module.has_display_variant_types() {
+            quote! { #display_mod, }
+        } else {
+            TokenStream::default()
+        };
+        let use_message_fn = if self.module.display_resolver().is_some() {
+            let message_fn = message_fn_ident();
+            quote! { #message_fn, }
+        } else {
+            TokenStream::default()
+        };
+        let use_tokens = quote! {
+            super::{
+                #use_display_mod #use_message_fn #private_mod, #category_names_mod,
+                #error_names_mod,
+                #categories_mod, #kinds_mod, #err_kind_name,
+                #err_name, #cat_name
+            }
+        };
+        if self.module.flat_kinds() {
+            tokens = quote! { use super::#use_tokens; };
+        }
+
+        for c in &self.module.categories {
+            if !self.module.cat_has_variant_types(c) {
+                continue;
+            }
+
+            let cvt = self.category_variant_types_tokens(c);
+            if self.module.flat_kinds() {
+                tokens = quote! {
+                    #tokens
+                    #cvt
+                };
+            } else {
+                let cat_mod_ident = format_ident!("{}", c.module_name());
+                let cat_mod_doc = doc_tokens(&format!("{} category error variant types.", c.name));
+
+                tokens = quote! {
+                    #tokens
+
+                    #cat_mod_doc
+                    pub mod #cat_mod_ident {
+                        use super::super::#use_tokens;
+                        #cvt
+                    }
+                };
+            }
+        }
+
+        let types_mod = types_mod_ident();
+        quote! {
+            #[doc = " Error variant types."]
+            pub mod #types_mod {
+                #tokens
+            }
+        }
+    }
+
+    fn category_variant_types_tokens(&self, c: &CategorySpec) -> TokenStream {
+        let mut tokens = TokenStream::default();
+
+        for e in &c.errors {
+            if !self.module.err_has_variant_type(c, e) {
+                continue;
+            }
+            let evt = self.error_variant_type_tokens(c, e);
+            tokens = quote! {
+                #tokens
+                #evt
+            };
+        }
+
+        tokens
+    }
+
+    fn error_variant_type_tokens(&self, c: &CategorySpec, e: &ErrorSpec) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let display_mod = error_displays_mod_ident();
+        let kinds_mod = self.error_kinds_mod_ident();
+        let private_mod = private_mod_ident();
+        let category_names_mod = category_names_mod_ident();
+        let categories_mod = self.categories_mod_ident();
+        let error_names_mod = error_names_mod_ident();
+        let cat_const_ident = format_ident!("{}", c.ident_name());
+        let err_kind_name_ident = self.err_kind_name_ident();
+        let err_name_ident = self.err_name_ident();
+        let cat_mod = format_ident!("{}", c.module_name());
+        let cat_name_ident = self.err_cat_name_ident();
+        let err_ident = format_ident!("{}", e.name);
+        let var_type_name = e.variant_type_name();
+        let var_type_ident = format_ident!("{}", var_type_name);
+        let err_doc = doc_tokens(self.module.err_kind_const_doc(c, e));
+        let display = if e.display.is_some() {
+            quote! { #display_mod::#cat_mod::#err_ident }
+        } else {
+            quote! { <Self as #rt::VariantType>::NAME }
+        };
+        let display_message = self.display_message_tokens(display);
+        let error_trait = if self.module.error_trait(self.spec.main.no_std) {
+            let error_trait_path = self.error_trait_path();
+            quote! { impl #error_trait_path for #var_type_ident {} }
+        } else {
+            TokenStream::default()
+        };
+        let err_kind_const = self.err_const_tokens(c, e, !self.module.flat_kinds());
+        let err_kind_tokens = quote! { #kinds_mod::#err_kind_const };
+        let track_caller = self.track_caller_tokens();
+        quote! {
+            #err_doc
+            #[derive(Clone, Copy)]
+            #[non_exhaustive]
+            pub struct #var_type_ident;
+
+            impl #var_type_ident {
+                #[doc = " Returns the struct name."]
+                #[inline]
+                pub fn name(&self) -> &'static str {
+                    <#var_type_ident as #rt::VariantType>::NAME
+                }
+
+                #[doc = " Returns the error kind constant."]
+                #[inline]
+                pub fn kind(&self) -> #err_kind_name_ident {
+                    <#var_type_ident as #rt::VariantType>::KIND
+                }
+
+                #[doc = " Returns the error category constant."]
+                #[inline]
+                pub fn category(&self) -> #cat_name_ident {
+                    <#var_type_ident as #rt::VariantType>::CATEGORY
+                }
+            }
+
+            impl core::fmt::Display for #var_type_ident {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    #display_message
+                }
+            }
+
+            impl core::fmt::Debug for #var_type_ident {
+                #[inline]
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    f.debug_struct(#var_type_name)
+                        .field("cat", &#private_mod::Ident(#category_names_mod::#cat_const_ident))
+                        .field("var", &#private_mod::Ident(#error_names_mod::#cat_mod::#err_ident))
+                        .finish()
+                }
+            }
+
+            impl #rt::VariantType for #var_type_ident {
+                type R = #private_mod::R;
+                type Category = #cat_name_ident;
+                type Kind = #err_kind_name_ident;
+
+                const CATEGORY: Self::Category = #categories_mod::#cat_const_ident;
+                const KIND: Self::Kind = #err_kind_tokens;
+                const NAME: &'static str = #var_type_name;
+            }
+
+            impl core::convert::From<#var_type_ident> for #err_kind_name_ident {
+                #[inline]
+                fn from(_: #var_type_ident) -> #err_kind_name_ident {
+                    <#var_type_ident as #rt::VariantType>::KIND
+                }
+            }
+
+            impl core::convert::From<#var_type_ident> for #err_name_ident {
+                #[inline]
+                #track_caller
+                fn from(_: #var_type_ident) -> Self {
+                    <#var_type_ident as #rt::VariantType>::KIND.into()
+                }
+            }
+
+            impl<T> core::convert::From<#var_type_ident> for core::result::Result<T, #err_name_ident> {
+                #[inline]
+                #track_caller
+                fn from(_: #var_type_ident) -> Self {
+                    <#var_type_ident as #rt::VariantType>::KIND.into()
+                }
+            }
+
+            impl<T> core::convert::From<#var_type_ident> for core::result::Result<T, #var_type_ident> {
+                #[inline]
+                fn from(v: #var_type_ident) -> Self {
+                    Err(v)
+                }
+            }
+
+            #error_trait
+        }
+    }
+
+    fn test_tokens(&self) -> TokenStream {
+        let tests_mod = tests_mod_ident();
+        if self.module.test(self.opts.test) {
+            let test_tokens = self.test_tokens_impl();
+            if test_tokens.is_empty() {
+                return TokenStream::default();
+            }
+            quote! {
+                #[cfg(test)]
+                mod #tests_mod {
+                    use super::*;
+                    #test_tokens
+                }
+            }
+        } else {
+            TokenStream::default()
+        }
+    }
+
+    fn test_tokens_impl(&self) -> TokenStream {
+        let tests = [
+            ("category_name", self.ut_category_name_tokens()),
+            ("category_display", self.ut_category_display()),
+            ("category_uniqueness", self.ut_category_uniqueness()),
+            ("category_values", self.ut_category_values()),
+            ("category_from_value", self.ut_category_from_value()),
+            ("category_from_name", self.ut_category_from_name()),
+            ("category_from_str", self.ut_category_from_str()),
+            ("category_kind_count", self.ut_category_kind_count()),
+            ("category_sets", self.ut_category_sets()),
+            ("category_kinds", self.ut_category_kinds()),
+            ("category_slices", self.ut_category_slices()),
+            ("category_default", self.ut_category_default()),
+            ("err_kind_name", self.ut_err_kind_name()),
+            ("err_kind_qualified_name", self.ut_err_kind_qualified_name()),
+            ("err_kind_display", self.ut_err_kind_display()),
+            ("catalog", self.ut_catalog()),
+            ("err_kind_uniqueness", self.ut_err_kind_uniqueness()),
+            (
+                "err_kind_value_uniqueness",
+                self.ut_err_kind_value_uniqueness(),
+            ),
+            ("err_kind_hash", self.ut_err_kind_hash()),
+            ("err_kind_matches", self.ut_err_kind_matches()),
+            ("err_kind_category", self.ut_err_kind_category()),
+            ("err_kind_is_in", self.ut_err_kind_is_in()),
+            ("map_by_name", self.ut_map_by_name()),
+            ("err_kind_assoc_consts", self.ut_err_kind_assoc_consts()),
+            ("prelude", self.ut_prelude()),
+            ("err_kind_iter", self.ut_err_kind_iter()),
+            ("err_kind_from_value", self.ut_err_kind_from_value()),
+            ("err_kind_try_from_value", self.ut_err_kind_try_from_value()),
+            ("err_kind_variant", self.ut_err_kind_variant()),
+            ("err_kind_into_value", self.ut_err_kind_into_value()),
+            ("err_kind_debug_stable", self.ut_err_kind_debug_stable()),
+            ("err_kind_from_str", self.ut_err_kind_from_str()),
+            ("err_kind_from_name", self.ut_err_kind_from_name()),
+            ("err_kind_arbitrary", self.ut_err_kind_arbitrary()),
+            ("err_kind_proptest", self.ut_err_kind_proptest()),
+            ("err_kind_serde", self.ut_err_kind_serde()),
+            ("err_kind_ord", self.ut_err_kind_ord()),
+            ("err_kind_index", self.ut_err_kind_index()),
+            ("err_kind_category_index", self.ut_err_kind_category_index()),
+            (
+                "err_kind_from_value_unchecked",
+                self.ut_err_kind_from_value_unchecked(),
+            ),
+            ("err_kind_default", self.ut_err_kind_default()),
+            ("value_widths", self.ut_value_widths()),
+            ("err_kind_groups", self.ut_err_kind_groups()),
+            ("err_display", self.ut_err_display()),
+            ("err_message", self.ut_err_message()),
+            ("err_boxed", self.ut_err_boxed()),
+            ("display_padding", self.ut_display_padding()),
+            ("display_unpadded", self.ut_display_unpadded()),
+            ("err_debug_location", self.ut_err_debug_location()),
+            ("bail", self.ut_bail()),
+            ("err_hash", self.ut_err_hash()),
+            ("err_from_kind_ref", self.ut_err_from_kind_ref()),
+            ("err_kind_error", self.ut_err_kind_error()),
+            ("result_from_err", self.ut_result_from_err()),
+            ("result_from_err_kind", self.ut_result_from_err_kind()),
+            ("variant_types_display", self.ut_variant_types_display()),
+            ("variant_types_to_kind", self.ut_variant_types_to_kind()),
+            ("variant_types_to_error", self.ut_variant_types_to_error()),
+            ("variant_types_to_result", self.ut_variant_types_to_result()),
+        ];
+        debug_assert!(tests
+            .iter()
+            .map(|(n, _)| *n)
+            .eq(GENERATED_TESTS.iter().copied()));
+        let tests = tests
+            .into_iter()
+            .filter(|(n, _)| self.opts.test_selected(n))
+            .map(|(_, t)| t);
+        quote! {
+            #(#tests)*
+        }
+    }
+
+    fn ut_category_name_tokens(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let categories_mod = self.categories_mod_ident();
+        let check_cat_name_iter = self.module.categories.iter().map(|c| {
+            let ident_name = c.ident_name();
+            let ident = format_ident!("{}", ident_name);
+            quote! {
+                assert_eq!(#ident.name(), #ident_name);
+                assert_eq!(#ident.as_str(), #ident_name);
+                assert_eq!(#rt::Category::name(&#ident), #ident_name)
+            }
+        });
+        quote! {
+            #[test]
+            fn test_category_name() {
+                use #categories_mod::*;
+                #(#check_cat_name_iter);*
+            }
+        }
+    }
+
+    fn ut_category_display(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let categories_mod = self.categories_mod_ident();
+        let check_cat_display_iter = self.module.categories.iter().map(|c| {
+            let ident_name = c.ident_name();
+            let ident = format_ident!("{}", ident_name);
+            quote! {
+                assert_eq!(format!("{}", #ident), #ident_name);
+            }
+        });
+        quote! {
+            #[test]
+            fn test_category_display() {
+                use #categories_mod::*;
+                #(#check_cat_display_iter)*
+            }
+        }
+    }
+
+    fn ut_category_uniqueness(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = self.n_categories_literal();
+        quote! {
+            #[test]
+            fn test_category_uniqueness() {
+                use #categories_mod::*;
+                use std::collections::HashSet;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                let set = HashSet::<#err_cat_name>::from_iter(cats);
+                assert_eq!(set.len(), #n_categories);
+            }
+        }
+    }
+
+    fn ut_category_from_name(&self) -> TokenStream {
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            #[test]
+            fn test_category_from_name() {
+                use #categories_mod::*;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                for c in cats {
+                    assert_eq!(#err_cat_name::from_name(c.name()), Some(c));
+                }
+                assert_eq!(#err_cat_name::from_name("UNKNOWN_CATEGORY"), None);
+            }
+        }
+    }
+
+    fn ut_category_from_str(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            #[test]
+            fn test_category_from_str() {
+                use #categories_mod::*;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                for c in cats {
+                    assert_eq!(c.name().parse::<#err_cat_name>(), Ok(c));
+                    assert_eq!(#err_cat_name::try_from(c.name()), Ok(c));
+                }
+                for s in ["", "UNKNOWN_CATEGORY", "unknown category"] {
+                    assert_eq!(s.parse::<#err_cat_name>(), Err(#rt::ParseKindError));
+                    assert_eq!(#err_cat_name::try_from(s), Err(#rt::ParseKindError));
+                }
+            }
+        }
+    }
+
+    fn ut_category_kind_count(&self) -> TokenStream {
+        let categories_mod = self.categories_mod_ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let cat_ident = format_ident!("{}", c.ident_name());
+            let n_errors = Literal::usize_unsuffixed(c.errors.len());
+            quote! {
+                assert_eq!(#categories_mod::#cat_ident.kind_count(), #n_errors);
+            }
+        });
+        quote! {
+            #[test]
+            fn test_category_kind_count() {
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_category_kinds(&self) -> TokenStream {
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().map(|c| {
+            let cat_ident = format_ident!("{}", c.ident_name());
+            let n_errors = Literal::usize_unsuffixed(c.errors.len());
+            let err_iter = c
+                .errors
+                .iter()
+                .map(|e| self.err_const_tokens(c, e, add_cat_mod));
+            quote! {
+                let kinds = #categories_mod::#cat_ident.kinds();
+                assert_eq!(kinds.len(), #n_errors);
+                assert!(kinds.clone().eq([#(#err_iter),*]));
+                assert!(kinds.rev().eq(#categories_mod::#cat_ident.into_iter().rev()));
+            }
+        });
+        quote! {
+            #[test]
+            fn test_category_kinds() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_category_slices(&self) -> TokenStream {
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let cat_ident = format_ident!("{}", c.ident_name());
+            let slice = if self.module.flat_kinds() {
+                let by_category_mod = by_category_mod_ident();
+                quote! { #err_kinds_mod::#by_category_mod::#cat_ident }
+            } else {
+                let cat_mod_ident = format_ident!("{}", c.module_name());
+                let all_ident = category_all_ident();
+                quote! { #err_kinds_mod::#cat_mod_ident::#all_ident }
+            };
+            quote! {
+                for k in #slice {
+                    assert_eq!(k.category(), #categories_mod::#cat_ident);
+                }
+                assert!(#slice.iter().copied().eq(#categories_mod::#cat_ident.kinds()));
+            }
+        });
+        quote! {
+            #[test]
+            fn test_category_slices() {
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_category_default(&self) -> TokenStream {
+        if !self.module.category_default() {
+            return TokenStream::default();
+        }
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_ident = format_ident!("{}", self.module.categories[0].ident_name());
+        quote! {
+            #[test]
+            fn test_category_default() {
+                assert_eq!(#err_cat_name::default(), #categories_mod::#cat_ident);
+            }
+        }
+    }
+
+    fn ut_category_sets(&self) -> TokenStream {
+        if !self.module.category_sets() {
+            return TokenStream::default();
+        }
+        let err_cat_name = self.err_cat_name_ident();
+        let err_cat_set_name = self.err_cat_set_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            #[test]
+            fn test_category_sets() {
+                use #categories_mod::*;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                assert!(#err_cat_set_name::new().is_empty());
+                for a in cats {
+                    let set = #err_cat_set_name::from(a);
+                    assert!(!set.is_empty());
+                    for b in cats {
+                        let union = a | b;
+                        assert!(union.contains(a));
+                        assert!(union.contains(b));
+                        assert_eq!(union, set | b);
+                        assert_eq!(union, set | #err_cat_set_name::from(b));
+                        for c in cats {
+                            assert_eq!(union.contains(c), c == a || c == b);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    fn ut_category_values(&self) -> TokenStream {
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        let category_max = Literal::usize_unsuffixed(self.module.categories.len() - 1);
+        let comparison = self.category_max_comparison();
+        quote! {
+            #[test]
+            fn test_category_values() {
+                use #categories_mod::*;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                for c in cats {
+                    assert!(c.0 #comparison #category_max);
+                }
+            }
+        }
+    }
+
+    fn ut_category_from_value(&self) -> TokenStream {
+        let err_cat_name = self.err_cat_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let private_mod = private_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
+        quote! {
+            #[test]
+            fn test_category_from_value() {
+                use #categories_mod::*;
+                let cats: [#err_cat_name; #n_categories] = #cat_arr;
+                for c in cats {
+                    assert_eq!(#err_cat_name::from_value(c.0), Some(c));
+                }
+                if let Some(v) = #private_mod::CAT_MAX.checked_add(1) {
+                    assert_eq!(#err_cat_name::from_value(v), None);
+                }
+            }
+        }
+    }
+
+    fn ut_value_widths(&self) -> TokenStream {
+        if self.module.value_widths.is_empty() {
+            return TokenStream::default();
+        }
+        let err_cat_name = self.err_cat_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let cat_arr = self.ut_cat_arr();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_categories = self.n_categories_literal();
+        let n_errors = self.n_errors_literal();
+        let iter = self.module.value_widths.iter().map(|w| {
+            let value_fn = format_ident!("value_u{w}");
+            quote! {
+                for c in cats {
+                    assert_eq!(u128::from(c.#value_fn()), u128::from(c.0));
+                }
+                for k in errs {
+                    assert_eq!(u128::from(k.#value_fn()), u128::from(k.value()));
+                }
+            }
+        });
+        quote! {
+            #[test]
+            fn test_value_widths() {
+                use #categories_mod::*;
+                use #err_kinds_mod::*;
                 let cats: [#err_cat_name; #n_categories] = #cat_arr;
-                let set = HashSet::<#err_cat_name>::from_iter(cats);
-                assert_eq!(set.len(), #n_categories);
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_name(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let ec_iter = c.errors.iter().map(|e| {
+                let name = e.name.as_str();
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(#ident.name(), #name);
+                    assert_eq!(#ident.as_str(), #name);
+                    assert_eq!(#rt::Kind::name(&#ident), #name);
+                }
+            });
+            quote! {
+                #(#ec_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_name() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_qualified_name(&self) -> TokenStream {
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().map(|c| {
+            let ec_iter = c.errors.iter().map(|e| {
+                let qualified_name = err_kind_qualified_name(c, e);
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(#ident.qualified_name(), #qualified_name);
+                }
+            });
+            quote! {
+                #(#ec_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_qualified_name() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_display(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().enumerate().map(|(i, c)| {
+            let ec_iter = c.errors.iter().enumerate().map(|(j, e)| {
+                let name = format!("{}{}", e.name, self.display_code_suffix(i, j));
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(format!("{}", #ident), #name);
+                }
+            });
+            quote! {
+                #(#ec_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_display() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_catalog(&self) -> TokenStream {
+        if !self.module.catalog() {
+            return TokenStream::default();
+        }
+        let catalog = catalog_ident();
+        let n_errors = self.n_errors_literal();
+        let flat_kinds = self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().map(move |e| {
+                let name = if flat_kinds {
+                    e.name.clone()
+                } else {
+                    err_kind_qualified_name(c, e)
+                };
+                let display = e.display.as_deref().unwrap_or(&e.name);
+                let doc = e.doc.as_deref().unwrap_or_default();
+                quote! { (#name, #display, #doc) }
+            })
+        });
+        quote! {
+            #[test]
+            fn test_catalog() {
+                let expected: [(&str, &str, &str); #n_errors] = [#(#iter),*];
+                assert_eq!(#catalog, expected);
+            }
+        }
+    }
+
+    fn ut_err_kind_uniqueness(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_kind_uniqueness() {
+                use #err_kinds_mod::*;
+                use std::collections::HashSet;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                let set = HashSet::<#err_kind_name>::from_iter(errs);
+                assert_eq!(set.len(), #n_errors);
+            }
+        }
+    }
+
+    fn ut_err_kind_value_uniqueness(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let repr_type = self.bits.repr_type.ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_kind_value_uniqueness() {
+                use #err_kinds_mod::*;
+                use std::collections::HashSet;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                let set = HashSet::<#repr_type>::from_iter(errs.iter().map(|ec| ec.value()));
+                assert_eq!(set.len(), #n_errors);
+            }
+        }
+    }
+
+    fn ut_err_kind_assoc_consts(&self) -> TokenStream {
+        if !self.module.assoc_consts() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let ident = format_ident!("{}", self.module.err_kind_assoc_const_name(c, e));
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                let name = &e.name;
+                quote! {
+                    assert_eq!(#err_kind_name::#ident, #err_kinds_mod::#err_const);
+                    assert_eq!(#err_kind_name::#ident.name(), #name);
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_assoc_consts() {
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_map_by_name(&self) -> TokenStream {
+        if self.module.map_by_name.is_empty() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let iter = self.module.map_by_name.iter().map(|t| {
+            let target = self.map_by_name_target(t);
+            let target_mod = format_ident!("{}", target.name());
+            let target_err_kind_name = format_ident!("{}", target.err_kind_name());
+            let target_err_name = format_ident!("{}", target.err_name());
+            let cfg = target
+                .feature
+                .as_deref()
+                .map(|f| quote! { #[cfg(feature = #f)] });
+            let asserts = self
+                .map_by_name_consts(target)
+                .into_iter()
+                .map(|(err_const, target_const)| {
+                    quote! {
+                        assert_eq!(
+                            super::super::#target_mod::#target_err_kind_name::from(#err_const),
+                            super::super::#target_const
+                        );
+                        assert_eq!(
+                            super::super::#target_mod::#target_err_name::from(#err_name::from(#err_const)).kind(),
+                            super::super::#target_const
+                        );
+                    }
+                });
+            quote! {
+                #cfg
+                {
+                    #(#asserts)*
+                }
+            }
+        });
+        quote! {
+            #[test]
+            fn test_map_by_name() {
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_prelude(&self) -> TokenStream {
+        if !self.module.prelude() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = &self.error_kinds_mod_ident();
+        let prelude_mod = prelude_mod_ident();
+        let n_errors = self.n_errors_literal();
+        let prelude_arr = self.ut_err_kind_arr_impl(false);
+        let add_cat_mod = !self.module.flat_kinds();
+        let kinds_iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().map(move |e| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                quote! { #err_kinds_mod::#err_const }
+            })
+        });
+        quote! {
+            #[test]
+            fn test_prelude() {
+                use #prelude_mod::*;
+                let kinds: [#err_kind_name; #n_errors] = #prelude_arr;
+                assert_eq!(kinds, [#(#kinds_iter),*]);
+            }
+        }
+    }
+
+    fn ut_err_kind_category(&self) -> TokenStream {
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                let cat_ident = format_ident!("{}", c.ident_name());
+                quote! {
+                    assert_eq!(#ident.category(), #categories_mod::#cat_ident);
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_category() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_is_in(&self) -> TokenStream {
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let cats = self.module.categories.iter().map(|c| {
+            let cat_ident = format_ident!("{}", c.ident_name());
+            quote! { #categories_mod::#cat_ident }
+        });
+        let Some((c, e)) = self
+            .module
+            .categories
+            .iter()
+            .find_map(|c| c.errors.first().map(|e| (c, e)))
+        else {
+            return TokenStream::default();
+        };
+        let first_ident = self.err_const_tokens(c, e, add_cat_mod);
+        let first_cat_ident = format_ident!("{}", c.ident_name());
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    for c in cats {
+                        assert_eq!(#ident.is_in(c), #ident.category() == c);
+                    }
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_is_in() {
+                use #err_kinds_mod::*;
+                const _: () = assert!(#first_ident.is_in(#categories_mod::#first_cat_ident));
+                let cats = [#(#cats),*];
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_from_value(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(#err_kind_name::from_value(#ident.value()).unwrap(), #ident);
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_from_value() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_try_from_value(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let private_mod = private_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let display = if self.spec.main.no_std() {
+            TokenStream::default()
+        } else {
+            quote! {
+                assert_eq!(format!("{}", err), format!("invalid error kind value: {}", bad));
+            }
+        };
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(#err_kind_name::try_from(#ident.value()), Ok(#ident));
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_try_from_value() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+                let bad = (0..=#private_mod::R::MAX).find(|v| #err_kind_name::from_value(*v).is_none());
+                if let Some(bad) = bad {
+                    let err = #err_kind_name::try_from(bad).unwrap_err();
+                    assert_eq!(err, #rt::InvalidValueError::new(bad));
+                    assert_eq!(err.value(), bad);
+                    #display
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_debug_stable(&self) -> TokenStream {
+        if self.spec.main.no_std() || !self.module.debug_stable() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_name_str = self.module.err_name();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kind_name_str = self.module.err_kind_name();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        let err_debug = if self.module.location() {
+            TokenStream::default()
+        } else {
+            quote! { format!("{:?}", #err_name::from(ek)), }
+        };
+        quote! {
+            #[test]
+            fn test_err_kind_debug_stable() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for ek in errs {
+                    for debug in [format!("{:?}", ek), #err_debug] {
+                        let rest = debug
+                            .replace(#err_name_str, "")
+                            .replace(#err_kind_name_str, "")
+                            .replace(ek.category().name(), "")
+                            .replace(ek.name(), "");
+                        assert!(!rest.contains(|c: char| c.is_ascii_digit()), "{}", debug);
+                    }
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_into_value(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let repr_type = self.bits.repr_type.ident();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    assert_eq!(#repr_type::from(#ident), #ident.value());
+                    assert_eq!(#repr_type::from(#err_name::from(#ident)), #ident.value());
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_into_value() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_variant(&self) -> TokenStream {
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().enumerate().map(move |(i, e)| {
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                let variant = self.usize_to_repr_type_literal(i).unwrap();
+                quote! {
+                    assert_eq!(#err_const.variant(), #variant);
+                }
+            })
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_variant() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_from_name(&self) -> TokenStream {
+        let err_kind_name = &self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().map(move |e| {
+                let names = self.err_kind_parse_names(c, e);
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    #(assert_eq!(#err_kind_name::from_name(#names), Some(#err_const));)*
+                }
+            })
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_from_name() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+                assert_eq!(#err_kind_name::from_name("UNKNOWN::ERROR_KIND"), None);
+            }
+        }
+    }
+
+    fn ut_err_kind_from_str(&self) -> TokenStream {
+        let rt = self.runtime_path_tokens();
+        let err_kind_name = &self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().flat_map(|c| {
+            c.errors.iter().map(move |e| {
+                let names = self.err_kind_parse_names(c, e);
+                let err_const = self.err_const_tokens(c, e, add_cat_mod);
+                quote! {
+                    #(
+                        assert_eq!(#names.parse::<#err_kind_name>(), Ok(#err_const));
+                        assert_eq!(#err_kind_name::try_from(#names), Ok(#err_const));
+                    )*
+                }
+            })
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_from_str() {
+                use #err_kinds_mod::*;
+                #(#iter)*
+                for s in ["", "UNKNOWN::ERROR_KIND", "unknown error kind"] {
+                    assert_eq!(s.parse::<#err_kind_name>(), Err(#rt::ParseKindError));
+                    assert_eq!(#err_kind_name::try_from(s), Err(#rt::ParseKindError));
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_arbitrary(&self) -> TokenStream {
+        if !self.module.arbitrary() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let private_mod = private_mod_ident();
+        quote! {
+            #[cfg(feature = "arbitrary")]
+            #[test]
+            fn test_err_kind_arbitrary() {
+                let data: [u8; 1024] = core::array::from_fn(|i| (i * 7) as u8);
+                let mut u = arbitrary::Unstructured::new(&data);
+                for _ in 0..256 {
+                    let k: #err_kind_name = arbitrary::Arbitrary::arbitrary(&mut u).unwrap();
+                    assert_eq!(#err_kind_name::from_value(k.value()), Some(k));
+                    assert!(#private_mod::ALL.contains(&k));
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_proptest(&self) -> TokenStream {
+        if !self.module.proptest() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let proptest_fn = format_ident!("{}", self.module.proptest_fn_name());
+        let private_mod = private_mod_ident();
+        quote! {
+            #[cfg(feature = "proptest")]
+            proptest::proptest! {
+                #[test]
+                fn test_err_kind_proptest(k in #proptest_fn()) {
+                    proptest::prop_assert_eq!(#err_kind_name::from_value(k.value()), Some(k));
+                    proptest::prop_assert!(#private_mod::ALL.contains(&k));
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_serde(&self) -> TokenStream {
+        if !self.module.serde() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.categories.iter().map(|c| {
+            let err_iter = c.errors.iter().map(|e| {
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                let repr = match self.module.serde_repr() {
+                    SerdeRepr::Name => quote! { #ident.qualified_name() },
+                    SerdeRepr::Value => quote! { #ident.value() },
+                };
+                quote! {
+                    assert_eq!(de(#repr), Ok(#ident));
+                }
+            });
+            quote! {
+                #(#err_iter)*
+            }
+        });
+        let private_mod = private_mod_ident();
+        let bad = match self.module.serde_repr() {
+            SerdeRepr::Name => quote! { Some("") },
+            SerdeRepr::Value => quote! {
+                (0..=#private_mod::R::MAX).find(|v| #err_kind_name::from_value(*v).is_none())
+            },
+        };
+        quote! {
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_err_kind_serde() {
+                use #err_kinds_mod::*;
+                use serde::{
+                    de::{value::Error, IntoDeserializer},
+                    Deserialize,
+                };
+                fn de<'a, T: IntoDeserializer<'a, Error>>(v: T) -> Result<#err_kind_name, Error> {
+                    #err_kind_name::deserialize(v.into_deserializer())
+                }
+                #(#iter)*
+                if let Some(bad) = #bad {
+                    assert!(de(bad).is_err());
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_ord(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        let cmp = match self.module.order_by() {
+            OrderBy::Value => quote! { w[0].value() < w[1].value() },
+            OrderBy::Name => quote! {
+                (w[0].name(), w[0].value()) < (w[1].name(), w[1].value())
+            },
+        };
+        quote! {
+            #[test]
+            fn test_err_kind_ord() {
+                use #err_kinds_mod::*;
+                let mut errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                errs.reverse();
+                errs.sort_unstable();
+                assert!(errs.windows(2).all(|w| #cmp));
+            }
+        }
+    }
+
+    fn ut_err_kind_default(&self) -> TokenStream {
+        let Some((c, e)) = self.module.default_error() else {
+            return TokenStream::default();
+        };
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_const = self.err_const_tokens(c, e, !self.module.flat_kinds());
+        quote! {
+            #[test]
+            fn test_err_kind_default() {
+                assert_eq!(#err_kind_name::default(), #err_kinds_mod::#err_const);
+                assert_eq!(#err_name::default().kind(), #err_kinds_mod::#err_const);
+            }
+        }
+    }
+
+    fn ut_err_kind_groups(&self) -> TokenStream {
+        if self.module.groups.is_empty() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        let add_cat_mod = !self.module.flat_kinds();
+        let iter = self.module.groups.iter().map(|g| {
+            let method = format_ident!("{}", g.method_name());
+            let members = self.module.group_members(g);
+            let n_members = Literal::usize_unsuffixed(members.len());
+            let members_iter = members
+                .into_iter()
+                .map(|(c, e)| self.err_const_tokens(c, e, add_cat_mod));
+            quote! {
+                #(assert!(#members_iter.#method());)*
+                assert_eq!(errs.iter().filter(|k| k.#method()).count(), #n_members);
+            }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_groups() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                #(#iter)*
+            }
+        }
+    }
+
+    fn ut_err_kind_from_value_unchecked(&self) -> TokenStream {
+        if !self.module.allow_unsafe() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_kind_from_value_unchecked() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for k in errs {
+                    let uk = unsafe { #err_kind_name::from_value_unchecked(k.value()) };
+                    assert_eq!(uk, k);
+                    assert_eq!(uk.name(), k.name());
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_iter(&self) -> TokenStream {
+        if !self.module.kind_iter() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        let variants = kind_variants_ident();
+        quote! {
+            #[test]
+            fn test_err_kind_iter() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                assert!(#err_kind_name::iter().eq(errs));
+                assert_eq!(#err_kind_name::#variants.len(), #n_errors);
+                for (k, name) in #err_kind_name::iter().zip(#err_kind_name::#variants) {
+                    assert_eq!(k.name(), *name);
+                }
+            }
+        }
+    }
+
+    fn ut_err_kind_index(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_kind_index() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                let mut seen = [false; #n_errors];
+                for (i, k) in errs.iter().enumerate() {
+                    assert_eq!(k.index(), i);
+                    assert!(!seen[k.index()]);
+                    seen[k.index()] = true;
+                }
+                assert!(seen.iter().all(|s| *s));
+            }
+        }
+    }
+
+    fn ut_err_kind_category_index(&self) -> TokenStream {
+        if !self.module.kind_to_cat() {
+            return TokenStream::default();
+        }
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let private_mod = private_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_kind_category_index() {
+                use #err_kinds_mod::*;
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for (i, k) in errs.iter().enumerate() {
+                    assert_eq!(k.index(), i);
+                    assert_eq!(k.category_index(), k.category_value() as usize);
+                    assert_eq!(#private_mod::KIND_TO_CAT[i], k.category_value());
+                }
+            }
+        }
+    }
+
+    fn ut_display_padding(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let pad = |s: &str| format!("{s:_^10.3}");
+        let cat_iter = self.module.categories.iter().map(|c| {
+            let ident_name = c.ident_name();
+            let ident = format_ident!("{}", ident_name);
+            let padded = pad(&ident_name);
+            quote! {
+                assert_eq!(format!("{:_^10.3}", #categories_mod::#ident), #padded);
+            }
+        });
+        let err_iter = self.module.categories.iter().enumerate().map(|(i, c)| {
+            let ec_iter = c.errors.iter().enumerate().map(|(j, e)| {
+                let add_cat_mod = !self.module.flat_kinds();
+                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                let suffix = self.display_code_suffix(i, j);
+                let padded_name = pad(&format!("{}{suffix}", e.name));
+                let padded_display = match self.module.display_resolver() {
+                    Some(_) => self.ut_resolved_display(
+                        e.display.as_deref().unwrap_or(&e.name),
+                        &format!("{{:_^10.3}}{suffix}"),
+                    ),
+                    None => {
+                        let padded = pad(&format!(
+                            "{}{suffix}",
+                            e.display.as_deref().unwrap_or(&e.name)
+                        ));
+                        quote! { #padded }
+                    }
+                };
+                quote! {
+                    assert_eq!(format!("{:_^10.3}", #ident), #padded_name);
+                    assert_eq!(format!("{:_^10.3}", #err_name::from(#ident)), #padded_display);
+                }
+            });
+            quote! {
+                #(#ec_iter)*
+            }
+        });
+        quote! {
+            #[test]
+            fn test_display_padding() {
+                use #err_kinds_mod::*;
+                #(#cat_iter)*
+                #(#err_iter)*
             }
         }
     }
 
-    fn ut_category_values(&self) -> TokenStream {
-        let err_cat_name = self.err_cat_name_ident();
-        let categories_mod = categories_mod_ident();
-        let cat_arr = self.ut_cat_arr();
-        let n_categories = Literal::usize_unsuffixed(self.module.categories.len());
-        let category_max = Literal::usize_unsuffixed(self.module.categories.len() - 1);
-        let comparison = self.category_max_comparison();
+    fn ut_err_boxed(&self) -> TokenStream {
+        if self.spec.main.no_std() || !self.module.error_trait(self.spec.main.no_std) {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
         quote! {
             #[test]
-            fn test_category_values() {
-                use #categories_mod::*;
-                let cats: [#err_cat_name; #n_categories] = #cat_arr;
-                for c in cats {
-                    assert!(c.0 #comparison #category_max);
+            fn test_err_boxed() {
+                use #err_kinds_mod::*;
+                type BoxedError = std::boxed::Box<dyn std::error::Error + Send + Sync>;
+                fn fail(kind: #err_kind_name) -> Result<(), BoxedError> {
+                    Err(kind)?
+                }
+                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for k in errs {
+                    let boxed = fail(k).unwrap_err();
+                    assert_eq!(boxed.to_string(), #err_name::from(k).to_string());
+                    assert_eq!(boxed.downcast_ref::<#err_name>().unwrap().kind(), k);
                 }
             }
         }
     }
 
-    fn ut_err_kind_name(&self) -> TokenStream {
-        let err_kinds_mod = error_kinds_mod_ident();
-        let iter = self.module.categories.iter().map(|c| {
-            let ec_iter = c.errors.iter().map(|e| {
-                let name = e.name.as_str();
+    fn ut_err_display(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let iter = self.module.categories.iter().enumerate().map(|(i, c)| {
+            let err_iter = c.errors.iter().enumerate().map(|(j, e)| {
                 let add_cat_mod = !self.module.flat_kinds();
-                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                let err_ident = self.err_const_tokens(c, e, add_cat_mod);
+                let display = if let Some(ref d) = e.display {
+                    d.as_str()
+                } else {
+                    e.name.as_str()
+                };
+                let suffix = self.display_code_suffix(i, j);
+                let display = match self.module.display_resolver() {
+                    Some(_) => self.ut_resolved_display(display, &format!("{{}}{suffix}")),
+                    None => {
+                        let display = format!("{display}{suffix}");
+                        quote! { #display }
+                    }
+                };
                 quote! {
-                    assert_eq!(#ident.name(), #name);
-                    assert_eq!(tighterror::Kind::name(&#ident), #name);
+                    assert_eq!(format!("{}", #err_name::from(#err_ident)), #display);
                 }
             });
             quote! {
-                #(#ec_iter)*
+                #(#err_iter)*
             }
         });
         quote! {
             #[test]
-            fn test_err_kind_name() {
+            fn test_err_display() {
                 use #err_kinds_mod::*;
                 #(#iter)*
             }
         }
     }
 
-    fn ut_err_kind_display(&self) -> TokenStream {
-        if self.spec.main.no_std() {
-            return TokenStream::default();
-        }
-        let err_kinds_mod = error_kinds_mod_ident();
+    fn ut_err_message(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let iter = self.module.categories.iter().map(|c| {
-            let ec_iter = c.errors.iter().map(|e| {
-                let name = e.name.as_str();
+            let err_iter = c.errors.iter().map(|e| {
                 let add_cat_mod = !self.module.flat_kinds();
-                let ident = self.err_const_tokens(c, e, add_cat_mod);
+                let err_ident = self.err_const_tokens(c, e, add_cat_mod);
+                let display = if let Some(ref d) = e.display {
+                    d.as_str()
+                } else {
+                    e.name.as_str()
+                };
                 quote! {
-                    assert_eq!(format!("{}", #ident), #name);
+                    assert_eq!(#err_ident.message(), #display);
+                    assert_eq!(#err_name::from(#err_ident).message(), #display);
                 }
             });
             quote! {
-                #(#ec_iter)*
+                #(#err_iter)*
             }
         });
         quote! {
             #[test]
-            fn test_err_kind_display() {
+            fn test_err_message() {
                 use #err_kinds_mod::*;
                 #(#iter)*
             }
         }
     }
 
-    fn ut_err_kind_uniqueness(&self) -> TokenStream {
+    fn ut_display_unpadded(&self) -> TokenStream {
         if self.spec.main.no_std() {
             return TokenStream::default();
         }
+        let err_name = self.err_name_ident();
         let err_kind_name = self.err_kind_name_ident();
-        let err_kinds_mod = error_kinds_mod_ident();
+        let categories_mod = self.categories_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let err_kind_arr = self.ut_err_kind_arr();
-        let n_errors =
-            Literal::usize_unsuffixed(self.module.categories.iter().map(|c| c.errors.len()).sum());
+        let n_errors = self.n_errors_literal();
+        let cat_iter = self.module.categories.iter().map(|c| {
+            let ident = format_ident!("{}", c.ident_name());
+            quote! { check(#categories_mod::#ident); }
+        });
         quote! {
             #[test]
-            fn test_err_kind_uniqueness() {
+            fn test_display_unpadded() {
                 use #err_kinds_mod::*;
-                use std::collections::HashSet;
-                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
-                let set = HashSet::<#err_kind_name>::from_iter(errs);
-                assert_eq!(set.len(), #n_errors);
+                fn check<T: core::fmt::Display>(v: T) {
+                    let s = format!("{}", v);
+                    assert_eq!(format!("{:1}", v), s);
+                    assert_eq!(format!("{:.4096}", v), s);
+                    assert_eq!(format!("{:1.4096}", v), s);
+                }
+                #(#cat_iter)*
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for k in kinds {
+                    check(k);
+                    check(#err_name::from(k));
+                }
             }
         }
     }
 
-    fn ut_err_kind_value_uniqueness(&self) -> TokenStream {
+    fn ut_err_debug_location(&self) -> TokenStream {
+        if self.spec.main.no_std() || !self.module.location() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let c = &self.module.categories[0];
+        let e = &c.errors[0];
+        let err_ident = self.err_const_tokens(c, e, !self.module.flat_kinds());
+        let rt = self.runtime_path_tokens();
+        quote! {
+            #[test]
+            fn test_err_debug_location() {
+                use #err_kinds_mod::*;
+                #[track_caller]
+                fn err_at_caller() -> (#err_name, #rt::Location) {
+                    (#err_name::from(#err_ident), #rt::Location::caller())
+                }
+                let (err, caller) = err_at_caller();
+                assert_eq!(err.location(), caller);
+                assert_eq!(err.location().file(), file!());
+                let debug = format!("{:?}", err);
+                assert!(debug.contains(file!()));
+                assert!(debug.contains(&format!("{:?}", err.kind())));
+            }
+        }
+    }
+
+    fn ut_result_from_err(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        self.ut_result_from(
+            format_ident!("test_result_from_err"),
+            quote! { #err_name },
+            self.module.result_from_err(),
+        )
+    }
+
+    fn ut_result_from_err_kind(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        self.ut_result_from(
+            format_ident!("test_result_from_err_kind"),
+            quote! { #err_kind_name },
+            self.module.result_from_err_kind(),
+        )
+    }
+
+    /// Checks whether `Result<(), Error>` implements `From<from>`.
+    ///
+    /// The inherent constant of `Probe` shadows the trait constant only if
+    /// the impl exists, so the absence of a disabled conversion is checked
+    /// without a `compile_fail` test. Both constants are also checked
+    /// directly, which keeps them used in either case.
+    fn ut_result_from(&self, test_name: Ident, from: TokenStream, expected: bool) -> TokenStream {
+        let err_name = self.err_name_ident();
+        let check = if expected {
+            quote! { assert!(implemented); }
+        } else {
+            quote! { assert!(!implemented); }
+        };
+        quote! {
+            #[test]
+            fn #test_name() {
+                struct Probe<T>(core::marker::PhantomData<T>);
+                trait Fallback {
+                    const IMPLEMENTED: bool = false;
+                }
+                impl<T> Fallback for Probe<T> {}
+                impl<T: core::convert::From<#from>> Probe<T> {
+                    const IMPLEMENTED: bool = true;
+                }
+                let fallback = <Probe<#from> as Fallback>::IMPLEMENTED;
+                assert!(!fallback);
+                let reflexive = Probe::<#from>::IMPLEMENTED;
+                assert!(reflexive);
+                let implemented = Probe::<Result<(), #err_name>>::IMPLEMENTED;
+                #check
+            }
+        }
+    }
+
+    fn ut_err_kind_hash(&self) -> TokenStream {
         if self.spec.main.no_std() {
             return TokenStream::default();
         }
         let err_kind_name = self.err_kind_name_ident();
-        let err_kinds_mod = error_kinds_mod_ident();
-        let repr_type = self.bits.repr_type.ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let err_kind_arr = self.ut_err_kind_arr();
-        let n_errors =
-            Literal::usize_unsuffixed(self.module.categories.iter().map(|c| c.errors.len()).sum());
+        let n_errors = self.n_errors_literal();
         quote! {
             #[test]
-            fn test_err_kind_value_uniqueness() {
+            fn test_err_kind_hash() {
                 use #err_kinds_mod::*;
-                use std::collections::HashSet;
-                let errs: [#err_kind_name; #n_errors] = #err_kind_arr;
-                let set = HashSet::<#repr_type>::from_iter(errs.iter().map(|ec| ec.value()));
-                assert_eq!(set.len(), #n_errors);
+                use std::{
+                    collections::{hash_map::RandomState, HashSet},
+                    hash::BuildHasher,
+                };
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                let s = RandomState::new();
+                let hashes = HashSet::<u64>::from_iter(kinds.iter().map(|k| s.hash_one(k)));
+                assert_eq!(hashes.len(), #n_errors);
+                for k in kinds {
+                    assert_eq!(s.hash_one(k), s.hash_one(k.value()));
+                }
             }
         }
     }
 
-    fn ut_err_kind_category(&self) -> TokenStream {
-        let categories_mod = categories_mod_ident();
-        let err_kinds_mod = error_kinds_mod_ident();
-        let iter = self.module.categories.iter().map(|c| {
-            let err_iter = c.errors.iter().map(|e| {
-                let add_cat_mod = !self.module.flat_kinds();
-                let ident = self.err_const_tokens(c, e, add_cat_mod);
-                let cat_ident = format_ident!("{}", c.ident_name());
-                quote! {
-                    assert_eq!(#ident.category(), #categories_mod::#cat_ident);
+    fn ut_err_kind_matches(&self) -> TokenStream {
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let add_cat_mod = !self.module.flat_kinds();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        let consts: Vec<TokenStream> = self
+            .module
+            .categories
+            .iter()
+            .flat_map(|c| c.errors.iter().map(move |e| (c, e)))
+            .map(|(c, e)| self.err_const_tokens(c, e, add_cat_mod))
+            .collect();
+        let Some(first) = consts.first() else {
+            return TokenStream::default();
+        };
+        let arms = consts.iter().enumerate().map(|(i, k)| {
+            let i = Literal::usize_unsuffixed(i);
+            quote! { Some(#k) => #i, }
+        });
+        quote! {
+            #[test]
+            fn test_err_kind_matches() {
+                use #err_kinds_mod::*;
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for (i, k) in kinds.into_iter().enumerate() {
+                    let j = match Some(k) {
+                        #(#arms)*
+                        _ => unreachable!(),
+                    };
+                    assert_eq!(i, j);
+                    assert_eq!(matches!(k, #first), i == 0);
                 }
-            });
-            quote! {
-                #(#err_iter)*
             }
-        });
+        }
+    }
+
+    fn ut_err_hash(&self) -> TokenStream {
+        if self.spec.main.no_std() {
+            return TokenStream::default();
+        }
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
         quote! {
             #[test]
-            fn test_err_kind_category() {
+            fn test_err_hash() {
                 use #err_kinds_mod::*;
-                #(#iter)*
+                use std::collections::HashSet;
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                let errs: Vec<#err_name> = kinds
+                    .iter()
+                    .chain(kinds.iter())
+                    .map(|k| #err_name::from(*k))
+                    .collect();
+                let set = HashSet::<#err_name>::from_iter(errs);
+                assert_eq!(set.len(), #n_errors);
+                for k in kinds {
+                    assert!(set.contains(&#err_name::from(k)));
+                }
             }
         }
     }
 
-    fn ut_err_kind_from_value(&self) -> TokenStream {
+    fn ut_err_from_kind_ref(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
         let err_kind_name = self.err_kind_name_ident();
-        let err_kinds_mod = error_kinds_mod_ident();
-        let iter = self.module.categories.iter().map(|c| {
-            let err_iter = c.errors.iter().map(|e| {
-                let add_cat_mod = !self.module.flat_kinds();
-                let ident = self.err_const_tokens(c, e, add_cat_mod);
-                quote! {
-                    assert_eq!(#err_kind_name::from_value(#ident.value()).unwrap(), #ident);
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
+        quote! {
+            #[test]
+            fn test_err_from_kind_ref() {
+                use #err_kinds_mod::*;
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for k in &kinds {
+                    let err: #err_name = k.into();
+                    assert_eq!(err.kind(), *k);
+                    assert_eq!(#err_name::from(k), #err_name::from(*k));
                 }
-            });
-            quote! {
-                #(#err_iter)*
             }
-        });
+        }
+    }
+
+    fn ut_err_kind_error(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        let err_kind_name = self.err_kind_name_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let err_kind_arr = self.ut_err_kind_arr();
+        let n_errors = self.n_errors_literal();
         quote! {
             #[test]
-            fn test_err_kind_from_value() {
+            fn test_err_kind_error() {
                 use #err_kinds_mod::*;
-                #(#iter)*
+                let kinds: [#err_kind_name; #n_errors] = #err_kind_arr;
+                for k in kinds {
+                    let err: #err_name = k.error();
+                    assert_eq!(err.kind(), k);
+                    assert_eq!(err.kind(), #err_name::from(k).kind());
+                }
             }
         }
     }
 
-    fn ut_err_display(&self) -> TokenStream {
-        if self.spec.main.no_std() {
+    fn ut_bail(&self) -> TokenStream {
+        if !self.module.location() {
             return TokenStream::default();
         }
         let err_name = self.err_name_ident();
-        let err_kinds_mod = error_kinds_mod_ident();
-        let iter = self.module.categories.iter().map(|c| {
-            let err_iter = c.errors.iter().map(|e| {
-                let add_cat_mod = !self.module.flat_kinds();
-                let err_ident = self.err_const_tokens(c, e, add_cat_mod);
-                let display = if let Some(ref d) = e.display {
-                    d.as_str()
-                } else {
-                    e.name.as_str()
-                };
-                quote! {
-                    assert_eq!(format!("{}", #err_name::from(#err_ident)), #display);
-                }
-            });
-            quote! {
-                #(#err_iter)*
-            }
-        });
+        let err_kinds_mod = self.error_kinds_mod_ident();
+        let c = &self.module.categories[0];
+        let e = &c.errors[0];
+        let err_ident = self.err_const_tokens(c, e, !self.module.flat_kinds());
         quote! {
             #[test]
-            fn test_err_display() {
+            fn test_bail() {
                 use #err_kinds_mod::*;
-                #(#iter)*
+                fn f(line: &mut u32) -> Result<(), #err_name> {
+                    *line = line!();
+                    bail!(#err_ident)
+                }
+                let mut line = 0;
+                let err = f(&mut line).unwrap_err();
+                assert_eq!(err.kind(), #err_ident);
+                assert_eq!(err.location().file(), file!());
+                assert_eq!(err.location().line(), line + 1);
             }
         }
     }
 
     fn ut_variant_types_display(&self) -> TokenStream {
-        if !self.module.has_variant_types() {
+        if self.spec.main.no_std() || !self.module.has_variant_types() {
             return TokenStream::default();
         }
 
@@ -1196,6 +4160,7 @@ impl<'a> ModuleGenerator<'a> {
                 } else {
                     e.variant_type_name()
                 };
+                let display = self.ut_resolved_display(&display, "{}");
                 quote! {
                     assert_eq!(format!("{}", #var_type_ident), #display);
                 }
@@ -1221,7 +4186,7 @@ impl<'a> ModuleGenerator<'a> {
             return TokenStream::default();
         }
 
-        let err_kinds_mod = error_kinds_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let err_kind_name = self.err_kind_name_ident();
 
         let iter = self.module.categories.iter().map(|c| {
@@ -1257,7 +4222,7 @@ impl<'a> ModuleGenerator<'a> {
             return TokenStream::default();
         }
 
-        let err_kinds_mod = error_kinds_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let err_name = self.err_name_ident();
 
         let iter = self.module.categories.iter().map(|c| {
@@ -1293,7 +4258,7 @@ impl<'a> ModuleGenerator<'a> {
             return TokenStream::default();
         }
 
-        let err_kinds_mod = error_kinds_mod_ident();
+        let err_kinds_mod = self.error_kinds_mod_ident();
         let err_name = self.err_name_ident();
 
         let iter = self.module.categories.iter().map(|c| {
@@ -1329,6 +4294,22 @@ impl<'a> ModuleGenerator<'a> {
         format_ident!("{}", self.module.err_cat_name())
     }
 
+    fn err_cat_set_name_ident(&self) -> Ident {
+        format_ident!("{}", self.module.err_cat_set_name())
+    }
+
+    fn err_cat_kinds_name_ident(&self) -> Ident {
+        format_ident!("{}", self.module.err_cat_kinds_name())
+    }
+
+    fn error_kinds_mod_ident(&self) -> Ident {
+        format_ident!("{}", self.module.kinds_mod_name())
+    }
+
+    fn categories_mod_ident(&self) -> Ident {
+        format_ident!("{}", self.module.categories_mod_name())
+    }
+
     fn err_name_ident(&self) -> Ident {
         format_ident!("{}", self.module.err_name())
     }
@@ -1337,6 +4318,28 @@ impl<'a> ModuleGenerator<'a> {
         format_ident!("{}", self.module.err_kind_name())
     }
 
+    fn value_widths_tokens(&self, what: &str) -> TokenStream {
+        let repr_bits = self.bits.repr_type.bits();
+        let iter = self.module.value_widths.iter().map(|w| {
+            let value_fn = format_ident!("value_u{w}");
+            let ty = format_ident!("u{w}");
+            let doc = format!(" Returns the {what} widened to `u{w}`.");
+            let body = if *w == repr_bits {
+                quote! { self.0 }
+            } else {
+                quote! { #ty::from(self.0) }
+            };
+            quote! {
+                #[doc = #doc]
+                #[inline]
+                pub fn #value_fn(&self) -> #ty {
+                    #body
+                }
+            }
+        });
+        quote! { #(#iter)* }
+    }
+
     fn u64_to_repr_type_literal(&self, v: u64) -> Result<Literal, TryFromIntError> {
         match self.bits.repr_type {
             ReprType::U8 => {
@@ -1376,11 +4379,67 @@ impl<'a> ModuleGenerator<'a> {
         }
     }
 
+    fn err_kind_error_fn_tokens(&self) -> TokenStream {
+        let err_name = self.err_name_ident();
+        if self.module.location() {
+            quote! {
+                #[doc = " Creates an error of this kind, same as `Error::from`."]
+                #[doc = ""]
+                #[doc = " The error location is the caller of this method."]
+                #[inline]
+                #[track_caller]
+                pub fn error(self) -> #err_name {
+                    #err_name::from(self)
+                }
+            }
+        } else {
+            quote! {
+                #[doc = " Creates an error of this kind, same as `Error::from`."]
+                #[inline]
+                pub const fn error(self) -> #err_name {
+                    #err_name(self)
+                }
+            }
+        }
+    }
+
+    fn track_caller_tokens(&self) -> TokenStream {
+        if self.module.location() {
+            quote! { #[track_caller] }
+        } else {
+            TokenStream::default()
+        }
+    }
+
     fn category_max_comparison(&self) -> TokenStream {
         let category_max = self.module.category_max();
         TokenStream::from_str(if category_max == 0 { "==" } else { "<=" }).unwrap()
     }
 
+    /// Returns the expected formatting of a display string, resolving it
+    /// if the display strings are message ids.
+    fn ut_resolved_display(&self, display: &str, fmt: &str) -> TokenStream {
+        if self.module.display_resolver().is_some() {
+            let message_fn = message_fn_ident();
+            quote! { format!(#fmt, #message_fn(#display)) }
+        } else {
+            quote! { #display }
+        }
+    }
+
+    /// Returns the suffix `display_with_code` appends to the display
+    /// string of the error kind, or an empty string if disabled.
+    fn display_code_suffix(&self, cat_idx: usize, var_idx: usize) -> String {
+        if !self.module.display_with_code() {
+            return String::new();
+        }
+        let value = kind_value(cat_idx, var_idx, &self.bits);
+        match self.module.display_code_radix() {
+            DisplayCodeRadix::Hex => format!(" [{value:#x}]"),
+            DisplayCodeRadix::Decimal => format!(" [{value}]"),
+        }
+    }
+
     fn ut_cat_arr(&self) -> TokenStream {
         let cat_iter = self
             .module
@@ -1396,8 +4455,16 @@ impl<'a> ModuleGenerator<'a> {
         let err_ident = format_ident!("{}", e.name);
         let cat_mod_ident = format_ident!("{}", c.module_name());
         if add_cat_mod {
-            quote! {
-                #cat_mod_ident::#err_ident
+            match e.subcategory_module_name() {
+                Some(sub_mod) => {
+                    let sub_mod_ident = format_ident!("{}", sub_mod);
+                    quote! {
+                        #cat_mod_ident::#sub_mod_ident::#err_ident
+                    }
+                }
+                None => quote! {
+                    #cat_mod_ident::#err_ident
+                },
             }
         } else {
             quote! {
@@ -1429,6 +4496,10 @@ impl<'a> ModuleGenerator<'a> {
         Literal::usize_unsuffixed(self.module.categories.len())
     }
 
+    fn n_errors_literal(&self) -> Literal {
+        Literal::usize_unsuffixed(self.module.categories.iter().map(|c| c.errors.len()).sum())
+    }
+
     fn ut_err_kind_arr_impl(&self, add_cat_mod: bool) -> TokenStream {
         let iter = self.module.categories.iter().map(|c| {
             let iter = c
@@ -1450,6 +4521,22 @@ impl<'a> ModuleGenerator<'a> {
         self.ut_err_kind_arr_impl(add_cat_mod)
     }
 
+    fn module_allow_tokens(&self) -> TokenStream {
+        if self.mod_doc {
+            outer_allow_tokens(&self.module.allows)
+        } else {
+            TokenStream::default()
+        }
+    }
+
+    fn module_cfg_tokens(&self) -> TokenStream {
+        if self.mod_doc {
+            outer_cfg_feature_tokens(self.module.feature.as_deref(), self.module.doc_cfg())
+        } else {
+            TokenStream::default()
+        }
+    }
+
     fn module_doc_tokens(&self) -> TokenStream {
         if self.mod_doc {
             outer_doc_tokens(self.module.doc())
@@ -4,27 +4,62 @@ pub const ERROR_KIND: &str = "ErrorKind";
 pub const CATEGORY_NAMES_MOD: &str = "_cn";
 pub const ERROR_NAMES_MOD: &str = "_n";
 pub const ERROR_DISPLAYS_MOD: &str = "_d";
+pub const ERROR_DOCS_MOD: &str = "_doc";
 pub const PRIVATE_MOD: &str = "_p";
 pub const CATEGORY_CONSTS_MOD: &str = "category";
 pub const ERROR_KINDS_MOD: &str = "kind";
 pub const VARIANTS_MOD: &str = "variant";
 pub const TYPES_MOD: &str = "types"; // singular `type` is rust-reserved
 pub const TESTS_MOD: &str = "test";
+pub const PRELUDE_MOD: &str = "prelude";
+pub const MESSAGE_FN: &str = "_message";
+pub const CATALOG: &str = "CATALOG";
+pub const CATEGORY_ALL: &str = "ALL";
+pub const KIND_VARIANTS: &str = "VARIANTS";
+pub const BY_CATEGORY_MOD: &str = "by_category";
 
-const ROOT_LEVEL: [&str; 11] = [
+const ROOT_LEVEL: [&str; 15] = [
     ERROR,
     ERROR_CATEGORY,
     ERROR_KIND,
     CATEGORY_NAMES_MOD,
     ERROR_NAMES_MOD,
     ERROR_DISPLAYS_MOD,
+    ERROR_DOCS_MOD,
     PRIVATE_MOD,
     CATEGORY_CONSTS_MOD,
     ERROR_KINDS_MOD,
     VARIANTS_MOD,
     TESTS_MOD,
+    PRELUDE_MOD,
+    MESSAGE_FN,
+    CATALOG,
+];
+
+/// Identifiers that cannot be used as category module names, i.e.,
+/// the `snake_case` form of a category name.
+const RESERVED_CATEGORY_MODULES: [&str; 15] = [
+    CATEGORY_NAMES_MOD,
+    ERROR_NAMES_MOD,
+    ERROR_DISPLAYS_MOD,
+    ERROR_DOCS_MOD,
+    PRIVATE_MOD,
+    CATEGORY_CONSTS_MOD,
+    "categories",
+    ERROR_KINDS_MOD,
+    "kinds",
+    VARIANTS_MOD,
+    "variants",
+    TYPES_MOD,
+    TESTS_MOD,
+    "tests",
+    "c", // the alias of `category` module in `kind` module
 ];
 
 pub fn is_root_level_ident(s: &str) -> bool {
-    ROOT_LEVEL.iter().any(|v| *v == s)
+    ROOT_LEVEL.contains(&s)
+}
+
+pub fn is_reserved_category_module(s: &str) -> bool {
+    RESERVED_CATEGORY_MODULES.contains(&s)
 }
@@ -1,5 +1,7 @@
-use crate::errors::TbError;
-use std::path::PathBuf;
+#[cfg(feature = "serde")]
+use crate::ResolvedSpec;
+use crate::{errors::TbError, CodegenReport};
+use std::{collections::BTreeMap, path::PathBuf};
 
 /// Options for the code generator.
 ///
@@ -20,10 +22,82 @@ use std::path::PathBuf;
 #[derive(Debug, Clone, Default)]
 pub struct CodegenOptions {
     pub(crate) spec: Option<PathBuf>,
+    pub(crate) spec_lang: Option<SpecLang>,
     pub(crate) output: Option<PathBuf>,
     pub(crate) test: Option<bool>,
+    pub(crate) tests: Option<Vec<String>>,
     pub(crate) update: Option<bool>,
+    pub(crate) header: Option<bool>,
     pub(crate) separate_files: Option<bool>,
+    pub(crate) module_filename: Option<bool>,
+    pub(crate) output_pattern: Option<String>,
+    pub(crate) output_ext: Option<String>,
+    pub(crate) split_tables: Option<bool>,
+    pub(crate) strict: Option<bool>,
+    pub(crate) strict_docs: Option<bool>,
+    pub(crate) require_display: Option<bool>,
+    pub(crate) lenient: Option<bool>,
+    pub(crate) output_mode: Option<OutputMode>,
+    pub(crate) edition: Option<Edition>,
+    pub(crate) rustfmt_config: Option<PathBuf>,
+    pub(crate) catalog_output: Option<PathBuf>,
+    pub(crate) no_std: Option<bool>,
+    pub(crate) threads: Option<usize>,
+}
+
+/// The output file writing mode.
+///
+/// See [`CodegenOptions::output_mode`] for more information.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum OutputMode {
+    /// The output file is overwritten wholesale.
+    #[default]
+    Overwrite,
+    /// Only the region between the code-generation markers is regenerated.
+    ///
+    /// Content outside the markers is preserved.
+    Append,
+}
+
+/// The markup language of the specification.
+///
+/// See [`CodegenOptions::spec_lang`] for more information.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum SpecLang {
+    /// YAML
+    #[cfg(feature = "yaml")]
+    Yaml,
+    /// TOML
+    #[cfg(feature = "toml")]
+    Toml,
+}
+
+/// The Rust edition used to format the generated code.
+///
+/// See [`CodegenOptions::edition`] for more information.
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub enum Edition {
+    /// Rust 2015.
+    Edition2015,
+    /// Rust 2018.
+    Edition2018,
+    /// Rust 2021.
+    #[default]
+    Edition2021,
+    /// Rust 2024.
+    Edition2024,
+}
+
+impl Edition {
+    /// Returns the edition as a string, e.g., `"2021"`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Edition2015 => "2015",
+            Self::Edition2018 => "2018",
+            Self::Edition2021 => "2021",
+            Self::Edition2024 => "2024",
+        }
+    }
 }
 
 impl CodegenOptions {
@@ -37,10 +111,15 @@ impl CodegenOptions {
     /// If a value is not specified the default specification filenames
     /// are used in the following order:
     /// * if the `yaml` feature is enabled the path [DEFAULT_SPEC_PATH_YAML]
-    ///   is used
+    ///   is used, or [DEFAULT_SPEC_PATH_YML] if the former isn't found
     /// * if specification file is still not found and the `toml` feature is
     ///   enabled the path [DEFAULT_SPEC_PATH_TOML] is used
     ///
+    /// If the value is a hyphen `-` the specification is read from `stdin`.
+    /// In this case the [`spec_lang`](Self::spec_lang) option is mandatory,
+    /// and relative paths in the specification are relative to the current
+    /// working directory.
+    ///
     /// # Examples
     /// ```rust
     /// # use tighterror_build::CodegenOptions;
@@ -48,6 +127,7 @@ impl CodegenOptions {
     /// ```
     ///
     /// [DEFAULT_SPEC_PATH_YAML]: crate::DEFAULT_SPEC_PATH_YAML
+    /// [DEFAULT_SPEC_PATH_YML]: crate::DEFAULT_SPEC_PATH_YML
     /// [DEFAULT_SPEC_PATH_TOML]: crate::DEFAULT_SPEC_PATH_TOML
     pub fn spec(&mut self, spec: impl Into<PathBuf>) -> &mut Self {
         self.spec = Some(spec.into());
@@ -72,6 +152,25 @@ impl CodegenOptions {
         self
     }
 
+    /// Sets the specification markup language.
+    ///
+    /// By default the language is detected from the specification filename
+    /// extension. A `Some` value overrides the detection.
+    ///
+    /// This option is mandatory when the specification is read from `stdin`,
+    /// because there is no filename to detect the language from.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::{CodegenOptions, SpecLang};
+    /// CodegenOptions::new().spec_lang(None);
+    /// CodegenOptions::new().spec("-").spec_lang(SpecLang::Yaml);
+    /// ```
+    pub fn spec_lang(&mut self, spec_lang: impl Into<Option<SpecLang>>) -> &mut Self {
+        self.spec_lang = spec_lang.into();
+        self
+    }
+
     /// Sets the output path.
     ///
     /// This can be either an absolute path, a relative path, or hyphen `-`.
@@ -79,7 +178,8 @@ impl CodegenOptions {
     ///
     /// If the path points to an existing directory the behavior depends on
     /// *separate files* mode. If *separate files* is disabled the output is
-    /// written into file `tighterror.rs` under the directory.
+    /// written into file `tighterror.rs` under the directory, or into a file
+    /// named after the module, see [`module_filename`](Self::module_filename).
     /// See [`separate_files`](Self::separate_files) for the case when the mode
     /// is enabled.
     ///
@@ -123,6 +223,10 @@ impl CodegenOptions {
     ///
     /// In `no_std` environments test cases that require `std` are excluded.
     ///
+    /// This option overrides the `MainObject::test` attribute in the
+    /// specification file. The `ModuleObject::test` attribute overrides
+    /// this option on a per-module basis.
+    ///
     /// # Examples
     /// ```rust
     /// # use tighterror_build::CodegenOptions;
@@ -135,6 +239,62 @@ impl CodegenOptions {
         self
     }
 
+    /// Selects the generated unit-tests to include.
+    ///
+    /// A selector includes the test of the same name, and all the tests
+    /// whose name ends with `_<selector>`. For example, `uniqueness`
+    /// includes all the uniqueness tests, and `from_value` includes
+    /// the `err_kind_from_value` test. Test names are listed in the
+    /// `MainObject::tests` attribute documentation. An unknown test name
+    /// is an error.
+    ///
+    /// If the value is `None` all the tests are included.
+    ///
+    /// The selection applies only to the modules that include the unit-test,
+    /// see [test](Self::test).
+    ///
+    /// This option overrides the `MainObject::tests` attribute in the
+    /// specification file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().tests(None);
+    /// CodegenOptions::new().tests(vec!["from_value".into(), "uniqueness".into()]);
+    /// ```
+    pub fn tests(&mut self, tests: impl Into<Option<Vec<String>>>) -> &mut Self {
+        self.tests = tests.into();
+        self
+    }
+
+    /// Enables the generated-by header.
+    ///
+    /// When enabled every generated file starts with a comment noting
+    /// the `tighterror-build` version, the specification file name and
+    /// the SHA-256 hash of the specification and its `doc_file` files, e.g.,
+    ///
+    /// ```text
+    /// // @generated by tighterror-build v0.0.22 from tighterror.yaml (sha256: 5f1e...)
+    /// ```
+    ///
+    /// The hash allows reviewers to verify that the generated code matches
+    /// the specification. In *append* output mode the comment starts the
+    /// region between the code-generation markers.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().header(None);
+    /// CodegenOptions::new().header(false);
+    /// CodegenOptions::new().header(Some(true));
+    /// ```
+    pub fn header(&mut self, header: impl Into<Option<bool>>) -> &mut Self {
+        self.header = header.into();
+        self
+    }
+
     /// Enables the *update* mode.
     ///
     /// If the value is `true` and the output file already exists
@@ -172,6 +332,9 @@ impl CodegenOptions {
     /// be written to `./src/errors.rs` and `./src/internal_errors.rs`
     /// respectively.
     ///
+    /// The module filenames can be customized using the
+    /// [`output_pattern`](Self::output_pattern) option.
+    ///
     /// If the output is written to `stdout` the *separate files* mode is
     /// implicitly disabled.
     pub fn separate_files(&mut self, separate_files: impl Into<Option<bool>>) -> &mut Self {
@@ -179,12 +342,436 @@ impl CodegenOptions {
         self
     }
 
+    /// Enables naming the output file after the module.
+    ///
+    /// When the [`output`](Self::output) option points to an existing
+    /// directory and *separate files* mode is disabled, the output is written
+    /// into file `tighterror.rs` under the directory. When this option is
+    /// enabled and the specification has a single module, the file is named
+    /// after the module with addition of the `.rs` suffix instead, e.g.,
+    /// `errors.rs`. Specifications with multiple modules are still written
+    /// into `tighterror.rs`.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().module_filename(None);
+    /// CodegenOptions::new().module_filename(true);
+    /// CodegenOptions::new().module_filename(Some(false));
+    /// ```
+    pub fn module_filename(&mut self, module_filename: impl Into<Option<bool>>) -> &mut Self {
+        self.module_filename = module_filename.into();
+        self
+    }
+
+    /// Sets the module file path pattern of the *separate files* mode.
+    ///
+    /// The pattern must contain the `{module}` placeholder, which is replaced
+    /// with the module name. The resulting path is relative to the
+    /// [`output`](Self::output) directory. Missing intermediate directories
+    /// are created. By default, the pattern is `{module}.rs`, where the
+    /// extension is set by [`output_ext`](Self::output_ext).
+    ///
+    /// For example, the pattern `gen_{module}.rs` writes a module named
+    /// `errors` to `gen_errors.rs`, and the pattern `{module}/mod.rs` writes
+    /// it to `errors/mod.rs`.
+    ///
+    /// A `Some` value defined here overrides the `MainObject::output_pattern`
+    /// attribute in the specification file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().output_pattern(None);
+    /// CodegenOptions::new().output_pattern("gen_{module}.rs".to_owned());
+    /// ```
+    pub fn output_pattern(&mut self, output_pattern: impl Into<Option<String>>) -> &mut Self {
+        self.output_pattern = output_pattern.into();
+        self
+    }
+
+    /// Sets the extension of the output files named by `tighterror-build`.
+    ///
+    /// The extension is used when the [`output`](Self::output) option points
+    /// to a directory, i.e., for `tighterror.rs` and the module files, and in
+    /// the default [`output_pattern`](Self::output_pattern) of the
+    /// *separate files* mode. It doesn't apply to an explicit output file path
+    /// or an explicit output pattern. The extension is given without the
+    /// leading dot and may contain dots itself, e.g., `gen.rs`.
+    ///
+    /// Default: `rs`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().output_ext(None);
+    /// CodegenOptions::new().output_ext("gen.rs".to_owned());
+    /// ```
+    pub fn output_ext(&mut self, output_ext: impl Into<Option<String>>) -> &mut Self {
+        self.output_ext = output_ext.into();
+        self
+    }
+
+    /// Enables writing the private tables of a module to a separate file.
+    ///
+    /// The names, display strings and documentation of the error kinds and
+    /// categories are kept in private tables. With large error sets the
+    /// tables dominate the module file. When enabled in *separate files* mode,
+    /// the tables of every module are written to a sibling file named
+    /// `<module>_tables.rs`, where the extension is set by
+    /// [`output_ext`](Self::output_ext), and the module file includes it
+    /// with `include!`. Hence, the tables file must be kept next to the
+    /// module file, and shouldn't be declared as a module.
+    ///
+    /// The generated code behaves identically with or without this option.
+    /// Outside of the *separate files* mode this option is ignored.
+    ///
+    /// This option overrides the `MainObject::split_tables` attribute in
+    /// the specification file.
+    ///
+    /// Default: `false`
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().split_tables(None);
+    /// CodegenOptions::new().split_tables(true);
+    /// CodegenOptions::new().split_tables(Some(false));
+    /// ```
+    pub fn split_tables(&mut self, split_tables: impl Into<Option<bool>>) -> &mut Self {
+        self.split_tables = split_tables.into();
+        self
+    }
+
+    /// Enables the *strict* mode.
+    ///
+    /// By default suspicious, but valid, specification definitions
+    /// are reported as warnings using the [log] crate, and code generation
+    /// proceeds. When enabled the warnings are reported as errors, and code
+    /// generation fails.
+    ///
+    /// For example, two errors in the same module sharing a `display` string
+    /// are reported as a warning. Similarly, a module that gains its second
+    /// category is reported, because category bits are added to the error
+    /// kind layout, compared to the existing output file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().strict(None);
+    /// CodegenOptions::new().strict(true);
+    /// CodegenOptions::new().strict(Some(false));
+    /// ```
+    ///
+    /// [log]: https://docs.rs/log/latest/log
+    pub fn strict(&mut self, strict: impl Into<Option<bool>>) -> &mut Self {
+        self.strict = strict.into();
+        self
+    }
+
+    /// Enables the *strict docs* mode.
+    ///
+    /// When enabled, documentation and display strings containing a tab
+    /// character are rejected, and code generation fails.
+    ///
+    /// YAML doesn't allow tabs in block scalar indentation. Depending on their
+    /// position, tabs may end a multi-line string early, silently truncating
+    /// the documentation text. This mode helps catching such mistakes.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().strict_docs(None);
+    /// CodegenOptions::new().strict_docs(true);
+    /// CodegenOptions::new().strict_docs(Some(false));
+    /// ```
+    pub fn strict_docs(&mut self, strict_docs: impl Into<Option<bool>>) -> &mut Self {
+        self.strict_docs = strict_docs.into();
+        self
+    }
+
+    /// Requires an explicit display string of every error.
+    ///
+    /// When enabled, code generation fails if an error lacks the `display`
+    /// attribute, i.e., it would be displayed as its name. The failure
+    /// names the offending error.
+    ///
+    /// This option overrides the `MainObject::require_display` attribute in
+    /// the specification file.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().require_display(None);
+    /// CodegenOptions::new().require_display(true);
+    /// CodegenOptions::new().require_display(Some(false));
+    /// ```
+    pub fn require_display(&mut self, require_display: impl Into<Option<bool>>) -> &mut Self {
+        self.require_display = require_display.into();
+        self
+    }
+
+    /// Enables the *lenient* parsing mode.
+    ///
+    /// By default an unknown attribute or root-level keyword in the
+    /// specification fails the parsing. When enabled such attributes are
+    /// logged as warnings using the [log] crate and ignored. This allows
+    /// sharing a specification that uses attributes of a newer
+    /// `tighterror-build` with an older version.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().lenient(None);
+    /// CodegenOptions::new().lenient(true);
+    /// CodegenOptions::new().lenient(Some(false));
+    /// ```
+    ///
+    /// [log]: https://docs.rs/log/latest/log
+    pub fn lenient(&mut self, lenient: impl Into<Option<bool>>) -> &mut Self {
+        self.lenient = lenient.into();
+        self
+    }
+
+    /// Sets the output mode.
+    ///
+    /// By default, [`OutputMode::Overwrite`], the output file is overwritten
+    /// wholesale.
+    ///
+    /// In [`OutputMode::Append`] the generated code is placed in a region
+    /// delimited by the following marker lines:
+    ///
+    /// ```text
+    /// // tighterror:begin
+    /// // tighterror:end
+    /// ```
+    ///
+    /// Only the region between the markers is regenerated, and content
+    /// outside of it, e.g., hand-written `impl` blocks, is preserved.
+    /// If the output file doesn't exist or doesn't contain the markers
+    /// the region is appended to the end of the file.
+    /// Similar to the [*update*](Self::update) mode, the output file is
+    /// written only if its content changes.
+    ///
+    /// Because the region may follow hand-written items, module doc comments
+    /// are not generated in this mode.
+    ///
+    /// If the output is written to `stdout` this option is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::{CodegenOptions, OutputMode};
+    /// CodegenOptions::new().output_mode(None);
+    /// CodegenOptions::new().output_mode(OutputMode::Append);
+    /// CodegenOptions::new().output_mode(Some(OutputMode::Overwrite));
+    /// ```
+    pub fn output_mode(&mut self, output_mode: impl Into<Option<OutputMode>>) -> &mut Self {
+        self.output_mode = output_mode.into();
+        self
+    }
+
+    /// Sets the Rust edition used to format the generated code.
+    ///
+    /// The edition is passed to `rustfmt` using the `--edition` flag.
+    /// By default, [`Edition::Edition2021`] is used.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::{CodegenOptions, Edition};
+    /// CodegenOptions::new().edition(None);
+    /// CodegenOptions::new().edition(Edition::Edition2018);
+    /// CodegenOptions::new().edition(Some(Edition::Edition2024));
+    /// ```
+    pub fn edition(&mut self, edition: impl Into<Option<Edition>>) -> &mut Self {
+        self.edition = edition.into();
+        self
+    }
+
+    /// Sets the `rustfmt` configuration file used to format the generated code.
+    ///
+    /// By default `rustfmt` runs from the directory of the output file, so
+    /// the project `rustfmt.toml` is discovered like when running `cargo fmt`,
+    /// regardless of the current working directory. When set, this file is
+    /// passed to `rustfmt` using the `--config-path` flag instead, e.g., to
+    /// format code generated outside of the project tree.
+    ///
+    /// A relative path is relative to the current working directory.
+    /// The code generation fails if the file doesn't exist.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().rustfmt_config("rustfmt.toml");
+    /// ```
+    pub fn rustfmt_config(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.rustfmt_config = Some(path.into());
+        self
+    }
+
+    /// Sets the `rustfmt` configuration file option.
+    ///
+    /// This method enhances [`rustfmt_config`](Self::rustfmt_config) to set
+    /// the configuration file option. This is handy when one needs to reset
+    /// the option back to `None` or has an `Option<PathBuf>` parsed from
+    /// command line.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().rustfmt_config_option(None);
+    /// CodegenOptions::new().rustfmt_config_option(Some("rustfmt.toml".into()));
+    /// ```
+    pub fn rustfmt_config_option(&mut self, path: Option<PathBuf>) -> &mut Self {
+        self.rustfmt_config = path;
+        self
+    }
+
+    /// Enables generation of code for a `no_std` environment.
+    ///
+    /// This option overrides the `MainObject::no_std` attribute in the
+    /// specification file.
+    ///
+    /// If neither this option nor the attribute is set, a `no_std` target
+    /// is detected when the code generator runs in a build script and the
+    /// `CARGO_CFG_TARGET_OS` environment variable equals `none`, i.e., the
+    /// target has no operating system and hence no `std`. Otherwise,
+    /// `no_std` is disabled.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().no_std(None);
+    /// CodegenOptions::new().no_std(true);
+    /// CodegenOptions::new().no_std(Some(false));
+    /// ```
+    pub fn no_std(&mut self, no_std: impl Into<Option<bool>>) -> &mut Self {
+        self.no_std = no_std.into();
+        self
+    }
+
+    /// Sets the number of threads used to generate the modules.
+    ///
+    /// The modules of a specification are independent, so with the
+    /// `parallel` feature enabled their code is generated in parallel using
+    /// a thread pool with this number of threads. By default, or if the
+    /// number is `0`, the global [rayon] thread pool is used. A single thread
+    /// disables the parallel generation.
+    ///
+    /// The generated code doesn't depend on the number of threads.
+    ///
+    /// Without the `parallel` feature this option is ignored and the modules
+    /// are generated one after another.
+    ///
+    /// [rayon]: https://docs.rs/rayon
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().threads(None);
+    /// CodegenOptions::new().threads(4);
+    /// CodegenOptions::new().threads(Some(1));
+    /// ```
+    pub fn threads(&mut self, threads: impl Into<Option<usize>>) -> &mut Self {
+        self.threads = threads.into();
+        self
+    }
+
+    /// Sets the catalog output path.
+    ///
+    /// When set, a machine-readable JSON catalog of the errors is written
+    /// to this path in addition to the generated code. The catalog lists
+    /// every module, category and error kind with its name, display string,
+    /// documentation and numeric value, as assigned in the generated code.
+    /// This is useful, e.g., for documentation sites.
+    ///
+    /// A relative path is relative to the current working directory.
+    /// By default the catalog isn't written.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().catalog_output("errors.json");
+    /// ```
+    pub fn catalog_output(&mut self, path: impl Into<PathBuf>) -> &mut Self {
+        self.catalog_output = Some(path.into());
+        self
+    }
+
+    /// Sets the catalog output path option.
+    ///
+    /// This method enhances [`catalog_output`](Self::catalog_output) to set
+    /// the catalog output path option. This is handy when one needs to reset
+    /// the option back to `None` or has an `Option<PathBuf>` parsed from
+    /// command line.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use tighterror_build::CodegenOptions;
+    /// CodegenOptions::new().catalog_output_option(None);
+    /// CodegenOptions::new().catalog_output_option(Some("errors.json".into()));
+    /// ```
+    pub fn catalog_output_option(&mut self, path: Option<PathBuf>) -> &mut Self {
+        self.catalog_output = path;
+        self
+    }
+
     /// Invokes the code generator [main function] using these options.
     ///
     /// See the struct documentation for a full example.
     ///
     /// [main function]: crate::codegen
-    pub fn codegen(&self) -> Result<(), TbError> {
+    pub fn codegen(&self) -> Result<CodegenReport, TbError> {
         super::codegen(self)
     }
+
+    /// Invokes the code generator and returns a unified diff against the
+    /// existing output instead of writing it.
+    ///
+    /// See [codegen_diff](crate::codegen_diff) for more information.
+    pub fn codegen_diff(&self) -> Result<String, TbError> {
+        super::codegen_diff(self)
+    }
+
+    /// Describes the layout of the generated error kinds without generating
+    /// code.
+    ///
+    /// See [codegen_layout](crate::codegen_layout) for more information.
+    pub fn layout(&self) -> Result<String, TbError> {
+        super::codegen_layout(self)
+    }
+
+    /// Computes the values of the error kinds without generating code.
+    ///
+    /// See [codegen_kind_values](crate::codegen_kind_values) for more
+    /// information.
+    pub fn kind_values(&self) -> Result<BTreeMap<String, BTreeMap<String, u64>>, TbError> {
+        super::codegen_kind_values(self)
+    }
+
+    /// Lists the error kinds without generating code.
+    ///
+    /// See [codegen_list](crate::codegen_list) for more information.
+    pub fn list(&self, json: bool) -> Result<String, TbError> {
+        super::codegen_list(self, json)
+    }
+
+    /// Parses the specification using these options and resolves the
+    /// default values.
+    ///
+    /// See [parse_spec](crate::parse_spec) for more information.
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn parse_spec(&self) -> Result<ResolvedSpec, TbError> {
+        super::parse_spec(self)
+    }
+
+    /// Validates the specification using these options without generating
+    /// code.
+    ///
+    /// See [validate](crate::validate) for more information.
+    pub fn validate(&self) -> Result<(), TbError> {
+        super::validate(self)
+    }
 }
@@ -0,0 +1,41 @@
+use std::path::{Path, PathBuf};
+
+/// A report of the files handled by the code generator.
+///
+/// Returned by [codegen](crate::codegen) and [CodegenOptions::codegen].
+///
+/// When the output is written to `stdout` both lists are empty.
+///
+/// [CodegenOptions::codegen]: crate::CodegenOptions::codegen
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CodegenReport {
+    pub(crate) written: Vec<PathBuf>,
+    pub(crate) unchanged: Vec<PathBuf>,
+}
+
+impl CodegenReport {
+    /// Returns the paths of the files that were created or overwritten.
+    ///
+    /// In *update* mode these are the files that were created or
+    /// whose data changed.
+    pub fn written(&self) -> &[PathBuf] {
+        &self.written
+    }
+
+    /// Returns the paths of the files that were left untouched.
+    ///
+    /// This list may be non-empty only in *update* mode, and includes
+    /// the files whose data equals the generated code.
+    pub fn unchanged(&self) -> &[PathBuf] {
+        &self.unchanged
+    }
+
+    pub(crate) fn add(&mut self, path: &Path, written: bool) {
+        let path = path.to_path_buf();
+        if written {
+            self.written.push(path);
+        } else {
+            self.unchanged.push(path);
+        }
+    }
+}
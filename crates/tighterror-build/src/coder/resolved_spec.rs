@@ -0,0 +1,26 @@
+use crate::spec::Spec;
+
+/// A parsed specification with the default values resolved.
+///
+/// Returned by [parse_spec](crate::parse_spec) and
+/// [CodegenOptions::parse_spec](crate::CodegenOptions::parse_spec).
+///
+/// The specification implements [serde::Serialize], so build scripts can
+/// export it to emit their own artifacts. The attributes missing in the
+/// specification file are set to the values used by the code generator,
+/// e.g., `flat_kinds` is `false` and `err_name` is `"Error"` unless defined
+/// otherwise. The serialized structure follows the specification file:
+/// the root object has the `path`, `hash`, `main` and `modules` attributes,
+/// and every module lists its `categories` and their `errors`. Implicit
+/// modules and categories are named explicitly, and the sub-category errors
+/// are listed in the category with the `subcategory` attribute set.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(transparent)]
+pub struct ResolvedSpec(Spec);
+
+impl ResolvedSpec {
+    pub(crate) fn new(mut spec: Spec) -> Self {
+        spec.resolve_defaults();
+        Self(spec)
+    }
+}
@@ -0,0 +1,2073 @@
+use crate::{
+    errors::kind::{
+        coder::{
+            BAD_INDEX, BAD_OUTPUT_MARKERS, CATEGORY_BITS_ADDED, RUSTFMT_CONFIG_NOT_FOUND,
+            TOO_MANY_BITS, VALUE_WIDTH_TOO_NARROW,
+        },
+        parser::{
+            BAD_OBJECT_ATTRIBUTE, BAD_SPEC_FILE_EXTENSION, BAD_VALUE, BAD_YAML, DUPLICATE_DISPLAY,
+            FAILED_TO_OPEN_SPEC_FILE, FAILED_TO_READ_DOC_FILE, MISSING_DISPLAY, NAME_COLLISION,
+            NON_UNIQUE_NAME, TAB_IN_DOC,
+        },
+    },
+    parser::testing::log_init,
+    CodegenOptions, Edition, OutputMode, SpecLang,
+};
+use std::{collections::BTreeMap, fs, path::Path};
+
+const SPEC: &str = "
+---
+modules:
+  - name: errors
+    categories:
+      - name: General
+        errors:
+          - BAD_FILE
+  - name: internal_errors
+    categories:
+      - name: General
+        errors:
+          - TIMEOUT
+";
+
+fn write_spec(dir: &Path, data: &str) -> std::path::PathBuf {
+    let path = dir.join("tighterror.yaml");
+    fs::write(&path, data).unwrap();
+    path
+}
+
+#[test]
+fn test_codegen_report_single_file() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(report.unchanged().is_empty());
+
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(report.unchanged().is_empty());
+}
+
+#[test]
+fn test_codegen_diff() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+
+    let diff = opts.codegen_diff().unwrap();
+    assert!(diff.starts_with("--- /dev/null\n"));
+    assert!(!output.exists());
+
+    opts.codegen().unwrap();
+    assert_eq!(opts.codegen_diff().unwrap(), "");
+
+    write_spec(dir.path(), "errors: [BAD_FILE, BAD_ARG]");
+    let data = fs::read_to_string(&output).unwrap();
+    let diff = opts.codegen_diff().unwrap();
+    assert!(diff.starts_with(&format!("--- {}\n", output.display())));
+    assert!(diff
+        .lines()
+        .any(|l| l.starts_with('+') && l.contains("BAD_ARG")));
+    assert_eq!(fs::read_to_string(&output).unwrap(), data);
+}
+
+#[test]
+fn test_codegen_diff_separate_files() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(dir.path()).separate_files(true);
+    opts.codegen().unwrap();
+    assert_eq!(opts.codegen_diff().unwrap(), "");
+
+    write_spec(dir.path(), &SPEC.replace("TIMEOUT", "QUEUE_FULL"));
+    let diff = opts.codegen_diff().unwrap();
+    let internal_errors = dir.path().join("internal_errors.rs");
+    assert!(diff.starts_with(&format!("--- {}\n", internal_errors.display())));
+    assert_eq!(diff.matches("\n+++ ").count(), 1);
+}
+
+#[test]
+fn test_codegen_module_filename() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "module:\n  name: my_errors\nerrors: [BAD_FILE]");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(dir.path());
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [dir.path().join("tighterror.rs")]);
+
+    opts.module_filename(true);
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [dir.path().join("my_errors.rs")]);
+}
+
+#[test]
+fn test_codegen_module_filename_multiple_modules() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+
+    let report = CodegenOptions::new()
+        .spec(&spec)
+        .output(dir.path())
+        .module_filename(true)
+        .codegen()
+        .unwrap();
+    assert_eq!(report.written(), [dir.path().join("tighterror.rs")]);
+    assert!(!dir.path().join("errors.rs").exists());
+}
+
+#[test]
+fn test_codegen_output_ext() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec)
+        .output(dir.path())
+        .output_ext("gen.rs".to_owned());
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [dir.path().join("tighterror.gen.rs")]);
+
+    let report = opts.separate_files(true).codegen().unwrap();
+    assert_eq!(
+        report.written(),
+        [
+            dir.path().join("errors.gen.rs"),
+            dir.path().join("internal_errors.gen.rs")
+        ]
+    );
+
+    let report = opts
+        .output_pattern("gen_{module}.rs".to_owned())
+        .codegen()
+        .unwrap();
+    assert_eq!(
+        report.written(),
+        [
+            dir.path().join("gen_errors.rs"),
+            dir.path().join("gen_internal_errors.rs")
+        ]
+    );
+
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let report = CodegenOptions::new()
+        .spec(&spec)
+        .output(dir.path())
+        .output_ext("gen.rs".to_owned())
+        .module_filename(true)
+        .codegen()
+        .unwrap();
+    assert_eq!(report.written(), [dir.path().join("errors.gen.rs")]);
+
+    for bad in ["", ".rs", "gen/rs", "gen\\rs"] {
+        let err = CodegenOptions::new()
+            .spec(&spec)
+            .output(dir.path())
+            .output_ext(bad.to_owned())
+            .codegen()
+            .unwrap_err();
+        assert_eq!(err.kind(), BAD_VALUE, "{bad}");
+    }
+}
+
+#[test]
+fn test_codegen_split_tables() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec)
+        .output(dir.path())
+        .separate_files(true)
+        .split_tables(true);
+    let report = opts.codegen().unwrap();
+    assert_eq!(
+        report.written(),
+        [
+            dir.path().join("errors.rs"),
+            dir.path().join("errors_tables.rs"),
+            dir.path().join("internal_errors.rs"),
+            dir.path().join("internal_errors_tables.rs"),
+        ]
+    );
+    let code = fs::read_to_string(dir.path().join("errors.rs")).unwrap();
+    assert!(code.contains("include!(\"errors_tables.rs\");"));
+    assert!(!code.contains("mod _n {"));
+    assert!(code.contains("mod _p {"));
+    let tables = fs::read_to_string(dir.path().join("errors_tables.rs")).unwrap();
+    assert!(tables.contains("mod _n {"));
+    assert!(tables.contains("mod _d {"));
+    assert!(!tables.contains("mod _p {"));
+
+    let report = opts.update(true).codegen().unwrap();
+    assert!(report.written().is_empty());
+    assert_eq!(opts.codegen_diff().unwrap(), "");
+
+    let report = opts
+        .update(false)
+        .output_ext("gen.rs".to_owned())
+        .codegen()
+        .unwrap();
+    assert!(report
+        .written()
+        .contains(&dir.path().join("errors_tables.gen.rs")));
+
+    let code = codegen_str(
+        dir.path(),
+        "main:\n  split_tables: true\nerrors: [BAD_FILE]",
+    )
+    .unwrap();
+    assert!(code.contains("mod _n {"));
+    assert!(!code.contains("include!("));
+}
+
+#[test]
+fn test_codegen_split_tables_module_collision() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        &format!("{SPEC}  - name: errors_tables\n    categories:\n      - name: General\n        errors: [QUEUE_FULL]\n"),
+    );
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(dir.path()).separate_files(true);
+    opts.codegen().unwrap();
+    fs::remove_file(dir.path().join("errors_tables.rs")).unwrap();
+
+    let err = opts.split_tables(true).codegen().unwrap_err();
+    assert_eq!(err.kind(), NAME_COLLISION);
+    assert!(!dir.path().join("errors_tables.rs").exists());
+}
+
+#[test]
+fn test_codegen_report_update() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output).update(true);
+
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(report.unchanged().is_empty());
+
+    let report = opts.codegen().unwrap();
+    assert!(report.written().is_empty());
+    assert_eq!(report.unchanged(), [output.as_path()]);
+
+    write_spec(dir.path(), "errors: [BAD_FILE, TIMEOUT]");
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(report.unchanged().is_empty());
+}
+
+#[test]
+fn test_codegen_report_update_separate_files() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+    let errors = dir.path().join("errors.rs");
+    let internal_errors = dir.path().join("internal_errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec)
+        .output(dir.path())
+        .update(true)
+        .separate_files(true);
+
+    let report = opts.codegen().unwrap();
+    assert_eq!(
+        report.written(),
+        [errors.as_path(), internal_errors.as_path()]
+    );
+    assert!(report.unchanged().is_empty());
+
+    write_spec(dir.path(), &SPEC.replace("TIMEOUT", "QUEUE_FULL"));
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [internal_errors.as_path()]);
+    assert_eq!(report.unchanged(), [errors.as_path()]);
+}
+
+#[test]
+fn test_codegen_output_pattern() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec)
+        .output(dir.path())
+        .separate_files(true)
+        .output_pattern("gen_{module}.rs".to_owned());
+    let report = opts.codegen().unwrap();
+    assert_eq!(
+        report.written(),
+        [
+            dir.path().join("gen_errors.rs"),
+            dir.path().join("gen_internal_errors.rs")
+        ]
+    );
+    assert!(!dir.path().join("errors.rs").exists());
+
+    let report = opts
+        .output_pattern("{module}/mod.rs".to_owned())
+        .update(true)
+        .codegen()
+        .unwrap();
+    let mod_rs = dir.path().join("errors/mod.rs");
+    assert_eq!(report.written()[0], mod_rs);
+    assert!(fs::read_to_string(&mod_rs).unwrap().contains("BAD_FILE"));
+
+    opts.output_pattern("gen_errors.rs".to_owned());
+    assert_eq!(opts.codegen().unwrap_err().kind(), BAD_VALUE);
+}
+
+#[test]
+fn test_codegen_output_pattern_in_spec() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        &SPEC.replacen(
+            "---\n",
+            "---\nmain:\n  output_pattern: gen_{module}.rs\n",
+            1,
+        ),
+    );
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(dir.path()).separate_files(true);
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written()[0], dir.path().join("gen_errors.rs"));
+
+    let report = opts
+        .output_pattern("{module}.rs".to_owned())
+        .codegen()
+        .unwrap();
+    assert_eq!(report.written()[0], dir.path().join("errors.rs"));
+}
+
+#[test]
+fn test_codegen_doc_file() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("docs")).unwrap();
+    fs::write(
+        dir.path().join("docs/bad_file.md"),
+        "A bad file.\n\nLong doc.\n",
+    )
+    .unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "errors:\n  - name: BAD_FILE\n    doc_file: docs/bad_file.md",
+    );
+    let output = dir.path().join("errors.rs");
+
+    CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .codegen()
+        .unwrap();
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.contains("A bad file."));
+    assert!(code.contains("Long doc."));
+}
+
+#[test]
+fn test_codegen_doc_file_missing() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "errors:\n  - name: BAD_FILE\n    doc_file: docs/missing.md",
+    );
+    let output = dir.path().join("errors.rs");
+
+    let err = CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .codegen()
+        .unwrap_err();
+    assert_eq!(err.kind(), FAILED_TO_READ_DOC_FILE);
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_codegen_lenient() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "module:\n  from_the_future: true\nerrors:\n  - BAD_FILE",
+    );
+    let output = dir.path().join("errors.rs");
+
+    let err = CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .codegen()
+        .unwrap_err();
+    assert_eq!(err.kind(), BAD_OBJECT_ATTRIBUTE);
+    assert!(!output.exists());
+
+    CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .lenient(true)
+        .codegen()
+        .unwrap();
+    assert!(fs::read_to_string(&output).unwrap().contains("BAD_FILE"));
+}
+
+const CATEGORIES_IN_ORDER: &str = "
+---
+categories:
+  - name: Parser
+    errors: [BAD_TOKEN, QUEUE_FULL]
+  - name: Processor
+    errors: [TIMEOUT]
+  - name: General
+    errors: [BAD_ARG]
+";
+
+const CATEGORIES_PINNED: &str = "
+---
+categories:
+  - name: General
+    index: 2
+    errors: [BAD_ARG]
+  - name: Parser
+    index: 0
+    errors: [BAD_TOKEN, QUEUE_FULL]
+  - name: Processor
+    index: 1
+    errors: [TIMEOUT]
+";
+
+const ERRORS_PINNED: &str = "
+---
+categories:
+  - name: Parser
+    errors:
+      - name: QUEUE_FULL
+        index: 1
+      - name: BAD_TOKEN
+        index: 0
+  - name: Processor
+    errors:
+      - name: TIMEOUT
+        index: 0
+  - name: General
+    errors: [BAD_ARG]
+";
+
+fn codegen_str(dir: &Path, spec: &str) -> Result<String, crate::errors::TbError> {
+    let spec = write_spec(dir, spec);
+    let output = dir.join("errors.rs");
+    CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .test(true)
+        .codegen()?;
+    Ok(fs::read_to_string(&output).unwrap())
+}
+
+#[test]
+fn test_codegen_category_index() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let in_order = codegen_str(dir.path(), CATEGORIES_IN_ORDER).unwrap();
+    let pinned = codegen_str(dir.path(), CATEGORIES_PINNED).unwrap();
+    assert_eq!(in_order, pinned);
+}
+
+#[test]
+fn test_codegen_category_index_bad() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    for bad in [
+        CATEGORIES_PINNED.replace("index: 2", "index: 1"),
+        CATEGORIES_PINNED.replace("index: 2", "index: 3"),
+        CATEGORIES_PINNED.replace("    index: 2\n", ""),
+    ] {
+        assert_eq!(codegen_str(dir.path(), &bad).unwrap_err().kind(), BAD_INDEX);
+    }
+}
+
+#[test]
+fn test_codegen_error_index() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let in_order = codegen_str(dir.path(), CATEGORIES_IN_ORDER).unwrap();
+    let pinned = codegen_str(dir.path(), ERRORS_PINNED).unwrap();
+    assert_eq!(in_order, pinned);
+
+    let spec = write_spec(dir.path(), ERRORS_PINNED);
+    let catalog = dir.path().join("errors.json");
+    CodegenOptions::new()
+        .spec(&spec)
+        .output(dir.path().join("errors.rs"))
+        .catalog_output(&catalog)
+        .codegen()
+        .unwrap();
+    let catalog = fs::read_to_string(&catalog).unwrap();
+    let bad_token = catalog.find("\"name\": \"BAD_TOKEN\"").unwrap();
+    let queue_full = catalog.find("\"name\": \"QUEUE_FULL\"").unwrap();
+    assert!(bad_token < queue_full);
+}
+
+#[test]
+fn test_codegen_error_index_bad() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    for bad in [
+        ERRORS_PINNED.replace("index: 1", "index: 0"),
+        ERRORS_PINNED.replace("index: 1", "index: 2"),
+        ERRORS_PINNED.replace("        index: 1\n", ""),
+    ] {
+        assert_eq!(codegen_str(dir.path(), &bad).unwrap_err().kind(), BAD_INDEX);
+    }
+}
+
+#[test]
+fn test_codegen_value_widths() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  value_widths: [8, 32]\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub fn value_u8(&self) -> u8"));
+    assert!(code.contains("pub fn value_u32(&self) -> u32"));
+    assert!(!code.contains("pub fn value_u16(&self)"));
+
+    let errors: Vec<String> = (0..257).map(|i| format!("ERR{i}")).collect();
+    for (widths, res) in [
+        ("[16, 64]", Ok(())),
+        ("[64, 8]", Err(VALUE_WIDTH_TOO_NARROW)),
+    ] {
+        let spec = format!(
+            "---\nmodule:\n  value_widths: {widths}\nerrors: [{}]\n",
+            errors.join(", ")
+        );
+        let got = codegen_str(dir.path(), &spec)
+            .map(|_| ())
+            .map_err(|e| e.kind());
+        assert_eq!(got, res, "{widths}");
+    }
+}
+
+#[test]
+fn test_codegen_category_sets() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  category_sets: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub struct ErrorCategorySet(u8);"));
+    assert!(code.contains("impl core::ops::BitOr for ErrorCategory {"));
+    assert!(code.contains("fn test_category_sets()"));
+
+    let spec = "---\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("ErrorCategorySet"));
+
+    for (n, res) in [(64, Ok(())), (65, Err(TOO_MANY_BITS))] {
+        let categories: String = (0..n)
+            .map(|i| format!("  - name: Cat{i}\n    errors: [ERR]\n"))
+            .collect();
+        let spec = format!("---\nmodule:\n  category_sets: true\ncategories:\n{categories}");
+        let got = codegen_str(dir.path(), &spec)
+            .map(|_| ())
+            .map_err(|e| e.kind());
+        assert_eq!(got, res, "{n}");
+    }
+}
+
+#[test]
+fn test_codegen_category_kinds() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  err_cat_name: MyCat\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub fn kinds(&self) -> MyCatKinds {"));
+    assert!(code.contains("pub struct MyCatKinds {"));
+    assert!(code.contains("impl ExactSizeIterator for MyCatKinds {}"));
+    assert!(code.contains("impl IntoIterator for MyCat {"));
+    assert!(code.contains("fn test_category_kinds()"));
+}
+
+#[test]
+fn test_codegen_catalog_static() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nerrors:\n  - name: BAD_FILE\n    doc: Bad file.\n  - BAD_ARG\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("CATALOG"));
+    assert!(!code.contains("mod _doc"));
+    assert!(!code.contains("fn test_catalog()"));
+
+    let spec = format!("---\nmodule:\n  catalog: true\n{}", &spec[4..]);
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub static CATALOG: [(&str, &str, &str); 2] = ["));
+    assert!(code.contains("\"GENERAL::BAD_FILE\",\n        _d::general::BAD_FILE,"));
+    assert!(code.contains("pub(crate) const BAD_FILE: &str = \"Bad file.\";"));
+    assert!(code.contains("pub(crate) const BAD_ARG: &str = \"\";"));
+    assert!(code.contains("fn test_catalog()"));
+
+    let spec = spec.replace("module:\n", "module:\n  flat_kinds: true\n");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub static CATALOG: [(&str, &str, &str); 2] = ["));
+    assert!(code.contains("_n::general::BAD_FILE,\n        _d::general::BAD_FILE,"));
+}
+
+#[test]
+fn test_codegen_prelude() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  prelude: true\ncategories:\n  - name: Parser\n    errors: [BAD_TOKEN]\n  - name: General\n    errors: [BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub mod prelude {"));
+    assert!(code.contains("pub use super::kind::{general::BAD_ARG, parser::BAD_TOKEN};"));
+    assert!(code.contains("fn test_prelude()"));
+
+    let spec = spec.replace("prelude: true", "prelude: true\n  flat_kinds: true");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub use super::kind::{BAD_ARG, BAD_TOKEN};"));
+
+    let spec = spec.replace("prelude: true", "prelude: false");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(!code.contains("pub mod prelude {"));
+    assert!(!code.contains("fn test_prelude()"));
+}
+
+#[test]
+fn test_codegen_category_default() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  category_default: true\ncategories:\n  - name: Parser\n    index: 1\n    errors: [BAD_TOKEN]\n  - name: General\n    index: 0\n    errors: [BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("impl core::default::Default for ErrorCategory {"));
+    assert!(code.contains("category::GENERAL\n"));
+    assert!(code.contains("fn test_category_default()"));
+
+    let spec = spec.replace("category_default: true", "category_default: false");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(!code.contains("impl core::default::Default for ErrorCategory {"));
+    assert!(!code.contains("fn test_category_default()"));
+}
+
+#[test]
+fn test_codegen_display_case() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec_str = |case: &str, display: &str| {
+        format!("---\nmodule:\n  display_case: {case}\nerrors:\n  - BAD_FILE: {display}\n")
+    };
+    for (case, display, converted) in [
+        ("sentence", "bad file", "Bad file"),
+        ("lower", "Bad file", "bad file"),
+        ("lower", "IO failure", "IO failure"),
+        ("lower", "I/O failed", "I/O failed"),
+        (
+            "lower",
+            "IPv6 address is invalid",
+            "IPv6 address is invalid",
+        ),
+        ("lower", "A File is missing", "a File is missing"),
+        ("none", "bad file", "bad file"),
+        ("none", "Bad file", "Bad file"),
+    ] {
+        let code = codegen_str(dir.path(), &spec_str(case, display)).unwrap();
+        assert!(
+            code.contains(&format!("\"{converted}\"")),
+            "{case}: {display}"
+        );
+        if display != converted {
+            assert!(
+                !code.contains(&format!("\"{display}\"")),
+                "{case}: {display}"
+            );
+        }
+    }
+}
+
+#[test]
+fn test_codegen_display_with_code() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  display_with_code: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("_n::C[self.category_value() as usize][self.variant() as usize]"));
+    assert!(code.contains("_d::C[self.0.category_value() as usize][self.0.variant() as usize]"));
+    assert!(
+        code.contains("pub static C: [&[&str]; 1] = [&[\"BAD_FILE [0x0]\", \"BAD_ARG [0x1]\"]];")
+    );
+    assert!(!code.contains("write!(f,"));
+
+    let spec = spec.replace("true", "true\n  display_code_radix: decimal");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("\"BAD_ARG [1]\""));
+
+    let spec = spec.replace(
+        "true\n",
+        "true\n  display_is_message_id: true\n  display_resolver: resolve\n",
+    );
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("write!(f, \" [{}]\", self.0.value())"));
+    assert_eq!(code.matches("pub static C:").count(), 1);
+
+    let spec = "---\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("write!(f,"));
+}
+
+#[test]
+fn test_codegen_kind_iter() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  kind_iter: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code
+        .contains("pub const VARIANTS: &'static [&'static str] = &[\"BAD_FILE\", \"BAD_ARG\"];"));
+    assert!(
+        code.contains("pub fn iter() -> core::iter::Copied<core::slice::Iter<'static, Self>> {")
+    );
+    assert!(code.contains("pub static ALL: [super::ErrorKind; 2]"));
+    assert!(code.contains("fn test_err_kind_iter()"));
+
+    let spec = spec.replace("kind_iter: true", "kind_iter: false");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(!code.contains("VARIANTS"));
+    assert!(!code.contains("pub static ALL: [super::"));
+}
+
+#[test]
+fn test_codegen_proptest() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec =
+        "---\nmodule:\n  proptest: true\n  err_kind_name: MyErrKind\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub fn any_my_err_kind() -> proptest::sample::Select<MyErrKind> {"));
+    assert!(code.contains("impl proptest::arbitrary::Arbitrary for MyErrKind {"));
+    assert!(code.contains("pub static ALL: [super::MyErrKind; 2]"));
+    assert!(code.contains("fn test_err_kind_proptest("));
+
+    let spec = "---\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("proptest"));
+    assert!(!code.contains("pub static ALL: [super::"));
+}
+
+#[test]
+fn test_codegen_doc_cfg() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  serde: true\n  arbitrary: true\n  proptest: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("#[cfg(feature = \"serde\")]\nimpl serde::Serialize for ErrorKind {"));
+    assert!(!code.contains("docsrs"));
+
+    let spec = spec.replace("module:\n", "module:\n  doc_cfg: true\n");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    for (feature, item) in [
+        ("serde", "impl serde::Serialize for ErrorKind {"),
+        ("serde", "impl<'de> serde::Deserialize<'de> for ErrorKind {"),
+        (
+            "arbitrary",
+            "impl<'a> arbitrary::Arbitrary<'a> for ErrorKind {",
+        ),
+        (
+            "proptest",
+            "impl proptest::arbitrary::Arbitrary for ErrorKind {",
+        ),
+        ("proptest", "#[inline]\npub fn any_error_kind()"),
+    ] {
+        let gated = format!(
+            "#[cfg(feature = \"{feature}\")]\n#[cfg_attr(docsrs, doc(cfg(feature = \"{feature}\")))]\n{item}"
+        );
+        assert!(code.contains(&gated), "{gated}");
+    }
+    // the generated tests aren't documented
+    assert_eq!(code.matches("cfg_attr(docsrs").count(), 5);
+}
+
+#[test]
+fn test_codegen_display_is_message_id() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  display_is_message_id: true\n  display_resolver: crate::i18n::message\nerrors:\n  - BAD_FILE: bad-file\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("fn _message(id: &'static str) -> impl core::fmt::Display {"));
+    assert!(code.contains("crate::i18n::message(id)"));
+    assert!(code.contains("core::fmt::Display::fmt(&_message(self.message()), f)"));
+    assert!(code.contains("format!(\"{}\", _message(\"bad-file\"))"));
+
+    let spec = "---\nerrors:\n  - BAD_FILE: bad-file\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("fn _message("));
+    assert!(code.contains("f.pad(self.message())"));
+}
+
+#[test]
+fn test_codegen_result_from_tests() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  result_from_err: false\nerrors: [BAD_FILE]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("for core::result::Result<T, Error>"));
+    let test = code.split("fn test_result_from_err()").nth(1).unwrap();
+    assert!(test.contains("assert!(!implemented);"));
+    let test = code.split("fn test_result_from_err_kind()").nth(1).unwrap();
+    assert!(test.contains("impl<T: core::convert::From<ErrorKind>> Probe<T>"));
+    assert!(test.contains("assert!(implemented);"));
+}
+
+#[test]
+fn test_codegen_debug_stable() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  debug_stable: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains(".field(\"val\", &self.0)"));
+    assert!(code.contains("fn test_err_kind_debug_stable()"));
+
+    let spec = "---\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains(".field(\"val\", &self.0)"));
+    assert!(!code.contains("fn test_err_kind_debug_stable()"));
+}
+
+#[test]
+fn test_codegen_qualified_name() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    for flat_kinds in [false, true] {
+        let spec = format!(
+            "---\nmodule:\n  flat_kinds: {flat_kinds}\ncategories:\n  - name: Parser\n    errors: [BAD_TOKEN]\n"
+        );
+        let code = codegen_str(dir.path(), &spec).unwrap();
+        assert!(code.contains("pub fn qualified_name(&self) -> &'static str {"));
+        assert!(code.contains("\"PARSER::BAD_TOKEN\""));
+        assert!(code.contains("fn test_err_kind_qualified_name()"));
+    }
+}
+
+#[test]
+fn test_codegen_display_category() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\ncategories:\n  - name: Parser\n    errors:\n      - BAD_TOKEN: \"{category} failed {{category}}.\"\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("\"Parser failed {category}.\""));
+    assert!(!code.contains("\"{category} failed"));
+}
+
+#[test]
+fn test_codegen_assoc_consts() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  assoc_consts: true\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub const GENERAL_BAD_FILE: Self = kind::general::BAD_FILE;"));
+    assert!(code.contains("fn test_err_kind_assoc_consts()"));
+
+    let spec = spec.replace(
+        "assoc_consts: true\n",
+        "assoc_consts: true\n  flat_kinds: true\n",
+    );
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub const BAD_FILE: Self = kind::BAD_FILE;"));
+
+    let spec = "---\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("pub const GENERAL_BAD_FILE: Self"));
+}
+
+#[test]
+fn test_codegen_module_feature() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = SPEC.replacen(
+        "  - name: internal_errors\n",
+        "  - name: internal_errors\n    feature: internal\n    doc_cfg: true\n",
+        1,
+    );
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains(
+        "#[cfg(feature = \"internal\")]\n#[cfg_attr(docsrs, doc(cfg(feature = \"internal\")))]\npub mod internal_errors {"
+    ));
+    assert!(!code.contains("#[cfg(feature = \"internal\")]\npub mod errors {"));
+    assert_eq!(code.matches("#[cfg(feature = ").count(), 1);
+
+    let spec = write_spec(dir.path(), &spec);
+    CodegenOptions::new()
+        .spec(&spec)
+        .output(dir.path())
+        .separate_files(true)
+        .codegen()
+        .unwrap();
+    let code = fs::read_to_string(dir.path().join("internal_errors.rs")).unwrap();
+    assert!(code.contains("#![cfg(feature = \"internal\")]"));
+    assert!(code.contains("#![cfg_attr(docsrs, doc(cfg(feature = \"internal\")))]"));
+    let code = fs::read_to_string(dir.path().join("errors.rs")).unwrap();
+    assert!(!code.contains("cfg(feature"));
+}
+
+#[test]
+fn test_codegen_main_test() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("errors.rs");
+    let codegen = |spec: &str, test: Option<bool>| {
+        let spec = write_spec(dir.path(), spec);
+        CodegenOptions::new()
+            .spec(&spec)
+            .output(&output)
+            .test(test)
+            .codegen()
+            .unwrap();
+        fs::read_to_string(&output).unwrap().contains("#[test]")
+    };
+    let main_test = |v: bool| SPEC.replacen("---\n", &format!("---\nmain:\n  test: {v}\n"), 1);
+
+    assert!(!codegen(SPEC, None));
+    assert!(codegen(&main_test(true), None));
+    assert!(!codegen(&main_test(false), None));
+    assert!(!codegen(&main_test(true), Some(false)));
+    assert!(codegen(&main_test(false), Some(true)));
+}
+
+#[test]
+fn test_codegen_catalog() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "
+---
+module:
+  doc: Parser errors.
+categories:
+  - name: Processor
+    index: 1
+    doc: Processing errors.
+    errors:
+      - TIMEOUT: Operation timed out.
+  - name: Parser
+    index: 0
+    errors:
+      - BAD_TOKEN
+      - name: QUEUE_FULL
+        display: Parser queue is full.
+        doc: The parser queue has no room for more tokens.
+",
+    );
+    let output = dir.path().join("errors.rs");
+    let catalog = dir.path().join("errors.json");
+
+    let report = CodegenOptions::new()
+        .spec(&spec)
+        .output(&output)
+        .catalog_output(&catalog)
+        .codegen()
+        .unwrap();
+    assert_eq!(report.written(), [output.as_path(), catalog.as_path()]);
+
+    let json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&catalog).unwrap()).unwrap();
+    let expected = serde_json::json!({
+        "modules": [{
+            "name": "errors",
+            "doc": "Parser errors.",
+            "categories": [
+                {
+                    "name": "Parser",
+                    "doc": "",
+                    "value": 0,
+                    "kinds": [
+                        {
+                            "name": "BAD_TOKEN",
+                            "display": "BAD_TOKEN",
+                            "doc": "",
+                            "category": "Parser",
+                            "value": 0,
+                        },
+                        {
+                            "name": "QUEUE_FULL",
+                            "display": "Parser queue is full.",
+                            "doc": "The parser queue has no room for more tokens.",
+                            "category": "Parser",
+                            "value": 1,
+                        },
+                    ],
+                },
+                {
+                    "name": "Processor",
+                    "doc": "Processing errors.",
+                    "value": 1,
+                    "kinds": [{
+                        "name": "TIMEOUT",
+                        "display": "Operation timed out.",
+                        "doc": "",
+                        "category": "Processor",
+                        "value": 2,
+                    }],
+                },
+            ],
+        }],
+    });
+    assert_eq!(json, expected);
+
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.contains("pub const CAT_BITS: usize = 1;"));
+    assert!(code.contains("pub const VAR_BITS: usize = 1;"));
+}
+
+#[test]
+fn test_codegen_same_err_name_in_multiple_modules() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    // multiple modules in a single file are always enclosed in module blocks,
+    // hence identical top-level identifiers of different modules don't collide
+    let spec = SPEC.replace(
+        "    categories:\n",
+        "    err_name: AppError\n    categories:\n",
+    );
+    assert_eq!(spec.matches("err_name: AppError").count(), 2);
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub mod errors {"));
+    assert!(code.contains("pub mod internal_errors {"));
+    assert_eq!(code.matches("pub struct AppError(").count(), 2);
+}
+
+#[test]
+fn test_codegen_variant_type_name_collides_across_modules() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = SPEC.replacen(
+        "    categories:\n",
+        "    err_name: Timeout\n    categories:\n",
+        1,
+    );
+    assert!(codegen_str(dir.path(), &spec).is_ok());
+
+    let spec = spec.replace(
+        "          - TIMEOUT\n",
+        "          - name: TIMEOUT\n            variant_type: true\n",
+    );
+    assert_eq!(
+        codegen_str(dir.path(), &spec).unwrap_err().kind(),
+        NAME_COLLISION
+    );
+
+    let spec = spec.replace("err_name: Timeout", "err_name: AppError");
+    assert!(codegen_str(dir.path(), &spec).is_ok());
+}
+
+#[test]
+fn test_codegen_is_deterministic() {
+    log_init();
+    let spec = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/multiple_modules/tighterror.yaml");
+    let dirs = [tempfile::tempdir().unwrap(), tempfile::tempdir().unwrap()];
+    let reports = dirs.each_ref().map(|d| {
+        CodegenOptions::new()
+            .spec(&spec)
+            .output(d.path())
+            .test(true)
+            .separate_files(true)
+            .codegen()
+            .unwrap()
+    });
+
+    assert!(!reports[0].written().is_empty());
+    assert_eq!(reports[0].written().len(), reports[1].written().len());
+    for (p0, p1) in reports[0].written().iter().zip(reports[1].written()) {
+        assert_eq!(p0.file_name(), p1.file_name());
+        assert_eq!(fs::read(p0).unwrap(), fs::read(p1).unwrap());
+    }
+}
+
+const HAND_WRITTEN_HEAD: &str = "//! Hand-written module doc.\n\nuse core::fmt;\n";
+const HAND_WRITTEN_TAIL: &str = "\nimpl Error {\n    fn custom(&self) {}\n}\n";
+
+#[test]
+fn test_codegen_output_mode_append() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+    fs::write(&output, HAND_WRITTEN_HEAD).unwrap();
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec)
+        .output(&output)
+        .output_mode(OutputMode::Append);
+
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.starts_with(HAND_WRITTEN_HEAD));
+    assert!(code.contains("\n// tighterror:begin\n"));
+    assert!(code.contains("pub const BAD_FILE"));
+    assert!(code.ends_with("// tighterror:end\n"));
+
+    let report = opts.codegen().unwrap();
+    assert!(report.written().is_empty());
+    assert_eq!(report.unchanged(), [output.as_path()]);
+
+    let code = format!("{code}{HAND_WRITTEN_TAIL}");
+    fs::write(&output, &code).unwrap();
+    write_spec(dir.path(), "errors: [BAD_FILE, TIMEOUT]");
+    let report = opts.codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.starts_with(HAND_WRITTEN_HEAD));
+    assert!(code.ends_with(&format!("// tighterror:end\n{HAND_WRITTEN_TAIL}")));
+    assert!(code.contains("pub const TIMEOUT"));
+    assert_eq!(code.matches("// tighterror:begin").count(), 1);
+}
+
+#[test]
+fn test_codegen_output_mode_append_bad_markers() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+
+    for bad in [
+        "// tighterror:begin\n",
+        "// tighterror:end\n",
+        "// tighterror:end\n// tighterror:begin\n",
+        "// tighterror:begin\n// tighterror:end\n// tighterror:begin\n",
+        "// tighterror:begin\n// tighterror:end\n// tighterror:end\n",
+    ] {
+        fs::write(&output, bad).unwrap();
+        let err = CodegenOptions::new()
+            .spec(&spec)
+            .output(&output)
+            .output_mode(OutputMode::Append)
+            .codegen()
+            .unwrap_err();
+        assert_eq!(err.kind(), BAD_OUTPUT_MARKERS);
+        assert_eq!(fs::read_to_string(&output).unwrap(), bad);
+    }
+}
+
+#[test]
+fn test_codegen_module_test_override() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let errors = dir.path().join("errors.rs");
+    let internal_errors = dir.path().join("internal_errors.rs");
+    let has_test = |p: &Path| fs::read_to_string(p).unwrap().contains("#[cfg(test)]");
+
+    for global in [true, false] {
+        let spec = write_spec(
+            dir.path(),
+            &SPEC.replace(
+                "  - name: internal_errors\n",
+                &format!("  - name: internal_errors\n    test: {}\n", !global),
+            ),
+        );
+        CodegenOptions::new()
+            .spec(&spec)
+            .output(dir.path())
+            .test(global)
+            .separate_files(true)
+            .codegen()
+            .unwrap();
+        assert_eq!(has_test(&errors), global);
+        assert_eq!(has_test(&internal_errors), !global);
+    }
+}
+
+#[test]
+fn test_codegen_module_allows() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+
+    let code = codegen_str(
+        dir.path(),
+        "module:\n  allows: [clippy::all, dead_code]\nerrors: [BAD_FILE]",
+    )
+    .unwrap();
+    assert!(code.contains("#![allow(clippy::all, dead_code)]"));
+
+    let code = codegen_str(
+        dir.path(),
+        &SPEC.replace(
+            "  - name: internal_errors\n",
+            "  - name: internal_errors\n    allows: [clippy::all]\n",
+        ),
+    )
+    .unwrap();
+    assert!(code.contains("#[allow(clippy::all)]\npub mod internal_errors {"));
+    assert_eq!(code.matches("allow(").count(), 1);
+}
+
+#[test]
+fn test_codegen_edition() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let output = dir.path().join("errors.rs");
+
+    for edition in [
+        Edition::Edition2015,
+        Edition::Edition2018,
+        Edition::Edition2021,
+        Edition::Edition2024,
+    ] {
+        let report = CodegenOptions::new()
+            .spec(&spec)
+            .output(&output)
+            .edition(edition)
+            .codegen()
+            .unwrap();
+        assert_eq!(report.written(), [output.as_path()]);
+        let code = fs::read_to_string(&output).unwrap();
+        assert!(code.contains("pub struct Error"));
+    }
+}
+
+#[test]
+fn test_codegen_rustfmt_config() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    let is_indented = |code: &str| code.lines().any(|l| l.starts_with("   pub fn "));
+
+    let out_dir = dir.path().join("out");
+    fs::create_dir(&out_dir).unwrap();
+    let output = out_dir.join("errors.rs");
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+    opts.codegen().unwrap();
+    assert!(!is_indented(&fs::read_to_string(&output).unwrap()));
+
+    fs::write(out_dir.join("rustfmt.toml"), "tab_spaces = 3\n").unwrap();
+    opts.codegen().unwrap();
+    assert!(is_indented(&fs::read_to_string(&output).unwrap()));
+
+    let config_dir = dir.path().join("config");
+    fs::create_dir(&config_dir).unwrap();
+    let config = config_dir.join("rustfmt.toml");
+    fs::write(&config, "tab_spaces = 4\n").unwrap();
+    opts.rustfmt_config(&config).codegen().unwrap();
+    assert!(!is_indented(&fs::read_to_string(&output).unwrap()));
+
+    let err = opts
+        .rustfmt_config(config_dir.join("missing.toml"))
+        .codegen()
+        .unwrap_err();
+    assert_eq!(err.kind(), RUSTFMT_CONFIG_NOT_FOUND);
+}
+
+#[test]
+fn test_codegen_yml_extension() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let data = "module:\n  err_name: AppError\nerrors:\n  - BAD_FILE: A file is bad.\n";
+    let yaml = write_spec(dir.path(), data);
+    let yml = dir.path().join("tighterror.yml");
+    fs::write(&yml, data).unwrap();
+
+    let yaml_spec = crate::parser::parse(Some(&yaml), None, false).unwrap();
+    let yml_spec = crate::parser::parse(Some(&yml), None, false).unwrap();
+    assert_eq!(yml_spec.path, yml);
+    assert_eq!(yml_spec.main, yaml_spec.main);
+    assert_eq!(yml_spec.modules, yaml_spec.modules);
+
+    let output = dir.path().join("errors.rs");
+    let report = CodegenOptions::new()
+        .spec(&yml)
+        .output(&output)
+        .codegen()
+        .unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(fs::read_to_string(&output)
+        .unwrap()
+        .contains("pub struct AppError("));
+}
+
+#[test]
+fn test_codegen_spec_lang() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = dir.path().join("errors.spec");
+    fs::write(&spec, "errors: [BAD_FILE]").unwrap();
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+    assert_eq!(opts.codegen().unwrap_err().kind(), BAD_SPEC_FILE_EXTENSION);
+
+    let report = opts.spec_lang(SpecLang::Yaml).codegen().unwrap();
+    assert_eq!(report.written(), [output.as_path()]);
+    assert!(fs::read_to_string(&output).unwrap().contains("BAD_FILE"));
+}
+
+#[test]
+fn test_codegen_category_bits_added() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("errors.rs");
+    let one = "categories:\n  - name: Cat1\n    errors: [ERR1]\n";
+    let two = format!("{one}  - name: Cat2\n    errors: [ERR2]\n");
+    let three = format!("{two}  - name: Cat3\n    errors: [ERR3]\n");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(write_spec(dir.path(), one))
+        .output(&output)
+        .strict(true);
+    opts.codegen().unwrap();
+
+    write_spec(dir.path(), &two);
+    assert_eq!(opts.codegen().unwrap_err().kind(), CATEGORY_BITS_ADDED);
+    opts.strict(false).codegen().unwrap();
+
+    write_spec(dir.path(), &three);
+    opts.strict(true).codegen().unwrap();
+}
+
+#[test]
+fn test_codegen_category_bits_added_separate_files() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let mut opts = CodegenOptions::new();
+    opts.spec(write_spec(dir.path(), SPEC))
+        .output(dir.path())
+        .separate_files(true)
+        .split_tables(true)
+        .strict(true);
+    opts.codegen().unwrap();
+    let code = fs::read_to_string(dir.path().join("internal_errors.rs")).unwrap();
+    assert!(code.starts_with("// tighterror:cat_bits internal_errors 0\n"));
+
+    let spec = SPEC.replacen(
+        "          - TIMEOUT\n",
+        "          - TIMEOUT\n      - name: Parser\n        errors:\n          - BAD_TOKEN\n",
+        1,
+    );
+    write_spec(dir.path(), &spec);
+    assert_eq!(opts.codegen().unwrap_err().kind(), CATEGORY_BITS_ADDED);
+    opts.strict(false).codegen().unwrap();
+    let code = fs::read_to_string(dir.path().join("internal_errors.rs")).unwrap();
+    assert!(code.starts_with("// tighterror:cat_bits internal_errors 1\n"));
+    opts.strict(true).codegen().unwrap();
+}
+
+#[test]
+fn test_codegen_strict_docs() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "errors:\n  - name: BAD_FILE\n    doc: \"Bad file.\\n\\tSee the manual.\"\n",
+    );
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+    assert_eq!(opts.codegen().unwrap().written(), [output.as_path()]);
+    assert_eq!(
+        opts.strict_docs(true).codegen().unwrap_err().kind(),
+        TAB_IN_DOC
+    );
+}
+
+#[test]
+fn test_codegen_require_display() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("errors.rs");
+    let good = write_spec(
+        dir.path(),
+        "errors:\n  - BAD_FILE: Bad file.\n  - BAD_ARG: Bad argument.\n",
+    );
+    let mut opts = CodegenOptions::new();
+    opts.spec(&good).output(&output).require_display(true);
+    assert_eq!(opts.codegen().unwrap().written(), [output.as_path()]);
+
+    let bad = write_spec(
+        dir.path(),
+        "errors:\n  - BAD_FILE: Bad file.\n  - BAD_ARG\n",
+    );
+    assert_eq!(
+        opts.spec(&bad).codegen().unwrap_err().kind(),
+        MISSING_DISPLAY
+    );
+    assert!(opts.require_display(None).codegen().is_ok());
+
+    let bad = write_spec(
+        dir.path(),
+        "main:\n  require_display: true\nerrors:\n  - BAD_FILE: Bad file.\n  - BAD_ARG\n",
+    );
+    assert_eq!(
+        opts.spec(&bad).validate().unwrap_err().kind(),
+        MISSING_DISPLAY
+    );
+    assert!(opts.require_display(false).validate().is_ok());
+}
+
+#[test]
+fn test_validate() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(dir.path(), SPEC);
+    let output = dir.path().join("errors.rs");
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).output(&output);
+    assert!(opts.validate().is_ok());
+    assert!(!output.exists());
+
+    let pinned = write_spec(dir.path(), CATEGORIES_PINNED);
+    assert!(crate::validate(opts.spec(&pinned)).is_ok());
+    assert!(!output.exists());
+}
+
+#[test]
+fn test_validate_bad() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let mut opts = CodegenOptions::new();
+    opts.spec(dir.path().join("tighterror.yaml"));
+    assert_eq!(
+        opts.validate().unwrap_err().kind(),
+        FAILED_TO_OPEN_SPEC_FILE
+    );
+
+    for (bad, kind) in [
+        (
+            SPEC.replace("TIMEOUT", "BAD_FILE\n          - BAD_FILE"),
+            NON_UNIQUE_NAME,
+        ),
+        (SPEC.replace("internal_errors", "errors"), NON_UNIQUE_NAME),
+        (SPEC.replace("BAD_FILE", "ERROR"), NAME_COLLISION),
+        (CATEGORIES_PINNED.replace("index: 2", "index: 1"), BAD_INDEX),
+        (CATEGORIES_PINNED.replace("index: 2", "index: 3"), BAD_INDEX),
+    ] {
+        write_spec(dir.path(), &bad);
+        assert_eq!(opts.validate().unwrap_err().kind(), kind, "{bad}");
+    }
+}
+
+#[test]
+fn test_validate_str() {
+    log_init();
+    assert!(crate::validate_str(SPEC, SpecLang::Yaml).is_ok());
+    assert!(crate::validate_str(CATEGORIES_PINNED, SpecLang::Yaml).is_ok());
+    assert!(crate::validate_str(
+        "errors:\n  - name: BAD_FILE\n    doc_file: missing.md\n",
+        SpecLang::Yaml
+    )
+    .is_ok());
+
+    for (bad, kind) in [
+        ("errors: [BAD_FILE\n".into(), BAD_YAML),
+        (
+            SPEC.replace("TIMEOUT", "BAD_FILE\n          - BAD_FILE"),
+            NON_UNIQUE_NAME,
+        ),
+        (SPEC.replace("BAD_FILE", "ERROR"), NAME_COLLISION),
+        (CATEGORIES_PINNED.replace("index: 2", "index: 3"), BAD_INDEX),
+    ] {
+        assert_eq!(
+            crate::validate_str(&bad, SpecLang::Yaml)
+                .unwrap_err()
+                .kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_validate_strict() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let mut opts = CodegenOptions::new();
+    opts.spec(write_spec(
+        dir.path(),
+        "errors:\n  - BAD_FILE: Bad file.\n  - BAD_PATH: Bad file.\n",
+    ));
+    assert!(opts.validate().is_ok());
+    assert_eq!(
+        opts.strict(true).validate().unwrap_err().kind(),
+        DUPLICATE_DISPLAY
+    );
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(write_spec(
+        dir.path(),
+        "errors:\n  - BAD_FILE: \"Bad\\tfile.\"\n",
+    ));
+    assert!(opts.validate().is_ok());
+    assert_eq!(
+        opts.strict_docs(true).validate().unwrap_err().kind(),
+        TAB_IN_DOC
+    );
+}
+
+#[test]
+fn test_codegen_tests_selection() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmain:\n  tests: [from_value, uniqueness]\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    for t in [
+        "fn test_category_from_value()",
+        "fn test_err_kind_from_value()",
+        "fn test_category_uniqueness()",
+        "fn test_err_kind_uniqueness()",
+        "fn test_err_kind_value_uniqueness()",
+    ] {
+        assert!(code.contains(t), "{t}");
+    }
+    assert!(!code.contains("fn test_category_name()"));
+    assert!(!code.contains("fn test_err_kind_into_value()"));
+
+    let spec_path = write_spec(dir.path(), spec);
+    let output = dir.path().join("errors.rs");
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec_path)
+        .output(&output)
+        .test(true)
+        .tests(vec!["err_kind_name".into()]);
+    opts.codegen().unwrap();
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(code.contains("fn test_err_kind_name()"));
+    assert!(!code.contains("fn test_err_kind_from_value()"));
+
+    opts.tests(vec!["from_value".into(), "unknown".into()]);
+    assert_eq!(opts.codegen().unwrap_err().kind(), BAD_VALUE);
+
+    // no selected test applies to the module
+    let spec = "---\nmain:\n  tests: [category_sets]\nerrors: [BAD_FILE, BAD_ARG]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("mod tests"));
+}
+
+#[test]
+fn test_codegen_layout() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "---\nmodules:\n  - name: errors\n    categories:\n      - name: Parser\n        index: 1\n        errors: [BAD_TOKEN, BAD_FILE, BAD_ARG]\n      - name: Coder\n        index: 0\n        errors: [TIMEOUT]\n  - name: io_errors\n    categories:\n      - name: General\n        errors: [CLOSED]\n",
+    );
+    let layout = CodegenOptions::new().spec(&spec).layout().unwrap();
+    assert_eq!(
+        layout,
+        "module errors: repr=u8 KIND_BITS=3 CAT_BITS=1 VAR_BITS=2\n  \
+         category Coder: index=0 variants=1\n  \
+         category Parser: index=1 variants=3\n\
+         module io_errors: repr=u8 KIND_BITS=1 CAT_BITS=0 VAR_BITS=1\n  \
+         category General: index=0 variants=1\n"
+    );
+}
+
+#[test]
+fn test_codegen_category_slices() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\ncategories:\n  - name: Parser\n    errors: [BAD_TOKEN, QUEUE_FULL]\n  - name: Io\n    errors: [TIMEOUT]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub static ALL: [EK; 2] = [BAD_TOKEN, QUEUE_FULL];"));
+    assert!(code.contains("pub static ALL: [EK; 1] = [TIMEOUT];"));
+    assert!(!code.contains("pub mod by_category"));
+    assert!(code.contains("fn test_category_slices()"));
+
+    let spec = format!("---\nmodule:\n  flat_kinds: true\n{}", &spec[4..]);
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("pub mod by_category {"));
+    assert!(code.contains("pub static PARSER: [EK; 2] = [super::BAD_TOKEN, super::QUEUE_FULL];"));
+    assert!(code.contains("pub static IO: [EK; 1] = [super::TIMEOUT];"));
+    assert!(!code.contains("pub static ALL: [EK;"));
+}
+
+#[test]
+fn test_codegen_subcategories() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  prelude: true\ncategories:\n  - name: Parser\n    errors: [BAD_INPUT]\n    subcategories:\n      - name: Lexer\n        errors: [BAD_TOKEN, BAD_CHAR]\n  - name: Io\n    errors: [TIMEOUT]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("pub mod parser {"));
+    assert!(code.contains("pub mod lexer {"));
+    assert!(code.contains("pub const BAD_TOKEN: EK = EK::new(c::PARSER, 1);"));
+    assert!(code.contains("pub const BAD_CHAR: EK = EK::new(c::PARSER, 2);"));
+    assert!(code.contains(
+        "io::TIMEOUT, parser::lexer::BAD_CHAR, parser::lexer::BAD_TOKEN, parser::BAD_INPUT,"
+    ));
+    assert!(code.contains("(\"BAD_TOKEN\", super::kind::parser::lexer::BAD_TOKEN)"));
+    assert!(!code.contains("kind::parser::BAD_TOKEN"));
+
+    let spec_path = write_spec(dir.path(), spec);
+    let layout = CodegenOptions::new().spec(&spec_path).layout().unwrap();
+    assert_eq!(
+        layout,
+        "module errors: repr=u8 KIND_BITS=3 CAT_BITS=1 VAR_BITS=2\n  \
+         category Parser: index=0 variants=3\n    \
+         subcategory Lexer: variants=2\n  \
+         category Io: index=1 variants=1\n"
+    );
+
+    let spec = spec.replace("prelude: true", "flat_kinds: true");
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(!code.contains("pub mod lexer {"));
+    assert!(code.contains("pub const BAD_TOKEN: EK = EK::new(c::PARSER, 1);"));
+}
+
+#[test]
+fn test_codegen_subcategories_index() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\ncategories:\n  - name: Parser\n    errors:\n      - name: BAD_INPUT\n        index: 0\n    subcategories:\n      - name: Lexer\n        errors:\n          - name: BAD_TOKEN\n            index: 2\n          - name: BAD_CHAR\n            index: 1\n  - name: Io\n    errors: [TIMEOUT]\n";
+    assert_eq!(codegen_str(dir.path(), spec).unwrap_err().kind(), BAD_INDEX);
+
+    let spec = "---\ncategories:\n  - name: Parser\n    errors: [BAD_INPUT]\n    subcategories:\n      - name: Lexer\n        errors:\n          - name: BAD_TOKEN\n            index: 0\n";
+    assert_eq!(codegen_str(dir.path(), spec).unwrap_err().kind(), BAD_INDEX);
+}
+
+#[test]
+fn test_codegen_kind_values() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "---\nmodules:\n  - name: errors\n    categories:\n      - name: Parser\n        index: 1\n        errors: [BAD_TOKEN, BAD_FILE, BAD_ARG]\n      - name: Coder\n        index: 0\n        errors: [TIMEOUT]\n  - name: io_errors\n    categories:\n      - name: General\n        errors: [CLOSED, RESET]\n",
+    );
+    let values = CodegenOptions::new().spec(&spec).kind_values().unwrap();
+    let expected = [
+        (
+            "errors",
+            vec![
+                ("CODER::TIMEOUT", 0),
+                ("PARSER::BAD_TOKEN", 4),
+                ("PARSER::BAD_FILE", 5),
+                ("PARSER::BAD_ARG", 6),
+            ],
+        ),
+        (
+            "io_errors",
+            vec![("GENERAL::CLOSED", 0), ("GENERAL::RESET", 1)],
+        ),
+    ]
+    .map(|(m, kinds)| {
+        let kinds = kinds.into_iter().map(|(k, v)| (k.to_owned(), v)).collect();
+        (m.to_owned(), kinds)
+    });
+    assert_eq!(values, BTreeMap::from(expected));
+}
+
+#[test]
+fn test_codegen_header() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("errors.rs");
+    let mut opts = CodegenOptions::new();
+    opts.output(&output);
+
+    let spec = write_spec(dir.path(), "errors: [BAD_FILE]");
+    opts.spec(&spec).codegen().unwrap();
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(!code.contains("@generated"));
+
+    opts.header(true).codegen().unwrap();
+    let code = fs::read_to_string(&output).unwrap();
+    let header = format!(
+        "// @generated by tighterror-build v{} from {} (sha256: {})\n",
+        env!("CARGO_PKG_VERSION"),
+        "tighterror.yaml",
+        "e5963db641d5c4c3590131c9cdfa46fdc53c39d30771852fe6838b1105de982f"
+    );
+    assert!(code.starts_with(&header), "{code}");
+
+    write_spec(dir.path(), "errors: [BAD_FILE, BAD_ARG]");
+    opts.codegen().unwrap();
+    let code = fs::read_to_string(&output).unwrap();
+    assert!(!code.starts_with(&header));
+    assert!(code.starts_with(&format!(
+        "// @generated by tighterror-build v{} from {} (sha256: {})\n",
+        env!("CARGO_PKG_VERSION"),
+        "tighterror.yaml",
+        "1e84b5735d14110986a126ed5caaac76f506e34b7d75567c102c10edd5d2db38"
+    )));
+
+    let first_line = |code: &str| code.lines().next().unwrap().to_owned();
+    fs::write(dir.path().join("bad_file.md"), "A bad file.").unwrap();
+    write_spec(
+        dir.path(),
+        "errors:\n  - name: BAD_FILE\n    doc_file: bad_file.md",
+    );
+    opts.codegen().unwrap();
+    let header = first_line(&fs::read_to_string(&output).unwrap());
+    fs::write(dir.path().join("bad_file.md"), "A very bad file.").unwrap();
+    opts.codegen().unwrap();
+    assert_ne!(first_line(&fs::read_to_string(&output).unwrap()), header);
+}
+
+#[test]
+fn test_codegen_spec_not_utf8() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = dir.path().join("tighterror.yaml");
+    fs::write(&spec, b"errors: [BAD_FILE\xff]").unwrap();
+    let err = CodegenOptions::new()
+        .spec(&spec)
+        .output(dir.path().join("errors.rs"))
+        .codegen()
+        .unwrap_err();
+    assert_eq!(err.kind(), BAD_YAML);
+}
+
+#[test]
+fn test_detect_no_std() {
+    use super::detect_no_std;
+    use std::ffi::OsStr;
+
+    assert_eq!(detect_no_std(Some(OsStr::new("none"))), Some(true));
+    for os in ["linux", "windows", "macos", "uefi", ""] {
+        assert_eq!(detect_no_std(Some(OsStr::new(os))), None, "{os}");
+    }
+    assert_eq!(detect_no_std(None), None);
+}
+
+#[test]
+fn test_codegen_no_std_option() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("errors.rs");
+    let mut opts = CodegenOptions::new();
+    opts.output(&output);
+
+    for (spec, opt, no_std) in [
+        ("errors: [BAD_FILE]", None, false),
+        ("errors: [BAD_FILE]", Some(true), true),
+        ("main:\n  no_std: true\nerrors: [BAD_FILE]", None, true),
+        (
+            "main:\n  no_std: true\nerrors: [BAD_FILE]",
+            Some(false),
+            false,
+        ),
+    ] {
+        let spec_path = write_spec(dir.path(), spec);
+        opts.spec(&spec_path).no_std(opt).codegen().unwrap();
+        let code = fs::read_to_string(&output).unwrap();
+        assert_eq!(
+            !code.contains("std::error::Error"),
+            no_std,
+            "{spec} {opt:?}"
+        );
+    }
+}
+
+#[test]
+fn test_codegen_err_from_kind_ref() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE]").unwrap();
+    assert!(code.contains("impl core::convert::From<&ErrorKind> for Error {"));
+    assert!(code.contains("fn test_err_from_kind_ref()"));
+
+    let code = codegen_str(dir.path(), "module:\n  location: true\nerrors: [BAD_FILE]").unwrap();
+    let from_ref = code
+        .split("impl core::convert::From<&ErrorKind> for Error {")
+        .nth(1)
+        .unwrap();
+    assert!(from_ref
+        .trim_start()
+        .starts_with("#[inline]\n    #[track_caller]"));
+}
+
+#[test]
+fn test_codegen_err_kind_error() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE]").unwrap();
+    assert!(code.contains("pub const fn error(self) -> Error {\n        Error(self)\n    }"));
+    assert!(code.contains("fn test_err_kind_error()"));
+
+    let code = codegen_str(dir.path(), "module:\n  location: true\nerrors: [BAD_FILE]").unwrap();
+    assert!(code.contains(
+        "#[track_caller]\n    pub fn error(self) -> Error {\n        Error::from(self)\n    }"
+    ));
+}
+
+#[test]
+fn test_codegen_err_kind_hash() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE]").unwrap();
+    assert!(code.contains("#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]\n#[repr(transparent)]\npub struct ErrorKind("));
+    assert!(code.contains("impl core::hash::Hash for ErrorKind {"));
+    assert!(code.contains("self.value().hash(state);"));
+    assert!(code.contains("fn test_err_kind_hash()"));
+
+    let code = codegen_str(dir.path(), "main:\n  no_std: true\nerrors: [BAD_FILE]").unwrap();
+    assert!(code.contains("impl core::hash::Hash for ErrorKind {"));
+    assert!(!code.contains("fn test_err_kind_hash()"));
+}
+
+#[test]
+fn test_codegen_err_kind_matches() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE, TIMEOUT]").unwrap();
+    assert!(code.contains("#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]"));
+    assert!(code.contains("fn test_err_kind_matches()"));
+    assert!(code.contains("Some(general::BAD_FILE) => 0,"));
+    assert!(code.contains("Some(general::TIMEOUT) => 1,"));
+    assert!(code.contains("assert_eq!(matches!(k, general::BAD_FILE), i == 0);"));
+
+    let code = codegen_str(dir.path(), "main:\n  no_std: true\nerrors: [BAD_FILE]").unwrap();
+    assert!(code.contains("fn test_err_kind_matches()"));
+}
+
+#[test]
+fn test_codegen_err_kind_index() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), CATEGORIES_PINNED).unwrap();
+    assert!(code.contains("pub static CAT_OFFSETS: [usize; 3] = [0, 2, 3];"));
+    assert!(code.contains("pub fn index(&self) -> usize {"));
+    assert!(code.contains("fn test_err_kind_index()"));
+    assert!(!code.contains("KIND_TO_CAT"));
+}
+
+#[test]
+fn test_codegen_err_kind_is_in() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE]").unwrap();
+    assert!(code.contains("pub const fn category(&self) -> ErrorCategory {"));
+    assert!(code.contains("pub const fn is_in(&self, cat: ErrorCategory) -> bool {"));
+    assert!(code.contains("const _: () = assert!(general::BAD_FILE.is_in(category::GENERAL));"));
+}
+
+#[test]
+fn test_codegen_err_kind_try_from_value() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let code = codegen_str(dir.path(), "errors: [BAD_FILE]").unwrap();
+    assert!(code.contains("impl core::convert::TryFrom<_p::R> for ErrorKind {"));
+    assert!(code.contains("type Error = tighterror::InvalidValueError<_p::R>;"));
+    assert!(code.contains("fn test_err_kind_try_from_value()"));
+}
+
+#[test]
+fn test_codegen_threads() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        &format!("{SPEC}  - name: more_errors\n    categories:\n      - name: General\n        errors: [QUEUE_FULL]\n"),
+    );
+    let serial = dir.path().join("serial");
+    let parallel = dir.path().join("parallel");
+    fs::create_dir(&serial).unwrap();
+    fs::create_dir(&parallel).unwrap();
+
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec);
+    opts.threads(1)
+        .output(serial.join("errors.rs"))
+        .codegen()
+        .unwrap();
+    opts.threads(4)
+        .output(parallel.join("errors.rs"))
+        .codegen()
+        .unwrap();
+    opts.threads(None)
+        .output(parallel.join("default.rs"))
+        .codegen()
+        .unwrap();
+    opts.threads(0)
+        .output(parallel.join("zero.rs"))
+        .codegen()
+        .unwrap();
+    let code = fs::read(serial.join("errors.rs")).unwrap();
+    assert_eq!(code, fs::read(parallel.join("errors.rs")).unwrap());
+    assert_eq!(code, fs::read(parallel.join("default.rs")).unwrap());
+    assert_eq!(code, fs::read(parallel.join("zero.rs")).unwrap());
+
+    opts.separate_files(true);
+    let report = opts.threads(1).output(&serial).codegen().unwrap();
+    assert_eq!(report.written().len(), 3);
+    opts.threads(4).output(&parallel).codegen().unwrap();
+    for p in report.written() {
+        let name = p.file_name().unwrap();
+        assert_eq!(fs::read(p).unwrap(), fs::read(parallel.join(name)).unwrap());
+    }
+}
+
+#[test]
+fn test_codegen_list() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "---\ncategories:\n  - name: Parser\n    errors:\n      - BAD_TOKEN\n      - name: BAD_CHAR\n        display: Bad character.\n  - name: General\n    errors: [BAD_ARG]\n",
+    );
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec);
+
+    let list = opts.list(false).unwrap();
+    assert_eq!(
+        list,
+        "category | kind      | value | display\n\
+         Parser   | BAD_TOKEN |     0 | BAD_TOKEN\n\
+         Parser   | BAD_CHAR  |     1 | Bad character.\n\
+         General  | BAD_ARG   |     2 | BAD_ARG\n"
+    );
+    let values = opts.kind_values().unwrap();
+    assert_eq!(values["errors"]["PARSER::BAD_CHAR"], 1);
+
+    let json = opts.list(true).unwrap();
+    assert!(json.contains("\"name\": \"BAD_CHAR\""));
+    assert!(json.contains("\"value\": 2"));
+
+    let spec = write_spec(dir.path(), SPEC);
+    let list = CodegenOptions::new().spec(&spec).list(false).unwrap();
+    assert!(list.starts_with("module errors:\ncategory | kind     | value | display\n"));
+    assert!(list.contains("module internal_errors:\n"));
+    assert!(list.contains("General  | TIMEOUT | "));
+}
+
+#[test]
+fn test_codegen_list_display_case() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "---\nmodule:\n  display_case: lower\nerrors:\n  - BAD_FILE: Bad file.\n  - BAD_URL: URL is bad.\n",
+    );
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec);
+
+    let list = opts.list(false).unwrap();
+    assert_eq!(
+        list,
+        "category | kind     | value | display\n\
+         General  | BAD_FILE |     0 | bad file.\n\
+         General  | BAD_URL  |     1 | URL is bad.\n"
+    );
+    let json = opts.list(true).unwrap();
+    assert!(json.contains("\"display\": \"bad file.\""));
+    assert!(!json.contains("Bad file."));
+}
+
+#[test]
+fn test_codegen_runtime_path() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec =
+        "---\nmodule:\n  location: true\nerrors:\n  - name: BAD_FILE\n    variant_type: true\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains("impl tighterror::Kind for ErrorKind {"));
+    assert!(code.contains("tighterror::Location::caller()"));
+
+    let spec = spec.replace(
+        "location: true",
+        "location: true\n  runtime_path: crate::rt",
+    );
+    let code = codegen_str(dir.path(), &spec).unwrap();
+    assert!(code.contains("impl crate::rt::Kind for ErrorKind {"));
+    assert!(code.contains("impl crate::rt::Error for Error {"));
+    assert!(code.contains("impl crate::rt::VariantType for BadFile {"));
+    assert!(code.contains("crate::rt::Location::caller()"));
+    assert!(!code.contains("tighterror::"));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_parse_spec() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = write_spec(
+        dir.path(),
+        "---\nmodule:\n  flat_kinds: true\nerrors:\n  - BAD_FILE\n  - name: QUEUE_FULL\n    display: Queue is full.\n",
+    );
+    let mut opts = CodegenOptions::new();
+    opts.spec(&spec).no_std(Some(true));
+    let spec = opts.parse_spec().unwrap();
+    let json = serde_json::to_value(&spec).unwrap();
+    assert_eq!(json["main"]["no_std"], true);
+    let module = &json["modules"][0];
+    assert_eq!(module["name"], "errors");
+    assert_eq!(module["flat_kinds"], true);
+    assert_eq!(module["err_name"], "Error");
+    assert_eq!(module["error_trait"], false);
+    assert_eq!(module["order_by"], "value");
+    let category = &module["categories"][0];
+    assert_eq!(category["name"], "General");
+    assert_eq!(category["doc"], "General error category.");
+    let errors = category["errors"].as_array().unwrap();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0]["name"], "BAD_FILE");
+    assert_eq!(errors[0]["display"], serde_json::Value::Null);
+    assert_eq!(errors[0]["variant_type"], false);
+    assert_eq!(errors[1]["name"], "QUEUE_FULL");
+    assert_eq!(errors[1]["display"], "Queue is full.");
+
+    write_spec(
+        dir.path(),
+        "---\nmodules:\n  - name: base\n    flat_kinds: true\n    categories:\n      - name: General\n        errors: [BAD_FILE]\n  - name: derived\n    extends: base\n    categories:\n      - name: General\n        errors: [QUEUE_FULL]\n",
+    );
+    let json = serde_json::to_value(opts.parse_spec().unwrap()).unwrap();
+    let module = &json["modules"][1];
+    assert_eq!(module["extends"], serde_json::Value::Null);
+    assert_eq!(module["flat_kinds"], true);
+    assert_eq!(module["test"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_codegen_display_unpadded() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nerrors:\n  - name: BAD_FILE\n    display: Bad file.\n    variant_type: true\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("f.write_str("));
+    assert!(code.contains("f.pad(self.message())"));
+    assert!(code.contains("fn test_display_unpadded()"));
+}
+
+#[test]
+fn test_codegen_bail() {
+    log_init();
+    let dir = tempfile::tempdir().unwrap();
+    let spec = "---\nmodule:\n  location: true\n  err_name: AppError\nerrors: [BAD_FILE]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(code.contains(
+        "pub const fn from_kind_at(kind: ErrorKind, location: tighterror::Location) -> Self {"
+    ));
+    assert!(code.contains("$crate::errors::AppError::from_kind_at("));
+    assert!(code.contains("tighterror::Location::caller(),"));
+    assert!(code.contains("fn test_bail()"));
+
+    let spec = "---\nerrors: [BAD_FILE]\n";
+    let code = codegen_str(dir.path(), spec).unwrap();
+    assert!(!code.contains("fn from_kind_at("));
+    assert!(!code.contains("macro_rules! bail"));
+}
@@ -1,3 +1,5 @@
+// tighterror:cat_bits errors 1
+
 //! Crate errors.
 
 /**
@@ -5,7 +7,6 @@
  *
  * See the [categories] module for category constants.
 */
-
 #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 #[repr(transparent)]
 pub struct TbErrorCategory(_p::R);
@@ -21,6 +22,50 @@ impl TbErrorCategory {
     pub fn name(&self) -> &'static str {
         _cn::A[self.0 as usize]
     }
+
+    /// An alias of [name](Self::name).
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the number of error kinds in the error category.
+    #[inline]
+    pub fn kind_count(&self) -> usize {
+        _p::VAR_MAXES[self.0 as usize] as usize + 1
+    }
+
+    /// Returns an iterator over the error kinds of the error category.
+    #[inline]
+    pub fn kinds(&self) -> TbErrorCategoryKinds {
+        TbErrorCategoryKinds {
+            cat: *self,
+            next: 0,
+            end: self.kind_count(),
+        }
+    }
+
+    /// Creates an error category from a raw value of the underlying Rust type.
+    ///
+    /// Returns `None` if the value doesn't denote an error category.
+    #[inline]
+    pub fn from_value(value: _p::R) -> Option<Self> {
+        if value <= _p::CAT_MAX {
+            Some(Self::new(value))
+        } else {
+            None
+        }
+    }
+
+    /// Creates an error category from its name.
+    ///
+    /// Returns `None` if the name doesn't denote an error category.
+    pub fn from_name(name: &str) -> Option<Self> {
+        _cn::A
+            .iter()
+            .position(|n| *n == name)
+            .map(|i| Self::new(i as _p::R))
+    }
 }
 
 impl tighterror::Category for TbErrorCategory {
@@ -31,6 +76,11 @@ impl tighterror::Category for TbErrorCategory {
     fn name(&self) -> &'static str {
         self.name()
     }
+
+    #[inline]
+    fn from_value(value: Self::R) -> Option<Self> {
+        Self::from_value(value)
+    }
 }
 
 impl core::fmt::Display for TbErrorCategory {
@@ -49,13 +99,94 @@ impl core::fmt::Debug for TbErrorCategory {
     }
 }
 
+impl core::str::FromStr for TbErrorCategory {
+    type Err = tighterror::ParseKindError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(tighterror::ParseKindError)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a str> for TbErrorCategory {
+    type Error = tighterror::ParseKindError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::from_name(s).ok_or(tighterror::ParseKindError)
+    }
+}
+
+/// An iterator over the error kinds of an error category.
+///
+/// Returned by [TbErrorCategory::kinds].
+#[derive(Clone, Debug)]
+pub struct TbErrorCategoryKinds {
+    cat: TbErrorCategory,
+    next: usize,
+    end: usize,
+}
+
+impl Iterator for TbErrorCategoryKinds {
+    type Item = TbErrorKind;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+
+        let variant = self.next as _p::R;
+        self.next += 1;
+        Some(TbErrorKind::new(self.cat, variant))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let n = self.end - self.next;
+        (n, Some(n))
+    }
+}
+
+impl DoubleEndedIterator for TbErrorCategoryKinds {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next == self.end {
+            return None;
+        }
+
+        self.end -= 1;
+        Some(TbErrorKind::new(self.cat, self.end as _p::R))
+    }
+}
+
+impl ExactSizeIterator for TbErrorCategoryKinds {}
+
+impl core::iter::FusedIterator for TbErrorCategoryKinds {}
+
+impl IntoIterator for TbErrorCategory {
+    type Item = TbErrorKind;
+    type IntoIter = TbErrorCategoryKinds;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.kinds()
+    }
+}
+
 /**
  * Error kind type.
  *
  * See the [kinds] module for error kind constants.
 */
-
-#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+///
+/// The numeric value of an error kind isn't stable. It depends on the
+/// position of the error kind in the specification, so it may change
+/// when the specification changes. The conversions from and to the value,
+/// e.g., [from_value](Self::from_value) and [value](Self::value), are
+/// consistent within the same generated code only. Use the error kind
+/// constants or names to identify error kinds across versions.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
 #[repr(transparent)]
 pub struct TbErrorKind(_p::R);
 
@@ -65,30 +196,64 @@ impl TbErrorKind {
     }
 
     #[inline]
-    fn category_value(&self) -> _p::R {
+    const fn category_value(&self) -> _p::R {
         (self.0 & _p::CAT_MASK) >> _p::VAR_BITS
     }
 
+    /// Returns the error category.
     #[inline]
-    fn variant_value(&self) -> _p::R {
-        self.0 & _p::VAR_MASK
+    pub const fn category(&self) -> TbErrorCategory {
+        TbErrorCategory::new(self.category_value())
     }
 
-    /// Returns the error category.
+    /// Returns `true` if the error kind belongs to the error category.
+    ///
+    /// Unlike comparing the [category](Self::category), this can be used
+    /// in const contexts.
     #[inline]
-    pub fn category(&self) -> TbErrorCategory {
-        TbErrorCategory::new(self.category_value())
+    pub const fn is_in(&self, cat: TbErrorCategory) -> bool {
+        self.category_value() == cat.0
+    }
+
+    /// Creates an error of this kind, same as `Error::from`.
+    #[inline]
+    pub const fn error(self) -> TbError {
+        TbError(self)
+    }
+
+    /// Returns the error kind index, i.e., its position among all error kinds
+    /// of the module, in the range `0..N`, where `N` is the number of error kinds.
+    ///
+    /// Unlike the [value](Self::value), the indices are dense and don't depend
+    /// on the bit layout of the error kinds, so they can index arrays sized
+    /// by the number of error kinds.
+    #[inline]
+    pub fn index(&self) -> usize {
+        _p::CAT_OFFSETS[self.category_value() as usize] + self.variant() as usize
     }
 
     /// Returns the error kind name.
     #[inline]
     pub fn name(&self) -> &'static str {
-        _n::A[self.category_value() as usize][self.variant_value() as usize]
+        _n::A[self.category_value() as usize][self.variant() as usize]
+    }
+
+    /// An alias of [name](Self::name).
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        self.name()
+    }
+
+    /// Returns the error kind name qualified by the category name, e.g., `CATEGORY::NAME`.
+    #[inline]
+    pub fn qualified_name(&self) -> &'static str {
+        _n::Q[self.category_value() as usize][self.variant() as usize]
     }
 
+    /// Returns the error message, i.e., the display string of the error.
     #[inline]
-    fn display(&self) -> &'static str {
-        _d::A[self.category_value() as usize][self.variant_value() as usize]
+    pub fn message(&self) -> &'static str {
+        _d::A[self.category_value() as usize][self.variant() as usize]
     }
 
     /// Returns the error kind value as the underlying Rust type.
@@ -97,6 +262,12 @@ impl TbErrorKind {
         self.0
     }
 
+    /// Returns the error kind variant, i.e., its index within the error category.
+    #[inline]
+    pub const fn variant(&self) -> _p::R {
+        self.0 & _p::VAR_MASK
+    }
+
     /// Creates an error kind from a raw value of the underlying Rust type.
     #[inline]
     pub fn from_value(value: _p::R) -> Option<Self> {
@@ -108,6 +279,18 @@ impl TbErrorKind {
             None
         }
     }
+
+    /// Creates an error kind from its name.
+    ///
+    /// A name qualified by the category name, e.g., `General::BAD_FILE`,
+    /// or by the category constant name as returned by [qualified_name](Self::qualified_name),
+    /// e.g., `GENERAL::BAD_FILE`, is always accepted. A plain name is accepted
+    /// only if it is unique in the module.
+    ///
+    /// Returns `None` if the name doesn't denote an error kind.
+    pub fn from_name(name: &str) -> Option<Self> {
+        _p::NAMES.iter().find(|(n, _)| *n == name).map(|(_, k)| *k)
+    }
 }
 
 impl tighterror::Kind for TbErrorKind {
@@ -130,6 +313,11 @@ impl tighterror::Kind for TbErrorKind {
         self.value()
     }
 
+    #[inline]
+    fn variant(&self) -> Self::R {
+        self.variant()
+    }
+
     #[inline]
     fn from_value(value: Self::R) -> Option<Self> {
         Self::from_value(value)
@@ -143,6 +331,17 @@ impl core::fmt::Display for TbErrorKind {
     }
 }
 
+impl core::hash::Hash for TbErrorKind {
+    /// Hashes the error kind value, consistently with `PartialEq`.
+    ///
+    /// The hash is the hash of [value](Self::value), so it doesn't depend
+    /// on the environment, e.g., `std` or `no_std`, but on the hasher only.
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
 impl core::fmt::Debug for TbErrorKind {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
@@ -154,6 +353,40 @@ impl core::fmt::Debug for TbErrorKind {
     }
 }
 
+impl core::convert::From<TbErrorKind> for _p::R {
+    #[inline]
+    fn from(kind: TbErrorKind) -> Self {
+        kind.value()
+    }
+}
+
+impl core::convert::TryFrom<_p::R> for TbErrorKind {
+    type Error = tighterror::InvalidValueError<_p::R>;
+
+    #[inline]
+    fn try_from(value: _p::R) -> Result<Self, Self::Error> {
+        Self::from_value(value).ok_or(tighterror::InvalidValueError::new(value))
+    }
+}
+
+impl core::str::FromStr for TbErrorKind {
+    type Err = tighterror::ParseKindError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_name(s).ok_or(tighterror::ParseKindError)
+    }
+}
+
+impl<'a> core::convert::TryFrom<&'a str> for TbErrorKind {
+    type Error = tighterror::ParseKindError;
+
+    #[inline]
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::from_name(s).ok_or(tighterror::ParseKindError)
+    }
+}
+
 impl<T> core::convert::From<TbErrorKind> for Result<T, TbError> {
     #[inline]
     fn from(v: TbErrorKind) -> Self {
@@ -166,7 +399,6 @@ impl<T> core::convert::From<TbErrorKind> for Result<T, TbError> {
  *
  * See the [kinds] module for error kind constants.
 */
-
 #[derive(Debug)]
 #[repr(transparent)]
 pub struct TbError(TbErrorKind);
@@ -183,6 +415,12 @@ impl TbError {
     pub fn location(&self) -> tighterror::Location {
         tighterror::Location::undefined()
     }
+
+    /// Returns the error message, i.e., the display string of the error kind.
+    #[inline]
+    pub fn message(&self) -> &'static str {
+        self.0.message()
+    }
 }
 
 impl tighterror::Error for TbError {
@@ -208,10 +446,24 @@ impl core::convert::From<TbErrorKind> for TbError {
     }
 }
 
+impl core::convert::From<&TbErrorKind> for TbError {
+    #[inline]
+    fn from(kind: &TbErrorKind) -> Self {
+        Self::from(*kind)
+    }
+}
+
+impl core::convert::From<TbError> for _p::R {
+    #[inline]
+    fn from(err: TbError) -> Self {
+        err.kind().value()
+    }
+}
+
 impl core::fmt::Display for TbError {
     #[inline]
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        f.pad(self.kind().display())
+        f.pad(self.message())
     }
 }
 
@@ -223,6 +475,16 @@ impl core::cmp::PartialEq for TbError {
     }
 }
 
+impl core::cmp::Eq for TbError {}
+
+impl core::hash::Hash for TbError {
+    /// Hashes the error kind only, consistently with `PartialEq`.
+    #[inline]
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state);
+    }
+}
+
 impl<T> core::convert::From<TbError> for core::result::Result<T, TbError> {
     #[inline]
     fn from(err: TbError) -> Self {
@@ -232,6 +494,13 @@ impl<T> core::convert::From<TbError> for core::result::Result<T, TbError> {
 
 impl std::error::Error for TbError {}
 
+impl core::convert::From<TbErrorKind> for std::boxed::Box<dyn std::error::Error + Send + Sync> {
+    #[inline]
+    fn from(kind: TbErrorKind) -> Self {
+        std::boxed::Box::new(TbError::from(kind))
+    }
+}
+
 mod _cn {
     pub const PARSER: &str = "PARSER";
     pub const CODER: &str = "CODER";
@@ -259,7 +528,20 @@ mod _n {
         pub(crate) const NON_UNIQUE_NAME: &str = "NON_UNIQUE_NAME";
         pub(crate) const SPEC_FILE_NOT_FOUND: &str = "SPEC_FILE_NOT_FOUND";
         pub(crate) const NAME_COLLISION: &str = "NAME_COLLISION";
-        pub static A: [&str; 19] = [
+        pub(crate) const RESERVED_NAME: &str = "RESERVED_NAME";
+        pub(crate) const FAILED_TO_READ_DOC_FILE: &str = "FAILED_TO_READ_DOC_FILE";
+        pub(crate) const BAD_VALUE: &str = "BAD_VALUE";
+        pub(crate) const DUPLICATE_DISPLAY: &str = "DUPLICATE_DISPLAY";
+        pub(crate) const NON_UNIQUE_DEFAULT: &str = "NON_UNIQUE_DEFAULT";
+        pub(crate) const BAD_REFERENCE: &str = "BAD_REFERENCE";
+        pub(crate) const BAD_DISPLAY_STRING: &str = "BAD_DISPLAY_STRING";
+        pub(crate) const FAILED_TO_READ_STDIN: &str = "FAILED_TO_READ_STDIN";
+        pub(crate) const SPEC_LANG_REQUIRED: &str = "SPEC_LANG_REQUIRED";
+        pub(crate) const TAB_IN_DOC: &str = "TAB_IN_DOC";
+        pub(crate) const MIRROR_CATEGORIES: &str = "MIRROR_CATEGORIES";
+        pub(crate) const MISSING_DISPLAY: &str = "MISSING_DISPLAY";
+        pub(crate) const EXTENDS_CYCLE: &str = "EXTENDS_CYCLE";
+        pub static A: [&str; 32] = [
             BAD_IDENTIFIER_CHARACTERS,
             BAD_IDENTIFIER_CASE,
             BAD_KEYWORD_TYPE,
@@ -279,6 +561,19 @@ mod _n {
             NON_UNIQUE_NAME,
             SPEC_FILE_NOT_FOUND,
             NAME_COLLISION,
+            RESERVED_NAME,
+            FAILED_TO_READ_DOC_FILE,
+            BAD_VALUE,
+            DUPLICATE_DISPLAY,
+            NON_UNIQUE_DEFAULT,
+            BAD_REFERENCE,
+            BAD_DISPLAY_STRING,
+            FAILED_TO_READ_STDIN,
+            SPEC_LANG_REQUIRED,
+            TAB_IN_DOC,
+            MIRROR_CATEGORIES,
+            MISSING_DISPLAY,
+            EXTENDS_CYCLE,
         ];
     }
 
@@ -292,7 +587,13 @@ mod _n {
         pub(crate) const RUSTFMT_NOT_FOUND: &str = "RUSTFMT_NOT_FOUND";
         pub(crate) const TOO_MANY_BITS: &str = "TOO_MANY_BITS";
         pub(crate) const OUTPUT_PATH_NOT_DIRECTORY: &str = "OUTPUT_PATH_NOT_DIRECTORY";
-        pub static A: [&str; 9] = [
+        pub(crate) const BAD_INDEX: &str = "BAD_INDEX";
+        pub(crate) const BAD_OUTPUT_MARKERS: &str = "BAD_OUTPUT_MARKERS";
+        pub(crate) const CATEGORY_BITS_ADDED: &str = "CATEGORY_BITS_ADDED";
+        pub(crate) const VALUE_WIDTH_TOO_NARROW: &str = "VALUE_WIDTH_TOO_NARROW";
+        pub(crate) const FAILED_TO_BUILD_THREAD_POOL: &str = "FAILED_TO_BUILD_THREAD_POOL";
+        pub(crate) const RUSTFMT_CONFIG_NOT_FOUND: &str = "RUSTFMT_CONFIG_NOT_FOUND";
+        pub static A: [&str; 15] = [
             CATEGORY_REQUIRED,
             ERROR_REQUIRED,
             FAILED_TO_PARSE_TOKENS,
@@ -302,10 +603,69 @@ mod _n {
             RUSTFMT_NOT_FOUND,
             TOO_MANY_BITS,
             OUTPUT_PATH_NOT_DIRECTORY,
+            BAD_INDEX,
+            BAD_OUTPUT_MARKERS,
+            CATEGORY_BITS_ADDED,
+            VALUE_WIDTH_TOO_NARROW,
+            FAILED_TO_BUILD_THREAD_POOL,
+            RUSTFMT_CONFIG_NOT_FOUND,
         ];
     }
 
     pub static A: [&[&str]; 2] = [&parser::A, &coder::A];
+    pub static Q: [&[&str]; 2] = [
+        &[
+            "PARSER::BAD_IDENTIFIER_CHARACTERS",
+            "PARSER::BAD_IDENTIFIER_CASE",
+            "PARSER::BAD_KEYWORD_TYPE",
+            "PARSER::BAD_MODULE_IDENTIFIER",
+            "PARSER::BAD_NAME",
+            "PARSER::BAD_OBJECT_ATTRIBUTE",
+            "PARSER::BAD_SPEC_FILE_EXTENSION",
+            "PARSER::BAD_TOML",
+            "PARSER::BAD_ROOT_LEVEL_KEYWORD",
+            "PARSER::BAD_VALUE_TYPE",
+            "PARSER::BAD_YAML",
+            "PARSER::EMPTY_IDENTIFIER",
+            "PARSER::EMPTY_LIST",
+            "PARSER::FAILED_TO_OPEN_SPEC_FILE",
+            "PARSER::MISSING_ATTRIBUTE",
+            "PARSER::MUTUALLY_EXCLUSIVE_KEYWORDS",
+            "PARSER::NON_UNIQUE_NAME",
+            "PARSER::SPEC_FILE_NOT_FOUND",
+            "PARSER::NAME_COLLISION",
+            "PARSER::RESERVED_NAME",
+            "PARSER::FAILED_TO_READ_DOC_FILE",
+            "PARSER::BAD_VALUE",
+            "PARSER::DUPLICATE_DISPLAY",
+            "PARSER::NON_UNIQUE_DEFAULT",
+            "PARSER::BAD_REFERENCE",
+            "PARSER::BAD_DISPLAY_STRING",
+            "PARSER::FAILED_TO_READ_STDIN",
+            "PARSER::SPEC_LANG_REQUIRED",
+            "PARSER::TAB_IN_DOC",
+            "PARSER::MIRROR_CATEGORIES",
+            "PARSER::MISSING_DISPLAY",
+            "PARSER::EXTENDS_CYCLE",
+        ],
+        &[
+            "CODER::CATEGORY_REQUIRED",
+            "CODER::ERROR_REQUIRED",
+            "CODER::FAILED_TO_PARSE_TOKENS",
+            "CODER::FAILED_TO_READ_OUTPUT_FILE",
+            "CODER::FAILED_TO_WRITE_OUTPUT_FILE",
+            "CODER::RUSTFMT_FAILED",
+            "CODER::RUSTFMT_NOT_FOUND",
+            "CODER::TOO_MANY_BITS",
+            "CODER::OUTPUT_PATH_NOT_DIRECTORY",
+            "CODER::BAD_INDEX",
+            "CODER::BAD_OUTPUT_MARKERS",
+            "CODER::CATEGORY_BITS_ADDED",
+            "CODER::VALUE_WIDTH_TOO_NARROW",
+            "CODER::FAILED_TO_BUILD_THREAD_POOL",
+            "CODER::RUSTFMT_CONFIG_NOT_FOUND",
+        ],
+    ];
 }
 
 mod _d {
@@ -334,7 +694,24 @@ mod _d {
         pub(crate) const NON_UNIQUE_NAME: &str = "A name is not unique.";
         pub(crate) const SPEC_FILE_NOT_FOUND: &str = "Specification file couldn't be found.";
         pub(crate) const NAME_COLLISION: &str = "Collision of names between different items.";
-        pub static A: [&str; 19] = [
+        pub(crate) const RESERVED_NAME: &str = "A name collides with a reserved identifier.";
+        pub(crate) const FAILED_TO_READ_DOC_FILE: &str = "Documentation file couldn't be read.";
+        pub(crate) const BAD_VALUE: &str = "Specification value is invalid.";
+        pub(crate) const DUPLICATE_DISPLAY: &str = "Different errors share a display string.";
+        pub(crate) const NON_UNIQUE_DEFAULT: &str = "More than one error is marked as default.";
+        pub(crate) const BAD_REFERENCE: &str = "A reference to an error is unknown or ambiguous.";
+        pub(crate) const BAD_DISPLAY_STRING: &str =
+            "A display string violates the module display policy.";
+        pub(crate) const FAILED_TO_READ_STDIN: &str = "Specification couldn't be read from stdin.";
+        pub(crate) const SPEC_LANG_REQUIRED: &str =
+            "Specification language must be set when reading from stdin.";
+        pub(crate) const TAB_IN_DOC: &str =
+            "A documentation or display string contains a tab character.";
+        pub(crate) const MIRROR_CATEGORIES: &str =
+            "Different categories define the same error names.";
+        pub(crate) const MISSING_DISPLAY: &str = "An error lacks an explicit display string.";
+        pub(crate) const EXTENDS_CYCLE: &str = "Module inheritance via `extends` forms a cycle.";
+        pub static A: [&str; 32] = [
             BAD_IDENTIFIER_CHARACTERS,
             BAD_IDENTIFIER_CASE,
             BAD_KEYWORD_TYPE,
@@ -354,6 +731,19 @@ mod _d {
             NON_UNIQUE_NAME,
             SPEC_FILE_NOT_FOUND,
             NAME_COLLISION,
+            RESERVED_NAME,
+            FAILED_TO_READ_DOC_FILE,
+            BAD_VALUE,
+            DUPLICATE_DISPLAY,
+            NON_UNIQUE_DEFAULT,
+            BAD_REFERENCE,
+            BAD_DISPLAY_STRING,
+            FAILED_TO_READ_STDIN,
+            SPEC_LANG_REQUIRED,
+            TAB_IN_DOC,
+            MIRROR_CATEGORIES,
+            MISSING_DISPLAY,
+            EXTENDS_CYCLE,
         ];
     }
 
@@ -368,7 +758,17 @@ mod _d {
         pub(crate) const TOO_MANY_BITS: &str =
             "The number of required bits exceeds the largest supported type u64.";
         pub(crate) const OUTPUT_PATH_NOT_DIRECTORY: &str = "Output path is not a directory.";
-        pub static A: [&str; 9] = [
+        pub(crate) const BAD_INDEX: &str = "Pinned indices are not unique or not contiguous.";
+        pub(crate) const BAD_OUTPUT_MARKERS: &str =
+            "Output file code-generation markers are malformed.";
+        pub(crate) const CATEGORY_BITS_ADDED: &str =
+            "A module gained category bits in its error kind layout.";
+        pub(crate) const VALUE_WIDTH_TOO_NARROW: &str =
+            "A requested value width is narrower than the underlying Rust type.";
+        pub(crate) const FAILED_TO_BUILD_THREAD_POOL: &str =
+            "Code generation thread pool couldn't be built.";
+        pub(crate) const RUSTFMT_CONFIG_NOT_FOUND: &str = "Rustfmt configuration file isn't found.";
+        pub static A: [&str; 15] = [
             CATEGORY_REQUIRED,
             ERROR_REQUIRED,
             FAILED_TO_PARSE_TOKENS,
@@ -378,6 +778,12 @@ mod _d {
             RUSTFMT_NOT_FOUND,
             TOO_MANY_BITS,
             OUTPUT_PATH_NOT_DIRECTORY,
+            BAD_INDEX,
+            BAD_OUTPUT_MARKERS,
+            CATEGORY_BITS_ADDED,
+            VALUE_WIDTH_TOO_NARROW,
+            FAILED_TO_BUILD_THREAD_POOL,
+            RUSTFMT_CONFIG_NOT_FOUND,
         ];
     }
 
@@ -390,9 +796,424 @@ mod _p {
     pub const CAT_BITS: usize = 1;
     pub const CAT_MAX: R = 1;
     pub const VAR_MASK: R = 31;
-    pub static VAR_MAXES: [R; 2] = [18, 8];
+    pub static VAR_MAXES: [R; 2] = [31, 14];
     pub const CAT_MASK: R = 32;
     pub const VAR_BITS: usize = 5;
+    pub static CAT_OFFSETS: [usize; 2] = [0, 32];
+    pub static NAMES: [(&str, super::TbErrorKind); 141] = [
+        (
+            "BAD_IDENTIFIER_CHARACTERS",
+            super::kind::parser::BAD_IDENTIFIER_CHARACTERS,
+        ),
+        (
+            "Parser::BAD_IDENTIFIER_CHARACTERS",
+            super::kind::parser::BAD_IDENTIFIER_CHARACTERS,
+        ),
+        (
+            "PARSER::BAD_IDENTIFIER_CHARACTERS",
+            super::kind::parser::BAD_IDENTIFIER_CHARACTERS,
+        ),
+        (
+            "BAD_IDENTIFIER_CASE",
+            super::kind::parser::BAD_IDENTIFIER_CASE,
+        ),
+        (
+            "Parser::BAD_IDENTIFIER_CASE",
+            super::kind::parser::BAD_IDENTIFIER_CASE,
+        ),
+        (
+            "PARSER::BAD_IDENTIFIER_CASE",
+            super::kind::parser::BAD_IDENTIFIER_CASE,
+        ),
+        ("BAD_KEYWORD_TYPE", super::kind::parser::BAD_KEYWORD_TYPE),
+        (
+            "Parser::BAD_KEYWORD_TYPE",
+            super::kind::parser::BAD_KEYWORD_TYPE,
+        ),
+        (
+            "PARSER::BAD_KEYWORD_TYPE",
+            super::kind::parser::BAD_KEYWORD_TYPE,
+        ),
+        (
+            "BAD_MODULE_IDENTIFIER",
+            super::kind::parser::BAD_MODULE_IDENTIFIER,
+        ),
+        (
+            "Parser::BAD_MODULE_IDENTIFIER",
+            super::kind::parser::BAD_MODULE_IDENTIFIER,
+        ),
+        (
+            "PARSER::BAD_MODULE_IDENTIFIER",
+            super::kind::parser::BAD_MODULE_IDENTIFIER,
+        ),
+        ("BAD_NAME", super::kind::parser::BAD_NAME),
+        ("Parser::BAD_NAME", super::kind::parser::BAD_NAME),
+        ("PARSER::BAD_NAME", super::kind::parser::BAD_NAME),
+        (
+            "BAD_OBJECT_ATTRIBUTE",
+            super::kind::parser::BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "Parser::BAD_OBJECT_ATTRIBUTE",
+            super::kind::parser::BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "PARSER::BAD_OBJECT_ATTRIBUTE",
+            super::kind::parser::BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "BAD_SPEC_FILE_EXTENSION",
+            super::kind::parser::BAD_SPEC_FILE_EXTENSION,
+        ),
+        (
+            "Parser::BAD_SPEC_FILE_EXTENSION",
+            super::kind::parser::BAD_SPEC_FILE_EXTENSION,
+        ),
+        (
+            "PARSER::BAD_SPEC_FILE_EXTENSION",
+            super::kind::parser::BAD_SPEC_FILE_EXTENSION,
+        ),
+        ("BAD_TOML", super::kind::parser::BAD_TOML),
+        ("Parser::BAD_TOML", super::kind::parser::BAD_TOML),
+        ("PARSER::BAD_TOML", super::kind::parser::BAD_TOML),
+        (
+            "BAD_ROOT_LEVEL_KEYWORD",
+            super::kind::parser::BAD_ROOT_LEVEL_KEYWORD,
+        ),
+        (
+            "Parser::BAD_ROOT_LEVEL_KEYWORD",
+            super::kind::parser::BAD_ROOT_LEVEL_KEYWORD,
+        ),
+        (
+            "PARSER::BAD_ROOT_LEVEL_KEYWORD",
+            super::kind::parser::BAD_ROOT_LEVEL_KEYWORD,
+        ),
+        ("BAD_VALUE_TYPE", super::kind::parser::BAD_VALUE_TYPE),
+        (
+            "Parser::BAD_VALUE_TYPE",
+            super::kind::parser::BAD_VALUE_TYPE,
+        ),
+        (
+            "PARSER::BAD_VALUE_TYPE",
+            super::kind::parser::BAD_VALUE_TYPE,
+        ),
+        ("BAD_YAML", super::kind::parser::BAD_YAML),
+        ("Parser::BAD_YAML", super::kind::parser::BAD_YAML),
+        ("PARSER::BAD_YAML", super::kind::parser::BAD_YAML),
+        ("EMPTY_IDENTIFIER", super::kind::parser::EMPTY_IDENTIFIER),
+        (
+            "Parser::EMPTY_IDENTIFIER",
+            super::kind::parser::EMPTY_IDENTIFIER,
+        ),
+        (
+            "PARSER::EMPTY_IDENTIFIER",
+            super::kind::parser::EMPTY_IDENTIFIER,
+        ),
+        ("EMPTY_LIST", super::kind::parser::EMPTY_LIST),
+        ("Parser::EMPTY_LIST", super::kind::parser::EMPTY_LIST),
+        ("PARSER::EMPTY_LIST", super::kind::parser::EMPTY_LIST),
+        (
+            "FAILED_TO_OPEN_SPEC_FILE",
+            super::kind::parser::FAILED_TO_OPEN_SPEC_FILE,
+        ),
+        (
+            "Parser::FAILED_TO_OPEN_SPEC_FILE",
+            super::kind::parser::FAILED_TO_OPEN_SPEC_FILE,
+        ),
+        (
+            "PARSER::FAILED_TO_OPEN_SPEC_FILE",
+            super::kind::parser::FAILED_TO_OPEN_SPEC_FILE,
+        ),
+        ("MISSING_ATTRIBUTE", super::kind::parser::MISSING_ATTRIBUTE),
+        (
+            "Parser::MISSING_ATTRIBUTE",
+            super::kind::parser::MISSING_ATTRIBUTE,
+        ),
+        (
+            "PARSER::MISSING_ATTRIBUTE",
+            super::kind::parser::MISSING_ATTRIBUTE,
+        ),
+        (
+            "MUTUALLY_EXCLUSIVE_KEYWORDS",
+            super::kind::parser::MUTUALLY_EXCLUSIVE_KEYWORDS,
+        ),
+        (
+            "Parser::MUTUALLY_EXCLUSIVE_KEYWORDS",
+            super::kind::parser::MUTUALLY_EXCLUSIVE_KEYWORDS,
+        ),
+        (
+            "PARSER::MUTUALLY_EXCLUSIVE_KEYWORDS",
+            super::kind::parser::MUTUALLY_EXCLUSIVE_KEYWORDS,
+        ),
+        ("NON_UNIQUE_NAME", super::kind::parser::NON_UNIQUE_NAME),
+        (
+            "Parser::NON_UNIQUE_NAME",
+            super::kind::parser::NON_UNIQUE_NAME,
+        ),
+        (
+            "PARSER::NON_UNIQUE_NAME",
+            super::kind::parser::NON_UNIQUE_NAME,
+        ),
+        (
+            "SPEC_FILE_NOT_FOUND",
+            super::kind::parser::SPEC_FILE_NOT_FOUND,
+        ),
+        (
+            "Parser::SPEC_FILE_NOT_FOUND",
+            super::kind::parser::SPEC_FILE_NOT_FOUND,
+        ),
+        (
+            "PARSER::SPEC_FILE_NOT_FOUND",
+            super::kind::parser::SPEC_FILE_NOT_FOUND,
+        ),
+        ("NAME_COLLISION", super::kind::parser::NAME_COLLISION),
+        (
+            "Parser::NAME_COLLISION",
+            super::kind::parser::NAME_COLLISION,
+        ),
+        (
+            "PARSER::NAME_COLLISION",
+            super::kind::parser::NAME_COLLISION,
+        ),
+        ("RESERVED_NAME", super::kind::parser::RESERVED_NAME),
+        ("Parser::RESERVED_NAME", super::kind::parser::RESERVED_NAME),
+        ("PARSER::RESERVED_NAME", super::kind::parser::RESERVED_NAME),
+        (
+            "FAILED_TO_READ_DOC_FILE",
+            super::kind::parser::FAILED_TO_READ_DOC_FILE,
+        ),
+        (
+            "Parser::FAILED_TO_READ_DOC_FILE",
+            super::kind::parser::FAILED_TO_READ_DOC_FILE,
+        ),
+        (
+            "PARSER::FAILED_TO_READ_DOC_FILE",
+            super::kind::parser::FAILED_TO_READ_DOC_FILE,
+        ),
+        ("BAD_VALUE", super::kind::parser::BAD_VALUE),
+        ("Parser::BAD_VALUE", super::kind::parser::BAD_VALUE),
+        ("PARSER::BAD_VALUE", super::kind::parser::BAD_VALUE),
+        ("DUPLICATE_DISPLAY", super::kind::parser::DUPLICATE_DISPLAY),
+        (
+            "Parser::DUPLICATE_DISPLAY",
+            super::kind::parser::DUPLICATE_DISPLAY,
+        ),
+        (
+            "PARSER::DUPLICATE_DISPLAY",
+            super::kind::parser::DUPLICATE_DISPLAY,
+        ),
+        (
+            "NON_UNIQUE_DEFAULT",
+            super::kind::parser::NON_UNIQUE_DEFAULT,
+        ),
+        (
+            "Parser::NON_UNIQUE_DEFAULT",
+            super::kind::parser::NON_UNIQUE_DEFAULT,
+        ),
+        (
+            "PARSER::NON_UNIQUE_DEFAULT",
+            super::kind::parser::NON_UNIQUE_DEFAULT,
+        ),
+        ("BAD_REFERENCE", super::kind::parser::BAD_REFERENCE),
+        ("Parser::BAD_REFERENCE", super::kind::parser::BAD_REFERENCE),
+        ("PARSER::BAD_REFERENCE", super::kind::parser::BAD_REFERENCE),
+        (
+            "BAD_DISPLAY_STRING",
+            super::kind::parser::BAD_DISPLAY_STRING,
+        ),
+        (
+            "Parser::BAD_DISPLAY_STRING",
+            super::kind::parser::BAD_DISPLAY_STRING,
+        ),
+        (
+            "PARSER::BAD_DISPLAY_STRING",
+            super::kind::parser::BAD_DISPLAY_STRING,
+        ),
+        (
+            "FAILED_TO_READ_STDIN",
+            super::kind::parser::FAILED_TO_READ_STDIN,
+        ),
+        (
+            "Parser::FAILED_TO_READ_STDIN",
+            super::kind::parser::FAILED_TO_READ_STDIN,
+        ),
+        (
+            "PARSER::FAILED_TO_READ_STDIN",
+            super::kind::parser::FAILED_TO_READ_STDIN,
+        ),
+        (
+            "SPEC_LANG_REQUIRED",
+            super::kind::parser::SPEC_LANG_REQUIRED,
+        ),
+        (
+            "Parser::SPEC_LANG_REQUIRED",
+            super::kind::parser::SPEC_LANG_REQUIRED,
+        ),
+        (
+            "PARSER::SPEC_LANG_REQUIRED",
+            super::kind::parser::SPEC_LANG_REQUIRED,
+        ),
+        ("TAB_IN_DOC", super::kind::parser::TAB_IN_DOC),
+        ("Parser::TAB_IN_DOC", super::kind::parser::TAB_IN_DOC),
+        ("PARSER::TAB_IN_DOC", super::kind::parser::TAB_IN_DOC),
+        ("MIRROR_CATEGORIES", super::kind::parser::MIRROR_CATEGORIES),
+        (
+            "Parser::MIRROR_CATEGORIES",
+            super::kind::parser::MIRROR_CATEGORIES,
+        ),
+        (
+            "PARSER::MIRROR_CATEGORIES",
+            super::kind::parser::MIRROR_CATEGORIES,
+        ),
+        ("MISSING_DISPLAY", super::kind::parser::MISSING_DISPLAY),
+        (
+            "Parser::MISSING_DISPLAY",
+            super::kind::parser::MISSING_DISPLAY,
+        ),
+        (
+            "PARSER::MISSING_DISPLAY",
+            super::kind::parser::MISSING_DISPLAY,
+        ),
+        ("EXTENDS_CYCLE", super::kind::parser::EXTENDS_CYCLE),
+        ("Parser::EXTENDS_CYCLE", super::kind::parser::EXTENDS_CYCLE),
+        ("PARSER::EXTENDS_CYCLE", super::kind::parser::EXTENDS_CYCLE),
+        ("CATEGORY_REQUIRED", super::kind::coder::CATEGORY_REQUIRED),
+        (
+            "Coder::CATEGORY_REQUIRED",
+            super::kind::coder::CATEGORY_REQUIRED,
+        ),
+        (
+            "CODER::CATEGORY_REQUIRED",
+            super::kind::coder::CATEGORY_REQUIRED,
+        ),
+        ("ERROR_REQUIRED", super::kind::coder::ERROR_REQUIRED),
+        ("Coder::ERROR_REQUIRED", super::kind::coder::ERROR_REQUIRED),
+        ("CODER::ERROR_REQUIRED", super::kind::coder::ERROR_REQUIRED),
+        (
+            "FAILED_TO_PARSE_TOKENS",
+            super::kind::coder::FAILED_TO_PARSE_TOKENS,
+        ),
+        (
+            "Coder::FAILED_TO_PARSE_TOKENS",
+            super::kind::coder::FAILED_TO_PARSE_TOKENS,
+        ),
+        (
+            "CODER::FAILED_TO_PARSE_TOKENS",
+            super::kind::coder::FAILED_TO_PARSE_TOKENS,
+        ),
+        (
+            "FAILED_TO_READ_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_READ_OUTPUT_FILE,
+        ),
+        (
+            "Coder::FAILED_TO_READ_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_READ_OUTPUT_FILE,
+        ),
+        (
+            "CODER::FAILED_TO_READ_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_READ_OUTPUT_FILE,
+        ),
+        (
+            "FAILED_TO_WRITE_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_WRITE_OUTPUT_FILE,
+        ),
+        (
+            "Coder::FAILED_TO_WRITE_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_WRITE_OUTPUT_FILE,
+        ),
+        (
+            "CODER::FAILED_TO_WRITE_OUTPUT_FILE",
+            super::kind::coder::FAILED_TO_WRITE_OUTPUT_FILE,
+        ),
+        ("RUSTFMT_FAILED", super::kind::coder::RUSTFMT_FAILED),
+        ("Coder::RUSTFMT_FAILED", super::kind::coder::RUSTFMT_FAILED),
+        ("CODER::RUSTFMT_FAILED", super::kind::coder::RUSTFMT_FAILED),
+        ("RUSTFMT_NOT_FOUND", super::kind::coder::RUSTFMT_NOT_FOUND),
+        (
+            "Coder::RUSTFMT_NOT_FOUND",
+            super::kind::coder::RUSTFMT_NOT_FOUND,
+        ),
+        (
+            "CODER::RUSTFMT_NOT_FOUND",
+            super::kind::coder::RUSTFMT_NOT_FOUND,
+        ),
+        ("TOO_MANY_BITS", super::kind::coder::TOO_MANY_BITS),
+        ("Coder::TOO_MANY_BITS", super::kind::coder::TOO_MANY_BITS),
+        ("CODER::TOO_MANY_BITS", super::kind::coder::TOO_MANY_BITS),
+        (
+            "OUTPUT_PATH_NOT_DIRECTORY",
+            super::kind::coder::OUTPUT_PATH_NOT_DIRECTORY,
+        ),
+        (
+            "Coder::OUTPUT_PATH_NOT_DIRECTORY",
+            super::kind::coder::OUTPUT_PATH_NOT_DIRECTORY,
+        ),
+        (
+            "CODER::OUTPUT_PATH_NOT_DIRECTORY",
+            super::kind::coder::OUTPUT_PATH_NOT_DIRECTORY,
+        ),
+        ("BAD_INDEX", super::kind::coder::BAD_INDEX),
+        ("Coder::BAD_INDEX", super::kind::coder::BAD_INDEX),
+        ("CODER::BAD_INDEX", super::kind::coder::BAD_INDEX),
+        ("BAD_OUTPUT_MARKERS", super::kind::coder::BAD_OUTPUT_MARKERS),
+        (
+            "Coder::BAD_OUTPUT_MARKERS",
+            super::kind::coder::BAD_OUTPUT_MARKERS,
+        ),
+        (
+            "CODER::BAD_OUTPUT_MARKERS",
+            super::kind::coder::BAD_OUTPUT_MARKERS,
+        ),
+        (
+            "CATEGORY_BITS_ADDED",
+            super::kind::coder::CATEGORY_BITS_ADDED,
+        ),
+        (
+            "Coder::CATEGORY_BITS_ADDED",
+            super::kind::coder::CATEGORY_BITS_ADDED,
+        ),
+        (
+            "CODER::CATEGORY_BITS_ADDED",
+            super::kind::coder::CATEGORY_BITS_ADDED,
+        ),
+        (
+            "VALUE_WIDTH_TOO_NARROW",
+            super::kind::coder::VALUE_WIDTH_TOO_NARROW,
+        ),
+        (
+            "Coder::VALUE_WIDTH_TOO_NARROW",
+            super::kind::coder::VALUE_WIDTH_TOO_NARROW,
+        ),
+        (
+            "CODER::VALUE_WIDTH_TOO_NARROW",
+            super::kind::coder::VALUE_WIDTH_TOO_NARROW,
+        ),
+        (
+            "FAILED_TO_BUILD_THREAD_POOL",
+            super::kind::coder::FAILED_TO_BUILD_THREAD_POOL,
+        ),
+        (
+            "Coder::FAILED_TO_BUILD_THREAD_POOL",
+            super::kind::coder::FAILED_TO_BUILD_THREAD_POOL,
+        ),
+        (
+            "CODER::FAILED_TO_BUILD_THREAD_POOL",
+            super::kind::coder::FAILED_TO_BUILD_THREAD_POOL,
+        ),
+        (
+            "RUSTFMT_CONFIG_NOT_FOUND",
+            super::kind::coder::RUSTFMT_CONFIG_NOT_FOUND,
+        ),
+        (
+            "Coder::RUSTFMT_CONFIG_NOT_FOUND",
+            super::kind::coder::RUSTFMT_CONFIG_NOT_FOUND,
+        ),
+        (
+            "CODER::RUSTFMT_CONFIG_NOT_FOUND",
+            super::kind::coder::RUSTFMT_CONFIG_NOT_FOUND,
+        ),
+    ];
+
     const _: () = assert!(KIND_BITS <= R::BITS as usize);
     const _: () = assert!(CAT_BITS <= usize::BITS as usize);
     pub(super) struct Ident<'a>(pub(super) &'a str);
@@ -482,6 +1303,81 @@ pub mod kind {
 
         /// Collision of names between different items.
         pub const NAME_COLLISION: EK = EK::new(c::PARSER, 18);
+
+        /// A name collides with a reserved identifier.
+        pub const RESERVED_NAME: EK = EK::new(c::PARSER, 19);
+
+        /// Documentation file couldn't be read.
+        pub const FAILED_TO_READ_DOC_FILE: EK = EK::new(c::PARSER, 20);
+
+        /// Specification value is invalid.
+        pub const BAD_VALUE: EK = EK::new(c::PARSER, 21);
+
+        /// Different errors share a display string.
+        pub const DUPLICATE_DISPLAY: EK = EK::new(c::PARSER, 22);
+
+        /// More than one error is marked as default.
+        pub const NON_UNIQUE_DEFAULT: EK = EK::new(c::PARSER, 23);
+
+        /// A reference to an error is unknown or ambiguous.
+        pub const BAD_REFERENCE: EK = EK::new(c::PARSER, 24);
+
+        /// A display string violates the module display policy.
+        pub const BAD_DISPLAY_STRING: EK = EK::new(c::PARSER, 25);
+
+        /// Specification couldn't be read from stdin.
+        pub const FAILED_TO_READ_STDIN: EK = EK::new(c::PARSER, 26);
+
+        /// Specification language must be set when reading from stdin.
+        pub const SPEC_LANG_REQUIRED: EK = EK::new(c::PARSER, 27);
+
+        /// A documentation or display string contains a tab character.
+        pub const TAB_IN_DOC: EK = EK::new(c::PARSER, 28);
+
+        /// Different categories define the same error names.
+        pub const MIRROR_CATEGORIES: EK = EK::new(c::PARSER, 29);
+
+        /// An error lacks an explicit display string.
+        pub const MISSING_DISPLAY: EK = EK::new(c::PARSER, 30);
+
+        /// Module inheritance via `extends` forms a cycle.
+        pub const EXTENDS_CYCLE: EK = EK::new(c::PARSER, 31);
+
+        /// All error kinds of the Parser category, ordered by value.
+        pub static ALL: [EK; 32] = [
+            BAD_IDENTIFIER_CHARACTERS,
+            BAD_IDENTIFIER_CASE,
+            BAD_KEYWORD_TYPE,
+            BAD_MODULE_IDENTIFIER,
+            BAD_NAME,
+            BAD_OBJECT_ATTRIBUTE,
+            BAD_SPEC_FILE_EXTENSION,
+            BAD_TOML,
+            BAD_ROOT_LEVEL_KEYWORD,
+            BAD_VALUE_TYPE,
+            BAD_YAML,
+            EMPTY_IDENTIFIER,
+            EMPTY_LIST,
+            FAILED_TO_OPEN_SPEC_FILE,
+            MISSING_ATTRIBUTE,
+            MUTUALLY_EXCLUSIVE_KEYWORDS,
+            NON_UNIQUE_NAME,
+            SPEC_FILE_NOT_FOUND,
+            NAME_COLLISION,
+            RESERVED_NAME,
+            FAILED_TO_READ_DOC_FILE,
+            BAD_VALUE,
+            DUPLICATE_DISPLAY,
+            NON_UNIQUE_DEFAULT,
+            BAD_REFERENCE,
+            BAD_DISPLAY_STRING,
+            FAILED_TO_READ_STDIN,
+            SPEC_LANG_REQUIRED,
+            TAB_IN_DOC,
+            MIRROR_CATEGORIES,
+            MISSING_DISPLAY,
+            EXTENDS_CYCLE,
+        ];
     }
 
     /// Coder category error kind constants.
@@ -515,5 +1411,42 @@ pub mod kind {
 
         /// Output path is not a directory.
         pub const OUTPUT_PATH_NOT_DIRECTORY: EK = EK::new(c::CODER, 8);
+
+        /// Pinned indices are not unique or not contiguous.
+        pub const BAD_INDEX: EK = EK::new(c::CODER, 9);
+
+        /// Output file code-generation markers are malformed.
+        pub const BAD_OUTPUT_MARKERS: EK = EK::new(c::CODER, 10);
+
+        /// A module gained category bits in its error kind layout.
+        pub const CATEGORY_BITS_ADDED: EK = EK::new(c::CODER, 11);
+
+        /// A requested value width is narrower than the underlying Rust type.
+        pub const VALUE_WIDTH_TOO_NARROW: EK = EK::new(c::CODER, 12);
+
+        /// Code generation thread pool couldn't be built.
+        pub const FAILED_TO_BUILD_THREAD_POOL: EK = EK::new(c::CODER, 13);
+
+        /// Rustfmt configuration file isn't found.
+        pub const RUSTFMT_CONFIG_NOT_FOUND: EK = EK::new(c::CODER, 14);
+
+        /// All error kinds of the Coder category, ordered by value.
+        pub static ALL: [EK; 15] = [
+            CATEGORY_REQUIRED,
+            ERROR_REQUIRED,
+            FAILED_TO_PARSE_TOKENS,
+            FAILED_TO_READ_OUTPUT_FILE,
+            FAILED_TO_WRITE_OUTPUT_FILE,
+            RUSTFMT_FAILED,
+            RUSTFMT_NOT_FOUND,
+            TOO_MANY_BITS,
+            OUTPUT_PATH_NOT_DIRECTORY,
+            BAD_INDEX,
+            BAD_OUTPUT_MARKERS,
+            CATEGORY_BITS_ADDED,
+            VALUE_WIDTH_TOO_NARROW,
+            FAILED_TO_BUILD_THREAD_POOL,
+            RUSTFMT_CONFIG_NOT_FOUND,
+        ];
     }
 }
@@ -11,6 +11,15 @@
 //! *tighterror-build* itself uses the *tighterror* framework.
 //! The [errors] module is a good example of how the end result looks like.
 //!
+//! The inner numeric value of a generated error kind is private, so an error
+//! kind can't be created from a literal:
+//!
+//! ```compile_fail
+//! use tighterror_build::errors::TbErrorKind;
+//!
+//! let kind = TbErrorKind(0);
+//! ```
+//!
 //! The following sections compare two main ways to use *tighterror-build*.
 //!
 //! # cargo-tighterror
@@ -114,6 +123,13 @@
 //! See [CodegenOptions] documentation for the full list of configurable
 //! attributes.
 //!
+//! ## Exporting the specification
+//!
+//! With the `serde` feature enabled, `parse_spec` returns the parsed
+//! specification with the default values resolved. It implements
+//! `serde::Serialize`, so build scripts can export it, e.g., as JSON,
+//! to emit their own artifacts.
+//!
 //! [cargo-tighterror]: https://crates.io/crates/cargo-tighterror
 //! [build script]: https://doc.rust-lang.org/cargo/reference/build-scripts.html
 //! [tighterror]: https://docs.rs/tighterror/latest/tighterror
@@ -128,6 +144,11 @@
 #[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
 pub const DEFAULT_SPEC_PATH_YAML: &str = "tighterror.yaml";
 
+/// The alternative default YAML specification file path.
+#[cfg(feature = "yaml")]
+#[cfg_attr(docsrs, doc(cfg(feature = "yaml")))]
+pub const DEFAULT_SPEC_PATH_YML: &str = "tighterror.yml";
+
 /// The default TOML specification file path.
 #[cfg(feature = "toml")]
 #[cfg_attr(docsrs, doc(cfg(feature = "toml")))]
@@ -1,9 +1,12 @@
 use crate::{
+    coder::SpecLang,
     errors::{kind::parser::*, TbError},
-    spec::Spec,
+    spec::{definitions::STDIN_PATH, spec_relative_path, Spec},
 };
+use sha2::{Digest, Sha256};
 use std::{
     fs::File,
+    io::{self, Read},
     path::{Path, PathBuf},
 };
 
@@ -26,8 +29,12 @@ cfg_if::cfg_if! {
 
 mod helpers;
 mod kws;
+mod warnings;
+pub(crate) use helpers::{check_output_ext, check_output_pattern, check_tests};
+pub(crate) use warnings::warn;
+pub use warnings::{check_displays, check_docs, check_warnings};
 #[cfg(test)]
-mod testing;
+pub(crate) mod testing;
 
 #[derive(Debug)]
 pub enum ParseMode {
@@ -35,19 +42,116 @@ pub enum ParseMode {
     List,
 }
 
-pub fn parse(spec: Option<&Path>) -> Result<Spec, TbError> {
+pub fn parse(spec: Option<&Path>, lang: Option<SpecLang>, lenient: bool) -> Result<Spec, TbError> {
     let path = spec_file_path(spec)?;
-    let mut spec = parse_path(path.into())?;
+    let (source, lang) = if path.as_os_str() == STDIN_PATH {
+        read_stdin(lang)?
+    } else {
+        read_path(path, lang)?
+    };
+    let mut spec = parse_str(&source, lang, lenient)?;
     spec.path = path.into();
+    let mut hasher = Sha256::new();
+    hasher.update(source.as_bytes());
+    read_doc_files(&mut spec, &mut hasher)?;
+    spec.hash = hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{b:02x}"))
+        .collect();
     Ok(spec)
 }
 
-fn parse_path(path: PathBuf) -> Result<Spec, TbError> {
+/// Parses a specification from a string.
+///
+/// Unlike [parse] the doc files aren't read and the hash isn't calculated.
+pub fn parse_str(source: &str, lang: SpecLang, lenient: bool) -> Result<Spec, TbError> {
+    match lang {
+        #[cfg(feature = "yaml")]
+        SpecLang::Yaml => YamlParser::parse_str(source, lenient),
+        #[cfg(feature = "toml")]
+        SpecLang::Toml => TomlParser::parse_str(source, lenient),
+    }
+}
+
+/// Reads the doc files of the specification.
+///
+/// The contents of the doc files are hashed in the specification order,
+/// so the specification hash changes when a doc file changes.
+fn read_doc_files(spec: &mut Spec, hasher: &mut Sha256) -> Result<(), TbError> {
+    let spec_path = spec.path.as_path();
+    for m in &mut spec.modules {
+        read_doc_file(spec_path, &m.doc_file, &mut m.doc, hasher)?;
+        for c in &mut m.categories {
+            read_doc_file(spec_path, &c.doc_file, &mut c.doc, hasher)?;
+            for e in &mut c.errors {
+                read_doc_file(spec_path, &e.doc_file, &mut e.doc, hasher)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn read_doc_file(
+    spec_path: &Path,
+    doc_file: &Option<PathBuf>,
+    doc: &mut Option<String>,
+    hasher: &mut Sha256,
+) -> Result<(), TbError> {
+    let Some(doc_file) = doc_file else {
+        return Ok(());
+    };
+    let path = spec_relative_path(spec_path, doc_file);
+    match std::fs::read_to_string(&path) {
+        Ok(data) => {
+            hasher.update(data.as_bytes());
+            *doc = Some(data.trim_end().to_owned());
+            Ok(())
+        }
+        Err(e) => {
+            log::error!("failed to read the doc file {:?}: {e}", path);
+            FAILED_TO_READ_DOC_FILE.into()
+        }
+    }
+}
+
+fn read_path(path: &Path, lang: Option<SpecLang>) -> Result<(String, SpecLang), TbError> {
+    let lang = match lang {
+        Some(l) => l,
+        None => spec_lang(path)?,
+    };
+    let mut s = String::new();
+    if let Err(e) = open_spec_file(path)?.read_to_string(&mut s) {
+        log::error!("failed to read the spec file {:?}: {e}", path);
+        return match lang {
+            #[cfg(feature = "yaml")]
+            SpecLang::Yaml => BAD_YAML.into(),
+            #[cfg(feature = "toml")]
+            SpecLang::Toml => BAD_TOML.into(),
+        };
+    }
+    Ok((s, lang))
+}
+
+fn read_stdin(lang: Option<SpecLang>) -> Result<(String, SpecLang), TbError> {
+    let Some(lang) = lang else {
+        log::error!("specification language must be set when reading from stdin");
+        return SPEC_LANG_REQUIRED.into();
+    };
+    let mut s = String::new();
+    if let Err(e) = io::stdin().lock().read_to_string(&mut s) {
+        log::error!("failed to read the specification from stdin: {e}");
+        return FAILED_TO_READ_STDIN.into();
+    }
+    Ok((s, lang))
+}
+
+fn spec_lang(path: &Path) -> Result<SpecLang, TbError> {
     match path.extension() {
         #[cfg(feature = "yaml")]
-        Some(e) if e == "yaml" => YamlParser::parse_file(open_spec_file(&path)?),
+        Some(e) if e == "yaml" || e == "yml" => Ok(SpecLang::Yaml),
         #[cfg(feature = "toml")]
-        Some(e) if e == "toml" => TomlParser::parse_file(open_spec_file(&path)?),
+        Some(e) if e == "toml" => Ok(SpecLang::Toml),
         Some(e) => {
             log::error!(
                 "specification file extension {:?} isn't supported: {:?}",
@@ -76,6 +180,11 @@ fn spec_file_path(spec: Option<&Path>) -> Result<&Path, TbError> {
         return Ok(Path::new(crate::DEFAULT_SPEC_PATH_YAML));
     }
 
+    #[cfg(feature = "yaml")]
+    if Path::new(crate::DEFAULT_SPEC_PATH_YML).is_file() {
+        return Ok(Path::new(crate::DEFAULT_SPEC_PATH_YML));
+    }
+
     #[cfg(feature = "toml")]
     if Path::new(crate::DEFAULT_SPEC_PATH_TOML).is_file() {
         return Ok(Path::new(crate::DEFAULT_SPEC_PATH_TOML));
@@ -84,7 +193,7 @@ fn spec_file_path(spec: Option<&Path>) -> Result<&Path, TbError> {
     SPEC_FILE_NOT_FOUND.into()
 }
 
-fn open_spec_file(path: &PathBuf) -> Result<File, TbError> {
+fn open_spec_file(path: &Path) -> Result<File, TbError> {
     match File::options().read(true).open(path) {
         Ok(f) => Ok(f),
         Err(e) => {
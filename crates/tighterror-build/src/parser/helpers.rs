@@ -3,11 +3,18 @@ use crate::{
     common::casing,
     errors::{kind::parser::*, TbError},
     parser::kws,
-    spec::{ErrorSpec, ModuleSpec},
+    spec::{
+        definitions::{GENERATED_TESTS, OUTPUT_PATTERN_PLACEHOLDER},
+        resolve_error_ref, test_selector_matches, CategorySpec, DisplayCase, DisplayCodeRadix,
+        ErrorSpec, ModuleSpec, OrderBy, SerdeRepr, Spec, SubcategorySpec,
+    },
 };
 use convert_case::Case;
 use regex::Regex;
-use std::collections::HashSet;
+use std::{
+    collections::{BTreeSet, HashSet},
+    path::Path,
+};
 
 fn check_ident_chars(ident: &str, desc: &str, case: Case) -> Result<(), TbError> {
     let rgs = match case {
@@ -64,6 +71,22 @@ pub fn check_module_ident(ident: &str, kw: &str) -> Result<(), TbError> {
     }
 }
 
+pub fn check_submodule_ident(ident: &str, kw: &str) -> Result<(), TbError> {
+    check_ident(ident, kw, Case::Snake)?;
+    if kw == kws::KINDS_MOD_NAME && ident == idents::ERROR_KINDS_MOD {
+        return Ok(());
+    }
+    if kw == kws::CATEGORIES_MOD_NAME && ident == idents::CATEGORY_CONSTS_MOD {
+        return Ok(());
+    }
+    if idents::is_root_level_ident(ident) || syn::parse_str::<syn::Ident>(ident).is_err() {
+        log::error!("`{}` cannot be a reserved identifier: {}", kw, ident);
+        BAD_MODULE_IDENTIFIER.into()
+    } else {
+        Ok(())
+    }
+}
+
 fn check_name(name: &str, desc: &str, case: Case) -> Result<(), TbError> {
     check_ident(name, desc, case)?;
     if kws::is_any_kw(name) {
@@ -83,14 +106,182 @@ pub fn check_error_name(name: &str) -> Result<(), TbError> {
     check_name(name, "ErrorObject::name", Case::UpperSnake)
 }
 
+fn check_category_module_name(name: &str, desc: &str) -> Result<(), TbError> {
+    check_name(name, desc, Case::UpperCamel)?;
+    let module_name = casing::convert_case(name, Case::UpperCamel, Case::Snake);
+    if idents::is_reserved_category_module(&module_name) {
+        log::error!("`{desc}` collides with a reserved module name: {name} -> {module_name}");
+        RESERVED_NAME.into()
+    } else {
+        Ok(())
+    }
+}
+
 pub fn check_category_name(name: &str) -> Result<(), TbError> {
-    check_name(name, "CategoryObject::name", Case::UpperCamel)
+    check_category_module_name(name, "CategoryObject::name")
+}
+
+pub fn check_subcategory_name(name: &str) -> Result<(), TbError> {
+    check_category_module_name(name, "SubcategoryObject::name")
 }
 
 pub fn check_variant_type_name(name: &str) -> Result<(), TbError> {
     check_name(name, "ErrorObject::variant_type", Case::UpperCamel)
 }
 
+pub fn check_group_name(name: &str) -> Result<(), TbError> {
+    check_name(name, "GroupObject::name", Case::Snake)
+}
+
+pub fn check_bridge_enum_variant_name(name: &str) -> Result<(), TbError> {
+    check_name(name, "BridgeEnumObject::variants", Case::UpperCamel)
+}
+
+pub fn check_bridge_enum_path(path: &str) -> Result<(), TbError> {
+    if syn::parse_str::<syn::Path>(path).is_ok() {
+        Ok(())
+    } else {
+        log::error!(
+            "`BridgeEnumObject::{}` must be a Rust path, e.g., `crate::Error`: {path:?}",
+            kws::BRIDGE_ENUM_PATH
+        );
+        BAD_VALUE.into()
+    }
+}
+
+pub fn check_display_resolver_path(path: &str) -> Result<(), TbError> {
+    if syn::parse_str::<syn::Path>(path).is_ok() {
+        Ok(())
+    } else {
+        log::error!(
+            "`ModuleObject::{}` must be a Rust path, e.g., `crate::i18n::message`: {path:?}",
+            kws::DISPLAY_RESOLVER
+        );
+        BAD_VALUE.into()
+    }
+}
+
+pub fn check_runtime_path(path: &str) -> Result<(), TbError> {
+    if syn::parse_str::<syn::Path>(path).is_ok() {
+        Ok(())
+    } else {
+        log::error!(
+            "`ModuleObject::{}` must be a Rust path, e.g., `crate::rt`: {path:?}",
+            kws::RUNTIME_PATH
+        );
+        BAD_VALUE.into()
+    }
+}
+
+pub fn check_output_pattern(pattern: &str) -> Result<(), TbError> {
+    if !pattern.contains(OUTPUT_PATTERN_PLACEHOLDER) {
+        log::error!(
+            "`{}` must contain the {OUTPUT_PATTERN_PLACEHOLDER} placeholder: {pattern:?}",
+            kws::OUTPUT_PATTERN
+        );
+        BAD_VALUE.into()
+    } else if Path::new(pattern).is_absolute() {
+        log::error!(
+            "`{}` must be relative to the output directory: {pattern:?}",
+            kws::OUTPUT_PATTERN
+        );
+        BAD_VALUE.into()
+    } else {
+        Ok(())
+    }
+}
+
+pub fn check_output_ext(ext: &str) -> Result<(), TbError> {
+    if ext.is_empty() || ext.starts_with('.') || ext.contains(['/', '\\']) {
+        log::error!(
+            "output file extension must be non-empty, without a leading dot \
+             or path separators: {ext:?}"
+        );
+        BAD_VALUE.into()
+    } else {
+        Ok(())
+    }
+}
+
+pub fn check_tests(tests: &[String]) -> Result<(), TbError> {
+    if tests.is_empty() {
+        log::error!(
+            "`{}` must not be empty, use `{}: false` to exclude the unit-tests",
+            kws::TESTS,
+            kws::TEST
+        );
+        return BAD_VALUE.into();
+    }
+
+    for t in tests {
+        if !GENERATED_TESTS.iter().any(|n| test_selector_matches(t, n)) {
+            log::error!(
+                "`{}` contains an unknown test name: {t:?}; valid names are {GENERATED_TESTS:?}",
+                kws::TESTS
+            );
+            return BAD_VALUE.into();
+        }
+    }
+
+    check_name_uniqueness("test", tests.iter().map(String::as_str))
+}
+
+pub fn check_lint_name(lint: &str) -> Result<(), TbError> {
+    let rg = Regex::new(r"^[a-z_][a-z0-9_]*(::[a-z_][a-z0-9_]*)?$").unwrap();
+    if rg.is_match(lint) {
+        Ok(())
+    } else {
+        log::error!(
+            "`ModuleObject::{}` must contain lint paths, e.g., `clippy::all`: {lint:?}",
+            kws::ALLOWS
+        );
+        BAD_VALUE.into()
+    }
+}
+
+pub fn check_allows(allows: &[String]) -> Result<(), TbError> {
+    for l in allows {
+        check_lint_name(l)?;
+    }
+    check_name_uniqueness("lint", allows.iter().map(|l| l.as_str()))
+}
+
+pub fn check_feature_name(feature: &str) -> Result<(), TbError> {
+    let rg = Regex::new(r"^[A-Za-z0-9_][A-Za-z0-9_+\-.]*$").unwrap();
+    if rg.is_match(feature) {
+        Ok(())
+    } else {
+        log::error!(
+            "`ModuleObject::{}` must be a Cargo feature name: {feature:?}",
+            kws::FEATURE
+        );
+        BAD_VALUE.into()
+    }
+}
+
+const VALUE_WIDTHS: [usize; 4] = [8, 16, 32, 64];
+
+pub fn check_value_widths(widths: &[usize]) -> Result<(), TbError> {
+    let mut seen = HashSet::new();
+    for w in widths {
+        if !VALUE_WIDTHS.contains(w) {
+            log::error!(
+                "`ModuleObject::{}` must contain widths out of {VALUE_WIDTHS:?}: {w}",
+                kws::VALUE_WIDTHS
+            );
+            return BAD_VALUE.into();
+        }
+        if !seen.insert(w) {
+            log::error!(
+                "`ModuleObject::{}` widths must be unique: {w}",
+                kws::VALUE_WIDTHS
+            );
+            return BAD_VALUE.into();
+        }
+    }
+    Ok(())
+}
+
 pub fn check_module_name(name: &str) -> Result<(), TbError> {
     if name.is_empty() {
         log::error!("module name cannot be an empty string");
@@ -103,11 +294,90 @@ pub fn check_module_name(name: &str) -> Result<(), TbError> {
     }
 }
 
+pub fn check_doc_file(doc: Option<&str>, obj: &str) -> Result<(), TbError> {
+    if doc.is_some() {
+        log::error!(
+            "{obj} attributes '{}' and '{}' are mutually exclusive",
+            kws::DOC,
+            kws::DOC_FILE
+        );
+        MUTUALLY_EXCLUSIVE_KEYWORDS.into()
+    } else {
+        Ok(())
+    }
+}
+
+pub fn parse_order_by(s: &str) -> Result<OrderBy, TbError> {
+    match s {
+        kws::ORDER_BY_NAME => Ok(OrderBy::Name),
+        kws::ORDER_BY_VALUE => Ok(OrderBy::Value),
+        _ => {
+            log::error!(
+                "ModuleObject::{} must be one of ['{}', '{}']: {s}",
+                kws::ORDER_BY,
+                kws::ORDER_BY_NAME,
+                kws::ORDER_BY_VALUE
+            );
+            BAD_VALUE.into()
+        }
+    }
+}
+
+pub fn parse_display_case(s: &str) -> Result<DisplayCase, TbError> {
+    match s {
+        kws::DISPLAY_CASE_SENTENCE => Ok(DisplayCase::Sentence),
+        kws::DISPLAY_CASE_LOWER => Ok(DisplayCase::Lower),
+        kws::DISPLAY_CASE_NONE => Ok(DisplayCase::None),
+        _ => {
+            log::error!(
+                "ModuleObject::{} must be one of ['{}', '{}', '{}']: {s}",
+                kws::DISPLAY_CASE,
+                kws::DISPLAY_CASE_SENTENCE,
+                kws::DISPLAY_CASE_LOWER,
+                kws::DISPLAY_CASE_NONE
+            );
+            BAD_VALUE.into()
+        }
+    }
+}
+
+pub fn parse_display_code_radix(s: &str) -> Result<DisplayCodeRadix, TbError> {
+    match s {
+        kws::DISPLAY_CODE_RADIX_HEX => Ok(DisplayCodeRadix::Hex),
+        kws::DISPLAY_CODE_RADIX_DECIMAL => Ok(DisplayCodeRadix::Decimal),
+        _ => {
+            log::error!(
+                "ModuleObject::{} must be one of ['{}', '{}']: {s}",
+                kws::DISPLAY_CODE_RADIX,
+                kws::DISPLAY_CODE_RADIX_HEX,
+                kws::DISPLAY_CODE_RADIX_DECIMAL
+            );
+            BAD_VALUE.into()
+        }
+    }
+}
+
+pub fn parse_serde_repr(s: &str) -> Result<SerdeRepr, TbError> {
+    match s {
+        kws::SERDE_REPR_NAME => Ok(SerdeRepr::Name),
+        kws::SERDE_REPR_VALUE => Ok(SerdeRepr::Value),
+        _ => {
+            log::error!(
+                "ModuleObject::{} must be one of ['{}', '{}']: {s}",
+                kws::SERDE_REPR,
+                kws::SERDE_REPR_NAME,
+                kws::SERDE_REPR_VALUE
+            );
+            BAD_VALUE.into()
+        }
+    }
+}
+
 fn get_non_unique_names<'a, I>(iter: I) -> Vec<String>
 where
     I: IntoIterator<Item = &'a str>,
 {
-    let mut ans = HashSet::new();
+    let mut ans = BTreeSet::new();
     let mut hs = HashSet::new();
 
     for n in iter {
@@ -122,7 +392,7 @@ where
     Vec::from_iter(ans)
 }
 
-fn check_name_uniqueness<'a, I>(item_name: &str, iter: I) -> Result<(), TbError>
+pub fn check_name_uniqueness<'a, I>(item_name: &str, iter: I) -> Result<(), TbError>
 where
     I: IntoIterator<Item = &'a str>,
 {
@@ -157,6 +427,35 @@ where
     check_name_uniqueness("category", iter)
 }
 
+/// Appends the errors of the sub-categories to the category errors.
+///
+/// The sub-category errors follow the errors defined directly in the
+/// category, so every sub-category occupies a contiguous range of variants.
+/// Error names must be unique in the category, including its sub-categories.
+pub fn add_subcategories(
+    c: &mut CategorySpec,
+    subcategories: Vec<(SubcategorySpec, Vec<ErrorSpec>)>,
+) -> Result<(), TbError> {
+    check_name_uniqueness(
+        "subcategory",
+        subcategories.iter().map(|(s, _)| s.name.as_str()),
+    )?;
+    for (s, errors) in subcategories {
+        c.errors.extend(errors.into_iter().map(|e| ErrorSpec {
+            subcategory: Some(s.name.clone()),
+            ..e
+        }));
+        c.subcategories.push(s);
+    }
+    check_error_name_uniqueness(c.errors.iter().map(|e| e.name.as_str()))?;
+    let variant_type_names = c
+        .errors
+        .iter()
+        .map(|e| e.variant_type_name())
+        .collect::<Vec<String>>();
+    check_variant_type_name_uniqueness(variant_type_names.iter().map(|s| s.as_str()))
+}
+
 pub fn check_module_error_name_uniqueness<'a, I>(iter: I) -> Result<(), TbError>
 where
     I: IntoIterator<Item = &'a str>,
@@ -164,6 +463,16 @@ where
     check_name_uniqueness("<flat_kinds> module error", iter)
 }
 
+pub fn check_prelude_name_uniqueness(m: &ModuleSpec) -> Result<(), TbError> {
+    if !m.prelude() || m.flat_kinds() {
+        return Ok(());
+    }
+    check_name_uniqueness(
+        "<prelude> module error",
+        m.errors_iter().map(|e| e.name.as_str()),
+    )
+}
+
 pub fn check_module_variant_type_name_uniqueness<'a, I>(iter: I) -> Result<(), TbError>
 where
     I: IntoIterator<Item = &'a str>,
@@ -171,6 +480,24 @@ where
     check_name_uniqueness("<flat_kinds> module variant type", iter)
 }
 
+pub fn check_flat_kinds_name_uniqueness(m: &ModuleSpec) -> Result<(), TbError> {
+    if !m.flat_kinds() {
+        return Ok(());
+    }
+    check_module_error_name_uniqueness(m.errors_iter().map(|e| e.name.as_str()))?;
+    let variant_type_names = m
+        .categories
+        .iter()
+        .flat_map(|c| {
+            c.errors
+                .iter()
+                .filter(move |e| m.err_has_variant_type(c, e))
+                .map(|e| e.variant_type_name())
+        })
+        .collect::<Vec<String>>();
+    check_module_variant_type_name_uniqueness(variant_type_names.iter().map(|s| s.as_str()))
+}
+
 pub fn check_module_name_uniqueness<'a, I>(iter: I) -> Result<(), TbError>
 where
     I: IntoIterator<Item = &'a str>,
@@ -178,9 +505,328 @@ where
     check_name_uniqueness("module", iter)
 }
 
-pub fn check_name_collisions(m: &ModuleSpec) -> Result<(), TbError> {
+/// Resolves the `extends` attribute of every module in a ModuleList.
+///
+/// Base modules are resolved before the modules extending them, so
+/// attributes propagate along inheritance chains.
+pub fn resolve_extends(modules: &mut [ModuleSpec]) -> Result<(), TbError> {
+    let mut resolved = vec![false; modules.len()];
+    let mut chain = Vec::new();
+    for i in 0..modules.len() {
+        resolve_module_extends(modules, i, &mut resolved, &mut chain)?;
+    }
+    Ok(())
+}
+
+fn resolve_module_extends(
+    modules: &mut [ModuleSpec],
+    i: usize,
+    resolved: &mut [bool],
+    chain: &mut Vec<usize>,
+) -> Result<(), TbError> {
+    if resolved[i] {
+        return Ok(());
+    }
+    let Some(base) = modules[i].extends.as_deref() else {
+        resolved[i] = true;
+        return Ok(());
+    };
+    if chain.contains(&i) {
+        let names: Vec<&str> = chain
+            .iter()
+            .chain([&i])
+            .map(|&j| modules[j].name())
+            .collect();
+        log::error!("`{}` forms a cycle: {}", kws::EXTENDS, names.join(" -> "));
+        return EXTENDS_CYCLE.into();
+    }
+    let Some(b) = modules.iter().position(|m| m.name() == base) else {
+        log::error!(
+            "`{}` references an unknown module in module {}: {base}",
+            kws::EXTENDS,
+            modules[i].name()
+        );
+        return BAD_REFERENCE.into();
+    };
+    chain.push(i);
+    resolve_module_extends(modules, b, resolved, chain)?;
+    chain.pop();
+    let base = modules[b].clone();
+    modules[i].inherit(&base);
+    resolved[i] = true;
+    Ok(())
+}
+
+/// Checks the names generated for module `m` don't collide.
+///
+/// The variant type names of the module are also checked against the
+/// type names of the other `modules` of the specification, which share
+/// the output file unless it is split into separate files.
+pub fn check_name_collisions(m: &ModuleSpec, modules: &[ModuleSpec]) -> Result<(), TbError> {
     check_struct_names_collision(m)?;
+    check_submodule_names_collision(m)?;
     check_variant_type_names_collision(m)?;
+    check_variant_type_names_cross_module_collision(m, modules)?;
+    check_assoc_const_names_collision(m)?;
+    check_category_all_name_collision(m)?;
+    Ok(())
+}
+
+pub fn check_default_error_uniqueness(m: &ModuleSpec) -> Result<(), TbError> {
+    let defaults = m
+        .errors_iter()
+        .filter(|e| e.is_default())
+        .map(|e| e.name.as_str())
+        .collect::<Vec<_>>();
+    if defaults.len() > 1 {
+        log::error!(
+            "at most one error can be marked as `{}` in module {}: {:?}",
+            kws::DEFAULT,
+            m.name(),
+            defaults
+        );
+        NON_UNIQUE_DEFAULT.into()
+    } else {
+        Ok(())
+    }
+}
+
+/// Group names reserved because the group method `is_<name>` would collide
+/// with, or be confused with, the error kind methods `is_in` and `error`.
+/// `index` is a keyword, and is rejected as a name anyway.
+const RESERVED_GROUP_NAMES: [&str; 2] = ["in", "error"];
+
+pub fn check_groups(m: &ModuleSpec) -> Result<(), TbError> {
+    check_name_uniqueness("group", m.groups.iter().map(|g| g.name.as_str()))?;
+    for g in &m.groups {
+        if RESERVED_GROUP_NAMES.contains(&g.name.as_str()) {
+            log::error!(
+                "group name `{}` is reserved for the error kind methods, rename the group",
+                g.name
+            );
+            return NAME_COLLISION.into();
+        }
+        let mut members = HashSet::new();
+        for r in &g.errors {
+            match resolve_error_ref(&m.categories, r).as_slice() {
+                [] => {
+                    log::error!("group `{}` references an unknown error: {r}", g.name);
+                    return BAD_REFERENCE.into();
+                }
+                [(c, e)] => {
+                    if !members.insert((c.name.as_str(), e.name.as_str())) {
+                        log::error!("group `{}` members must be unique: {r}", g.name);
+                        return NON_UNIQUE_NAME.into();
+                    }
+                }
+                _ => {
+                    log::error!(
+                        "group `{}` references an ambiguous error, \
+                        qualify it with a category name: {r}",
+                        g.name
+                    );
+                    return BAD_REFERENCE.into();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn check_map_by_name(spec: &Spec) -> Result<(), TbError> {
+    for m in &spec.modules {
+        for t in &m.map_by_name {
+            let Some(target) = spec
+                .modules
+                .iter()
+                .find(|tm| tm.name() == t && tm.name() != m.name())
+            else {
+                log::error!(
+                    "`{}` references an unknown module in module {}: {t}",
+                    kws::MAP_BY_NAME,
+                    m.name()
+                );
+                return BAD_REFERENCE.into();
+            };
+            for c in &m.categories {
+                for e in &c.errors {
+                    match target.resolve_same_name(c, e).len() {
+                        1 => {}
+                        0 => {
+                            log::error!(
+                                "`{}` error has no same-named error in module {t}: {}::{}::{}",
+                                kws::MAP_BY_NAME,
+                                m.name(),
+                                c.name,
+                                e.name
+                            );
+                            return BAD_REFERENCE.into();
+                        }
+                        _ => {
+                            log::error!(
+                                "`{}` error has ambiguous same-named errors in module {t}: {}::{}::{}",
+                                kws::MAP_BY_NAME,
+                                m.name(),
+                                c.name,
+                                e.name
+                            );
+                            return BAD_REFERENCE.into();
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn check_bridge_enum(m: &ModuleSpec) -> Result<(), TbError> {
+    let Some(b) = &m.bridge_enum else {
+        return Ok(());
+    };
+    let mut mapped = HashSet::new();
+    for (r, _) in &b.variants {
+        match resolve_error_ref(&m.categories, r).as_slice() {
+            [] => {
+                log::error!("`{}` references an unknown error: {r}", kws::BRIDGE_ENUM);
+                return BAD_REFERENCE.into();
+            }
+            [(c, e)] => {
+                if !mapped.insert((c.name.as_str(), e.name.as_str())) {
+                    log::error!("`{}` maps an error more than once: {r}", kws::BRIDGE_ENUM);
+                    return NON_UNIQUE_NAME.into();
+                }
+            }
+            _ => {
+                log::error!(
+                    "`{}` references an ambiguous error, \
+                    qualify it with a category name: {r}",
+                    kws::BRIDGE_ENUM
+                );
+                return BAD_REFERENCE.into();
+            }
+        }
+    }
+    for c in &m.categories {
+        for e in &c.errors {
+            if !mapped.contains(&(c.name.as_str(), e.name.as_str())) {
+                log::error!(
+                    "`{}` must map every error of module '{}': {}::{} is missing",
+                    kws::BRIDGE_ENUM,
+                    m.name(),
+                    c.name,
+                    e.name
+                );
+                return MISSING_ATTRIBUTE.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn check_display_resolver(m: &ModuleSpec) -> Result<(), TbError> {
+    match (m.display_is_message_id(), m.display_resolver()) {
+        (true, None) => {
+            log::error!(
+                "`{}` requires `{}` in module {}",
+                kws::DISPLAY_IS_MESSAGE_ID,
+                kws::DISPLAY_RESOLVER,
+                m.name()
+            );
+            MISSING_ATTRIBUTE.into()
+        }
+        (false, Some(_)) => {
+            log::error!(
+                "`{}` requires `{}: true` in module {}",
+                kws::DISPLAY_RESOLVER,
+                kws::DISPLAY_IS_MESSAGE_ID,
+                m.name()
+            );
+            BAD_OBJECT_ATTRIBUTE.into()
+        }
+        _ => Ok(()),
+    }
+}
+
+pub fn check_serde_repr(m: &ModuleSpec) -> Result<(), TbError> {
+    if m.serde_repr.is_some() && !m.serde() {
+        log::error!(
+            "`{}` requires `{}: true` in module {}",
+            kws::SERDE_REPR,
+            kws::SERDE,
+            m.name()
+        );
+        return BAD_OBJECT_ATTRIBUTE.into();
+    }
+    Ok(())
+}
+
+const DISPLAY_TERMINATORS: [char; 3] = ['.', '?', '!'];
+const DISPLAY_CATEGORY_TOKEN: &str = "{category}";
+
+/// Replaces the `{category}` token in display strings with the name of the
+/// owning category, and unescapes `{{` and `}}`.
+pub fn expand_display_category(m: &mut ModuleSpec) {
+    for c in &mut m.categories {
+        for e in &mut c.errors {
+            if let Some(ref mut display) = e.display {
+                *display = expand_display(display, &c.name);
+            }
+        }
+    }
+}
+
+fn expand_display(display: &str, category: &str) -> String {
+    let mut expanded = String::with_capacity(display.len());
+    let mut rest = display;
+    while let Some(i) = rest.find(['{', '}']) {
+        expanded.push_str(&rest[..i]);
+        rest = &rest[i..];
+        if rest.starts_with("{{") || rest.starts_with("}}") {
+            expanded.push_str(&rest[..1]);
+            rest = &rest[2..];
+        } else if let Some(r) = rest.strip_prefix(DISPLAY_CATEGORY_TOKEN) {
+            expanded.push_str(category);
+            rest = r;
+        } else {
+            expanded.push_str(&rest[..1]);
+            rest = &rest[1..];
+        }
+    }
+    expanded.push_str(rest);
+    expanded
+}
+
+pub fn apply_display_period(m: &mut ModuleSpec) -> Result<(), TbError> {
+    let normalize = m.normalize_display_period();
+    let enforce = m.enforce_display_period();
+    if (!normalize && !enforce) || m.display_is_message_id() {
+        return Ok(());
+    }
+    let module_name = m.name().to_owned();
+    for c in &mut m.categories {
+        for e in &mut c.errors {
+            let Some(ref mut display) = e.display else {
+                continue;
+            };
+            if display.ends_with(DISPLAY_TERMINATORS) {
+                continue;
+            }
+            if normalize {
+                display.push('.');
+            } else {
+                log::error!(
+                    "display string must end with one of {:?} in module {} [`{}`]: {}: {:?}",
+                    DISPLAY_TERMINATORS,
+                    module_name,
+                    kws::ENFORCE_DISPLAY_PERIOD,
+                    e.name,
+                    display
+                );
+                return BAD_DISPLAY_STRING.into();
+            }
+        }
+    }
     Ok(())
 }
 
@@ -199,6 +845,91 @@ pub fn check_struct_names_collision(m: &ModuleSpec) -> Result<(), TbError> {
         log::error!("error category name equals error kind name: {err_cat_name}");
         return NAME_COLLISION.into();
     }
+
+    if m.category_sets() {
+        let err_cat_set_name = m.err_cat_set_name();
+        if err_cat_set_name == err_name {
+            log::error!("error category set name equals error name: {err_cat_set_name}");
+            return NAME_COLLISION.into();
+        } else if err_cat_set_name == err_kind_name {
+            log::error!("error category set name equals error kind name: {err_cat_set_name}");
+            return NAME_COLLISION.into();
+        }
+    }
+
+    let err_cat_kinds_name = m.err_cat_kinds_name();
+    if err_cat_kinds_name == err_name {
+        log::error!("error category kinds name equals error name: {err_cat_kinds_name}");
+        return NAME_COLLISION.into();
+    } else if err_cat_kinds_name == err_kind_name {
+        log::error!("error category kinds name equals error kind name: {err_cat_kinds_name}");
+        return NAME_COLLISION.into();
+    }
+    Ok(())
+}
+
+fn check_submodule_names_collision(m: &ModuleSpec) -> Result<(), TbError> {
+    let kinds_mod_name = m.kinds_mod_name();
+    if kinds_mod_name == m.categories_mod_name() {
+        log::error!(
+            "error kinds module name equals error categories module name: {kinds_mod_name}"
+        );
+        NAME_COLLISION.into()
+    } else {
+        Ok(())
+    }
+}
+
+fn check_assoc_const_names_collision(m: &ModuleSpec) -> Result<(), TbError> {
+    if !m.assoc_consts() {
+        return Ok(());
+    }
+    let mut names = HashSet::new();
+    for c in &m.categories {
+        for e in &c.errors {
+            let name = m.err_kind_assoc_const_name(c, e);
+            if m.kind_iter() && name == idents::KIND_VARIANTS {
+                log::error!(
+                    "associated constant name of error `{}` in category `{}` is reserved \
+                    for the error kind names array, rename the error: {name}",
+                    e.name,
+                    c.name
+                );
+                return NAME_COLLISION.into();
+            }
+            if !names.insert(name) {
+                log::error!(
+                    "associated constant name of error `{}` in category `{}` isn't unique: {}",
+                    e.name,
+                    c.name,
+                    m.err_kind_assoc_const_name(c, e)
+                );
+                return NAME_COLLISION.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_category_all_name_collision(m: &ModuleSpec) -> Result<(), TbError> {
+    if m.flat_kinds() {
+        return Ok(());
+    }
+    for c in &m.categories {
+        if let Some(e) = c
+            .errors
+            .iter()
+            .find(|e| e.subcategory.is_none() && e.name == idents::CATEGORY_ALL)
+        {
+            log::error!(
+                "error name `{}` in category `{}` is reserved for the array of \
+                the category error kinds, rename the error",
+                e.name,
+                c.name
+            );
+            return NAME_COLLISION.into();
+        }
+    }
     Ok(())
 }
 
@@ -211,6 +942,35 @@ fn check_variant_type_names_collision(m: &ModuleSpec) -> Result<(), TbError> {
     Ok(())
 }
 
+fn check_variant_type_names_cross_module_collision(
+    m: &ModuleSpec,
+    modules: &[ModuleSpec],
+) -> Result<(), TbError> {
+    for other in modules.iter().filter(|o| o.name() != m.name()) {
+        let type_names = [
+            (kws::ERR_NAME, other.err_name()),
+            (kws::ERR_CAT_NAME, other.err_cat_name()),
+            (kws::ERR_KIND_NAME, other.err_kind_name()),
+        ];
+        for c in &m.categories {
+            for e in c.errors.iter().filter(|e| m.err_has_variant_type(c, e)) {
+                let name = e.variant_type_name();
+                if let Some((kw, _)) = type_names.iter().find(|(_, n)| *n == name) {
+                    log::error!(
+                        "variant type name of error `{}` in module `{}` equals \
+                        the `{kw}` of module `{}`: {name}",
+                        e.name,
+                        m.name(),
+                        other.name()
+                    );
+                    return NAME_COLLISION.into();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
 fn check_variant_type_names_collision_impl(m: &ModuleSpec, e: &ErrorSpec) -> Result<(), TbError> {
     let name = e.variant_type_name();
 
@@ -238,6 +998,20 @@ fn check_variant_type_names_collision_impl(m: &ModuleSpec, e: &ErrorSpec) -> Res
             name
         );
         return NAME_COLLISION.into();
+    } else if m.category_sets() && name == m.err_cat_set_name() {
+        log::error!(
+            "variant type name equals module's error category set name: {} {}",
+            e.name,
+            name
+        );
+        return NAME_COLLISION.into();
+    } else if name == m.err_cat_kinds_name() {
+        log::error!(
+            "variant type name equals module's error category kinds name: {} {}",
+            e.name,
+            name
+        );
+        return NAME_COLLISION.into();
     }
 
     Ok(())
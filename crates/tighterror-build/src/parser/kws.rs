@@ -2,7 +2,9 @@ pub const ERR_CAT_DOC: &str = "err_cat_doc";
 pub const DISPLAY: &str = "display";
 pub const DOC: &str = "doc";
 pub const DOC_FROM_DISPLAY: &str = "doc_from_display";
+pub const DOC_FILE: &str = "doc_file";
 pub const OUTPUT: &str = "output";
+pub const OUTPUT_PATTERN: &str = "output_pattern";
 pub const ERR_DOC: &str = "err_doc";
 pub const ERR_KIND_DOC: &str = "err_kind_doc";
 pub const ERRORS: &str = "errors";
@@ -11,6 +13,7 @@ pub const NAME: &str = "name";
 pub const RESULT_FROM_ERR: &str = "result_from_err";
 pub const RESULT_FROM_ERR_KIND: &str = "result_from_err_kind";
 pub const ERROR_TRAIT: &str = "error_trait";
+pub const CORE_ERROR: &str = "core_error";
 pub const ERR_NAME: &str = "err_name";
 pub const ERR_KIND_NAME: &str = "err_kind_name";
 pub const ERR_CAT_NAME: &str = "err_cat_name";
@@ -19,8 +22,60 @@ pub const MODULE: &str = "module";
 pub const MODULES: &str = "modules";
 pub const CATEGORY: &str = "category";
 pub const CATEGORIES: &str = "categories";
+pub const SUBCATEGORIES: &str = "subcategories";
 pub const FLAT_KINDS: &str = "flat_kinds";
+pub const PRELUDE: &str = "prelude";
 pub const VARIANT_TYPE: &str = "variant_type";
+pub const LOCATION: &str = "location";
+pub const ARBITRARY: &str = "arbitrary";
+pub const PROPTEST: &str = "proptest";
+pub const ORDER_BY: &str = "order_by";
+pub const KIND_TO_CAT: &str = "kind_to_cat";
+pub const KIND_ITER: &str = "kind_iter";
+pub const ALLOW_UNSAFE: &str = "allow_unsafe";
+pub const INDEX: &str = "index";
+pub const DEFAULT: &str = "default";
+pub const GROUPS: &str = "groups";
+pub const TEST: &str = "test";
+pub const TESTS: &str = "tests";
+pub const REQUIRE_DISPLAY: &str = "require_display";
+pub const SPLIT_TABLES: &str = "split_tables";
+pub const ENFORCE_DISPLAY_PERIOD: &str = "enforce_display_period";
+pub const NORMALIZE_DISPLAY_PERIOD: &str = "normalize_display_period";
+pub const DISPLAY_CASE: &str = "display_case";
+pub const DISPLAY_WITH_CODE: &str = "display_with_code";
+pub const DISPLAY_CODE_RADIX: &str = "display_code_radix";
+pub const DISPLAY_IS_MESSAGE_ID: &str = "display_is_message_id";
+pub const DISPLAY_RESOLVER: &str = "display_resolver";
+pub const RUNTIME_PATH: &str = "runtime_path";
+pub const ALLOWS: &str = "allows";
+pub const SERDE: &str = "serde";
+pub const SERDE_REPR: &str = "serde_repr";
+pub const BRIDGE_ENUM: &str = "bridge_enum";
+pub const VALUE_WIDTHS: &str = "value_widths";
+pub const FEATURE: &str = "feature";
+pub const KINDS_MOD_NAME: &str = "kinds_mod_name";
+pub const CATEGORIES_MOD_NAME: &str = "categories_mod_name";
+pub const CATEGORY_SETS: &str = "category_sets";
+pub const CATEGORY_DEFAULT: &str = "category_default";
+pub const DOC_CFG: &str = "doc_cfg";
+pub const CATALOG: &str = "catalog";
+pub const ASSOC_CONSTS: &str = "assoc_consts";
+pub const MAP_BY_NAME: &str = "map_by_name";
+pub const DEBUG_STABLE: &str = "debug_stable";
+pub const EXTENDS: &str = "extends";
+
+pub const ORDER_BY_NAME: &str = "name";
+pub const ORDER_BY_VALUE: &str = "value";
+pub const SERDE_REPR_NAME: &str = "name";
+pub const SERDE_REPR_VALUE: &str = "value";
+pub const DISPLAY_CASE_SENTENCE: &str = "sentence";
+pub const DISPLAY_CASE_LOWER: &str = "lower";
+pub const DISPLAY_CASE_NONE: &str = "none";
+pub const DISPLAY_CODE_RADIX_HEX: &str = "hex";
+pub const DISPLAY_CODE_RADIX_DECIMAL: &str = "decimal";
+pub const BRIDGE_ENUM_PATH: &str = "path";
+pub const BRIDGE_ENUM_VARIANTS: &str = "variants";
 
 pub const ROOT_KWS: [&str; 6] = [MAIN, ERRORS, MODULE, MODULES, CATEGORY, CATEGORIES];
 pub const REQUIRED_ROOT_KWS: [&str; 3] = [ERRORS, CATEGORIES, MODULES];
@@ -32,12 +87,15 @@ pub const MUTUALLY_EXCLUSIVE_ROOT_KWS: [(&str, &str); 6] = [
     (CATEGORIES, MODULES),
     (MODULE, MODULES),
 ];
-pub const ALL_KWS: [&str; 23] = [
+pub const MODULE_CATEGORIES_EXCLUSIVE_ROOT_KWS: [&str; 3] = [ERRORS, CATEGORY, CATEGORIES];
+pub const ALL_KWS: [&str; 66] = [
     ERR_CAT_DOC,
     DISPLAY,
     DOC,
     DOC_FROM_DISPLAY,
+    DOC_FILE,
     OUTPUT,
+    OUTPUT_PATTERN,
     ERR_KIND_DOC,
     ERR_DOC,
     ERRORS,
@@ -46,6 +104,7 @@ pub const ALL_KWS: [&str; 23] = [
     RESULT_FROM_ERR,
     RESULT_FROM_ERR_KIND,
     ERROR_TRAIT,
+    CORE_ERROR,
     ERR_NAME,
     ERR_KIND_NAME,
     ERR_CAT_NAME,
@@ -54,13 +113,53 @@ pub const ALL_KWS: [&str; 23] = [
     MODULES,
     CATEGORY,
     CATEGORIES,
+    SUBCATEGORIES,
     FLAT_KINDS,
+    PRELUDE,
     VARIANT_TYPE,
+    LOCATION,
+    ARBITRARY,
+    PROPTEST,
+    ORDER_BY,
+    KIND_TO_CAT,
+    KIND_ITER,
+    ALLOW_UNSAFE,
+    INDEX,
+    DEFAULT,
+    GROUPS,
+    TEST,
+    TESTS,
+    REQUIRE_DISPLAY,
+    SPLIT_TABLES,
+    ENFORCE_DISPLAY_PERIOD,
+    NORMALIZE_DISPLAY_PERIOD,
+    DISPLAY_CASE,
+    DISPLAY_WITH_CODE,
+    DISPLAY_CODE_RADIX,
+    DISPLAY_IS_MESSAGE_ID,
+    DISPLAY_RESOLVER,
+    RUNTIME_PATH,
+    ALLOWS,
+    SERDE,
+    SERDE_REPR,
+    BRIDGE_ENUM,
+    VALUE_WIDTHS,
+    FEATURE,
+    KINDS_MOD_NAME,
+    CATEGORIES_MOD_NAME,
+    CATEGORY_SETS,
+    CATEGORY_DEFAULT,
+    DOC_CFG,
+    CATALOG,
+    ASSOC_CONSTS,
+    MAP_BY_NAME,
+    DEBUG_STABLE,
+    EXTENDS,
 ];
 
 #[inline]
 fn contains(arr: &[&str], s: &str) -> bool {
-    arr.iter().any(|kw| *kw == s)
+    arr.contains(&s)
 }
 
 #[inline]
@@ -1,12 +1,11 @@
 use crate::{
     errors::{kind::parser::*, TbError},
-    parser::{helpers::*, kws, ParseMode},
+    parser::{helpers::*, kws, warn, ParseMode},
     spec::{
-        definitions::DEFAULT_FLAT_KINDS, CategorySpec, ErrorSpec, MainSpec, ModuleSpec, Spec,
-        IMPLICIT_CATEGORY_NAME,
+        BridgeEnumSpec, CategorySpec, ErrorSpec, GroupSpec, MainSpec, ModuleSpec, Spec,
+        SubcategorySpec, IMPLICIT_CATEGORY_NAME,
     },
 };
-use std::fs::File;
 use toml::{value::Array, Table, Value};
 
 // ----------------------------------------------------------------------------
@@ -15,21 +14,9 @@ use toml::{value::Array, Table, Value};
 pub struct TomlParser;
 
 impl TomlParser {
-    pub fn parse_file(mut file: File) -> Result<Spec, TbError> {
-        use std::io::Read;
-
-        let mut s = String::new();
-        if let Err(e) = file.read_to_string(&mut s) {
-            log::error!("failed to read the specification file: {e}");
-            return BAD_TOML.into();
-        }
-
-        Self::parse_str(&s)
-    }
-
-    pub fn parse_str(s: &str) -> Result<Spec, TbError> {
+    pub fn parse_str(s: &str, lenient: bool) -> Result<Spec, TbError> {
         match toml::from_str(s) {
-            Ok(v) => Self::value(v),
+            Ok(v) => Self::value(v, lenient),
             Err(e) => {
                 log::error!("failed to deserialize TOML: {e}");
                 BAD_TOML.into()
@@ -37,9 +24,9 @@ impl TomlParser {
         }
     }
 
-    fn value(value: toml::Value) -> Result<Spec, TbError> {
+    fn value(value: toml::Value, lenient: bool) -> Result<Spec, TbError> {
         match value {
-            Value::Table(t) => Self::table(t),
+            Value::Table(t) => Self::table(t, lenient),
             v => {
                 log::error!(
                     "specification document must be a Table: deserialized a {}",
@@ -50,27 +37,27 @@ impl TomlParser {
         }
     }
 
-    fn table(mut table: toml::Table) -> Result<Spec, TbError> {
-        Self::check_toplevel_attributes(&table)?;
+    fn table(mut table: toml::Table, lenient: bool) -> Result<Spec, TbError> {
+        Self::check_toplevel_attributes(&table, lenient)?;
 
         let mut spec = Spec::default();
 
         if let Some(v) = table.remove(kws::MAIN) {
-            spec.main = MainParser::value(v)?;
+            spec.main = MainParser::value(v, lenient)?;
         }
 
         if let Some(v) = table.remove(kws::MODULES) {
-            spec.modules = ModuleListParser::value(v)?;
+            spec.modules = ModuleListParser::value(v, lenient)?;
         }
 
         if let Some(v) = table.remove(kws::MODULE) {
             let mp = ModuleParser(ParseMode::Single);
-            spec.modules.push(mp.value(v)?);
+            spec.modules.push(mp.value(v, lenient)?);
         }
 
         if let Some(v) = table.remove(kws::CATEGORY) {
             let parser = CategoryParser(ParseMode::Single);
-            let cat_spec = parser.value(v)?;
+            let cat_spec = parser.value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 m.categories.push(cat_spec);
             } else {
@@ -80,7 +67,7 @@ impl TomlParser {
         }
 
         if let Some(v) = table.remove(kws::CATEGORIES) {
-            let categories = CategoryListParser::value(v)?;
+            let categories = CategoryListParser::value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 m.categories = categories;
             } else {
@@ -90,7 +77,7 @@ impl TomlParser {
         }
 
         if let Some(v) = table.remove(kws::ERRORS) {
-            let errors = ErrorListParser::value(v)?;
+            let errors = ErrorListParser::value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 if let Some(c) = m.categories.first_mut() {
                     c.errors = errors;
@@ -105,28 +92,35 @@ impl TomlParser {
             }
         }
 
+        for m in &mut spec.modules {
+            expand_display_category(m);
+            apply_display_period(m)?;
+        }
+
         for m in &spec.modules {
-            if m.flat_kinds.unwrap_or(DEFAULT_FLAT_KINDS) {
-                check_module_error_name_uniqueness(m.errors_iter().map(|e| e.name.as_str()))?;
-                let variant_type_names = m
-                    .errors_iter()
-                    .map(|e| e.variant_type_name())
-                    .collect::<Vec<String>>();
-                check_module_variant_type_name_uniqueness(
-                    variant_type_names.iter().map(|s| s.as_str()),
-                )?;
-            }
-            check_name_collisions(m)?;
+            check_flat_kinds_name_uniqueness(m)?;
+            check_prelude_name_uniqueness(m)?;
+            check_name_collisions(m, &spec.modules)?;
+            check_default_error_uniqueness(m)?;
+            check_groups(m)?;
+            check_bridge_enum(m)?;
+            check_display_resolver(m)?;
+            check_serde_repr(m)?;
         }
 
+        check_map_by_name(&spec)?;
+
         Ok(spec)
     }
 
-    fn check_toplevel_attributes(table: &toml::Table) -> Result<(), TbError> {
+    fn check_toplevel_attributes(table: &toml::Table, lenient: bool) -> Result<(), TbError> {
         for k in table.keys() {
             if !kws::is_root_kw(k) {
-                log::error!("invalid root-level keyword: {}", k);
-                return BAD_ROOT_LEVEL_KEYWORD.into();
+                warn(
+                    !lenient,
+                    BAD_ROOT_LEVEL_KEYWORD,
+                    format_args!("invalid root-level keyword: {k}"),
+                )?;
             }
         }
 
@@ -137,9 +131,28 @@ impl TomlParser {
             }
         }
 
-        if !table
-            .keys()
-            .any(|k| kws::REQUIRED_ROOT_KWS.iter().any(|req| req == k))
+        let module_categories = table
+            .get(kws::MODULE)
+            .and_then(|v| v.as_table())
+            .is_some_and(|t| t.contains_key(kws::CATEGORIES));
+
+        if module_categories {
+            for k in kws::MODULE_CATEGORIES_EXCLUSIVE_ROOT_KWS {
+                if table.contains_key(k) {
+                    log::error!(
+                        "`{}` defined in root-level `{}` attribute and root-level attribute '{k}' are mutually exclusive",
+                        kws::CATEGORIES,
+                        kws::MODULE
+                    );
+                    return MUTUALLY_EXCLUSIVE_KEYWORDS.into();
+                }
+            }
+        }
+
+        if !module_categories
+            && !table
+                .keys()
+                .any(|k| kws::REQUIRED_ROOT_KWS.iter().any(|req| req == k))
         {
             log::error!(
                 "one of {:?} root-level attributes must be specified",
@@ -158,9 +171,9 @@ impl TomlParser {
 pub struct MainParser;
 
 impl MainParser {
-    fn value(v: Value) -> Result<MainSpec, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<MainSpec, TbError> {
         match v {
-            Value::Table(t) => Self::table(t),
+            Value::Table(t) => Self::table(t, lenient),
             ref ov => {
                 log::error!(
                     "MainObject must be a Table: deserialized a {}",
@@ -171,21 +184,48 @@ impl MainParser {
         }
     }
 
-    fn table(mut t: toml::Table) -> Result<MainSpec, TbError> {
+    fn table(mut t: toml::Table, lenient: bool) -> Result<MainSpec, TbError> {
         let mut main_spec = MainSpec::default();
 
         if let Some(v) = t.remove(kws::OUTPUT) {
             main_spec.output = Some(v2string(v, kws::OUTPUT)?.into());
         }
 
+        if let Some(v) = t.remove(kws::OUTPUT_PATTERN) {
+            let pattern = v2string(v, kws::OUTPUT_PATTERN)?;
+            check_output_pattern(&pattern)?;
+            main_spec.output_pattern = Some(pattern);
+        }
+
         if let Some(v) = t.remove(kws::NO_STD) {
             main_spec.no_std = Some(v2bool(v, kws::NO_STD)?);
         }
 
-        if let Some((k, _)) = t.into_iter().next() {
-            let key = check_key(&k)?;
-            log::error!("invalid MainObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        if let Some(v) = t.remove(kws::TEST) {
+            main_spec.test = Some(v2bool(v, kws::TEST)?);
+        }
+
+        if let Some(v) = t.remove(kws::TESTS) {
+            let tests = v2string_list(v, kws::TESTS)?;
+            check_tests(&tests)?;
+            main_spec.tests = Some(tests);
+        }
+
+        if let Some(v) = t.remove(kws::REQUIRE_DISPLAY) {
+            main_spec.require_display = Some(v2bool(v, kws::REQUIRE_DISPLAY)?);
+        }
+
+        if let Some(v) = t.remove(kws::SPLIT_TABLES) {
+            main_spec.split_tables = Some(v2bool(v, kws::SPLIT_TABLES)?);
+        }
+
+        for (k, _) in t {
+            let key = check_key(&k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid MainObject attribute: {key}"),
+            )?;
         }
 
         Ok(main_spec)
@@ -198,9 +238,9 @@ impl MainParser {
 pub struct ModuleParser(ParseMode);
 
 impl ModuleParser {
-    fn value(&self, v: Value) -> Result<ModuleSpec, TbError> {
+    fn value(&self, v: Value, lenient: bool) -> Result<ModuleSpec, TbError> {
         match v {
-            Value::Table(t) => self.table(t),
+            Value::Table(t) => self.table(t, lenient),
             ref ov => {
                 log::error!(
                     "ModuleObject must be a Table: deserialized a {}",
@@ -211,22 +251,21 @@ impl ModuleParser {
         }
     }
 
-    fn table(&self, mut t: toml::Table) -> Result<ModuleSpec, TbError> {
+    fn table(&self, mut t: toml::Table, lenient: bool) -> Result<ModuleSpec, TbError> {
         let mut mod_spec = ModuleSpec::default();
 
         if let Some(v) = t.remove(kws::NAME) {
             mod_spec.name = Some(v2string(v, kws::NAME)?);
         }
 
+        if let Some(v) = t.remove(kws::EXTENDS) {
+            let base = v2string(v, kws::EXTENDS)?;
+            check_module_name(&base)?;
+            mod_spec.extends = Some(base);
+        }
+
         if let Some(v) = t.remove(kws::CATEGORIES) {
-            if let ParseMode::Single = self.0 {
-                log::error!(
-                    "CategoryList is not allowed in root-level `{}` attribute",
-                    kws::MODULE
-                );
-                return BAD_OBJECT_ATTRIBUTE.into();
-            }
-            mod_spec.categories = CategoryListParser::value(v)?;
+            mod_spec.categories = CategoryListParser::value(v, lenient)?;
         }
 
         if let Some(v) = t.remove(kws::DOC_FROM_DISPLAY) {
@@ -249,6 +288,11 @@ impl ModuleParser {
             mod_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = t.remove(kws::DOC_FILE) {
+            check_doc_file(mod_spec.doc.as_deref(), "ModuleObject")?;
+            mod_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = t.remove(kws::RESULT_FROM_ERR) {
             mod_spec.result_from_err = Some(v2bool(v, kws::RESULT_FROM_ERR)?);
         }
@@ -261,6 +305,10 @@ impl ModuleParser {
             mod_spec.error_trait = Some(v2bool(v, kws::ERROR_TRAIT)?);
         }
 
+        if let Some(v) = t.remove(kws::CORE_ERROR) {
+            mod_spec.core_error = Some(v2bool(v, kws::CORE_ERROR)?);
+        }
+
         if let Some(v) = t.remove(kws::ERR_NAME) {
             let err_name = v2string(v, kws::ERR_NAME)?;
             check_module_ident(&err_name, kws::ERR_NAME)?;
@@ -279,18 +327,170 @@ impl ModuleParser {
             mod_spec.err_cat_name = Some(err_cat_name);
         }
 
+        if let Some(v) = t.remove(kws::KINDS_MOD_NAME) {
+            let kinds_mod_name = v2string(v, kws::KINDS_MOD_NAME)?;
+            check_submodule_ident(&kinds_mod_name, kws::KINDS_MOD_NAME)?;
+            mod_spec.kinds_mod_name = Some(kinds_mod_name);
+        }
+
+        if let Some(v) = t.remove(kws::CATEGORIES_MOD_NAME) {
+            let categories_mod_name = v2string(v, kws::CATEGORIES_MOD_NAME)?;
+            check_submodule_ident(&categories_mod_name, kws::CATEGORIES_MOD_NAME)?;
+            mod_spec.categories_mod_name = Some(categories_mod_name);
+        }
+
         if let Some(v) = t.remove(kws::FLAT_KINDS) {
             mod_spec.flat_kinds = Some(v2bool(v, kws::FLAT_KINDS)?);
         }
 
+        if let Some(v) = t.remove(kws::PRELUDE) {
+            mod_spec.prelude = Some(v2bool(v, kws::PRELUDE)?);
+        }
+
         if let Some(v) = t.remove(kws::VARIANT_TYPE) {
             mod_spec.oes.variant_type = Some(v2bool(v, kws::VARIANT_TYPE)?);
         }
 
-        if let Some((k, _)) = t.into_iter().next() {
-            let key = check_key(&k)?;
-            log::error!("invalid ModuleObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        if let Some(v) = t.remove(kws::LOCATION) {
+            mod_spec.location = Some(v2bool(v, kws::LOCATION)?);
+        }
+
+        if let Some(v) = t.remove(kws::ARBITRARY) {
+            mod_spec.arbitrary = Some(v2bool(v, kws::ARBITRARY)?);
+        }
+
+        if let Some(v) = t.remove(kws::PROPTEST) {
+            mod_spec.proptest = Some(v2bool(v, kws::PROPTEST)?);
+        }
+
+        if let Some(v) = t.remove(kws::ORDER_BY) {
+            mod_spec.order_by = Some(parse_order_by(&v2string(v, kws::ORDER_BY)?)?);
+        }
+
+        if let Some(v) = t.remove(kws::SERDE) {
+            mod_spec.serde = Some(v2bool(v, kws::SERDE)?);
+        }
+
+        if let Some(v) = t.remove(kws::SERDE_REPR) {
+            mod_spec.serde_repr = Some(parse_serde_repr(&v2string(v, kws::SERDE_REPR)?)?);
+        }
+
+        if let Some(v) = t.remove(kws::KIND_TO_CAT) {
+            mod_spec.kind_to_cat = Some(v2bool(v, kws::KIND_TO_CAT)?);
+        }
+
+        if let Some(v) = t.remove(kws::KIND_ITER) {
+            mod_spec.kind_iter = Some(v2bool(v, kws::KIND_ITER)?);
+        }
+
+        if let Some(v) = t.remove(kws::DEBUG_STABLE) {
+            mod_spec.debug_stable = Some(v2bool(v, kws::DEBUG_STABLE)?);
+        }
+
+        if let Some(v) = t.remove(kws::CATEGORY_SETS) {
+            mod_spec.category_sets = Some(v2bool(v, kws::CATEGORY_SETS)?);
+        }
+
+        if let Some(v) = t.remove(kws::CATEGORY_DEFAULT) {
+            mod_spec.category_default = Some(v2bool(v, kws::CATEGORY_DEFAULT)?);
+        }
+
+        if let Some(v) = t.remove(kws::DOC_CFG) {
+            mod_spec.doc_cfg = Some(v2bool(v, kws::DOC_CFG)?);
+        }
+
+        if let Some(v) = t.remove(kws::CATALOG) {
+            mod_spec.catalog = Some(v2bool(v, kws::CATALOG)?);
+        }
+
+        if let Some(v) = t.remove(kws::ASSOC_CONSTS) {
+            mod_spec.assoc_consts = Some(v2bool(v, kws::ASSOC_CONSTS)?);
+        }
+
+        if let Some(v) = t.remove(kws::ALLOW_UNSAFE) {
+            mod_spec.allow_unsafe = Some(v2bool(v, kws::ALLOW_UNSAFE)?);
+        }
+
+        if let Some(v) = t.remove(kws::ENFORCE_DISPLAY_PERIOD) {
+            mod_spec.enforce_display_period = Some(v2bool(v, kws::ENFORCE_DISPLAY_PERIOD)?);
+        }
+
+        if let Some(v) = t.remove(kws::NORMALIZE_DISPLAY_PERIOD) {
+            mod_spec.normalize_display_period = Some(v2bool(v, kws::NORMALIZE_DISPLAY_PERIOD)?);
+        }
+
+        if let Some(v) = t.remove(kws::DISPLAY_CASE) {
+            mod_spec.display_case = Some(parse_display_case(&v2string(v, kws::DISPLAY_CASE)?)?);
+        }
+
+        if let Some(v) = t.remove(kws::DISPLAY_WITH_CODE) {
+            mod_spec.display_with_code = Some(v2bool(v, kws::DISPLAY_WITH_CODE)?);
+        }
+
+        if let Some(v) = t.remove(kws::DISPLAY_CODE_RADIX) {
+            let s = v2string(v, kws::DISPLAY_CODE_RADIX)?;
+            mod_spec.display_code_radix = Some(parse_display_code_radix(&s)?);
+        }
+
+        if let Some(v) = t.remove(kws::DISPLAY_IS_MESSAGE_ID) {
+            mod_spec.display_is_message_id = Some(v2bool(v, kws::DISPLAY_IS_MESSAGE_ID)?);
+        }
+
+        if let Some(v) = t.remove(kws::DISPLAY_RESOLVER) {
+            let resolver = v2string(v, kws::DISPLAY_RESOLVER)?;
+            check_display_resolver_path(&resolver)?;
+            mod_spec.display_resolver = Some(resolver);
+        }
+
+        if let Some(v) = t.remove(kws::RUNTIME_PATH) {
+            let path = v2string(v, kws::RUNTIME_PATH)?;
+            check_runtime_path(&path)?;
+            mod_spec.runtime_path = Some(path);
+        }
+
+        if let Some(v) = t.remove(kws::TEST) {
+            mod_spec.test = Some(v2bool(v, kws::TEST)?);
+        }
+
+        if let Some(v) = t.remove(kws::MAP_BY_NAME) {
+            mod_spec.map_by_name = v2string_list(v, kws::MAP_BY_NAME)?;
+            check_name_uniqueness(
+                kws::MAP_BY_NAME,
+                mod_spec.map_by_name.iter().map(|s| s.as_str()),
+            )?;
+        }
+
+        if let Some(v) = t.remove(kws::ALLOWS) {
+            mod_spec.allows = v2string_list(v, kws::ALLOWS)?;
+            check_allows(&mod_spec.allows)?;
+        }
+
+        if let Some(v) = t.remove(kws::FEATURE) {
+            let feature = v2string(v, kws::FEATURE)?;
+            check_feature_name(&feature)?;
+            mod_spec.feature = Some(feature);
+        }
+
+        if let Some(v) = t.remove(kws::VALUE_WIDTHS) {
+            mod_spec.value_widths = v2usize_list(v, kws::VALUE_WIDTHS)?;
+            check_value_widths(&mod_spec.value_widths)?;
+        }
+
+        if let Some(v) = t.remove(kws::GROUPS) {
+            mod_spec.groups = GroupListParser::value(v)?;
+        }
+
+        if let Some(v) = t.remove(kws::BRIDGE_ENUM) {
+            mod_spec.bridge_enum = Some(BridgeEnumParser::value(v, lenient)?);
+        }
+
+        for (k, _) in t {
+            let key = check_key(&k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid ModuleObject attribute: {key}"),
+            )?;
         }
 
         if let Some(ref n) = mod_spec.name {
@@ -307,6 +507,9 @@ impl ModuleParser {
                 log::error!("ModuleObject name is mandatory in ModuleList");
                 return MISSING_ATTRIBUTE.into();
             }
+        } else if mod_spec.extends.is_some() {
+            log::error!("`{}` is allowed only in ModuleList", kws::EXTENDS);
+            return BAD_OBJECT_ATTRIBUTE.into();
         }
 
         Ok(mod_spec)
@@ -319,9 +522,9 @@ impl ModuleParser {
 struct ModuleListParser;
 
 impl ModuleListParser {
-    fn value(v: Value) -> Result<Vec<ModuleSpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<ModuleSpec>, TbError> {
         match v {
-            Value::Array(a) => Self::array(a),
+            Value::Array(a) => Self::array(a, lenient),
             ref ov => {
                 log::error!("ModuleList must be an Array: deserialized {:?}", ov);
                 BAD_VALUE_TYPE.into()
@@ -329,17 +532,18 @@ impl ModuleListParser {
         }
     }
 
-    fn array(s: Array) -> Result<Vec<ModuleSpec>, TbError> {
+    fn array(s: Array, lenient: bool) -> Result<Vec<ModuleSpec>, TbError> {
         let mut modules = Vec::new();
         for v in s.into_iter() {
             let mp = ModuleParser(ParseMode::List);
-            modules.push(mp.value(v)?);
+            modules.push(mp.value(v, lenient)?);
         }
         if modules.is_empty() {
             log::error!("Empty ModuleList is not allowed");
             return EMPTY_LIST.into();
         }
         check_module_name_uniqueness(modules.iter().map(|m| m.name()))?;
+        resolve_extends(&mut modules)?;
         Ok(modules)
     }
 }
@@ -350,9 +554,9 @@ impl ModuleListParser {
 pub struct ErrorListParser;
 
 impl ErrorListParser {
-    fn value(v: Value) -> Result<Vec<ErrorSpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<ErrorSpec>, TbError> {
         match v {
-            Value::Array(a) => Self::array(a),
+            Value::Array(a) => Self::array(a, lenient),
             ref ov => {
                 log::error!(
                     "`{}` must be an Array: deserialized a {}",
@@ -364,12 +568,12 @@ impl ErrorListParser {
         }
     }
 
-    fn array(a: toml::value::Array) -> Result<Vec<ErrorSpec>, TbError> {
+    fn array(a: toml::value::Array, lenient: bool) -> Result<Vec<ErrorSpec>, TbError> {
         let mut errors = Vec::new();
         for v in a.into_iter() {
             match v {
                 Value::String(s) => errors.push(ErrorParser::string(s)?),
-                Value::Table(t) => errors.push(ErrorParser::table(t)?),
+                Value::Table(t) => errors.push(ErrorParser::table(t, lenient)?),
                 ov => {
                     log::error!(
                         "ErrorObject must be a String or a Table: deserialized {:?}",
@@ -407,7 +611,7 @@ impl ErrorParser {
         })
     }
 
-    fn table(mut t: toml::Table) -> Result<ErrorSpec, TbError> {
+    fn table(mut t: toml::Table, lenient: bool) -> Result<ErrorSpec, TbError> {
         let mut err_spec = ErrorSpec::default();
 
         if let Some(v) = t.remove(kws::NAME) {
@@ -422,10 +626,23 @@ impl ErrorParser {
             err_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = t.remove(kws::DOC_FILE) {
+            check_doc_file(err_spec.doc.as_deref(), "ErrorObject")?;
+            err_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = t.remove(kws::DOC_FROM_DISPLAY) {
             err_spec.oes.doc_from_display = Some(v2bool(v, kws::DOC_FROM_DISPLAY)?);
         }
 
+        if let Some(v) = t.remove(kws::DEFAULT) {
+            err_spec.default = Some(v2bool(v, kws::DEFAULT)?);
+        }
+
+        if let Some(v) = t.remove(kws::INDEX) {
+            err_spec.index = Some(v2usize(v, kws::INDEX)?);
+        }
+
         if let Some(v) = t.remove(kws::VARIANT_TYPE) {
             match v {
                 Value::Boolean(b) => err_spec.oes.variant_type = Some(b),
@@ -444,10 +661,13 @@ impl ErrorParser {
             }
         }
 
-        if let Some((k, _)) = t.into_iter().next() {
-            let key = check_key(&k)?;
-            log::error!("invalid ErrorObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        for (k, _) in t {
+            let key = check_key(&k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid ErrorObject attribute: {key}"),
+            )?;
         }
 
         check_error_name(&err_spec.name)?;
@@ -462,9 +682,9 @@ impl ErrorParser {
 struct CategoryParser(ParseMode);
 
 impl CategoryParser {
-    fn value(&self, v: Value) -> Result<CategorySpec, TbError> {
+    fn value(&self, v: Value, lenient: bool) -> Result<CategorySpec, TbError> {
         match v {
-            Value::Table(t) => self.table(t),
+            Value::Table(t) => self.table(t, lenient),
             ref ov => {
                 log::error!(
                     "ModuleObject must be a Table: deserialized a {}",
@@ -475,7 +695,7 @@ impl CategoryParser {
         }
     }
 
-    fn table(&self, mut t: Table) -> Result<CategorySpec, TbError> {
+    fn table(&self, mut t: Table, lenient: bool) -> Result<CategorySpec, TbError> {
         let mut cat_spec = CategorySpec::default();
 
         if let Some(v) = t.remove(kws::NAME) {
@@ -488,10 +708,19 @@ impl CategoryParser {
             cat_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = t.remove(kws::DOC_FILE) {
+            check_doc_file(cat_spec.doc.as_deref(), "CategoryObject")?;
+            cat_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = t.remove(kws::DOC_FROM_DISPLAY) {
             cat_spec.oes.doc_from_display = Some(v2bool(v, kws::DOC_FROM_DISPLAY)?);
         }
 
+        if let Some(v) = t.remove(kws::INDEX) {
+            cat_spec.index = Some(v2usize(v, kws::INDEX)?);
+        }
+
         if let Some(v) = t.remove(kws::ERRORS) {
             if matches!(self.0, ParseMode::Single) {
                 log::error!(
@@ -500,16 +729,30 @@ impl CategoryParser {
                 );
                 return BAD_OBJECT_ATTRIBUTE.into();
             }
-            cat_spec.errors = ErrorListParser::value(v)?;
+            cat_spec.errors = ErrorListParser::value(v, lenient)?;
+        }
+
+        if let Some(v) = t.remove(kws::SUBCATEGORIES) {
+            if matches!(self.0, ParseMode::Single) {
+                log::error!(
+                    "SubcategoryList is not allowed in root-level '{}' attribute",
+                    kws::CATEGORY
+                );
+                return BAD_OBJECT_ATTRIBUTE.into();
+            }
+            add_subcategories(&mut cat_spec, SubcategoryListParser::value(v, lenient)?)?;
         }
 
         if let Some(v) = t.remove(kws::VARIANT_TYPE) {
             cat_spec.oes.variant_type = Some(v2bool(v, kws::VARIANT_TYPE)?);
         }
 
-        if let Some((k, _)) = t.into_iter().next() {
-            log::error!("invalid CategoryObject attribute: {}", k);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        for (k, _) in t {
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid CategoryObject attribute: {k}"),
+            )?;
         }
 
         match self.0 {
@@ -536,13 +779,103 @@ impl CategoryParser {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct SubcategoryParser;
+
+impl SubcategoryParser {
+    fn table(mut t: Table, lenient: bool) -> Result<(SubcategorySpec, Vec<ErrorSpec>), TbError> {
+        let mut sub_spec = SubcategorySpec::default();
+        let mut errors = Vec::new();
+
+        if let Some(v) = t.remove(kws::NAME) {
+            let name = v2string(v, kws::NAME)?;
+            check_subcategory_name(&name)?;
+            sub_spec.name = name;
+        }
+
+        if let Some(v) = t.remove(kws::DOC) {
+            sub_spec.doc = Some(v2string(v, kws::DOC)?);
+        }
+
+        if let Some(v) = t.remove(kws::ERRORS) {
+            errors = ErrorListParser::value(v, lenient)?;
+        }
+
+        for (k, _) in t {
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid SubcategoryObject attribute: {k}"),
+            )?;
+        }
+
+        if sub_spec.name.is_empty() {
+            log::error!("SubcategoryObject name is mandatory in SubcategoryList");
+            return MISSING_ATTRIBUTE.into();
+        }
+
+        if errors.is_empty() {
+            log::error!("ErrorList not found: subcategory_name = {}", sub_spec.name);
+            return MISSING_ATTRIBUTE.into();
+        }
+
+        Ok((sub_spec, errors))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct SubcategoryListParser;
+
+impl SubcategoryListParser {
+    fn value(v: Value, lenient: bool) -> Result<Vec<(SubcategorySpec, Vec<ErrorSpec>)>, TbError> {
+        match v {
+            Value::Array(a) => Self::array(a, lenient),
+            ref ov => {
+                log::error!(
+                    "SubcategoryList must be an Array: deserialized a {}",
+                    value_type_name(ov)
+                );
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn array(
+        a: Vec<Value>,
+        lenient: bool,
+    ) -> Result<Vec<(SubcategorySpec, Vec<ErrorSpec>)>, TbError> {
+        let mut subcategories = Vec::new();
+        for v in a.into_iter() {
+            match v {
+                Value::Table(t) => subcategories.push(SubcategoryParser::table(t, lenient)?),
+                ov => {
+                    log::error!(
+                        "SubcategoryObject in SubcategoryList must be a Table: deserialized {:?}",
+                        ov
+                    );
+                    return BAD_VALUE_TYPE.into();
+                }
+            }
+        }
+        if subcategories.is_empty() {
+            log::error!("Empty SubcategoryList is not allowed");
+            return EMPTY_LIST.into();
+        }
+        Ok(subcategories)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Debug)]
 struct CategoryListParser;
 
 impl CategoryListParser {
-    fn value(v: Value) -> Result<Vec<CategorySpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<CategorySpec>, TbError> {
         match v {
-            Value::Array(a) => Self::array(a),
+            Value::Array(a) => Self::array(a, lenient),
             ref ov => {
                 log::error!(
                     "CategoryList must be an Array: deserialized a {}",
@@ -553,13 +886,13 @@ impl CategoryListParser {
         }
     }
 
-    fn array(a: Vec<Value>) -> Result<Vec<CategorySpec>, TbError> {
+    fn array(a: Vec<Value>, lenient: bool) -> Result<Vec<CategorySpec>, TbError> {
         let mut categories = Vec::new();
         for v in a.into_iter() {
             match v {
                 Value::Table(t) => {
                     let parser = CategoryParser(ParseMode::List);
-                    let cat_spec = parser.table(t)?;
+                    let cat_spec = parser.table(t, lenient)?;
                     categories.push(cat_spec);
                 }
                 ov => {
@@ -582,6 +915,132 @@ impl CategoryListParser {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct GroupListParser;
+
+impl GroupListParser {
+    fn value(v: Value) -> Result<Vec<GroupSpec>, TbError> {
+        match v {
+            Value::Table(t) => Self::table(t),
+            ref ov => {
+                log::error!(
+                    "GroupList must be a Table: deserialized a {}",
+                    value_type_name(ov)
+                );
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn table(t: Table) -> Result<Vec<GroupSpec>, TbError> {
+        let mut groups = Vec::new();
+        for (name, v) in t.into_iter() {
+            check_group_name(&name)?;
+            let errors = match v {
+                Value::Array(a) => a
+                    .into_iter()
+                    .map(|v| v2string(v, kws::GROUPS))
+                    .collect::<Result<Vec<_>, _>>()?,
+                ref ov => {
+                    log::error!(
+                        "group `{name}` must be an Array: deserialized a {}",
+                        value_type_name(ov)
+                    );
+                    return BAD_VALUE_TYPE.into();
+                }
+            };
+            if errors.is_empty() {
+                log::error!("Empty group is not allowed: {name}");
+                return EMPTY_LIST.into();
+            }
+            groups.push(GroupSpec { name, errors });
+        }
+        if groups.is_empty() {
+            log::error!("Empty GroupList is not allowed");
+            return EMPTY_LIST.into();
+        }
+        Ok(groups)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct BridgeEnumParser;
+
+impl BridgeEnumParser {
+    fn value(v: Value, lenient: bool) -> Result<BridgeEnumSpec, TbError> {
+        match v {
+            Value::Table(t) => Self::table(t, lenient),
+            ref ov => {
+                log::error!(
+                    "BridgeEnumObject must be a Table: deserialized a {}",
+                    value_type_name(ov)
+                );
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn table(mut t: Table, lenient: bool) -> Result<BridgeEnumSpec, TbError> {
+        let Some(path) = t.remove(kws::BRIDGE_ENUM_PATH) else {
+            log::error!(
+                "BridgeEnumObject must have a `{}` attribute",
+                kws::BRIDGE_ENUM_PATH
+            );
+            return MISSING_ATTRIBUTE.into();
+        };
+        let path = v2string(path, kws::BRIDGE_ENUM_PATH)?;
+        check_bridge_enum_path(&path)?;
+
+        let Some(variants) = t.remove(kws::BRIDGE_ENUM_VARIANTS) else {
+            log::error!(
+                "BridgeEnumObject must have a `{}` attribute",
+                kws::BRIDGE_ENUM_VARIANTS
+            );
+            return MISSING_ATTRIBUTE.into();
+        };
+        let variants = Self::variants(variants)?;
+
+        for (k, _) in t {
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid BridgeEnumObject attribute: {k}"),
+            )?;
+        }
+
+        Ok(BridgeEnumSpec { path, variants })
+    }
+
+    fn variants(v: Value) -> Result<Vec<(String, String)>, TbError> {
+        let t = match v {
+            Value::Table(t) => t,
+            ref ov => {
+                log::error!(
+                    "`{}` must be a Table: deserialized a {}",
+                    kws::BRIDGE_ENUM_VARIANTS,
+                    value_type_name(ov)
+                );
+                return BAD_VALUE_TYPE.into();
+            }
+        };
+        let mut variants = Vec::new();
+        for (r, v) in t.into_iter() {
+            let variant = v2string(v, kws::BRIDGE_ENUM_VARIANTS)?;
+            check_bridge_enum_variant_name(&variant)?;
+            variants.push((r, variant));
+        }
+        if variants.is_empty() {
+            log::error!("Empty `{}` is not allowed", kws::BRIDGE_ENUM_VARIANTS);
+            return EMPTY_LIST.into();
+        }
+        Ok(variants)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 fn value_type_name(value: &Value) -> &'static str {
     match value {
         Value::Array(_) => "Array",
@@ -594,8 +1053,8 @@ fn value_type_name(value: &Value) -> &'static str {
     }
 }
 
-fn check_key(k: &str) -> Result<&str, TbError> {
-    if !kws::is_any_kw(k) {
+fn check_key(k: &str, lenient: bool) -> Result<&str, TbError> {
+    if !kws::is_any_kw(k) && !lenient {
         log::error!("invalid Table key: {}", k);
         BAD_OBJECT_ATTRIBUTE.into()
     } else {
@@ -613,6 +1072,46 @@ fn v2string(v: Value, kw: &str) -> Result<String, TbError> {
     }
 }
 
+fn v2string_list(v: Value, kw: &str) -> Result<Vec<String>, TbError> {
+    match v {
+        Value::Array(a) => a.into_iter().map(|v| v2string(v, kw)).collect(),
+        ov => {
+            log::error!("`{}` must be an Array: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
+fn v2usize_list(v: Value, kw: &str) -> Result<Vec<usize>, TbError> {
+    match v {
+        Value::Array(a) => a.into_iter().map(|v| v2usize(v, kw)).collect(),
+        ov => {
+            log::error!("`{}` must be an Array: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
+fn v2usize(v: Value, kw: &str) -> Result<usize, TbError> {
+    match v {
+        Value::Integer(i) => match usize::try_from(i) {
+            Ok(n) => Ok(n),
+            Err(_) => {
+                log::error!(
+                    "`{}` must be a non-negative Integer: deserialized {}",
+                    kw,
+                    i
+                );
+                BAD_VALUE.into()
+            }
+        },
+        ov => {
+            log::error!("`{}` must be an Integer: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
 fn v2bool(v: Value, kw: &str) -> Result<bool, TbError> {
     match v {
         Value::Boolean(b) => Ok(b),
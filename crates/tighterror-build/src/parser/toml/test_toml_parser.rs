@@ -8,7 +8,10 @@ use crate::{
         },
         toml::*,
     },
-    spec::{ErrorSpec, OverridableErrorSpec},
+    spec::{
+        BridgeEnumSpec, DisplayCase, DisplayCodeRadix, ErrorSpec, GroupSpec, OrderBy,
+        OverridableErrorSpec, SerdeRepr,
+    },
 };
 
 const GOOD_BOOLEANS: [(&str, bool); 2] = [("true", true), ("false", false)];
@@ -45,7 +48,7 @@ errors = [\"SINGLE_ERROR\"]
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -71,7 +74,7 @@ display = \"The single error in the test.\"
     let spec = spec_from_err(err);
 
     for s in [s1, s2] {
-        let res = TomlParser::parse_str(s).unwrap();
+        let res = TomlParser::parse_str(s, false).unwrap();
         assert_eq!(res, spec);
     }
 }
@@ -88,7 +91,7 @@ name = \"SINGLE_ERROR\"
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -110,7 +113,7 @@ fn test_err_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -120,7 +123,7 @@ fn test_err_doc_from_display() {
             bad
         );
 
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -139,7 +142,7 @@ fn test_err_display() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -147,7 +150,7 @@ fn test_err_display() {
     for bad in ["1"] {
         let s = format!("[[errors]]\nname = \"TEST_ERROR\"\ndisplay = {}", bad);
         assert_eq!(
-            TomlParser::parse_str(&s).unwrap_err().kind(),
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -168,7 +171,7 @@ fn test_err_doc() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -187,19 +190,99 @@ Second line.
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 
     #[allow(clippy::single_element_loop)]
     for bad in ["1"] {
         let s = format!("[[errors]]\nname = \"TEST_ERROR\"\ndoc = {}", bad);
         assert_eq!(
-            TomlParser::parse_str(&s).unwrap_err().kind(),
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
 }
 
+#[test]
+fn test_err_default() {
+    log_init();
+    for good in GOOD_BOOLEANS {
+        let s = format!("[[errors]]\nname = \"TEST_ERROR\"\ndefault = {}", good.0);
+        let err = ErrorSpec {
+            name: "TEST_ERROR".into(),
+            default: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_err(err);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(res, spec);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!("[[errors]]\nname = \"TEST_ERROR\"\ndefault = {}", bad);
+        let res = TomlParser::parse_str(&s, false);
+        assert_eq!(res.unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_err_index() {
+    log_init();
+    let s = "[[errors]]\nname = \"TEST_ERROR\"\nindex = 0";
+    let err = ErrorSpec {
+        name: "TEST_ERROR".into(),
+        index: Some(0),
+        ..Default::default()
+    };
+    let spec = spec_from_err(err);
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(res, spec);
+
+    for (bad, kind) in [
+        ("-1", BAD_VALUE),
+        ("1.5", BAD_VALUE_TYPE),
+        ("\"1\"", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[[errors]]\nname = \"TEST_ERROR\"\nindex = {bad}");
+        let res = TomlParser::parse_str(&s, false);
+        assert_eq!(res.unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_err_default_uniqueness() {
+    log_init();
+    let s = r#"
+[[categories]]
+name = "Cat1"
+errors = [{ name = "ERR1", default = true }]
+
+[[categories]]
+name = "Cat2"
+errors = [{ name = "ERR2", default = true }]
+"#;
+    let res = TomlParser::parse_str(s, false);
+    assert_eq!(res.unwrap_err().kind(), NON_UNIQUE_DEFAULT);
+
+    let s = r#"
+[[modules]]
+name = "mod1"
+[[modules.categories]]
+name = "Cat1"
+errors = [{ name = "ERR1", default = true }, { name = "ERR2", default = false }]
+
+[[modules]]
+name = "mod2"
+[[modules.categories]]
+name = "Cat1"
+errors = [{ name = "ERR1", default = true }]
+"#;
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(res.modules[0].default_error().unwrap().1.name, "ERR1");
+    assert_eq!(res.modules[1].default_error().unwrap().1.name, "ERR1");
+}
+
 #[test]
 fn test_err_name() {
     log_init();
@@ -217,13 +300,13 @@ fn test_err_name() {
 
     for (bad, kind) in BAD_NAMES {
         let s = format!("[[errors]]\nname = {}", bad);
-        let res = TomlParser::parse_str(&s);
+        let res = TomlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), *kind);
     }
 
     for (bad, kind) in BAD_NAMES {
         let s = format!("errors = [{}]", bad);
-        let res = TomlParser::parse_str(&s);
+        let res = TomlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), *kind);
     }
 }
@@ -258,7 +341,7 @@ errors = [
     let spec = spec_from_err(err);
 
     for s in [s1, s2] {
-        let res = TomlParser::parse_str(s).unwrap();
+        let res = TomlParser::parse_str(s, false).unwrap();
         assert_eq!(res, spec);
     }
 }
@@ -335,7 +418,7 @@ doc_from_display = true
     };
     let spec = spec_from_err_iter([err1, err2, err3, err4, err5]);
     for s in [s1, s2] {
-        let res = TomlParser::parse_str(s).unwrap();
+        let res = TomlParser::parse_str(s, false).unwrap();
         assert_eq!(res, spec);
     }
 }
@@ -347,7 +430,7 @@ fn test_top_kws() {
 my_errors = [\"BAD_ERROR\"]
 ";
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         BAD_ROOT_LEVEL_KEYWORD
     );
 
@@ -357,7 +440,7 @@ doc_from_display = true
 
 ";
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 }
@@ -379,7 +462,7 @@ fn test_module_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -388,7 +471,7 @@ fn test_module_doc_from_display() {
             "[module]\ndoc_from_display = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -401,12 +484,18 @@ fn test_module_name() {
             name: Some(good_name.into()),
             ..Default::default()
         };
-        assert_eq!(spec_from_module(module), TomlParser::parse_str(&s).unwrap());
+        assert_eq!(
+            spec_from_module(module),
+            TomlParser::parse_str(&s, false).unwrap()
+        );
     }
 
     for bad_name in ["\"\"", "\"My_Errors\"", "\"ERRORS\""] {
         let s = format!("[module]\nname = {bad_name}\n[[errors]]\nname = \"DUMMY_ERR\"");
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), BAD_NAME);
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_NAME
+        );
     }
 }
 
@@ -429,7 +518,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -444,7 +533,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -467,7 +556,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -482,7 +571,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -505,7 +594,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -520,7 +609,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -543,7 +632,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -558,7 +647,7 @@ name = \"DUMMY_ERR\"
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -576,7 +665,7 @@ fn test_module_result_from_err() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -585,7 +674,7 @@ fn test_module_result_from_err() {
             "[module]\nresult_from_err = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -603,7 +692,7 @@ fn test_module_result_from_err_kind() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -612,7 +701,7 @@ fn test_module_result_from_err_kind() {
             "[module]\nresult_from_err_kind = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -630,7 +719,7 @@ fn test_error_trait() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -639,7 +728,34 @@ fn test_error_trait() {
             "[module]\nerror_trait = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_core_error() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ncore_error = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            core_error: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\ncore_error = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -657,7 +773,7 @@ fn test_module_flat_kinds() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -666,263 +782,1256 @@ fn test_module_flat_kinds() {
             "[module]\nflat_kinds = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
 #[test]
-fn test_module_flat_kinds_error_name_uniqueness() {
+fn test_module_prelude() {
     log_init();
 
-    let s = r#"
-[module]
-flat_kinds = true
-
-[[categories]]
-name = "Cat1"
-
-[[categories.errors]]
-name = "ERR1"
-display = "first error"
-
-[[categories]]
-name = "Cat2"
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nprelude = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            prelude: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
 
-[[categories.errors]]
-name = "ERR1"
-display = "another first error"
-"#;
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nprelude = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
 
-    assert_eq!(TomlParser::parse_str(s), NON_UNIQUE_NAME.into());
+#[test]
+fn test_module_prelude_error_name_uniqueness() {
+    log_init();
 
     let s = r#"
 [module]
-flat_kinds = true
+prelude = true
 
 [[categories]]
 name = "Cat1"
-
-[[categories.errors]]
-name = "ERR1"
-display = "first error"
+errors = ["ERR1"]
 
 [[categories]]
 name = "Cat2"
-
-[[categories.errors]]
-name = "ERR2"
-display = "second error"
+errors = ["ERR1"]
 "#;
+    assert_eq!(TomlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    let s = s.replace("prelude = true", "prelude = false");
+    assert!(TomlParser::parse_str(&s, false).is_ok());
 }
 
 #[test]
-fn test_module_categories_forbidden_in_single() {
+fn test_module_category_default() {
     log_init();
 
-    let s = r#"
-[module]
-name = "my_errors"
-
-[[module.categories]]
-name = "BadCategory"
-errors = ["BAD_ERROR"]
-
-[[errors]]
-name = "GOOD_ERROR"
-"#;
-
-    assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
-        BAD_OBJECT_ATTRIBUTE
-    );
-
-    let s = r#"
-[module]
-name = "my_errors"
-
-[[errors]]
-name = "GOOD_ERROR"
-"#;
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ncategory_default = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            category_default: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\ncategory_default = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
 }
 
 #[test]
-fn test_no_std() {
+fn test_module_doc_cfg() {
     log_init();
 
     for good in GOOD_BOOLEANS {
         let s = format!(
-            "[main]\nno_std = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            "[module]\ndoc_cfg = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             good.0
         );
-        let main = MainSpec {
-            no_std: Some(good.1),
+        let module = ModuleSpec {
+            doc_cfg: Some(good.1),
             ..Default::default()
         };
-        let spec = spec_from_main(main);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
     for (bad, kind) in BAD_BOOLEANS {
         let s = format!(
-            "[main]\nno_std = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            "[module]\ndoc_cfg = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
 #[test]
-fn test_error_name() {
+fn test_module_catalog() {
     log_init();
-    for good in ["MyError", idents::ERROR] {
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ncatalog = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
         let module = ModuleSpec {
-            err_name: Some(good.into()),
+            catalog: Some(good.1),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&format!(
-            "[module]\nerr_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            good
-        ))
-        .unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
-        let s = format!(
-            "[module]\nerr_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            bad
-        );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
-    }
-
-    for bad in [idents::ERROR_CATEGORY, idents::ERROR_KIND] {
+    for (bad, kind) in BAD_BOOLEANS {
         let s = format!(
-            "[module]\nerr_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            "[module]\ncatalog = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
 #[test]
-fn test_error_kind_name() {
+fn test_module_location() {
     log_init();
-    for good in ["MyErrorKind", idents::ERROR_KIND] {
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nlocation = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
         let module = ModuleSpec {
-            err_kind_name: Some(good.into()),
+            location: Some(good.1),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&format!(
-            "[module]\nerr_kind_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            good
-        ))
-        .unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
-        let s = format!(
-            "[module]\nerr_kind_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            bad
-        );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
-    }
-
-    for bad in [idents::ERROR, idents::ERROR_CATEGORY] {
+    for (bad, kind) in BAD_BOOLEANS {
         let s = format!(
-            "[module]\nerr_kind_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            "[module]\nlocation = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
 #[test]
-fn test_error_cat_name() {
+fn test_module_display_is_message_id() {
     log_init();
-    for good in ["MyErrorCategory", idents::ERROR_CATEGORY] {
+
+    for good in GOOD_BOOLEANS {
+        let resolver = good.1.then_some("crate::i18n::message");
+        let resolver_line = resolver
+            .map(|r| format!("display_resolver = \"{r}\"\n"))
+            .unwrap_or_default();
+        let s = format!(
+            "[module]\ndisplay_is_message_id = {}\n{resolver_line}[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
         let module = ModuleSpec {
-            err_cat_name: Some(good.into()),
+            display_is_message_id: Some(good.1),
+            display_resolver: resolver.map(String::from),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&format!(
-            "[module]\nerr_cat_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            good
-        ))
-        .unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
+    for (bad, kind) in BAD_BOOLEANS {
         let s = format!(
-            "[module]\nerr_cat_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            "[module]\ndisplay_is_message_id = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 
-    for bad in [idents::ERROR, idents::ERROR_KIND] {
-        let s = format!(
-            "[module]\nerr_cat_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
-            bad
-        );
-        assert_eq!(TomlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
-    }
-}
+    let s = "[module]\ndisplay_is_message_id = true\n[[errors]]\nname = \"DUMMY_ERR\"";
+    assert_eq!(TomlParser::parse_str(s, false), MISSING_ATTRIBUTE.into());
 
-#[test]
-fn test_error_list_unique_names() {
-    log_init();
+    let s = "[module]\ndisplay_resolver = \"message\"\n[[errors]]\nname = \"DUMMY_ERR\"";
+    assert_eq!(TomlParser::parse_str(s, false), BAD_OBJECT_ATTRIBUTE.into());
 
-    let s = "errors = [\"FIRST_ERROR\",  \"FIRST_ERROR\", \"SECOND_ERROR\"]";
-    assert_eq!(TomlParser::parse_str(s), NON_UNIQUE_NAME.into());
+    let s = "[module]\ndisplay_is_message_id = true\ndisplay_resolver = \"not a path\"\n[[errors]]\nname = \"DUMMY_ERR\"";
+    assert_eq!(TomlParser::parse_str(s, false), BAD_VALUE.into());
 
-    let s = "errors = [\"FIRST_ERROR\",  \"SECOND_ERROR\"]";
-    assert!(TomlParser::parse_str(s).is_ok());
+    let s = "[module]\ndisplay_is_message_id = true\ndisplay_resolver = \"message\"\nnormalize_display_period = true\n[[errors]]\nname = \"DUMMY_ERR\"\ndisplay = \"dummy-err\"";
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(
+        res.modules[0].categories[0].errors[0].display.as_deref(),
+        Some("dummy-err")
+    );
 }
 
 #[test]
-fn test_category_name() {
+fn test_module_arbitrary() {
     log_init();
 
-    for good in ["TheCategory", IMPLICIT_CATEGORY_NAME] {
-        let category = CategorySpec {
-            name: good.to_owned(),
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\narbitrary = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            arbitrary: Some(good.1),
             ..Default::default()
         };
-
-        let spec = spec_from_category(category);
-        let res = TomlParser::parse_str(&format!(
-            "[category]\nname = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\""
-        ))
-        .unwrap();
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
-        assert_eq!(
-            TomlParser::parse_str(&format!(
-                "[category]\nname = {bad}\n[[errors]]\nname = \"DUMMY_ERR\""
-            ))
-            .unwrap_err()
-            .kind(),
-            kind
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\narbitrary = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
         );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
+}
 
-    for bad in [kws::MAIN, kws::ERRORS] {
-        assert_eq!(
-            TomlParser::parse_str(&format!(
-                "[category]\nname = \"{bad}\"\n[[errors]]\nname = \"DUMMY_ERR\""
-            ))
+#[test]
+fn test_module_proptest() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nproptest = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            proptest: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nproptest = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_kind_to_cat() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nkind_to_cat = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            kind_to_cat: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nkind_to_cat = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_kind_iter() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nkind_iter = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            kind_iter: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nkind_iter = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_debug_stable() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ndebug_stable = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            debug_stable: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\ndebug_stable = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_assoc_consts() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nassoc_consts = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            assoc_consts: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nassoc_consts = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    let s = "[module]\nassoc_consts = true\n[[categories]]\nname = \"Parser\"\nerrors = [\"BAD_TOKEN\"]\n[[categories]]\nname = \"ParserBad\"\nerrors = [\"TOKEN\"]\n";
+    assert_eq!(TomlParser::parse_str(s, false), NAME_COLLISION.into());
+    let s = s.replace(
+        "assoc_consts = true\n",
+        "assoc_consts = true\nflat_kinds = true\n",
+    );
+    assert!(TomlParser::parse_str(&s, false).is_ok());
+
+    let s = "[module]\nassoc_consts = true\nflat_kinds = true\nkind_iter = true\n[[errors]]\nname = \"VARIANTS\"\n";
+    assert_eq!(TomlParser::parse_str(s, false), NAME_COLLISION.into());
+    let s = s.replace("kind_iter = true\n", "");
+    assert!(TomlParser::parse_str(&s, false).is_ok());
+}
+
+#[test]
+fn test_module_category_sets() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ncategory_sets = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            category_sets: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\ncategory_sets = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    let s = "[module]\ncategory_sets = true\nerr_name = \"ErrorCategorySet\"\n[[errors]]\nname = \"DUMMY_ERR\"";
+    assert_eq!(TomlParser::parse_str(s, false), NAME_COLLISION.into());
+}
+
+#[test]
+fn test_module_err_cat_kinds_name_collision() {
+    log_init();
+
+    for name in ["err_name", "err_kind_name"] {
+        let s =
+            format!("[module]\n{name} = \"ErrorCategoryKinds\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false), NAME_COLLISION.into());
+    }
+}
+
+#[test]
+fn test_category_all_name_collision() {
+    log_init();
+
+    let s = "errors = [\"ALL\"]";
+    assert_eq!(TomlParser::parse_str(s, false), NAME_COLLISION.into());
+
+    let s = "errors = [\"ALL\"]\n[module]\nflat_kinds = true";
+    assert!(TomlParser::parse_str(s, false).is_ok());
+}
+
+#[test]
+fn test_module_allow_unsafe() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nallow_unsafe = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            allow_unsafe: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nallow_unsafe = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_enforce_display_period() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nenforce_display_period = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            enforce_display_period: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nenforce_display_period = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_normalize_display_period() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nnormalize_display_period = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            normalize_display_period: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nnormalize_display_period = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_test() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ntest = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            test: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!("[module]\ntest = {}\n[[errors]]\nname = \"DUMMY_ERR\"", bad);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_order_by() {
+    log_init();
+
+    for (good, order_by) in [("name", OrderBy::Name), ("value", OrderBy::Value)] {
+        let s = format!("[module]\norder_by = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let module = ModuleSpec {
+            order_by: Some(order_by),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"Name\"", BAD_VALUE),
+        ("\"display\"", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("1", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[module]\norder_by = {bad}\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_display_case() {
+    log_init();
+
+    for (good, display_case) in [
+        ("sentence", DisplayCase::Sentence),
+        ("lower", DisplayCase::Lower),
+        ("none", DisplayCase::None),
+    ] {
+        let s = format!("[module]\ndisplay_case = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let module = ModuleSpec {
+            display_case: Some(display_case),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"Sentence\"", BAD_VALUE),
+        ("\"upper\"", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("1", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[module]\ndisplay_case = {bad}\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_display_with_code() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\ndisplay_with_code = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            display_with_code: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\ndisplay_with_code = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_display_code_radix() {
+    log_init();
+
+    for (good, radix) in [
+        ("hex", DisplayCodeRadix::Hex),
+        ("decimal", DisplayCodeRadix::Decimal),
+    ] {
+        let s =
+            format!("[module]\ndisplay_code_radix = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let module = ModuleSpec {
+            display_code_radix: Some(radix),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"Hex\"", BAD_VALUE),
+        ("\"octal\"", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("16", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[module]\ndisplay_code_radix = {bad}\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_serde() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[module]\nserde = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let module = ModuleSpec {
+            serde: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[module]\nserde = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_serde_repr() {
+    log_init();
+
+    for (good, serde_repr) in [("name", SerdeRepr::Name), ("value", SerdeRepr::Value)] {
+        let s = format!(
+            "[module]\nserde = true\nserde_repr = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\""
+        );
+        let module = ModuleSpec {
+            serde: Some(true),
+            serde_repr: Some(serde_repr),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"Name\"", BAD_VALUE),
+        ("\"display\"", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("1", BAD_VALUE_TYPE),
+    ] {
+        let s =
+            format!("[module]\nserde = true\nserde_repr = {bad}\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for serde in ["", "serde = false\n"] {
+        let s =
+            format!("[module]\n{serde}serde_repr = \"value\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_OBJECT_ATTRIBUTE
+        );
+    }
+}
+
+#[test]
+fn test_module_flat_kinds_error_name_uniqueness() {
+    log_init();
+
+    let s = r#"
+[module]
+flat_kinds = true
+
+[[categories]]
+name = "Cat1"
+
+[[categories.errors]]
+name = "ERR1"
+display = "first error"
+
+[[categories]]
+name = "Cat2"
+
+[[categories.errors]]
+name = "ERR1"
+display = "another first error"
+"#;
+
+    assert_eq!(TomlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
+
+    let s = r#"
+[module]
+flat_kinds = true
+
+[[categories]]
+name = "Cat1"
+
+[[categories.errors]]
+name = "ERR1"
+display = "first error"
+
+[[categories]]
+name = "Cat2"
+
+[[categories.errors]]
+name = "ERR2"
+display = "second error"
+"#;
+
+    assert!(TomlParser::parse_str(s, false).is_ok());
+}
+
+#[test]
+fn test_module_categories_in_single() {
+    log_init();
+
+    let nested = r#"
+[module]
+name = "my_errors"
+
+[[module.categories]]
+name = "Parser"
+errors = ["BAD_TOKEN"]
+
+[[module.categories]]
+name = "Processor"
+errors = ["QUEUE_FULL"]
+"#;
+
+    let root_level = r#"
+[module]
+name = "my_errors"
+
+[[categories]]
+name = "Parser"
+errors = ["BAD_TOKEN"]
+
+[[categories]]
+name = "Processor"
+errors = ["QUEUE_FULL"]
+"#;
+
+    let spec = TomlParser::parse_str(nested, false).unwrap();
+    assert_eq!(spec, TomlParser::parse_str(root_level, false).unwrap());
+    assert_eq!(spec.modules.len(), 1);
+    assert_eq!(spec.modules[0].name(), "my_errors");
+    assert_eq!(spec.modules[0].categories.len(), 2);
+
+    for kw in ["errors", "categories"] {
+        let s = format!(
+            r#"
+[[module.categories]]
+name = "Parser"
+errors = ["BAD_TOKEN"]
+
+[[{kw}]]
+name = "GoodError"
+"#
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            MUTUALLY_EXCLUSIVE_KEYWORDS
+        );
+    }
+
+    let s = r#"
+[[module.categories]]
+name = "Parser"
+errors = ["BAD_TOKEN"]
+
+[category]
+name = "General"
+"#;
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        MUTUALLY_EXCLUSIVE_KEYWORDS
+    );
+
+    let s = r#"
+[module]
+name = "my_errors"
+"#;
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        MISSING_ATTRIBUTE
+    );
+}
+
+#[test]
+fn test_no_std() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[main]\nno_std = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let main = MainSpec {
+            no_std: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_main(main);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!(
+            "[main]\nno_std = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_main_test() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[main]\ntest = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let main = MainSpec {
+            test: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_main(main);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!("[main]\ntest = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"", bad);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_main_require_display() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[main]\nrequire_display = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let main = MainSpec {
+            require_display: Some(good.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!("[main]\nrequire_display = {bad}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_main_split_tables() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "[main]\nsplit_tables = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good.0
+        );
+        let main = MainSpec {
+            split_tables: Some(good.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in BAD_BOOLEANS {
+        let s = format!("[main]\nsplit_tables = {bad}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_main_tests() {
+    log_init();
+
+    for (good, tests) in [
+        (
+            "[\"from_value\", \"uniqueness\"]",
+            vec!["from_value", "uniqueness"],
+        ),
+        ("[\"err_kind_name\"]", vec!["err_kind_name"]),
+        ("[\"bail\"]", vec!["bail"]),
+    ] {
+        let s = format!("[main]\ntests = {good}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let main = MainSpec {
+            tests: Some(tests.into_iter().map(String::from).collect()),
+            ..Default::default()
+        };
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in [
+        ("[]", BAD_VALUE),
+        ("[\"unknown\"]", BAD_VALUE),
+        ("[\"ind_name\"]", BAD_VALUE),
+        ("[\"from_value\", \"unknown\"]", BAD_VALUE),
+        ("[\"bail\", \"bail\"]", NON_UNIQUE_NAME),
+        ("\"from_value\"", BAD_VALUE_TYPE),
+        ("[true]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[main]\ntests = {bad}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_output_pattern() {
+    log_init();
+
+    for good in ["gen_{module}.rs", "{module}/mod.rs", "{module}"] {
+        let s = format!("[main]\noutput_pattern = \"{good}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let main = MainSpec {
+            output_pattern: Some(good.into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in [
+        ("\"gen_errors.rs\"", BAD_VALUE),
+        ("\"{mod}.rs\"", BAD_VALUE),
+        ("\"/tmp/{module}.rs\"", BAD_VALUE),
+        ("[\"{module}.rs\"]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[main]\noutput_pattern = {bad}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_error_name() {
+    log_init();
+    for good in ["MyError", idents::ERROR] {
+        let module = ModuleSpec {
+            err_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(
+            &format!(
+                "[module]\nerr_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "[module]\nerr_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR_CATEGORY, idents::ERROR_KIND] {
+        let s = format!(
+            "[module]\nerr_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+}
+
+#[test]
+fn test_submodule_names() {
+    log_init();
+
+    for (kw, good) in [
+        ("kinds_mod_name", "kinds"),
+        ("kinds_mod_name", "kind"),
+        ("categories_mod_name", "error_categories"),
+        ("categories_mod_name", "category"),
+    ] {
+        let res = TomlParser::parse_str(
+            &format!("[module]\n{kw} = \"{good}\"\n\n[[errors]]\nname = \"DUMMY_ERR\""),
+            false,
+        )
+        .unwrap();
+        let module = &res.modules[0];
+        assert_eq!(module.kinds_mod_name.is_some(), kw == "kinds_mod_name");
+        assert_eq!(
+            module.categories_mod_name.is_some(),
+            kw == "categories_mod_name"
+        );
+        if kw == "kinds_mod_name" {
+            assert_eq!(module.kinds_mod_name(), good);
+        } else {
+            assert_eq!(module.categories_mod_name(), good);
+        }
+    }
+
+    for kw in ["kinds_mod_name", "categories_mod_name"] {
+        for (bad, kind) in [
+            ("\"Kinds\"", BAD_IDENTIFIER_CASE),
+            ("\"my kinds\"", BAD_IDENTIFIER_CHARACTERS),
+            ("\"\"", EMPTY_IDENTIFIER),
+            ("1", BAD_VALUE_TYPE),
+            ("\"variant\"", BAD_MODULE_IDENTIFIER),
+            ("\"test\"", BAD_MODULE_IDENTIFIER),
+            ("\"type\"", BAD_MODULE_IDENTIFIER),
+            ("\"self\"", BAD_MODULE_IDENTIFIER),
+        ] {
+            let s = format!("[module]\n{kw} = {bad}\n\n[[errors]]\nname = \"DUMMY_ERR\"");
+            assert_eq!(
+                TomlParser::parse_str(&s, false).unwrap_err().kind(),
+                kind,
+                "{kw}: {bad}"
+            );
+        }
+    }
+
+    for s in [
+        "[module]\nkinds_mod_name = \"category\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+        "[module]\ncategories_mod_name = \"kind\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+    ] {
+        assert_eq!(
+            TomlParser::parse_str(s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+
+    let s = "[module]\nkinds_mod_name = \"errs\"\ncategories_mod_name = \"errs\"\n\n[[errors]]\nname = \"DUMMY_ERR\"";
+    assert_eq!(TomlParser::parse_str(s, false), NAME_COLLISION.into());
+}
+
+#[test]
+fn test_error_kind_name() {
+    log_init();
+    for good in ["MyErrorKind", idents::ERROR_KIND] {
+        let module = ModuleSpec {
+            err_kind_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(
+            &format!(
+                "[module]\nerr_kind_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "[module]\nerr_kind_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR, idents::ERROR_CATEGORY] {
+        let s = format!(
+            "[module]\nerr_kind_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+}
+
+#[test]
+fn test_error_cat_name() {
+    log_init();
+    for good in ["MyErrorCategory", idents::ERROR_CATEGORY] {
+        let module = ModuleSpec {
+            err_cat_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(
+            &format!(
+                "[module]\nerr_cat_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "[module]\nerr_cat_name = {}\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR, idents::ERROR_KIND] {
+        let s = format!(
+            "[module]\nerr_cat_name = \"{}\"\n\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+}
+
+#[test]
+fn test_error_list_unique_names() {
+    log_init();
+
+    let s = "errors = [\"FIRST_ERROR\",  \"FIRST_ERROR\", \"SECOND_ERROR\"]";
+    assert_eq!(TomlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
+
+    let s = "errors = [\"FIRST_ERROR\",  \"SECOND_ERROR\"]";
+    assert!(TomlParser::parse_str(s, false).is_ok());
+}
+
+#[test]
+fn test_category_name() {
+    log_init();
+
+    for good in ["TheCategory", IMPLICIT_CATEGORY_NAME] {
+        let category = CategorySpec {
+            name: good.to_owned(),
+            ..Default::default()
+        };
+
+        let spec = spec_from_category(category);
+        let res = TomlParser::parse_str(
+            &format!("[category]\nname = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\""),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        assert_eq!(
+            TomlParser::parse_str(
+                &format!("[category]\nname = {bad}\n[[errors]]\nname = \"DUMMY_ERR\""),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            kind
+        );
+    }
+
+    for bad in [kws::MAIN, kws::ERRORS] {
+        assert_eq!(
+            TomlParser::parse_str(
+                &format!("[category]\nname = \"{bad}\"\n[[errors]]\nname = \"DUMMY_ERR\""),
+                false
+            )
             .unwrap_err()
             .kind(),
             BAD_IDENTIFIER_CASE
@@ -930,6 +2039,40 @@ fn test_category_name() {
     }
 }
 
+#[test]
+fn test_category_name_reserved_module() {
+    log_init();
+
+    for bad in [
+        "Kind",
+        "Kinds",
+        "Category",
+        "Categories",
+        "Variant",
+        "Types",
+        "Test",
+        "C",
+    ] {
+        assert_eq!(
+            TomlParser::parse_str(
+                &format!("[[categories]]\nname = \"{bad}\"\nerrors = [\"DUMMY_ERR\"]"),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            RESERVED_NAME
+        );
+    }
+
+    for good in ["KindOf", "Categorical", "Variants2", "Testing", "Cc"] {
+        assert!(TomlParser::parse_str(
+            &format!("[[categories]]\nname = \"{good}\"\nerrors = [\"DUMMY_ERR\"]"),
+            false
+        )
+        .is_ok());
+    }
+}
+
 #[test]
 fn test_category_doc() {
     let s = r#"
@@ -948,7 +2091,7 @@ name = "DUMMY_ERR"
         doc: Some("Category long doc string.\n\nAppears on multiple lines.\n".into()),
         ..Default::default()
     });
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = r#"
@@ -963,8 +2106,108 @@ name = "DUMMY_ERR"
         doc: Some("".into()),
         ..Default::default()
     });
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+}
+
+#[test]
+fn test_doc_file() {
+    log_init();
+    let s = r#"
+[module]
+doc_file = "docs/module.md"
+
+[category]
+doc_file = "docs/category.md"
+
+[[errors]]
+name = "DUMMY_ERR"
+doc_file = "/abs/error.md"
+"#;
+    let err = ErrorSpec {
+        name: "DUMMY_ERR".into(),
+        doc_file: Some("/abs/error.md".into()),
+        ..Default::default()
+    };
+    let cat = CategorySpec {
+        name: IMPLICIT_CATEGORY_NAME.into(),
+        doc_file: Some("docs/category.md".into()),
+        errors: vec![err],
+        ..Default::default()
+    };
+    let module = ModuleSpec {
+        doc_file: Some("docs/module.md".into()),
+        categories: vec![cat],
+        ..Default::default()
+    };
+    let spec = Spec {
+        modules: vec![module],
+        ..Default::default()
+    };
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+
+    assert_eq!(
+        TomlParser::parse_str(
+            "[module]\ndoc_file = 1\n[[errors]]\nname = \"DUMMY_ERR\"",
+            false
+        )
+        .unwrap_err()
+        .kind(),
+        BAD_VALUE_TYPE
+    );
+}
+
+#[test]
+fn test_doc_and_doc_file_mutual_exclusion() {
+    log_init();
+    let specs = [
+        "[module]\ndoc = \"Doc.\"\ndoc_file = \"doc.md\"\n[[errors]]\nname = \"DUMMY_ERR\"",
+        "[category]\ndoc = \"Doc.\"\ndoc_file = \"doc.md\"\n[[errors]]\nname = \"DUMMY_ERR\"",
+        "[[errors]]\nname = \"DUMMY_ERR\"\ndoc = \"Doc.\"\ndoc_file = \"doc.md\"",
+    ];
+    for s in specs {
+        assert_eq!(
+            TomlParser::parse_str(s, false).unwrap_err().kind(),
+            MUTUALLY_EXCLUSIVE_KEYWORDS
+        );
+    }
+}
+
+#[test]
+fn test_category_index() {
+    log_init();
+    let s = r#"
+[category]
+index = 0
+
+[[errors]]
+name = "DUMMY_ERR"
+"#;
+    let spec = spec_from_category(CategorySpec {
+        name: IMPLICIT_CATEGORY_NAME.into(),
+        index: Some(0),
+        ..Default::default()
+    });
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
+
+    for (bad, kind) in [
+        ("-1", BAD_VALUE),
+        ("1.5", BAD_VALUE_TYPE),
+        ("\"1\"", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        assert_eq!(
+            TomlParser::parse_str(
+                &format!("[category]\nindex = {bad}\n[[errors]]\nname = \"DUMMY_ERR\""),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            kind
+        );
+    }
 }
 
 #[test]
@@ -985,7 +2228,7 @@ fn test_category_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_category(cat);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -994,7 +2237,7 @@ fn test_category_doc_from_display() {
             "[category]\ndoc_from_display = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), kind);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), kind);
     }
 }
 
@@ -1013,7 +2256,7 @@ errors = ["DUMMY_ERR"]
 "#;
 
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         BAD_OBJECT_ATTRIBUTE
     );
 }
@@ -1029,7 +2272,7 @@ doc = "Custom category."
 doc_from_display = false
 "#;
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 }
@@ -1049,7 +2292,7 @@ errors = ["DUMMY_ERR"]
 name = "DUMMY_ERR2"
 "#;
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 }
@@ -1062,7 +2305,10 @@ fn test_categories_list_cannot_be_empty() {
 categories = []
 "#;
 
-    assert_eq!(TomlParser::parse_str(s).unwrap_err().kind(), EMPTY_LIST);
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
 }
 
 #[test]
@@ -1076,7 +2322,7 @@ doc = "Custom category."
 doc_from_display = false
 "#;
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 
@@ -1087,7 +2333,24 @@ doc = "Custom category."
 doc_from_display = false
 errors = []
 "#;
-    assert_eq!(TomlParser::parse_str(s).unwrap_err().kind(), EMPTY_LIST);
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
+
+    let s = r#"
+[[categories]]
+name = "Cat1"
+errors = ["DUMMY_ERR"]
+
+[[categories]]
+name = "Cat2"
+errors = []
+"#;
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
 }
 
 #[test]
@@ -1101,7 +2364,7 @@ doc_from_display = true
 errors = ["DUMMY_ERR"]
 "#;
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 }
@@ -1126,6 +2389,9 @@ errors = ["DUMMY_ERR2"]
     let cat1 = CategorySpec {
         name: "Cat1".into(),
         doc: Some("First category.".into()),
+        doc_file: None,
+        index: None,
+        subcategories: Vec::new(),
         oes: OverridableErrorSpec {
             doc_from_display: Some(false),
             ..Default::default()
@@ -1157,7 +2423,7 @@ errors = ["DUMMY_ERR2"]
         ..Default::default()
     };
 
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -1182,14 +2448,214 @@ name = "Cat2"
 errors = ["DUMMY_ERR2"]
 
 [[categories]]
-name = "Cat2"
-errors = ["DUMMY_ERR3"]
+name = "Cat2"
+errors = ["DUMMY_ERR3"]
+
+"#;
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        NON_UNIQUE_NAME
+    );
+}
+
+#[test]
+fn test_category_subcategories() {
+    log_init();
+
+    let s = r#"
+[[categories]]
+name = "Parser"
+errors = ["BAD_INPUT"]
+
+[[categories.subcategories]]
+name = "Lexer"
+doc = "Lexer errors."
+errors = ["BAD_TOKEN", "BAD_CHAR"]
+
+[[categories.subcategories]]
+name = "Grammar"
+errors = ["UNEXPECTED_TOKEN"]
+
+[[categories]]
+name = "Io"
+
+[[categories.subcategories]]
+name = "Net"
+errors = ["TIMEOUT"]
+"#;
+
+    let err = |name: &str, subcategory: Option<&str>| ErrorSpec {
+        name: name.into(),
+        subcategory: subcategory.map(Into::into),
+        ..Default::default()
+    };
+
+    let parser = CategorySpec {
+        name: "Parser".into(),
+        subcategories: vec![
+            SubcategorySpec {
+                name: "Lexer".into(),
+                doc: Some("Lexer errors.".into()),
+            },
+            SubcategorySpec {
+                name: "Grammar".into(),
+                doc: None,
+            },
+        ],
+        errors: vec![
+            err("BAD_INPUT", None),
+            err("BAD_TOKEN", Some("Lexer")),
+            err("BAD_CHAR", Some("Lexer")),
+            err("UNEXPECTED_TOKEN", Some("Grammar")),
+        ],
+        ..Default::default()
+    };
+
+    let io = CategorySpec {
+        name: "Io".into(),
+        subcategories: vec![SubcategorySpec {
+            name: "Net".into(),
+            doc: None,
+        }],
+        errors: vec![err("TIMEOUT", Some("Net"))],
+        ..Default::default()
+    };
+
+    let spec = Spec {
+        modules: vec![ModuleSpec {
+            categories: vec![parser, io],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let res = TomlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+}
+
+#[test]
+fn test_category_subcategories_bad() {
+    log_init();
+
+    let bad = [
+        (
+            r#"
+errors = ["BAD_INPUT"]
+
+[category]
+name = "Parser"
+
+[[category.subcategories]]
+name = "Lexer"
+errors = ["BAD_TOKEN"]
+"#,
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+subcategories = []
+"#,
+            EMPTY_LIST,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+subcategories = "Lexer"
+"#,
+            BAD_VALUE_TYPE,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+errors = ["BAD_TOKEN"]
+"#,
+            MISSING_ATTRIBUTE,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+name = "Lexer"
+"#,
+            MISSING_ATTRIBUTE,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+name = "lexer"
+errors = ["BAD_TOKEN"]
+"#,
+            BAD_IDENTIFIER_CASE,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+name = "Tests"
+errors = ["BAD_TOKEN"]
+"#,
+            RESERVED_NAME,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+name = "Lexer"
+errors = ["BAD_TOKEN"]
+
+[[categories.subcategories]]
+name = "Lexer"
+errors = ["BAD_CHAR"]
+"#,
+            NON_UNIQUE_NAME,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+errors = ["BAD_TOKEN"]
+
+[[categories.subcategories]]
+name = "Lexer"
+errors = ["BAD_TOKEN"]
+"#,
+            NON_UNIQUE_NAME,
+        ),
+        (
+            r#"
+[[categories]]
+name = "Parser"
+
+[[categories.subcategories]]
+name = "Lexer"
+errors = ["BAD_TOKEN"]
+
+[[categories.subcategories.subcategories]]
+name = "Inner"
+errors = ["BAD_CHAR"]
+"#,
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+    ];
 
-"#;
-    assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
-        NON_UNIQUE_NAME
-    );
+    for (s, kind) in bad {
+        assert_eq!(TomlParser::parse_str(s, false).unwrap_err().kind(), kind);
+    }
 }
 
 #[test]
@@ -1275,7 +2741,7 @@ errors = ["M2C2E1", "M2C2E2"]
         ],
         ..Default::default()
     };
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -1296,7 +2762,7 @@ name = "MY_OTHER_ERROR"
 "#;
 
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1305,7 +2771,7 @@ name = "MY_OTHER_ERROR"
 name = "MY_OTHER_ERROR"
 "#;
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    assert!(TomlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1325,7 +2791,7 @@ name = "OtherCategory"
 "#;
 
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1338,7 +2804,7 @@ name = "General"
 errors = ["MY_ERROR"]
 "#;
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    assert!(TomlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1359,7 +2825,7 @@ errors = ["OTHER_ERROR"]
 "#;
 
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1369,7 +2835,7 @@ name = "OtherCategory"
 errors = ["OTHER_ERROR"]
 "#;
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    assert!(TomlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1389,7 +2855,7 @@ name = "my_module"
 "#;
 
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1402,7 +2868,7 @@ name = "General"
 errors = ["MY_ERROR"]
 "#;
 
-    assert!(TomlParser::parse_str(s).is_ok());
+    assert!(TomlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1428,7 +2894,7 @@ doc = "Second category."
 errors = ["ANOTHER_ERR"]
 "#;
     assert_eq!(
-        TomlParser::parse_str(s).unwrap_err().kind(),
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
         NON_UNIQUE_NAME
     );
 
@@ -1450,7 +2916,7 @@ name = "Cat2"
 doc = "Second category."
 errors = ["ANOTHER_ERR"]
 "#;
-    assert!(TomlParser::parse_str(s).is_ok());
+    assert!(TomlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1473,7 +2939,7 @@ variant_type = "MyErr"
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = TomlParser::parse_str(s).unwrap();
+    let res = TomlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 
     for val in [true, false] {
@@ -1487,7 +2953,7 @@ variant_type = "MyErr"
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 }
@@ -1504,6 +2970,7 @@ fn test_error_variant_type_bad_name() {
         ("CustomErr", NAME_COLLISION),
         ("CustomErrCat", NAME_COLLISION),
         ("CustomErrKind", NAME_COLLISION),
+        ("CustomErrCatKinds", NAME_COLLISION),
         ("ExistingErr", NON_UNIQUE_NAME),
     ];
 
@@ -1523,7 +2990,7 @@ variant_type = "ExistingErr"
             "{pfx}\n[[errors]]\nname = \"MY_ERR\"\nvariant_type = \"{}\"",
             tc.0
         );
-        assert_eq!(TomlParser::parse_str(&s).unwrap_err().kind(), tc.1);
+        assert_eq!(TomlParser::parse_str(&s, false).unwrap_err().kind(), tc.1);
     }
 }
 
@@ -1542,7 +3009,7 @@ fn test_category_variant_type() {
             ..Default::default()
         };
         let spec = spec_from_category(cat);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -1551,7 +3018,7 @@ fn test_category_variant_type() {
             "[category]\nvariant_type = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad.0
         );
-        let res = TomlParser::parse_str(&s);
+        let res = TomlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), bad.1);
     }
 }
@@ -1570,7 +3037,7 @@ fn test_module_variant_type() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = TomlParser::parse_str(&s).unwrap();
+        let res = TomlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -1579,7 +3046,7 @@ fn test_module_variant_type() {
             "[module]\nvariant_type = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
             bad.0
         );
-        let res = TomlParser::parse_str(&s);
+        let res = TomlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), bad.1);
     }
 }
@@ -1614,6 +3081,646 @@ variant_type = "ErrOne"
 name = "CAT_TWO_ERR_TWO"
 "#;
 
-    let res = TomlParser::parse_str(s);
+    let res = TomlParser::parse_str(s, false);
     assert_eq!(res.unwrap_err().kind(), NON_UNIQUE_NAME);
+
+    let unique = s.replacen(r#""ErrOne""#, r#""ErrThree""#, 1);
+    assert!(TomlParser::parse_str(&unique, false).is_ok());
+
+    let not_flat = s.replace("flat_kinds = true\n", "");
+    assert!(TomlParser::parse_str(&not_flat, false).is_ok());
+
+    // the implicit `ErrOne` of an error without a variant type doesn't collide
+    let implicit = s.replace("variant_type = true\n", "").replace(
+        "name = \"CAT_ONE_ERR_ONE\"\nvariant_type = \"ErrOne\"\n",
+        "name = \"ERR_ONE\"\n",
+    );
+    assert!(TomlParser::parse_str(&implicit, false).is_ok());
+}
+
+#[test]
+fn test_module_groups() {
+    log_init();
+
+    let spec_str = |groups: &str| {
+        format!(
+            r#"
+[module.groups]
+{groups}
+
+[[categories]]
+name = "Cat1"
+errors = ["ERR1", "ERR2"]
+
+[[categories]]
+name = "Cat2"
+errors = ["ERR1", "ERR3"]
+"#
+        )
+    };
+
+    let s = spec_str("retryable = [\"ERR2\", \"Cat2::ERR1\", \"ERR3\"]");
+    let res = TomlParser::parse_str(&s, false).unwrap();
+    let groups = &res.modules[0].groups;
+    assert_eq!(
+        groups,
+        &[GroupSpec {
+            name: "retryable".into(),
+            errors: vec!["ERR2".into(), "Cat2::ERR1".into(), "ERR3".into()],
+        }]
+    );
+    let members = res.modules[0].group_members(&groups[0]);
+    assert_eq!(
+        members
+            .iter()
+            .map(|(c, e)| (c.name.as_str(), e.name.as_str()))
+            .collect::<Vec<_>>(),
+        [("Cat1", "ERR2"), ("Cat2", "ERR1"), ("Cat2", "ERR3")]
+    );
+
+    for (bad, kind) in [
+        ("retryable = [\"ERR4\"]", BAD_REFERENCE),
+        ("retryable = [\"Cat3::ERR1\"]", BAD_REFERENCE),
+        ("retryable = [\"Cat1::ERR3\"]", BAD_REFERENCE),
+        ("retryable = [\"ERR1\"]", BAD_REFERENCE),
+        ("retryable = [\"ERR2\", \"Cat1::ERR2\"]", NON_UNIQUE_NAME),
+        ("retryable = []", EMPTY_LIST),
+        ("retryable = \"ERR2\"", BAD_VALUE_TYPE),
+        ("retryable = [1]", BAD_VALUE_TYPE),
+        ("Retryable = [\"ERR2\"]", BAD_IDENTIFIER_CASE),
+        ("retry-able = [\"ERR2\"]", BAD_IDENTIFIER_CHARACTERS),
+        ("in = [\"ERR2\"]", NAME_COLLISION),
+        ("index = [\"ERR2\"]", BAD_NAME),
+        ("error = [\"ERR2\"]", NAME_COLLISION),
+        ("", EMPTY_LIST),
+    ] {
+        let s = spec_str(bad);
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_bridge_enum() {
+    log_init();
+
+    let spec_str = |bridge_enum: &str| {
+        format!(
+            r#"
+[module.bridge_enum]
+{bridge_enum}
+
+[[categories]]
+name = "Cat1"
+errors = ["ERR1", "ERR2"]
+
+[[categories]]
+name = "Cat2"
+errors = ["ERR1"]
+"#
+        )
+    };
+    let variants = r#"variants = { "Cat1::ERR1" = "One", "Cat2::ERR1" = "One", ERR2 = "Two" }"#;
+
+    let s = spec_str(&format!("path = \"crate::legacy::Error\"\n{variants}"));
+    let res = TomlParser::parse_str(&s, false).unwrap();
+    let bridge_enum = res.modules[0].bridge_enum.as_ref().unwrap();
+    assert_eq!(
+        bridge_enum,
+        &BridgeEnumSpec {
+            path: "crate::legacy::Error".into(),
+            variants: vec![
+                ("Cat1::ERR1".into(), "One".into()),
+                ("Cat2::ERR1".into(), "One".into()),
+                ("ERR2".into(), "Two".into()),
+            ],
+        }
+    );
+    assert_eq!(
+        res.modules[0]
+            .bridge_enum_variants(bridge_enum)
+            .iter()
+            .map(|(c, e, v)| (c.name.as_str(), e.name.as_str(), *v))
+            .collect::<Vec<_>>(),
+        [
+            ("Cat1", "ERR1", "One"),
+            ("Cat2", "ERR1", "One"),
+            ("Cat1", "ERR2", "Two")
+        ]
+    );
+
+    let with_path = |v: String| format!("path = \"Error\"\n{v}");
+    for (bad, kind) in [
+        (variants.to_owned(), MISSING_ATTRIBUTE),
+        ("path = \"Error\"".into(), MISSING_ATTRIBUTE),
+        (format!("path = \"not a path\"\n{variants}"), BAD_VALUE),
+        (format!("path = [\"Error\"]\n{variants}"), BAD_VALUE_TYPE),
+        (
+            format!("{}\nname = \"Bad\"", with_path(variants.into())),
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (with_path("variants = {}".into()), EMPTY_LIST),
+        (with_path("variants = [\"ERR2\"]".into()), BAD_VALUE_TYPE),
+        (
+            with_path("variants = { ERR2 = \"Two\" }".into()),
+            MISSING_ATTRIBUTE,
+        ),
+        (
+            with_path(variants.replace("ERR2 =", "ERR3 =")),
+            BAD_REFERENCE,
+        ),
+        (
+            with_path(variants.replace("\"Cat1::ERR1\"", "ERR1")),
+            BAD_REFERENCE,
+        ),
+        (
+            with_path(variants.replace("ERR2 =", "\"Cat1::ERR2\" = \"Dup\", ERR2 =")),
+            NON_UNIQUE_NAME,
+        ),
+        (
+            with_path(variants.replace("\"Two\"", "\"two\"")),
+            BAD_IDENTIFIER_CASE,
+        ),
+    ] {
+        let s = spec_str(&bad);
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+
+    let s = spec_str("").replace(
+        "[module.bridge_enum]\n",
+        "[module]\nbridge_enum = \"Error\"",
+    );
+    assert_eq!(
+        TomlParser::parse_str(&s, false).unwrap_err().kind(),
+        BAD_VALUE_TYPE
+    );
+}
+
+#[test]
+fn test_module_display_period() {
+    log_init();
+
+    let spec_str = |attrs: &str| {
+        format!(
+            r#"
+[module]
+{attrs}
+
+[[errors]]
+name = "PERIOD"
+display = "Display with a period."
+
+[[errors]]
+name = "QUESTION"
+display = "Is it a display?"
+
+[[errors]]
+name = "EXCLAMATION"
+display = "A display!"
+
+[[errors]]
+name = "NO_DISPLAY"
+
+[[errors]]
+name = "NO_PERIOD"
+display = "Display without a period"
+"#
+        )
+    };
+    let displays = |s: &str| {
+        let spec = TomlParser::parse_str(s, false).unwrap();
+        spec.modules[0]
+            .errors_iter()
+            .map(|e| e.display.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let s = spec_str("enforce_display_period = true");
+    assert_eq!(
+        TomlParser::parse_str(&s, false).unwrap_err().kind(),
+        BAD_DISPLAY_STRING
+    );
+
+    let s = spec_str("enforce_display_period = false");
+    assert_eq!(displays(&s)[4].as_deref(), Some("Display without a period"));
+
+    let expected = [
+        Some("Display with a period."),
+        Some("Is it a display?"),
+        Some("A display!"),
+        None,
+        Some("Display without a period."),
+    ];
+    for attrs in [
+        "normalize_display_period = true",
+        "normalize_display_period = true\nenforce_display_period = true",
+    ] {
+        let s = spec_str(attrs);
+        assert_eq!(displays(&s), expected.map(|d| d.map(String::from)));
+    }
+
+    let s = spec_str("enforce_display_period = true").replace(" without a period", ".");
+    assert!(TomlParser::parse_str(&s, false).is_ok());
+}
+
+#[test]
+fn test_module_display_case_keeps_displays() {
+    log_init();
+
+    let spec_str = |attrs: &str| {
+        format!(
+            r#"
+[module]
+{attrs}
+
+[[errors]]
+name = "LOWER"
+display = "a lowercase display"
+
+[[errors]]
+name = "UPPER"
+display = "An uppercase display"
+
+[[errors]]
+name = "ACRONYM"
+display = "I/O failed"
+
+[[errors]]
+name = "NO_DISPLAY"
+"#
+        )
+    };
+    let displays = |s: &str| {
+        let spec = TomlParser::parse_str(s, false).unwrap();
+        spec.modules[0]
+            .errors_iter()
+            .map(|e| e.display.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let expected = [
+        Some("a lowercase display"),
+        Some("An uppercase display"),
+        Some("I/O failed"),
+        None,
+    ];
+    for attrs in [
+        "display_case = \"sentence\"",
+        "display_case = \"lower\"",
+        "display_case = \"none\"",
+    ] {
+        let s = spec_str(attrs);
+        assert_eq!(displays(&s), expected.map(|d| d.map(String::from)));
+    }
+}
+
+#[test]
+fn test_display_category() {
+    log_init();
+
+    let s = r#"
+[[categories]]
+name = "Parser"
+errors = [
+    { name = "BAD_TOKEN", display = "{category} failed." },
+    { name = "QUEUE_FULL", display = "{{category}} is {{literal}}, {category}}} and {{{category}." },
+    { name = "TIMEOUT", display = "{unknown} {category" },
+]
+
+[[categories]]
+name = "Processor"
+errors = [
+    { name = "BAD_TOKEN", display = "{category}: {category}." },
+]
+"#;
+    let spec = TomlParser::parse_str(s, false).unwrap();
+    let displays = spec.modules[0]
+        .errors_iter()
+        .map(|e| e.display.clone().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        displays,
+        [
+            "Parser failed.",
+            "{category} is {literal}, Parser} and {Parser.",
+            "{unknown} {category",
+            "Processor: Processor.",
+        ]
+    );
+
+    let s = "[module]\nenforce_display_period = true\n[[errors]]\nname = \"BAD_ARG\"\ndisplay = \"{category}\"";
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        BAD_DISPLAY_STRING
+    );
+}
+
+#[test]
+fn test_module_allows() {
+    log_init();
+
+    for (good, allows) in ["[]", "[\"dead_code\"]", "[\"clippy::all\", \"dead_code\"]"]
+        .into_iter()
+        .zip([vec![], vec!["dead_code"], vec!["clippy::all", "dead_code"]])
+    {
+        let s = format!(
+            "[module]\nallows = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good
+        );
+        let module = ModuleSpec {
+            allows: allows.into_iter().map(String::from).collect(),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("[\"Clippy::All\"]", BAD_VALUE),
+        ("[\"clippy::\"]", BAD_VALUE),
+        ("[\"clippy::a::b\"]", BAD_VALUE),
+        ("[\"dead code\"]", BAD_VALUE),
+        ("[\"dead_code\", \"dead_code\"]", NON_UNIQUE_NAME),
+        ("\"dead_code\"", BAD_VALUE_TYPE),
+        ("[1]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!(
+            "[module]\nallows = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_map_by_name() {
+    log_init();
+    let spec_str = |map: &str, errors: &str| {
+        format!(
+            r#"
+[[modules]]
+name = "errors"
+categories = [
+    {{ name = "Parser", errors = ["BAD_TOKEN", "QUEUE_FULL"] }},
+    {{ name = "General", errors = ["QUEUE_FULL", "TIMEOUT"] }},
+]
+
+[[modules]]
+name = "internal_errors"
+map_by_name = {map}
+categories = [
+    {{ name = "Parser", errors = ["QUEUE_FULL"] }},
+    {{ name = "Io", errors = [{errors}] }},
+]
+"#
+        )
+    };
+
+    let spec = TomlParser::parse_str(&spec_str(r#"["errors"]"#, r#""TIMEOUT""#), false).unwrap();
+    assert_eq!(spec.modules[1].map_by_name, ["errors"]);
+
+    for (map, errors, kind) in [
+        (r#"["errors"]"#, r#""QUEUE_FULL""#, BAD_REFERENCE),
+        (r#"["errors"]"#, r#""BAD_FILE""#, BAD_REFERENCE),
+        (r#"["unknown_errors"]"#, r#""TIMEOUT""#, BAD_REFERENCE),
+        (r#"["internal_errors"]"#, r#""TIMEOUT""#, BAD_REFERENCE),
+        (r#"["errors", "errors"]"#, r#""TIMEOUT""#, NON_UNIQUE_NAME),
+        (r#""errors""#, r#""TIMEOUT""#, BAD_VALUE_TYPE),
+    ] {
+        let s = spec_str(map, errors);
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{map} {errors}"
+        );
+    }
+}
+
+#[test]
+fn test_module_extends() {
+    log_init();
+    let spec_str = |base: &str, mid: &str| {
+        format!(
+            r#"
+[[modules]]
+name = "leaf_errors"
+extends = "mid_errors"
+categories = [{{ name = "General", errors = ["TIMEOUT"] }}]
+
+[[modules]]
+name = "mid_errors"
+extends = {mid}
+error_trait = true
+categories = [{{ name = "General", errors = ["QUEUE_FULL"] }}]
+
+[[modules]]
+name = "base_errors"{base}
+doc = "Base errors."
+flat_kinds = true
+error_trait = false
+allows = ["dead_code"]
+categories = [{{ name = "General", errors = ["BAD_TOKEN"] }}]
+"#
+        )
+    };
+
+    let spec = TomlParser::parse_str(&spec_str("", r#""base_errors""#), false).unwrap();
+    let leaf = &spec.modules[0];
+    assert_eq!(leaf.extends.as_deref(), Some("mid_errors"));
+    assert_eq!(leaf.flat_kinds, Some(true));
+    assert_eq!(leaf.error_trait, Some(true));
+    assert_eq!(leaf.allows, ["dead_code"]);
+    assert_eq!(leaf.doc, None);
+    assert_eq!(leaf.categories[0].errors[0].name, "TIMEOUT");
+    assert_eq!(spec.modules[1].error_trait, Some(true));
+    assert_eq!(spec.modules[2].error_trait, Some(false));
+
+    for (base, mid, kind) in [
+        ("", r#""unknown_errors""#, BAD_REFERENCE),
+        ("", r#""mid_errors""#, EXTENDS_CYCLE),
+        (
+            "\nextends = \"leaf_errors\"",
+            r#""base_errors""#,
+            EXTENDS_CYCLE,
+        ),
+        ("", r#""BadErrors""#, BAD_NAME),
+        ("", r#"["base_errors"]"#, BAD_VALUE_TYPE),
+    ] {
+        let s = spec_str(base, mid);
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{base:?} {mid}"
+        );
+    }
+
+    let s = "errors = [\"DUMMY_ERR\"]\n\n[module]\nextends = \"errors\"";
+    assert_eq!(
+        TomlParser::parse_str(s, false).unwrap_err().kind(),
+        BAD_OBJECT_ATTRIBUTE
+    );
+}
+
+#[test]
+fn test_module_value_widths() {
+    log_init();
+
+    for (good, widths) in ["[]", "[8]", "[64, 16]"]
+        .into_iter()
+        .zip([vec![], vec![8], vec![64, 16]])
+    {
+        let s = format!(
+            "[module]\nvalue_widths = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good
+        );
+        let module = ModuleSpec {
+            value_widths: widths,
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("[0]", BAD_VALUE),
+        ("[12]", BAD_VALUE),
+        ("[128]", BAD_VALUE),
+        ("[-8]", BAD_VALUE),
+        ("[8, 8]", BAD_VALUE),
+        ("8", BAD_VALUE_TYPE),
+        ("[\"u8\"]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!(
+            "[module]\nvalue_widths = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_feature() {
+    log_init();
+
+    for good in ["std", "serde_json", "tokio-1", "v1.2", "a+b", "2d"] {
+        let s = format!(
+            "[module]\nfeature = \"{}\"\n[[errors]]\nname = \"DUMMY_ERR\"",
+            good
+        );
+        let module = ModuleSpec {
+            feature: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"\"", BAD_VALUE),
+        ("\"-std\"", BAD_VALUE),
+        ("\"my feature\"", BAD_VALUE),
+        ("\"dep:serde\"", BAD_VALUE),
+        ("[\"std\"]", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!(
+            "[module]\nfeature = {}\n[[errors]]\nname = \"DUMMY_ERR\"",
+            bad
+        );
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_lenient() {
+    log_init();
+
+    let good = TomlParser::parse_str("errors = [\"DUMMY_ERR\"]", false).unwrap();
+    for (s, kind) in [
+        (
+            "foo = \"bar\"\nerrors = [\"DUMMY_ERR\"]",
+            BAD_ROOT_LEVEL_KEYWORD,
+        ),
+        (
+            "errors = [\"DUMMY_ERR\"]\n[main]\nfoo = \"bar\"",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "errors = [\"DUMMY_ERR\"]\n[module]\nfoo = \"bar\"",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "errors = [\"DUMMY_ERR\"]\n[category]\nfoo = \"bar\"",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "errors = [{ name = \"DUMMY_ERR\", foo = \"bar\" }]",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+    ] {
+        assert_eq!(
+            TomlParser::parse_str(s, false).unwrap_err().kind(),
+            kind,
+            "{s}"
+        );
+        assert_eq!(TomlParser::parse_str(s, true).unwrap(), good);
+    }
+}
+
+#[test]
+fn test_module_runtime_path() {
+    log_init();
+
+    for good in [
+        "tighterror",
+        "::tighterror",
+        "crate::rt",
+        "my_errors::tighterror",
+    ] {
+        let s = format!("[module]\nruntime_path = \"{good}\"\n[[errors]]\nname = \"DUMMY_ERR\"");
+        let module = ModuleSpec {
+            runtime_path: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = TomlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"\"", BAD_VALUE),
+        ("\"not a path\"", BAD_VALUE),
+        ("\"tighterror::\"", BAD_VALUE),
+        ("[\"tighterror\"]", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("[module]\nruntime_path = {bad}\n[[errors]]\nname = \"DUMMY_ERR\"");
+        assert_eq!(
+            TomlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
 }
@@ -0,0 +1,232 @@
+use crate::{
+    errors::{kind::parser::*, TbError, TbErrorKind},
+    spec::{ModuleSpec, Spec},
+};
+use std::{collections::HashMap, fmt::Arguments};
+
+/// Checks the specification for suspicious, but valid, definitions.
+///
+/// Every finding is logged as a warning. In strict mode the first finding is
+/// logged as an error and returned instead.
+pub fn check_warnings(spec: &Spec, strict: bool) -> Result<(), TbError> {
+    for m in &spec.modules {
+        check_display_uniqueness(m, strict)?;
+        check_mirror_categories(m, strict)?;
+    }
+    Ok(())
+}
+
+pub(crate) fn warn(strict: bool, kind: TbErrorKind, args: Arguments<'_>) -> Result<(), TbError> {
+    if strict {
+        log::error!("{args}");
+        kind.into()
+    } else {
+        log::warn!("{args}");
+        Ok(())
+    }
+}
+
+/// Checks that documentation and display strings don't contain tab characters.
+///
+/// Tabs in YAML block scalars may silently truncate the text. Unlike
+/// [check_warnings], a finding is always an error.
+pub fn check_docs(spec: &Spec) -> Result<(), TbError> {
+    for m in &spec.modules {
+        let mn = m.name();
+        check_doc_tabs(&m.doc, format_args!("module '{mn}' doc"))?;
+        check_doc_tabs(&m.err_doc, format_args!("module '{mn}' err_doc"))?;
+        check_doc_tabs(&m.err_kind_doc, format_args!("module '{mn}' err_kind_doc"))?;
+        check_doc_tabs(&m.err_cat_doc, format_args!("module '{mn}' err_cat_doc"))?;
+        for c in &m.categories {
+            let cn = &c.name;
+            check_doc_tabs(&c.doc, format_args!("category '{mn}::{cn}' doc"))?;
+            for e in &c.errors {
+                let en = &e.name;
+                check_doc_tabs(&e.doc, format_args!("error '{mn}::{cn}::{en}' doc"))?;
+                check_doc_tabs(&e.display, format_args!("error '{mn}::{cn}::{en}' display"))?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that every error has an explicit display string.
+///
+/// An error without a display string is displayed as its name, which is
+/// rarely appropriate in a public API. A finding is always an error.
+pub fn check_displays(spec: &Spec) -> Result<(), TbError> {
+    for m in &spec.modules {
+        for c in &m.categories {
+            if let Some(e) = c.errors.iter().find(|e| e.display.is_none()) {
+                log::error!(
+                    "error '{}::{}::{}' lacks an explicit display string, \
+                     which is required by `require_display`",
+                    m.name(),
+                    c.name,
+                    e.name
+                );
+                return MISSING_DISPLAY.into();
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_doc_tabs(doc: &Option<String>, what: Arguments<'_>) -> Result<(), TbError> {
+    match doc {
+        Some(d) if d.contains('\t') => {
+            log::error!("{what} contains a tab character: {d:?}");
+            TAB_IN_DOC.into()
+        }
+        _ => Ok(()),
+    }
+}
+
+fn check_display_uniqueness(m: &ModuleSpec, strict: bool) -> Result<(), TbError> {
+    let mut displays = HashMap::<&str, (&str, &str)>::new();
+    for c in &m.categories {
+        for e in &c.errors {
+            let Some(display) = e.display.as_deref() else {
+                continue;
+            };
+            if let Some((other_cat, other_err)) = displays.get(display) {
+                warn(
+                    strict,
+                    DUPLICATE_DISPLAY,
+                    format_args!(
+                        "errors '{}::{}' and '{}::{}' in module '{}' share a display string: {display:?}",
+                        other_cat,
+                        other_err,
+                        c.name,
+                        e.name,
+                        m.name()
+                    ),
+                )?;
+            } else {
+                displays.insert(display, (&c.name, &e.name));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check_mirror_categories(m: &ModuleSpec, strict: bool) -> Result<(), TbError> {
+    let mut names = HashMap::<Vec<&str>, &str>::new();
+    for c in &m.categories {
+        let mut key: Vec<&str> = c.errors.iter().map(|e| e.name.as_str()).collect();
+        key.sort_unstable();
+        if let Some(other_cat) = names.get(&key) {
+            warn(
+                strict,
+                MIRROR_CATEGORIES,
+                format_args!(
+                    "categories '{}' and '{}' in module '{}' define the same error names, \
+                    consider merging them",
+                    other_cat,
+                    c.name,
+                    m.name()
+                ),
+            )?;
+        } else {
+            names.insert(key, &c.name);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod testing {
+    use super::*;
+    use crate::{
+        parser::testing::{log_init, spec_from_err_iter},
+        spec::{CategorySpec, ErrorSpec},
+    };
+
+    fn err(name: &str, display: Option<&str>) -> ErrorSpec {
+        ErrorSpec {
+            name: name.into(),
+            display: display.map(String::from),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_duplicate_display() {
+        log_init();
+        let spec = spec_from_err_iter([
+            err("BAD_FILE", Some("Bad file.")),
+            err("BAD_PATH", Some("Bad file.")),
+        ]);
+        assert!(check_warnings(&spec, false).is_ok());
+        assert_eq!(
+            check_warnings(&spec, true).unwrap_err().kind(),
+            DUPLICATE_DISPLAY
+        );
+    }
+
+    fn cat(name: &str, errors: &[&str]) -> CategorySpec {
+        CategorySpec {
+            name: name.into(),
+            errors: errors.iter().map(|e| err(e, None)).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mirror_categories() {
+        log_init();
+        let mut spec = spec_from_err_iter([err("BAD_FILE", None)]);
+        spec.modules[0].categories = vec![
+            cat("Reader", &["BAD_FILE", "TIMEOUT"]),
+            cat("Writer", &["TIMEOUT", "BAD_FILE"]),
+        ];
+        assert!(check_warnings(&spec, false).is_ok());
+        assert_eq!(
+            check_warnings(&spec, true).unwrap_err().kind(),
+            MIRROR_CATEGORIES
+        );
+
+        spec.modules[0].categories = vec![
+            cat("Reader", &["BAD_FILE", "TIMEOUT"]),
+            cat("Writer", &["BAD_FILE"]),
+            cat("Parser", &["BAD_FILE", "TIMEOUT", "BAD_TOKEN"]),
+        ];
+        assert!(check_warnings(&spec, true).is_ok());
+    }
+
+    #[test]
+    fn test_tab_in_doc() {
+        log_init();
+        let spec = spec_from_err_iter([ErrorSpec {
+            name: "BAD_FILE".into(),
+            doc: Some("Bad file.\n\tIndented with a tab.".into()),
+            ..Default::default()
+        }]);
+        assert!(check_warnings(&spec, true).is_ok());
+        assert_eq!(check_docs(&spec).unwrap_err().kind(), TAB_IN_DOC);
+
+        let spec = spec_from_err_iter([err("BAD_FILE", Some("Bad\tfile."))]);
+        assert_eq!(check_docs(&spec).unwrap_err().kind(), TAB_IN_DOC);
+
+        let spec = spec_from_err_iter([ErrorSpec {
+            name: "BAD_FILE".into(),
+            doc: Some("Bad file.\n    Indented with spaces.".into()),
+            display: Some("Bad file.".into()),
+            ..Default::default()
+        }]);
+        assert!(check_docs(&spec).is_ok());
+    }
+
+    #[test]
+    fn test_unique_display() {
+        log_init();
+        let spec = spec_from_err_iter([
+            err("BAD_FILE", Some("Bad file.")),
+            err("BAD_PATH", Some("Bad path.")),
+            err("TIMEOUT", None),
+            err("QUEUE_FULL", None),
+        ]);
+        assert!(check_warnings(&spec, false).is_ok());
+        assert!(check_warnings(&spec, true).is_ok());
+    }
+}
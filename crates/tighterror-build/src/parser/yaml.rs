@@ -1,14 +1,13 @@
 use crate::{
     errors::{kind::parser::*, TbError},
-    parser::{helpers::*, kws, ParseMode},
+    parser::{helpers::*, kws, warn, ParseMode},
     spec::{
-        definitions::DEFAULT_FLAT_KINDS, CategorySpec, ErrorSpec, MainSpec, ModuleSpec, Spec,
-        IMPLICIT_CATEGORY_NAME,
+        BridgeEnumSpec, CategorySpec, ErrorSpec, GroupSpec, MainSpec, ModuleSpec, Spec,
+        SubcategorySpec, IMPLICIT_CATEGORY_NAME,
     },
 };
 use log::error;
 use serde_yaml::{Mapping, Sequence, Value};
-use std::fs::File;
 
 // ----------------------------------------------------------------------------
 
@@ -16,20 +15,9 @@ use std::fs::File;
 pub struct YamlParser;
 
 impl YamlParser {
-    pub fn parse_file(file: File) -> Result<Spec, TbError> {
-        match serde_yaml::from_reader(file) {
-            Ok(v) => Self::value(v),
-            Err(e) => {
-                log::error!("failed to deserialize YAML: {e}");
-                BAD_YAML.into()
-            }
-        }
-    }
-
-    #[cfg(test)]
-    pub fn parse_str(s: &str) -> Result<Spec, TbError> {
+    pub fn parse_str(s: &str, lenient: bool) -> Result<Spec, TbError> {
         match serde_yaml::from_str(s) {
-            Ok(v) => Self::value(v),
+            Ok(v) => Self::value(v, lenient),
             Err(e) => {
                 log::error!("failed to deserialize YAML: {e}");
                 BAD_YAML.into()
@@ -37,9 +25,9 @@ impl YamlParser {
         }
     }
 
-    fn value(value: Value) -> Result<Spec, TbError> {
+    fn value(value: Value, lenient: bool) -> Result<Spec, TbError> {
         match value {
-            Value::Mapping(m) => Self::mapping(m),
+            Value::Mapping(m) => Self::mapping(m, lenient),
             v => {
                 error!(
                     "specification YAML document must be a Mapping: deserialized a {}",
@@ -50,27 +38,27 @@ impl YamlParser {
         }
     }
 
-    fn mapping(mut m: Mapping) -> Result<Spec, TbError> {
-        Self::check_toplevel_attributes(&m)?;
+    fn mapping(mut m: Mapping, lenient: bool) -> Result<Spec, TbError> {
+        Self::check_toplevel_attributes(&m, lenient)?;
 
         let mut spec = Spec::default();
 
         if let Some(v) = m.remove(kws::MAIN) {
-            spec.main = MainParser::value(v)?;
+            spec.main = MainParser::value(v, lenient)?;
         }
 
         if let Some(v) = m.remove(kws::MODULES) {
-            spec.modules = ModuleListParser::value(v)?;
+            spec.modules = ModuleListParser::value(v, lenient)?;
         }
 
         if let Some(v) = m.remove(kws::MODULE) {
             let mp = ModuleParser(ParseMode::Single);
-            spec.modules.push(mp.value(v)?);
+            spec.modules.push(mp.value(v, lenient)?);
         }
 
         if let Some(v) = m.remove(kws::CATEGORY) {
             let parser = CategoryParser(ParseMode::Single);
-            let cat_spec = parser.value(v)?;
+            let cat_spec = parser.value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 m.categories.push(cat_spec);
             } else {
@@ -80,7 +68,7 @@ impl YamlParser {
         }
 
         if let Some(v) = m.remove(kws::CATEGORIES) {
-            let categories = CategoryListParser::value(v)?;
+            let categories = CategoryListParser::value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 m.categories = categories;
             } else {
@@ -90,7 +78,7 @@ impl YamlParser {
         }
 
         if let Some(v) = m.remove(kws::ERRORS) {
-            let errors = ErrorListParser::value(v)?;
+            let errors = ErrorListParser::value(v, lenient)?;
             if let Some(m) = spec.modules.first_mut() {
                 if let Some(c) = m.categories.first_mut() {
                     c.errors = errors;
@@ -105,30 +93,37 @@ impl YamlParser {
             }
         }
 
+        for m in &mut spec.modules {
+            expand_display_category(m);
+            apply_display_period(m)?;
+        }
+
         for m in &spec.modules {
-            if m.flat_kinds.unwrap_or(DEFAULT_FLAT_KINDS) {
-                check_module_error_name_uniqueness(m.errors_iter().map(|e| e.name.as_str()))?;
-                let variant_type_names = m
-                    .errors_iter()
-                    .map(|e| e.variant_type_name())
-                    .collect::<Vec<String>>();
-                check_module_variant_type_name_uniqueness(
-                    variant_type_names.iter().map(|s| s.as_str()),
-                )?;
-            }
-            check_name_collisions(m)?;
+            check_flat_kinds_name_uniqueness(m)?;
+            check_prelude_name_uniqueness(m)?;
+            check_name_collisions(m, &spec.modules)?;
+            check_default_error_uniqueness(m)?;
+            check_groups(m)?;
+            check_bridge_enum(m)?;
+            check_display_resolver(m)?;
+            check_serde_repr(m)?;
         }
 
+        check_map_by_name(&spec)?;
+
         Ok(spec)
     }
 
-    fn check_toplevel_attributes(m: &Mapping) -> Result<(), TbError> {
+    fn check_toplevel_attributes(m: &Mapping, lenient: bool) -> Result<(), TbError> {
         for k in m.keys() {
             match k {
                 Value::String(s) => {
                     if !kws::is_root_kw(s) {
-                        error!("invalid root-level keyword: {}", s);
-                        return BAD_ROOT_LEVEL_KEYWORD.into();
+                        warn(
+                            !lenient,
+                            BAD_ROOT_LEVEL_KEYWORD,
+                            format_args!("invalid root-level keyword: {s}"),
+                        )?;
                     }
                 }
                 ov => {
@@ -145,11 +140,31 @@ impl YamlParser {
             }
         }
 
-        if !m.keys().any(|k| {
-            kws::REQUIRED_ROOT_KWS
-                .iter()
-                .any(|req| k.as_str().map(|key| key == *req).unwrap_or(false))
-        }) {
+        let module_categories = m
+            .get(kws::MODULE)
+            .and_then(|v| v.as_mapping())
+            .is_some_and(|mm| mm.contains_key(kws::CATEGORIES));
+
+        if module_categories {
+            for k in kws::MODULE_CATEGORIES_EXCLUSIVE_ROOT_KWS {
+                if m.contains_key(k) {
+                    error!(
+                        "`{}` defined in root-level `{}` attribute and root-level attribute '{k}' are mutually exclusive",
+                        kws::CATEGORIES,
+                        kws::MODULE
+                    );
+                    return MUTUALLY_EXCLUSIVE_KEYWORDS.into();
+                }
+            }
+        }
+
+        if !module_categories
+            && !m.keys().any(|k| {
+                kws::REQUIRED_ROOT_KWS
+                    .iter()
+                    .any(|req| k.as_str().map(|key| key == *req).unwrap_or(false))
+            })
+        {
             error!(
                 "one of {:?} root-level attributes must be specified",
                 kws::REQUIRED_ROOT_KWS
@@ -167,9 +182,9 @@ impl YamlParser {
 struct MainParser;
 
 impl MainParser {
-    fn value(v: Value) -> Result<MainSpec, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<MainSpec, TbError> {
         match v {
-            Value::Mapping(m) => Self::mapping(m),
+            Value::Mapping(m) => Self::mapping(m, lenient),
             ref ov => {
                 error!(
                     "MainObject must be a Mapping: deserialized a {}",
@@ -180,21 +195,48 @@ impl MainParser {
         }
     }
 
-    fn mapping(mut m: Mapping) -> Result<MainSpec, TbError> {
+    fn mapping(mut m: Mapping, lenient: bool) -> Result<MainSpec, TbError> {
         let mut main_spec = MainSpec::default();
 
         if let Some(v) = m.remove(kws::OUTPUT) {
             main_spec.output = Some(v2string(v, kws::OUTPUT)?.into());
         }
 
+        if let Some(v) = m.remove(kws::OUTPUT_PATTERN) {
+            let pattern = v2string(v, kws::OUTPUT_PATTERN)?;
+            check_output_pattern(&pattern)?;
+            main_spec.output_pattern = Some(pattern);
+        }
+
         if let Some(v) = m.remove(kws::NO_STD) {
             main_spec.no_std = Some(v2bool(v, kws::NO_STD)?);
         }
 
-        if let Some((k, _)) = m.into_iter().next() {
-            let key = v2key(k)?;
-            error!("invalid MainObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        if let Some(v) = m.remove(kws::TEST) {
+            main_spec.test = Some(v2bool(v, kws::TEST)?);
+        }
+
+        if let Some(v) = m.remove(kws::TESTS) {
+            let tests = v2string_list(v, kws::TESTS)?;
+            check_tests(&tests)?;
+            main_spec.tests = Some(tests);
+        }
+
+        if let Some(v) = m.remove(kws::REQUIRE_DISPLAY) {
+            main_spec.require_display = Some(v2bool(v, kws::REQUIRE_DISPLAY)?);
+        }
+
+        if let Some(v) = m.remove(kws::SPLIT_TABLES) {
+            main_spec.split_tables = Some(v2bool(v, kws::SPLIT_TABLES)?);
+        }
+
+        for (k, _) in m {
+            let key = v2key(k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid MainObject attribute: {key}"),
+            )?;
         }
 
         Ok(main_spec)
@@ -207,9 +249,9 @@ impl MainParser {
 struct ModuleParser(ParseMode);
 
 impl ModuleParser {
-    fn value(self, v: Value) -> Result<ModuleSpec, TbError> {
+    fn value(self, v: Value, lenient: bool) -> Result<ModuleSpec, TbError> {
         match v {
-            Value::Mapping(m) => self.mapping(m),
+            Value::Mapping(m) => self.mapping(m, lenient),
             ref ov => {
                 error!(
                     "ModuleObject must be a Mapping: deserialized a {}",
@@ -220,22 +262,21 @@ impl ModuleParser {
         }
     }
 
-    fn mapping(self, mut m: Mapping) -> Result<ModuleSpec, TbError> {
+    fn mapping(self, mut m: Mapping, lenient: bool) -> Result<ModuleSpec, TbError> {
         let mut mod_spec = ModuleSpec::default();
 
         if let Some(v) = m.remove(kws::NAME) {
             mod_spec.name = Some(v2string(v, kws::NAME)?);
         }
 
+        if let Some(v) = m.remove(kws::EXTENDS) {
+            let base = v2string(v, kws::EXTENDS)?;
+            check_module_name(&base)?;
+            mod_spec.extends = Some(base);
+        }
+
         if let Some(v) = m.remove(kws::CATEGORIES) {
-            if let ParseMode::Single = self.0 {
-                error!(
-                    "CategoryList is not allowed in root-level `{}` attribute",
-                    kws::MODULE
-                );
-                return BAD_OBJECT_ATTRIBUTE.into();
-            }
-            mod_spec.categories = CategoryListParser::value(v)?;
+            mod_spec.categories = CategoryListParser::value(v, lenient)?;
         }
 
         if let Some(v) = m.remove(kws::DOC_FROM_DISPLAY) {
@@ -258,6 +299,11 @@ impl ModuleParser {
             mod_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = m.remove(kws::DOC_FILE) {
+            check_doc_file(mod_spec.doc.as_deref(), "ModuleObject")?;
+            mod_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = m.remove(kws::RESULT_FROM_ERR) {
             mod_spec.result_from_err = Some(v2bool(v, kws::RESULT_FROM_ERR)?);
         }
@@ -270,6 +316,10 @@ impl ModuleParser {
             mod_spec.error_trait = Some(v2bool(v, kws::ERROR_TRAIT)?);
         }
 
+        if let Some(v) = m.remove(kws::CORE_ERROR) {
+            mod_spec.core_error = Some(v2bool(v, kws::CORE_ERROR)?);
+        }
+
         if let Some(v) = m.remove(kws::ERR_NAME) {
             let err_name = v2string(v, kws::ERR_NAME)?;
             check_module_ident(&err_name, kws::ERR_NAME)?;
@@ -288,18 +338,170 @@ impl ModuleParser {
             mod_spec.err_cat_name = Some(err_cat_name);
         }
 
+        if let Some(v) = m.remove(kws::KINDS_MOD_NAME) {
+            let kinds_mod_name = v2string(v, kws::KINDS_MOD_NAME)?;
+            check_submodule_ident(&kinds_mod_name, kws::KINDS_MOD_NAME)?;
+            mod_spec.kinds_mod_name = Some(kinds_mod_name);
+        }
+
+        if let Some(v) = m.remove(kws::CATEGORIES_MOD_NAME) {
+            let categories_mod_name = v2string(v, kws::CATEGORIES_MOD_NAME)?;
+            check_submodule_ident(&categories_mod_name, kws::CATEGORIES_MOD_NAME)?;
+            mod_spec.categories_mod_name = Some(categories_mod_name);
+        }
+
         if let Some(v) = m.remove(kws::FLAT_KINDS) {
             mod_spec.flat_kinds = Some(v2bool(v, kws::FLAT_KINDS)?);
         }
 
+        if let Some(v) = m.remove(kws::PRELUDE) {
+            mod_spec.prelude = Some(v2bool(v, kws::PRELUDE)?);
+        }
+
         if let Some(v) = m.remove(kws::VARIANT_TYPE) {
             mod_spec.oes.variant_type = Some(v2bool(v, kws::VARIANT_TYPE)?);
         }
 
-        if let Some((k, _)) = m.into_iter().next() {
-            let key = v2key(k)?;
-            error!("invalid ModuleObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        if let Some(v) = m.remove(kws::LOCATION) {
+            mod_spec.location = Some(v2bool(v, kws::LOCATION)?);
+        }
+
+        if let Some(v) = m.remove(kws::ARBITRARY) {
+            mod_spec.arbitrary = Some(v2bool(v, kws::ARBITRARY)?);
+        }
+
+        if let Some(v) = m.remove(kws::PROPTEST) {
+            mod_spec.proptest = Some(v2bool(v, kws::PROPTEST)?);
+        }
+
+        if let Some(v) = m.remove(kws::ORDER_BY) {
+            mod_spec.order_by = Some(parse_order_by(&v2string(v, kws::ORDER_BY)?)?);
+        }
+
+        if let Some(v) = m.remove(kws::SERDE) {
+            mod_spec.serde = Some(v2bool(v, kws::SERDE)?);
+        }
+
+        if let Some(v) = m.remove(kws::SERDE_REPR) {
+            mod_spec.serde_repr = Some(parse_serde_repr(&v2string(v, kws::SERDE_REPR)?)?);
+        }
+
+        if let Some(v) = m.remove(kws::KIND_TO_CAT) {
+            mod_spec.kind_to_cat = Some(v2bool(v, kws::KIND_TO_CAT)?);
+        }
+
+        if let Some(v) = m.remove(kws::KIND_ITER) {
+            mod_spec.kind_iter = Some(v2bool(v, kws::KIND_ITER)?);
+        }
+
+        if let Some(v) = m.remove(kws::DEBUG_STABLE) {
+            mod_spec.debug_stable = Some(v2bool(v, kws::DEBUG_STABLE)?);
+        }
+
+        if let Some(v) = m.remove(kws::CATEGORY_SETS) {
+            mod_spec.category_sets = Some(v2bool(v, kws::CATEGORY_SETS)?);
+        }
+
+        if let Some(v) = m.remove(kws::CATEGORY_DEFAULT) {
+            mod_spec.category_default = Some(v2bool(v, kws::CATEGORY_DEFAULT)?);
+        }
+
+        if let Some(v) = m.remove(kws::DOC_CFG) {
+            mod_spec.doc_cfg = Some(v2bool(v, kws::DOC_CFG)?);
+        }
+
+        if let Some(v) = m.remove(kws::CATALOG) {
+            mod_spec.catalog = Some(v2bool(v, kws::CATALOG)?);
+        }
+
+        if let Some(v) = m.remove(kws::ASSOC_CONSTS) {
+            mod_spec.assoc_consts = Some(v2bool(v, kws::ASSOC_CONSTS)?);
+        }
+
+        if let Some(v) = m.remove(kws::ALLOW_UNSAFE) {
+            mod_spec.allow_unsafe = Some(v2bool(v, kws::ALLOW_UNSAFE)?);
+        }
+
+        if let Some(v) = m.remove(kws::ENFORCE_DISPLAY_PERIOD) {
+            mod_spec.enforce_display_period = Some(v2bool(v, kws::ENFORCE_DISPLAY_PERIOD)?);
+        }
+
+        if let Some(v) = m.remove(kws::NORMALIZE_DISPLAY_PERIOD) {
+            mod_spec.normalize_display_period = Some(v2bool(v, kws::NORMALIZE_DISPLAY_PERIOD)?);
+        }
+
+        if let Some(v) = m.remove(kws::DISPLAY_CASE) {
+            mod_spec.display_case = Some(parse_display_case(&v2string(v, kws::DISPLAY_CASE)?)?);
+        }
+
+        if let Some(v) = m.remove(kws::DISPLAY_WITH_CODE) {
+            mod_spec.display_with_code = Some(v2bool(v, kws::DISPLAY_WITH_CODE)?);
+        }
+
+        if let Some(v) = m.remove(kws::DISPLAY_CODE_RADIX) {
+            let s = v2string(v, kws::DISPLAY_CODE_RADIX)?;
+            mod_spec.display_code_radix = Some(parse_display_code_radix(&s)?);
+        }
+
+        if let Some(v) = m.remove(kws::DISPLAY_IS_MESSAGE_ID) {
+            mod_spec.display_is_message_id = Some(v2bool(v, kws::DISPLAY_IS_MESSAGE_ID)?);
+        }
+
+        if let Some(v) = m.remove(kws::DISPLAY_RESOLVER) {
+            let resolver = v2string(v, kws::DISPLAY_RESOLVER)?;
+            check_display_resolver_path(&resolver)?;
+            mod_spec.display_resolver = Some(resolver);
+        }
+
+        if let Some(v) = m.remove(kws::RUNTIME_PATH) {
+            let path = v2string(v, kws::RUNTIME_PATH)?;
+            check_runtime_path(&path)?;
+            mod_spec.runtime_path = Some(path);
+        }
+
+        if let Some(v) = m.remove(kws::TEST) {
+            mod_spec.test = Some(v2bool(v, kws::TEST)?);
+        }
+
+        if let Some(v) = m.remove(kws::MAP_BY_NAME) {
+            mod_spec.map_by_name = v2string_list(v, kws::MAP_BY_NAME)?;
+            check_name_uniqueness(
+                kws::MAP_BY_NAME,
+                mod_spec.map_by_name.iter().map(|s| s.as_str()),
+            )?;
+        }
+
+        if let Some(v) = m.remove(kws::ALLOWS) {
+            mod_spec.allows = v2string_list(v, kws::ALLOWS)?;
+            check_allows(&mod_spec.allows)?;
+        }
+
+        if let Some(v) = m.remove(kws::FEATURE) {
+            let feature = v2string(v, kws::FEATURE)?;
+            check_feature_name(&feature)?;
+            mod_spec.feature = Some(feature);
+        }
+
+        if let Some(v) = m.remove(kws::VALUE_WIDTHS) {
+            mod_spec.value_widths = v2usize_list(v, kws::VALUE_WIDTHS)?;
+            check_value_widths(&mod_spec.value_widths)?;
+        }
+
+        if let Some(v) = m.remove(kws::GROUPS) {
+            mod_spec.groups = GroupListParser::value(v)?;
+        }
+
+        if let Some(v) = m.remove(kws::BRIDGE_ENUM) {
+            mod_spec.bridge_enum = Some(BridgeEnumParser::value(v, lenient)?);
+        }
+
+        for (k, _) in m {
+            let key = v2key(k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid ModuleObject attribute: {key}"),
+            )?;
         }
 
         if let Some(ref n) = mod_spec.name {
@@ -316,6 +518,9 @@ impl ModuleParser {
                 error!("ModuleObject name is mandatory in ModuleList");
                 return MISSING_ATTRIBUTE.into();
             }
+        } else if mod_spec.extends.is_some() {
+            error!("`{}` is allowed only in ModuleList", kws::EXTENDS);
+            return BAD_OBJECT_ATTRIBUTE.into();
         }
 
         Ok(mod_spec)
@@ -328,9 +533,9 @@ impl ModuleParser {
 struct ModuleListParser;
 
 impl ModuleListParser {
-    fn value(v: Value) -> Result<Vec<ModuleSpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<ModuleSpec>, TbError> {
         match v {
-            Value::Sequence(s) => Self::sequence(s),
+            Value::Sequence(s) => Self::sequence(s, lenient),
             ref ov => {
                 error!("ModuleList must be a Sequence: deserialized {:?}", ov);
                 BAD_VALUE_TYPE.into()
@@ -338,30 +543,165 @@ impl ModuleListParser {
         }
     }
 
-    fn sequence(s: Sequence) -> Result<Vec<ModuleSpec>, TbError> {
+    fn sequence(s: Sequence, lenient: bool) -> Result<Vec<ModuleSpec>, TbError> {
         let mut modules = Vec::new();
         for v in s.into_iter() {
             let mp = ModuleParser(ParseMode::List);
-            modules.push(mp.value(v)?);
+            modules.push(mp.value(v, lenient)?);
         }
         if modules.is_empty() {
             error!("Empty ModuleList is not allowed");
             return EMPTY_LIST.into();
         }
         check_module_name_uniqueness(modules.iter().map(|m| m.name()))?;
+        resolve_extends(&mut modules)?;
         Ok(modules)
     }
 }
 
 // ----------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct GroupListParser;
+
+impl GroupListParser {
+    fn value(v: Value) -> Result<Vec<GroupSpec>, TbError> {
+        match v {
+            Value::Mapping(m) => Self::mapping(m),
+            ref ov => {
+                error!(
+                    "GroupList must be a Mapping: deserialized a {}",
+                    value_type_name(ov)
+                );
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn mapping(m: Mapping) -> Result<Vec<GroupSpec>, TbError> {
+        let mut groups = Vec::new();
+        for (k, v) in m.into_iter() {
+            let name = match k {
+                Value::String(s) => s,
+                ov => {
+                    error!("group name must be a String: deserialized {:?}", ov);
+                    return BAD_VALUE_TYPE.into();
+                }
+            };
+            check_group_name(&name)?;
+            let errors = match v {
+                Value::Sequence(s) => s
+                    .into_iter()
+                    .map(|v| v2string(v, kws::GROUPS))
+                    .collect::<Result<Vec<_>, _>>()?,
+                ref ov => {
+                    error!(
+                        "group `{name}` must be a Sequence: deserialized a {}",
+                        value_type_name(ov)
+                    );
+                    return BAD_VALUE_TYPE.into();
+                }
+            };
+            if errors.is_empty() {
+                error!("Empty group is not allowed: {name}");
+                return EMPTY_LIST.into();
+            }
+            groups.push(GroupSpec { name, errors });
+        }
+        if groups.is_empty() {
+            error!("Empty GroupList is not allowed");
+            return EMPTY_LIST.into();
+        }
+        Ok(groups)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct BridgeEnumParser;
+
+impl BridgeEnumParser {
+    fn value(v: Value, lenient: bool) -> Result<BridgeEnumSpec, TbError> {
+        match v {
+            Value::Mapping(m) => Self::mapping(m, lenient),
+            ref ov => {
+                error!(
+                    "BridgeEnumObject must be a Mapping: deserialized a {}",
+                    value_type_name(ov)
+                );
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn mapping(mut m: Mapping, lenient: bool) -> Result<BridgeEnumSpec, TbError> {
+        let Some(path) = m.remove(kws::BRIDGE_ENUM_PATH) else {
+            error!(
+                "BridgeEnumObject must have a `{}` attribute",
+                kws::BRIDGE_ENUM_PATH
+            );
+            return MISSING_ATTRIBUTE.into();
+        };
+        let path = v2string(path, kws::BRIDGE_ENUM_PATH)?;
+        check_bridge_enum_path(&path)?;
+
+        let Some(variants) = m.remove(kws::BRIDGE_ENUM_VARIANTS) else {
+            error!(
+                "BridgeEnumObject must have a `{}` attribute",
+                kws::BRIDGE_ENUM_VARIANTS
+            );
+            return MISSING_ATTRIBUTE.into();
+        };
+        let variants = Self::variants(variants)?;
+
+        for (k, _) in m {
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid BridgeEnumObject attribute: {k:?}"),
+            )?;
+        }
+
+        Ok(BridgeEnumSpec { path, variants })
+    }
+
+    fn variants(v: Value) -> Result<Vec<(String, String)>, TbError> {
+        let m = match v {
+            Value::Mapping(m) => m,
+            ref ov => {
+                error!(
+                    "`{}` must be a Mapping: deserialized a {}",
+                    kws::BRIDGE_ENUM_VARIANTS,
+                    value_type_name(ov)
+                );
+                return BAD_VALUE_TYPE.into();
+            }
+        };
+        let mut variants = Vec::new();
+        for (k, v) in m.into_iter() {
+            let r = v2string(k, kws::BRIDGE_ENUM_VARIANTS)?;
+            let variant = v2string(v, kws::BRIDGE_ENUM_VARIANTS)?;
+            check_bridge_enum_variant_name(&variant)?;
+            variants.push((r, variant));
+        }
+        if variants.is_empty() {
+            error!("Empty `{}` is not allowed", kws::BRIDGE_ENUM_VARIANTS);
+            return EMPTY_LIST.into();
+        }
+        Ok(variants)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Debug)]
 struct ErrorListParser;
 
 impl ErrorListParser {
-    fn value(v: Value) -> Result<Vec<ErrorSpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<ErrorSpec>, TbError> {
         match v {
-            Value::Sequence(s) => Self::sequence(s),
+            Value::Sequence(s) => Self::sequence(s, lenient),
             ref ov => {
                 error!("ErrorList must be a Sequence: deserialized {:?}", ov);
                 BAD_VALUE_TYPE.into()
@@ -369,12 +709,12 @@ impl ErrorListParser {
         }
     }
 
-    fn sequence(s: Sequence) -> Result<Vec<ErrorSpec>, TbError> {
+    fn sequence(s: Sequence, lenient: bool) -> Result<Vec<ErrorSpec>, TbError> {
         let mut errors = Vec::new();
         for v in s.into_iter() {
             match v {
                 Value::String(s) => errors.push(ErrorParser::string(s)?),
-                Value::Mapping(m) => errors.push(ErrorParser::mapping(m)?),
+                Value::Mapping(m) => errors.push(ErrorParser::mapping(m, lenient)?),
                 ov => {
                     error!(
                         "ErrorObject in ErrorList must be a String or a Mapping: deserialized {:?}",
@@ -424,7 +764,7 @@ impl ErrorParser {
         false
     }
 
-    fn mapping(m: Mapping) -> Result<ErrorSpec, TbError> {
+    fn mapping(m: Mapping, lenient: bool) -> Result<ErrorSpec, TbError> {
         match m.len() {
             0 => {
                 error!(
@@ -434,7 +774,7 @@ impl ErrorParser {
                 MISSING_ATTRIBUTE.into()
             }
             1 if Self::is_short_mapping(&m) => Self::short_mapping(m),
-            _ => Self::long_mapping(m),
+            _ => Self::long_mapping(m, lenient),
         }
     }
 
@@ -473,7 +813,7 @@ impl ErrorParser {
         })
     }
 
-    fn long_mapping(mut m: Mapping) -> Result<ErrorSpec, TbError> {
+    fn long_mapping(mut m: Mapping, lenient: bool) -> Result<ErrorSpec, TbError> {
         let mut err_spec = ErrorSpec::default();
 
         if let Some(v) = m.remove(kws::NAME) {
@@ -488,10 +828,23 @@ impl ErrorParser {
             err_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = m.remove(kws::DOC_FILE) {
+            check_doc_file(err_spec.doc.as_deref(), "ErrorObject")?;
+            err_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = m.remove(kws::DOC_FROM_DISPLAY) {
             err_spec.oes.doc_from_display = Some(v2bool(v, kws::DOC_FROM_DISPLAY)?);
         }
 
+        if let Some(v) = m.remove(kws::DEFAULT) {
+            err_spec.default = Some(v2bool(v, kws::DEFAULT)?);
+        }
+
+        if let Some(v) = m.remove(kws::INDEX) {
+            err_spec.index = Some(v2usize(v, kws::INDEX)?);
+        }
+
         if let Some(v) = m.remove(kws::VARIANT_TYPE) {
             match v {
                 Value::Bool(b) => err_spec.oes.variant_type = Some(b),
@@ -510,10 +863,13 @@ impl ErrorParser {
             }
         }
 
-        if let Some((k, _)) = m.into_iter().next() {
-            let key = v2key(k)?;
-            error!("invalid ErrorObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        for (k, _) in m {
+            let key = v2key(k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid ErrorObject attribute: {key}"),
+            )?;
         }
 
         check_error_name(&err_spec.name)?;
@@ -528,9 +884,9 @@ impl ErrorParser {
 struct CategoryParser(ParseMode);
 
 impl CategoryParser {
-    fn value(&self, v: Value) -> Result<CategorySpec, TbError> {
+    fn value(&self, v: Value, lenient: bool) -> Result<CategorySpec, TbError> {
         match v {
-            Value::Mapping(m) => self.mapping(m),
+            Value::Mapping(m) => self.mapping(m, lenient),
             ref ov => {
                 error!(
                     "CategoryObject must be a Mapping: deserialized a {}",
@@ -541,7 +897,7 @@ impl CategoryParser {
         }
     }
 
-    fn mapping(&self, mut m: Mapping) -> Result<CategorySpec, TbError> {
+    fn mapping(&self, mut m: Mapping, lenient: bool) -> Result<CategorySpec, TbError> {
         let mut cat_spec = CategorySpec::default();
 
         if let Some(v) = m.remove(kws::NAME) {
@@ -554,10 +910,19 @@ impl CategoryParser {
             cat_spec.doc = Some(v2string(v, kws::DOC)?);
         }
 
+        if let Some(v) = m.remove(kws::DOC_FILE) {
+            check_doc_file(cat_spec.doc.as_deref(), "CategoryObject")?;
+            cat_spec.doc_file = Some(v2string(v, kws::DOC_FILE)?.into());
+        }
+
         if let Some(v) = m.remove(kws::DOC_FROM_DISPLAY) {
             cat_spec.oes.doc_from_display = Some(v2bool(v, kws::DOC_FROM_DISPLAY)?);
         }
 
+        if let Some(v) = m.remove(kws::INDEX) {
+            cat_spec.index = Some(v2usize(v, kws::INDEX)?);
+        }
+
         if let Some(v) = m.remove(kws::ERRORS) {
             if matches!(self.0, ParseMode::Single) {
                 error!(
@@ -566,17 +931,31 @@ impl CategoryParser {
                 );
                 return BAD_OBJECT_ATTRIBUTE.into();
             }
-            cat_spec.errors = ErrorListParser::value(v)?;
+            cat_spec.errors = ErrorListParser::value(v, lenient)?;
+        }
+
+        if let Some(v) = m.remove(kws::SUBCATEGORIES) {
+            if matches!(self.0, ParseMode::Single) {
+                error!(
+                    "SubcategoryList is not allowed in root-level '{}' attribute",
+                    kws::CATEGORY
+                );
+                return BAD_OBJECT_ATTRIBUTE.into();
+            }
+            add_subcategories(&mut cat_spec, SubcategoryListParser::value(v, lenient)?)?;
         }
 
         if let Some(v) = m.remove(kws::VARIANT_TYPE) {
             cat_spec.oes.variant_type = Some(v2bool(v, kws::VARIANT_TYPE)?);
         }
 
-        if let Some((k, _)) = m.into_iter().next() {
-            let key = v2key(k)?;
-            error!("invalid CategoryObject attribute: {}", key);
-            return BAD_OBJECT_ATTRIBUTE.into();
+        for (k, _) in m {
+            let key = v2key(k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid CategoryObject attribute: {key}"),
+            )?;
         }
 
         match self.0 {
@@ -603,13 +982,104 @@ impl CategoryParser {
 
 // ----------------------------------------------------------------------------
 
+#[derive(Debug)]
+struct SubcategoryParser;
+
+impl SubcategoryParser {
+    fn mapping(
+        mut m: Mapping,
+        lenient: bool,
+    ) -> Result<(SubcategorySpec, Vec<ErrorSpec>), TbError> {
+        let mut sub_spec = SubcategorySpec::default();
+        let mut errors = Vec::new();
+
+        if let Some(v) = m.remove(kws::NAME) {
+            let name = v2string(v, kws::NAME)?;
+            check_subcategory_name(&name)?;
+            sub_spec.name = name;
+        }
+
+        if let Some(v) = m.remove(kws::DOC) {
+            sub_spec.doc = Some(v2string(v, kws::DOC)?);
+        }
+
+        if let Some(v) = m.remove(kws::ERRORS) {
+            errors = ErrorListParser::value(v, lenient)?;
+        }
+
+        for (k, _) in m {
+            let key = v2key(k, lenient)?;
+            warn(
+                !lenient,
+                BAD_OBJECT_ATTRIBUTE,
+                format_args!("invalid SubcategoryObject attribute: {key}"),
+            )?;
+        }
+
+        if sub_spec.name.is_empty() {
+            error!("SubcategoryObject name is mandatory in SubcategoryList");
+            return MISSING_ATTRIBUTE.into();
+        }
+
+        if errors.is_empty() {
+            error!("ErrorList not found: subcategory_name = {}", sub_spec.name);
+            return MISSING_ATTRIBUTE.into();
+        }
+
+        Ok((sub_spec, errors))
+    }
+}
+
+// ----------------------------------------------------------------------------
+
+#[derive(Debug)]
+struct SubcategoryListParser;
+
+impl SubcategoryListParser {
+    fn value(v: Value, lenient: bool) -> Result<Vec<(SubcategorySpec, Vec<ErrorSpec>)>, TbError> {
+        match v {
+            Value::Sequence(s) => Self::sequence(s, lenient),
+            ref ov => {
+                error!("SubcategoryList must be a Sequence: deserialized {:?}", ov);
+                BAD_VALUE_TYPE.into()
+            }
+        }
+    }
+
+    fn sequence(
+        s: Sequence,
+        lenient: bool,
+    ) -> Result<Vec<(SubcategorySpec, Vec<ErrorSpec>)>, TbError> {
+        let mut subcategories = Vec::new();
+        for v in s.into_iter() {
+            match v {
+                Value::Mapping(m) => subcategories.push(SubcategoryParser::mapping(m, lenient)?),
+                ov => {
+                    error!(
+                        "SubcategoryObject in SubcategoryList must be a Mapping: deserialized {:?}",
+                        ov
+                    );
+                    return BAD_VALUE_TYPE.into();
+                }
+            }
+        }
+        if subcategories.is_empty() {
+            error!("Empty SubcategoryList is not allowed");
+            return EMPTY_LIST.into();
+        }
+        Ok(subcategories)
+    }
+}
+
+// ----------------------------------------------------------------------------
+
 #[derive(Debug)]
 struct CategoryListParser;
 
 impl CategoryListParser {
-    fn value(v: Value) -> Result<Vec<CategorySpec>, TbError> {
+    fn value(v: Value, lenient: bool) -> Result<Vec<CategorySpec>, TbError> {
         match v {
-            Value::Sequence(s) => Self::sequence(s),
+            Value::Sequence(s) => Self::sequence(s, lenient),
             ref ov => {
                 error!("CategoryList must be a Sequence: deserialized {:?}", ov);
                 BAD_VALUE_TYPE.into()
@@ -617,13 +1087,13 @@ impl CategoryListParser {
         }
     }
 
-    fn sequence(s: Sequence) -> Result<Vec<CategorySpec>, TbError> {
+    fn sequence(s: Sequence, lenient: bool) -> Result<Vec<CategorySpec>, TbError> {
         let mut categories = Vec::new();
         for v in s.into_iter() {
             match v {
                 Value::Mapping(m) => {
                     let parser = CategoryParser(ParseMode::List);
-                    let cat_spec = parser.mapping(m)?;
+                    let cat_spec = parser.mapping(m, lenient)?;
                     categories.push(cat_spec);
                 }
                 ov => {
@@ -658,7 +1128,27 @@ fn value_type_name(v: &Value) -> &'static str {
     }
 }
 
-fn v2key(v: Value) -> Result<String, TbError> {
+fn v2string_list(v: Value, kw: &str) -> Result<Vec<String>, TbError> {
+    match v {
+        Value::Sequence(s) => s.into_iter().map(|v| v2string(v, kw)).collect(),
+        ov => {
+            error!("`{}` must be a Sequence: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
+fn v2usize_list(v: Value, kw: &str) -> Result<Vec<usize>, TbError> {
+    match v {
+        Value::Sequence(s) => s.into_iter().map(|v| v2usize(v, kw)).collect(),
+        ov => {
+            error!("`{}` must be a Sequence: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
+fn v2key(v: Value, lenient: bool) -> Result<String, TbError> {
     let key = match v {
         Value::String(s) => s,
         ov => {
@@ -667,7 +1157,7 @@ fn v2key(v: Value) -> Result<String, TbError> {
         }
     };
 
-    if !kws::is_any_kw(&key) {
+    if !kws::is_any_kw(&key) && !lenient {
         error!("invalid Mapping key: {}", key);
         BAD_OBJECT_ATTRIBUTE.into()
     } else {
@@ -685,6 +1175,25 @@ fn v2string(v: Value, kw: &str) -> Result<String, TbError> {
     }
 }
 
+fn v2usize(v: Value, kw: &str) -> Result<usize, TbError> {
+    match v {
+        Value::Number(n) => match n.as_u64().and_then(|n| usize::try_from(n).ok()) {
+            Some(n) => Ok(n),
+            None => {
+                error!(
+                    "`{}` must be a non-negative Integer: deserialized {}",
+                    kw, n
+                );
+                BAD_VALUE.into()
+            }
+        },
+        ov => {
+            error!("`{}` must be a Number: deserialized {:?}", kw, ov);
+            BAD_VALUE_TYPE.into()
+        }
+    }
+}
+
 fn v2bool(v: Value, kw: &str) -> Result<bool, TbError> {
     match v {
         Value::Bool(b) => Ok(b),
@@ -8,7 +8,10 @@ use crate::{
         },
         yaml::*,
     },
-    spec::{ErrorSpec, OverridableErrorSpec, IMPLICIT_CATEGORY_NAME},
+    spec::{
+        BridgeEnumSpec, DisplayCase, DisplayCodeRadix, ErrorSpec, GroupSpec, OrderBy,
+        OverridableErrorSpec, SerdeRepr, IMPLICIT_CATEGORY_NAME,
+    },
 };
 
 const GOOD_BOOLEANS: [(&str, bool); 4] = [
@@ -39,7 +42,7 @@ meta:
 ---
 errors:
 ";
-    let res = YamlParser::parse_str(s);
+    let res = YamlParser::parse_str(s, false);
     assert_eq!(res, BAD_YAML.into());
 }
 
@@ -57,7 +60,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -76,7 +79,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -93,7 +96,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -115,7 +118,7 @@ fn test_err_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -125,7 +128,7 @@ fn test_err_doc_from_display() {
             bad
         );
 
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
     }
 }
@@ -148,7 +151,7 @@ fn test_err_display() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -158,7 +161,7 @@ fn test_err_display() {
             bad
         );
 
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
     }
 }
@@ -178,7 +181,7 @@ fn test_err_doc() {
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
@@ -197,17 +200,108 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 
     for bad in ["null", "1"] {
         let s = format!("---\nerrors:\n  - name: TEST_ERROR\n    doc: {}", bad);
 
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
+        assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
+    }
+}
+
+#[test]
+fn test_err_default() {
+    log_init();
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nerrors:\n  - name: TEST_ERROR\n    default: {}",
+            good.0
+        );
+        let err = ErrorSpec {
+            name: "TEST_ERROR".into(),
+            default: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_err(err);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(res, spec);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nerrors:\n  - name: TEST_ERROR\n    default: {}", bad);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
     }
 }
 
+#[test]
+fn test_err_index() {
+    log_init();
+    let s = "---\nerrors:\n  - name: TEST_ERROR\n    index: 0";
+    let err = ErrorSpec {
+        name: "TEST_ERROR".into(),
+        index: Some(0),
+        ..Default::default()
+    };
+    let spec = spec_from_err(err);
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(res, spec);
+
+    for (bad, kind) in [
+        ("-1", BAD_VALUE),
+        ("1.5", BAD_VALUE),
+        ("\"1\"", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nerrors:\n  - name: TEST_ERROR\n    index: {bad}");
+        let res = YamlParser::parse_str(&s, false);
+        assert_eq!(res.unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_err_default_uniqueness() {
+    log_init();
+    let s = "
+---
+categories:
+  - name: Cat1
+    errors:
+      - name: ERR1
+        default: true
+  - name: Cat2
+    errors:
+      - name: ERR2
+        default: true
+";
+    let res = YamlParser::parse_str(s, false);
+    assert_eq!(res.unwrap_err().kind(), NON_UNIQUE_DEFAULT);
+
+    let s = "
+---
+modules:
+  - name: mod1
+    categories:
+      - name: Cat1
+        errors:
+          - name: ERR1
+            default: true
+          - name: ERR2
+            default: false
+  - name: mod2
+    categories:
+      - name: Cat1
+        errors:
+          - name: ERR1
+            default: true
+";
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(res.modules[0].default_error().unwrap().1.name, "ERR1");
+    assert_eq!(res.modules[1].default_error().unwrap().1.name, "ERR1");
+}
+
 #[test]
 fn test_err_name() {
     log_init();
@@ -226,13 +320,13 @@ fn test_err_name() {
 
     for (bad, kind) in BAD_NAMES {
         let s = format!("---\nerrors:\n  - name: {}", bad);
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), *kind);
     }
 
     for (bad, kind) in BAD_NAMES {
         let s = format!("---\nerrors:\n  - {}", bad);
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), *kind);
     }
 }
@@ -259,7 +353,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -315,7 +409,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err_iter([err1, err2, err3, err4, err5]);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 }
 
@@ -328,7 +422,7 @@ my_errors:
     - BAD_ERROR
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err(),
+        YamlParser::parse_str(s, false).unwrap_err(),
         BAD_ROOT_LEVEL_KEYWORD.into()
     );
 
@@ -338,7 +432,7 @@ true:
     - BAD_ERROR
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err(),
+        YamlParser::parse_str(s, false).unwrap_err(),
         BAD_KEYWORD_TYPE.into()
     );
 
@@ -349,7 +443,7 @@ module:
 
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err(),
+        YamlParser::parse_str(s, false).unwrap_err(),
         MISSING_ATTRIBUTE.into()
     );
 }
@@ -371,7 +465,7 @@ fn test_module_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -381,7 +475,7 @@ fn test_module_doc_from_display() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -396,12 +490,18 @@ fn test_module_name() {
             name: Some(good_name.into()),
             ..Default::default()
         };
-        assert_eq!(spec_from_module(module), YamlParser::parse_str(&s).unwrap());
+        assert_eq!(
+            spec_from_module(module),
+            YamlParser::parse_str(&s, false).unwrap()
+        );
     }
 
     for bad_name in ["\"\"", "My_Errors", "ERRORS"] {
         let s = format!("---\nmodule:\n  name: {bad_name}\nerrors:\n  - DUMMY_ERR");
-        assert_eq!(YamlParser::parse_str(&s).unwrap_err().kind(), BAD_NAME);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_NAME
+        );
     }
 }
 
@@ -424,7 +524,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -440,7 +540,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -463,7 +563,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -479,7 +579,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -502,7 +602,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -518,7 +618,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -541,7 +641,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -557,7 +657,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_module(module);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -575,7 +675,7 @@ fn test_module_result_from_err() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -585,7 +685,7 @@ fn test_module_result_from_err() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -605,7 +705,7 @@ fn test_module_result_from_err_kind() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -615,7 +715,7 @@ fn test_module_result_from_err_kind() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -635,7 +735,7 @@ fn test_error_trait() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -645,7 +745,37 @@ fn test_error_trait() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_core_error() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  core_error: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            core_error: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  core_error: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -665,7 +795,7 @@ fn test_module_flat_kinds() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -675,227 +805,1265 @@ fn test_module_flat_kinds() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
 }
 
 #[test]
-fn test_module_flat_kinds_error_name_uniqueness() {
+fn test_module_prelude() {
     log_init();
 
-    let s = "
----
-module:
-  flat_kinds: true
-
-categories:
-  - name: Cat1
-    errors:
-      - ERR1: first error
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  prelude: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            prelude: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
 
-  - name: Cat2
-    errors:
-      - ERR1: another first error
-";
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmodule:\n  prelude: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
 
-    assert_eq!(YamlParser::parse_str(s), NON_UNIQUE_NAME.into());
+#[test]
+fn test_module_prelude_error_name_uniqueness() {
+    log_init();
 
     let s = "
 ---
 module:
-  flat_kinds: true
+  prelude: true
 
 categories:
   - name: Cat1
     errors:
-      - ERR1: first error
-
+      - ERR1
   - name: Cat2
     errors:
-      - ERR2: another first error
+      - ERR1
 ";
+    assert_eq!(YamlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    let s = s.replace("prelude: true", "prelude: false");
+    assert!(YamlParser::parse_str(&s, false).is_ok());
 }
 
 #[test]
-fn test_module_categories_forbidden_in_single() {
+fn test_module_category_default() {
     log_init();
 
-    let s = r#"
----
-module:
-  name: my_errors
-  categories:
-    - name: BadCategory
-      errors:
-        - BAD_ERROR
-
-errors:
-  - GOOD_ERROR
-"#;
-
-    assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
-        BAD_OBJECT_ATTRIBUTE
-    );
-
-    let s = r#"
----
-module:
-  name: my_errors
-
-errors:
-  - GOOD_ERROR
-"#;
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  category_default: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            category_default: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  category_default: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
 }
 
 #[test]
-fn test_no_std() {
+fn test_module_doc_cfg() {
     log_init();
 
     for good in GOOD_BOOLEANS {
-        let s = format!("---\nmain:\n  no_std: {}\n\nerrors:\n  - DUMMY_ERR", good.0);
-        let main = MainSpec {
-            no_std: Some(good.1),
+        let s = format!(
+            "---\nmodule:\n  doc_cfg: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            doc_cfg: Some(good.1),
             ..Default::default()
         };
-        let spec = spec_from_main(main);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
     for bad in BAD_BOOLEANS {
-        let s = format!("---\nmain:\n  no_std: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        let s = format!("---\nmodule:\n  doc_cfg: {}\n\nerrors:\n  - DUMMY_ERR", bad);
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
 }
 
 #[test]
-fn test_error_name() {
+fn test_module_catalog() {
     log_init();
-    for good in ["MyError", idents::ERROR] {
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  catalog: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
         let module = ModuleSpec {
-            err_name: Some(good.into()),
+            catalog: Some(good.1),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&format!(
-            "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            good
-        ))
-        .unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
-        let s = format!(
-            "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            bad
-        );
-        assert_eq!(YamlParser::parse_str(&s).unwrap_err().kind(), kind);
-    }
-
-    for bad in [idents::ERROR_CATEGORY, idents::ERROR_KIND] {
-        let s = format!(
-            "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            bad
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmodule:\n  catalog: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
         );
-        assert_eq!(YamlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
     }
-
-    let s = "---\nerrors:\n  - {}";
-
-    assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
-        MISSING_ATTRIBUTE
-    );
 }
 
 #[test]
-fn test_error_kind_name() {
+fn test_module_location() {
     log_init();
-    for good in ["MyErrorKind", idents::ERROR_KIND] {
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  location: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
         let module = ModuleSpec {
-            err_kind_name: Some(good.into()),
+            location: Some(good.1),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&format!(
-            "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            good
-        ))
-        .unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
+    for bad in BAD_BOOLEANS {
         let s = format!(
-            "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            "---\nmodule:\n  location: {}\n\nerrors:\n  - DUMMY_ERR",
             bad
         );
-        assert_eq!(YamlParser::parse_str(&s).unwrap_err().kind(), kind);
-    }
-
-    for bad in [idents::ERROR, idents::ERROR_CATEGORY] {
-        let s = format!(
-            "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            bad
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
         );
-        assert_eq!(YamlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
     }
 }
 
 #[test]
-fn test_error_cat_name() {
+fn test_module_display_is_message_id() {
     log_init();
-    for good in ["MyErrorCategory", idents::ERROR_CATEGORY] {
+
+    for good in GOOD_BOOLEANS {
+        let resolver = good.1.then_some("crate::i18n::message");
+        let resolver_line = resolver
+            .map(|r| format!("  display_resolver: {r}\n"))
+            .unwrap_or_default();
+        let s = format!(
+            "---\nmodule:\n  display_is_message_id: {}\n{resolver_line}\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
         let module = ModuleSpec {
-            err_cat_name: Some(good.into()),
+            display_is_message_id: Some(good.1),
+            display_resolver: resolver.map(String::from),
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&format!(
-            "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            good
-        ))
-        .unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
-    for (bad, kind) in BAD_IDENTS {
+    for bad in BAD_BOOLEANS {
         let s = format!(
-            "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            "---\nmodule:\n  display_is_message_id: {}\n\nerrors:\n  - DUMMY_ERR",
             bad
         );
-        assert_eq!(YamlParser::parse_str(&s).unwrap_err().kind(), kind);
-    }
-
-    for bad in [idents::ERROR, idents::ERROR_KIND] {
-        let s = format!(
-            "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
-            bad
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
         );
-        assert_eq!(YamlParser::parse_str(&s), BAD_MODULE_IDENTIFIER.into());
     }
+
+    let s = "---\nmodule:\n  display_is_message_id: true\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(YamlParser::parse_str(s, false), MISSING_ATTRIBUTE.into());
+
+    let s = "---\nmodule:\n  display_resolver: message\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(YamlParser::parse_str(s, false), BAD_OBJECT_ATTRIBUTE.into());
+
+    let s = "---\nmodule:\n  display_is_message_id: true\n  display_resolver: not a path\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(YamlParser::parse_str(s, false), BAD_VALUE.into());
+
+    let s = "---\nmodule:\n  display_is_message_id: true\n  display_resolver: message\n  normalize_display_period: true\n\nerrors:\n  - DUMMY_ERR: dummy-err";
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(
+        res.modules[0].categories[0].errors[0].display.as_deref(),
+        Some("dummy-err")
+    );
 }
 
 #[test]
-fn test_error_list_unique_names() {
+fn test_module_arbitrary() {
     log_init();
 
-    let s = "---\nerrors:\n  - FIRST_ERROR\n  - FIRST_ERROR\n  - SECOND_ERROR\n";
-    assert_eq!(YamlParser::parse_str(s), NON_UNIQUE_NAME.into());
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  arbitrary: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            arbitrary: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  arbitrary: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_proptest() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  proptest: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            proptest: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  proptest: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_kind_to_cat() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  kind_to_cat: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            kind_to_cat: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  kind_to_cat: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_kind_iter() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  kind_iter: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            kind_iter: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  kind_iter: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_debug_stable() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  debug_stable: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            debug_stable: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  debug_stable: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_assoc_consts() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  assoc_consts: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            assoc_consts: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  assoc_consts: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+
+    let s = "---\nmodule:\n  assoc_consts: true\n\ncategories:\n  - name: Parser\n    errors: [BAD_TOKEN]\n  - name: ParserBad\n    errors: [TOKEN]\n";
+    assert_eq!(YamlParser::parse_str(s, false), NAME_COLLISION.into());
+    let s = s.replace(
+        "assoc_consts: true\n",
+        "assoc_consts: true\n  flat_kinds: true\n",
+    );
+    assert!(YamlParser::parse_str(&s, false).is_ok());
+
+    let s = "---\nmodule:\n  assoc_consts: true\n  flat_kinds: true\n  kind_iter: true\n\nerrors:\n  - VARIANTS\n";
+    assert_eq!(YamlParser::parse_str(s, false), NAME_COLLISION.into());
+    let s = s.replace("  kind_iter: true\n", "");
+    assert!(YamlParser::parse_str(&s, false).is_ok());
+}
+
+#[test]
+fn test_module_category_sets() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  category_sets: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            category_sets: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  category_sets: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+
+    let s = "---\nmodule:\n  category_sets: true\n  err_name: ErrorCategorySet\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(YamlParser::parse_str(s, false), NAME_COLLISION.into());
+}
+
+#[test]
+fn test_module_err_cat_kinds_name_collision() {
+    log_init();
+
+    for name in ["err_name", "err_kind_name"] {
+        let s = format!("---\nmodule:\n  {name}: ErrorCategoryKinds\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(YamlParser::parse_str(&s, false), NAME_COLLISION.into());
+    }
+}
+
+#[test]
+fn test_category_all_name_collision() {
+    log_init();
+
+    let s = "---\nerrors:\n  - ALL";
+    assert_eq!(YamlParser::parse_str(s, false), NAME_COLLISION.into());
+
+    let s = "---\nmodule:\n  flat_kinds: true\n\nerrors:\n  - ALL";
+    assert!(YamlParser::parse_str(s, false).is_ok());
+}
+
+#[test]
+fn test_module_allow_unsafe() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  allow_unsafe: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            allow_unsafe: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  allow_unsafe: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_enforce_display_period() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  enforce_display_period: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            enforce_display_period: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  enforce_display_period: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_normalize_display_period() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  normalize_display_period: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            normalize_display_period: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  normalize_display_period: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_test() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!("---\nmodule:\n  test: {}\n\nerrors:\n  - DUMMY_ERR", good.0);
+        let module = ModuleSpec {
+            test: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmodule:\n  test: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_order_by() {
+    log_init();
+
+    for (good, order_by) in [("name", OrderBy::Name), ("value", OrderBy::Value)] {
+        let s = format!("---\nmodule:\n  order_by: {good}\n\nerrors:\n  - DUMMY_ERR");
+        let module = ModuleSpec {
+            order_by: Some(order_by),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("Name", BAD_VALUE),
+        ("display", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  order_by: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_display_case() {
+    log_init();
+
+    for (good, display_case) in [
+        ("sentence", DisplayCase::Sentence),
+        ("lower", DisplayCase::Lower),
+        ("none", DisplayCase::None),
+    ] {
+        let s = format!("---\nmodule:\n  display_case: {good}\n\nerrors:\n  - DUMMY_ERR");
+        let module = ModuleSpec {
+            display_case: Some(display_case),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("Sentence", BAD_VALUE),
+        ("upper", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  display_case: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_display_with_code() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  display_with_code: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            display_with_code: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  display_with_code: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_display_code_radix() {
+    log_init();
+
+    for (good, radix) in [
+        ("hex", DisplayCodeRadix::Hex),
+        ("decimal", DisplayCodeRadix::Decimal),
+    ] {
+        let s = format!("---\nmodule:\n  display_code_radix: {good}\n\nerrors:\n  - DUMMY_ERR");
+        let module = ModuleSpec {
+            display_code_radix: Some(radix),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("Hex", BAD_VALUE),
+        ("octal", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("16", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  display_code_radix: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+}
+
+#[test]
+fn test_module_serde() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmodule:\n  serde: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let module = ModuleSpec {
+            serde: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmodule:\n  serde: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_module_serde_repr() {
+    log_init();
+
+    for (good, serde_repr) in [("name", SerdeRepr::Name), ("value", SerdeRepr::Value)] {
+        let s =
+            format!("---\nmodule:\n  serde: true\n  serde_repr: {good}\n\nerrors:\n  - DUMMY_ERR");
+        let module = ModuleSpec {
+            serde: Some(true),
+            serde_repr: Some(serde_repr),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("Name", BAD_VALUE),
+        ("display", BAD_VALUE),
+        ("\"\"", BAD_VALUE),
+        ("true", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        let s =
+            format!("---\nmodule:\n  serde: true\n  serde_repr: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for serde in ["", "  serde: false\n"] {
+        let s = format!("---\nmodule:\n{serde}  serde_repr: value\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_OBJECT_ATTRIBUTE
+        );
+    }
+}
+
+#[test]
+fn test_module_flat_kinds_error_name_uniqueness() {
+    log_init();
+
+    let s = "
+---
+module:
+  flat_kinds: true
+
+categories:
+  - name: Cat1
+    errors:
+      - ERR1: first error
+
+  - name: Cat2
+    errors:
+      - ERR1: another first error
+";
+
+    assert_eq!(YamlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
+
+    let s = "
+---
+module:
+  flat_kinds: true
+
+categories:
+  - name: Cat1
+    errors:
+      - ERR1: first error
+
+  - name: Cat2
+    errors:
+      - ERR2: another first error
+";
+
+    assert!(YamlParser::parse_str(s, false).is_ok());
+}
+
+#[test]
+fn test_module_categories_in_single() {
+    log_init();
+
+    let nested = r#"
+---
+module:
+  name: my_errors
+  categories:
+    - name: Parser
+      errors:
+        - BAD_TOKEN
+    - name: Processor
+      errors:
+        - QUEUE_FULL
+"#;
+
+    let root_level = r#"
+---
+module:
+  name: my_errors
+
+categories:
+  - name: Parser
+    errors:
+      - BAD_TOKEN
+  - name: Processor
+    errors:
+      - QUEUE_FULL
+"#;
+
+    let spec = YamlParser::parse_str(nested, false).unwrap();
+    assert_eq!(spec, YamlParser::parse_str(root_level, false).unwrap());
+    assert_eq!(spec.modules.len(), 1);
+    assert_eq!(spec.modules[0].name(), "my_errors");
+    assert_eq!(spec.modules[0].categories.len(), 2);
+
+    for kw in ["errors", "categories"] {
+        let s = format!(
+            r#"
+---
+module:
+  categories:
+    - name: Parser
+      errors:
+        - BAD_TOKEN
+
+{kw}:
+  - GOOD_ERROR
+"#
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            MUTUALLY_EXCLUSIVE_KEYWORDS
+        );
+    }
+
+    let s = r#"
+---
+module:
+  categories:
+    - name: Parser
+      errors:
+        - BAD_TOKEN
+
+category:
+  name: General
+"#;
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        MUTUALLY_EXCLUSIVE_KEYWORDS
+    );
+
+    let s = r#"
+---
+module:
+  name: my_errors
+"#;
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        MISSING_ATTRIBUTE
+    );
+}
+
+#[test]
+fn test_no_std() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!("---\nmain:\n  no_std: {}\n\nerrors:\n  - DUMMY_ERR", good.0);
+        let main = MainSpec {
+            no_std: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_main(main);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmain:\n  no_std: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_main_test() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!("---\nmain:\n  test: {}\n\nerrors:\n  - DUMMY_ERR", good.0);
+        let main = MainSpec {
+            test: Some(good.1),
+            ..Default::default()
+        };
+        let spec = spec_from_main(main);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmain:\n  test: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_main_require_display() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmain:\n  require_display: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let main = MainSpec {
+            require_display: Some(good.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmain:\n  require_display: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_main_split_tables() {
+    log_init();
+
+    for good in GOOD_BOOLEANS {
+        let s = format!(
+            "---\nmain:\n  split_tables: {}\n\nerrors:\n  - DUMMY_ERR",
+            good.0
+        );
+        let main = MainSpec {
+            split_tables: Some(good.1),
+            ..Default::default()
+        };
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for bad in BAD_BOOLEANS {
+        let s = format!("---\nmain:\n  split_tables: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_main_tests() {
+    log_init();
+
+    for (good, tests) in [
+        ("[from_value, uniqueness]", vec!["from_value", "uniqueness"]),
+        ("[err_kind_name]", vec!["err_kind_name"]),
+        ("[bail]", vec!["bail"]),
+    ] {
+        let s = format!("---\nmain:\n  tests: {good}\n\nerrors:\n  - DUMMY_ERR");
+        let main = MainSpec {
+            tests: Some(tests.into_iter().map(String::from).collect()),
+            ..Default::default()
+        };
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in [
+        ("[]", BAD_VALUE),
+        ("[unknown]", BAD_VALUE),
+        ("[ind_name]", BAD_VALUE),
+        ("[from_value, unknown]", BAD_VALUE),
+        ("[bail, bail]", NON_UNIQUE_NAME),
+        ("from_value", BAD_VALUE_TYPE),
+        ("[true]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmain:\n  tests: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_output_pattern() {
+    log_init();
+
+    for good in ["gen_{module}.rs", "{module}/mod.rs", "{module}"] {
+        let s = format!("---\nmain:\n  output_pattern: \"{good}\"\n\nerrors:\n  - DUMMY_ERR");
+        let main = MainSpec {
+            output_pattern: Some(good.into()),
+            ..Default::default()
+        };
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap(),
+            spec_from_main(main)
+        );
+    }
+
+    for (bad, kind) in [
+        ("gen_errors.rs", BAD_VALUE),
+        ("\"{mod}.rs\"", BAD_VALUE),
+        ("\"/tmp/{module}.rs\"", BAD_VALUE),
+        ("[\"{module}.rs\"]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmain:\n  output_pattern: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_error_name() {
+    log_init();
+    for good in ["MyError", idents::ERROR] {
+        let module = ModuleSpec {
+            err_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(
+            &format!(
+                "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR_CATEGORY, idents::ERROR_KIND] {
+        let s = format!(
+            "\n---\nmodule:\n  err_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+
+    let s = "---\nerrors:\n  - {}";
+
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        MISSING_ATTRIBUTE
+    );
+}
+
+#[test]
+fn test_submodule_names() {
+    log_init();
+
+    for (kw, good) in [
+        ("kinds_mod_name", "kinds"),
+        ("kinds_mod_name", "kind"),
+        ("categories_mod_name", "error_categories"),
+        ("categories_mod_name", "category"),
+    ] {
+        let res = YamlParser::parse_str(
+            &format!("---\nmodule:\n  {kw}: {good}\n\nerrors:\n  - DUMMY_ERR"),
+            false,
+        )
+        .unwrap();
+        let module = &res.modules[0];
+        assert_eq!(module.kinds_mod_name.is_some(), kw == "kinds_mod_name");
+        assert_eq!(
+            module.categories_mod_name.is_some(),
+            kw == "categories_mod_name"
+        );
+        if kw == "kinds_mod_name" {
+            assert_eq!(module.kinds_mod_name(), good);
+        } else {
+            assert_eq!(module.categories_mod_name(), good);
+        }
+    }
+
+    for kw in ["kinds_mod_name", "categories_mod_name"] {
+        for (bad, kind) in [
+            ("Kinds", BAD_IDENTIFIER_CASE),
+            ("my kinds", BAD_IDENTIFIER_CHARACTERS),
+            ("\"\"", EMPTY_IDENTIFIER),
+            ("null", BAD_VALUE_TYPE),
+            ("variant", BAD_MODULE_IDENTIFIER),
+            ("test", BAD_MODULE_IDENTIFIER),
+            ("type", BAD_MODULE_IDENTIFIER),
+            ("self", BAD_MODULE_IDENTIFIER),
+        ] {
+            let s = format!("---\nmodule:\n  {kw}: {bad}\n\nerrors:\n  - DUMMY_ERR");
+            assert_eq!(
+                YamlParser::parse_str(&s, false).unwrap_err().kind(),
+                kind,
+                "{kw}: {bad}"
+            );
+        }
+    }
+
+    for s in [
+        "---\nmodule:\n  kinds_mod_name: category\n\nerrors:\n  - DUMMY_ERR",
+        "---\nmodule:\n  categories_mod_name: kind\n\nerrors:\n  - DUMMY_ERR",
+    ] {
+        assert_eq!(
+            YamlParser::parse_str(s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+
+    let s = "---\nmodule:\n  kinds_mod_name: errs\n  categories_mod_name: errs\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(YamlParser::parse_str(s, false), NAME_COLLISION.into());
+}
+
+#[test]
+fn test_error_kind_name() {
+    log_init();
+    for good in ["MyErrorKind", idents::ERROR_KIND] {
+        let module = ModuleSpec {
+            err_kind_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(
+            &format!(
+                "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR, idents::ERROR_CATEGORY] {
+        let s = format!(
+            "\n---\nmodule:\n  err_kind_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+}
+
+#[test]
+fn test_error_cat_name() {
+    log_init();
+    for good in ["MyErrorCategory", idents::ERROR_CATEGORY] {
+        let module = ModuleSpec {
+            err_cat_name: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(
+            &format!(
+                "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+                good
+            ),
+            false,
+        )
+        .unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in BAD_IDENTS {
+        let s = format!(
+            "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), kind);
+    }
+
+    for bad in [idents::ERROR, idents::ERROR_KIND] {
+        let s = format!(
+            "\n---\nmodule:\n  err_cat_name: {}\n\nerrors:\n  - DUMMY_ERR\n",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false),
+            BAD_MODULE_IDENTIFIER.into()
+        );
+    }
+}
+
+#[test]
+fn test_error_list_unique_names() {
+    log_init();
+
+    let s = "---\nerrors:\n  - FIRST_ERROR\n  - FIRST_ERROR\n  - SECOND_ERROR\n";
+    assert_eq!(YamlParser::parse_str(s, false), NON_UNIQUE_NAME.into());
 
     let s = "---\nerrors:\n  - FIRST_ERROR\n  - SECOND_ERROR\n";
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -909,18 +2077,20 @@ fn test_category_name() {
         };
 
         let spec = spec_from_category(category);
-        let res = YamlParser::parse_str(&format!(
-            "---\ncategory:\n  name: {good}\nerrors:\n  - DUMMY_ERR\n"
-        ))
+        let res = YamlParser::parse_str(
+            &format!("---\ncategory:\n  name: {good}\nerrors:\n  - DUMMY_ERR\n"),
+            false,
+        )
         .unwrap();
         assert_eq!(spec, res);
     }
 
     for (bad, kind) in BAD_IDENTS {
         assert_eq!(
-            YamlParser::parse_str(&format!(
-                "---\ncategory:\n  name: {bad}\nerrors:\n  - DUMMY_ERR\n"
-            ))
+            YamlParser::parse_str(
+                &format!("---\ncategory:\n  name: {bad}\nerrors:\n  - DUMMY_ERR\n"),
+                false
+            )
             .unwrap_err()
             .kind(),
             kind
@@ -929,9 +2099,10 @@ fn test_category_name() {
 
     for bad in [kws::MAIN, kws::ERRORS] {
         assert_eq!(
-            YamlParser::parse_str(&format!(
-                "---\ncategory:\n  name: {bad}\nerrors:\n  - DUMMY_ERR\n"
-            ))
+            YamlParser::parse_str(
+                &format!("---\ncategory:\n  name: {bad}\nerrors:\n  - DUMMY_ERR\n"),
+                false
+            )
             .unwrap_err()
             .kind(),
             BAD_IDENTIFIER_CASE
@@ -939,6 +2110,40 @@ fn test_category_name() {
     }
 }
 
+#[test]
+fn test_category_name_reserved_module() {
+    log_init();
+
+    for bad in [
+        "Kind",
+        "Kinds",
+        "Category",
+        "Categories",
+        "Variant",
+        "Types",
+        "Test",
+        "C",
+    ] {
+        assert_eq!(
+            YamlParser::parse_str(
+                &format!("---\ncategories:\n  - name: {bad}\n    errors:\n      - DUMMY_ERR\n"),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            RESERVED_NAME
+        );
+    }
+
+    for good in ["KindOf", "Categorical", "Variants2", "Testing", "Cc"] {
+        assert!(YamlParser::parse_str(
+            &format!("---\ncategories:\n  - name: {good}\n    errors:\n      - DUMMY_ERR\n"),
+            false
+        )
+        .is_ok());
+    }
+}
+
 #[test]
 fn test_category_doc() {
     let s = "
@@ -957,7 +2162,7 @@ errors:
         doc: Some("Category long doc string.\n\nAppears on multiple lines.\n".into()),
         ..Default::default()
     });
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     let s = "
@@ -972,14 +2177,66 @@ errors:
         doc: Some("".into()),
         ..Default::default()
     });
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+
+    for bad in ["1", "null"] {
+        assert_eq!(
+            YamlParser::parse_str(
+                &format!("---\ncategory:\n  doc: {bad}\nerrors:\n  - DUMMY_ERR"),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            BAD_VALUE_TYPE
+        );
+    }
+}
+
+#[test]
+fn test_doc_file() {
+    log_init();
+    let s = "
+---
+module:
+  doc_file: docs/module.md
+
+category:
+  doc_file: docs/category.md
+
+errors:
+  - name: DUMMY_ERR
+    doc_file: /abs/error.md
+";
+    let err = ErrorSpec {
+        name: "DUMMY_ERR".into(),
+        doc_file: Some("/abs/error.md".into()),
+        ..Default::default()
+    };
+    let cat = CategorySpec {
+        name: IMPLICIT_CATEGORY_NAME.into(),
+        doc_file: Some("docs/category.md".into()),
+        errors: vec![err],
+        ..Default::default()
+    };
+    let module = ModuleSpec {
+        doc_file: Some("docs/module.md".into()),
+        categories: vec![cat],
+        ..Default::default()
+    };
+    let spec = Spec {
+        modules: vec![module],
+        ..Default::default()
+    };
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 
     for bad in ["1", "null"] {
         assert_eq!(
-            YamlParser::parse_str(&format!(
-                "---\ncategory:\n  doc: {bad}\nerrors:\n  - DUMMY_ERR"
-            ))
+            YamlParser::parse_str(
+                &format!("---\nmodule:\n  doc_file: {bad}\nerrors:\n  - DUMMY_ERR"),
+                false
+            )
             .unwrap_err()
             .kind(),
             BAD_VALUE_TYPE
@@ -987,6 +2244,58 @@ errors:
     }
 }
 
+#[test]
+fn test_doc_and_doc_file_mutual_exclusion() {
+    log_init();
+    let specs = [
+        "---\nmodule:\n  doc: Doc.\n  doc_file: doc.md\nerrors:\n  - DUMMY_ERR",
+        "---\ncategory:\n  doc: Doc.\n  doc_file: doc.md\nerrors:\n  - DUMMY_ERR",
+        "---\nerrors:\n  - name: DUMMY_ERR\n    doc: Doc.\n    doc_file: doc.md",
+    ];
+    for s in specs {
+        assert_eq!(
+            YamlParser::parse_str(s, false).unwrap_err().kind(),
+            MUTUALLY_EXCLUSIVE_KEYWORDS
+        );
+    }
+}
+
+#[test]
+fn test_category_index() {
+    log_init();
+    let s = "
+---
+category:
+  index: 0
+errors:
+  - DUMMY_ERR
+";
+    let spec = spec_from_category(CategorySpec {
+        name: IMPLICIT_CATEGORY_NAME.into(),
+        index: Some(0),
+        ..Default::default()
+    });
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+
+    for (bad, kind) in [
+        ("-1", BAD_VALUE),
+        ("1.5", BAD_VALUE),
+        ("\"1\"", BAD_VALUE_TYPE),
+        ("null", BAD_VALUE_TYPE),
+    ] {
+        assert_eq!(
+            YamlParser::parse_str(
+                &format!("---\ncategory:\n  index: {bad}\nerrors:\n  - DUMMY_ERR"),
+                false
+            )
+            .unwrap_err()
+            .kind(),
+            kind
+        );
+    }
+}
+
 #[test]
 fn test_category_doc_from_display() {
     log_init();
@@ -1005,7 +2314,7 @@ fn test_category_doc_from_display() {
             ..Default::default()
         };
         let spec = spec_from_category(cat);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(spec, res);
     }
 
@@ -1015,7 +2324,7 @@ fn test_category_doc_from_display() {
             bad
         );
         assert_eq!(
-            YamlParser::parse_str(&s).unwrap_err().kind(),
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
             BAD_VALUE_TYPE
         );
     }
@@ -1039,7 +2348,7 @@ errors:
 ";
 
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         BAD_OBJECT_ATTRIBUTE
     );
 }
@@ -1056,7 +2365,7 @@ category:
   doc_from_display: false
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 }
@@ -1077,7 +2386,7 @@ errors:
   - DUMMY_ERR2
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 }
@@ -1091,7 +2400,10 @@ fn test_categories_list_cannot_be_empty() {
 categories: []
 ";
 
-    assert_eq!(YamlParser::parse_str(s).unwrap_err().kind(), EMPTY_LIST);
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
 }
 
 #[test]
@@ -1106,7 +2418,7 @@ categories:
     doc_from_display: false
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 
@@ -1118,7 +2430,24 @@ categories:
     doc_from_display: false
     errors: []
 ";
-    assert_eq!(YamlParser::parse_str(s).unwrap_err().kind(), EMPTY_LIST);
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
+
+    let s = "
+---
+categories:
+  - name: Cat1
+    errors:
+      - DUMMY_ERR
+  - name: Cat2
+    errors: []
+";
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        EMPTY_LIST
+    );
 }
 
 #[test]
@@ -1134,7 +2463,7 @@ categories:
       - DUMMY_ERR
 ";
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MISSING_ATTRIBUTE
     );
 }
@@ -1160,6 +2489,9 @@ categories:
     let cat1 = CategorySpec {
         name: "Cat1".into(),
         doc: Some("First category.".into()),
+        doc_file: None,
+        index: None,
+        subcategories: Vec::new(),
         oes: OverridableErrorSpec {
             doc_from_display: Some(false),
             ..Default::default()
@@ -1191,7 +2523,7 @@ categories:
         ..Default::default()
     };
 
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -1202,26 +2534,224 @@ fn test_category_name_uniqueness() {
     let s = "
 ---
 categories:
-  - name: Cat1
-    doc: First category.
-    doc_from_display: false
-    errors:
-      - DUMMY_ERR
-  - name: Cat1
-    doc_from_display: true
-    errors:
-      - DUMMY_ERR2
-  - name: Cat2
-    errors:
-      - DUMMY_ERR
-  - name: Cat2
-    errors:
-      - DUMMY_ERR
-";
-    assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
-        NON_UNIQUE_NAME
-    );
+  - name: Cat1
+    doc: First category.
+    doc_from_display: false
+    errors:
+      - DUMMY_ERR
+  - name: Cat1
+    doc_from_display: true
+    errors:
+      - DUMMY_ERR2
+  - name: Cat2
+    errors:
+      - DUMMY_ERR
+  - name: Cat2
+    errors:
+      - DUMMY_ERR
+";
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        NON_UNIQUE_NAME
+    );
+}
+
+#[test]
+fn test_category_subcategories() {
+    log_init();
+
+    let s = "
+---
+categories:
+  - name: Parser
+    errors:
+      - BAD_INPUT
+    subcategories:
+      - name: Lexer
+        doc: Lexer errors.
+        errors:
+          - BAD_TOKEN
+          - BAD_CHAR
+      - name: Grammar
+        errors:
+          - UNEXPECTED_TOKEN
+  - name: Io
+    subcategories:
+      - name: Net
+        errors:
+          - TIMEOUT
+";
+
+    let err = |name: &str, subcategory: Option<&str>| ErrorSpec {
+        name: name.into(),
+        subcategory: subcategory.map(Into::into),
+        ..Default::default()
+    };
+
+    let parser = CategorySpec {
+        name: "Parser".into(),
+        subcategories: vec![
+            SubcategorySpec {
+                name: "Lexer".into(),
+                doc: Some("Lexer errors.".into()),
+            },
+            SubcategorySpec {
+                name: "Grammar".into(),
+                doc: None,
+            },
+        ],
+        errors: vec![
+            err("BAD_INPUT", None),
+            err("BAD_TOKEN", Some("Lexer")),
+            err("BAD_CHAR", Some("Lexer")),
+            err("UNEXPECTED_TOKEN", Some("Grammar")),
+        ],
+        ..Default::default()
+    };
+
+    let io = CategorySpec {
+        name: "Io".into(),
+        subcategories: vec![SubcategorySpec {
+            name: "Net".into(),
+            doc: None,
+        }],
+        errors: vec![err("TIMEOUT", Some("Net"))],
+        ..Default::default()
+    };
+
+    let spec = Spec {
+        modules: vec![ModuleSpec {
+            categories: vec![parser, io],
+            ..Default::default()
+        }],
+        ..Default::default()
+    };
+
+    let res = YamlParser::parse_str(s, false).unwrap();
+    assert_eq!(spec, res);
+}
+
+#[test]
+fn test_category_subcategories_bad() {
+    log_init();
+
+    let bad = [
+        (
+            "
+---
+category:
+  name: Parser
+  subcategories:
+    - name: Lexer
+      errors: [BAD_TOKEN]
+errors: [BAD_INPUT]
+",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories: []
+",
+            EMPTY_LIST,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories: Lexer
+",
+            BAD_VALUE_TYPE,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - errors: [BAD_TOKEN]
+",
+            MISSING_ATTRIBUTE,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - name: Lexer
+",
+            MISSING_ATTRIBUTE,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - name: lexer
+        errors: [BAD_TOKEN]
+",
+            BAD_IDENTIFIER_CASE,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - name: Tests
+        errors: [BAD_TOKEN]
+",
+            RESERVED_NAME,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - name: Lexer
+        errors: [BAD_TOKEN]
+      - name: Lexer
+        errors: [BAD_CHAR]
+",
+            NON_UNIQUE_NAME,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    errors: [BAD_TOKEN]
+    subcategories:
+      - name: Lexer
+        errors: [BAD_TOKEN]
+",
+            NON_UNIQUE_NAME,
+        ),
+        (
+            "
+---
+categories:
+  - name: Parser
+    subcategories:
+      - name: Lexer
+        errors: [BAD_TOKEN]
+        subcategories:
+          - name: Inner
+            errors: [BAD_CHAR]
+",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+    ];
+
+    for (s, kind) in bad {
+        assert_eq!(YamlParser::parse_str(s, false).unwrap_err().kind(), kind);
+    }
 }
 
 #[test]
@@ -1306,7 +2836,7 @@ modules:
         ],
         ..Default::default()
     };
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(spec, res);
 }
 
@@ -1327,7 +2857,7 @@ errors:
 "#;
 
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1341,7 +2871,7 @@ modules:
           - MY_ERROR
 "#;
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1361,7 +2891,7 @@ category:
 "#;
 
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1375,7 +2905,7 @@ modules:
           - MY_ERROR
 "#;
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1398,7 +2928,7 @@ categories:
 "#;
 
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1410,7 +2940,7 @@ categories:
       - OTHER_ERROR
 "#;
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1430,7 +2960,7 @@ module:
 "#;
 
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         MUTUALLY_EXCLUSIVE_KEYWORDS
     );
 
@@ -1444,7 +2974,7 @@ modules:
           - MY_ERROR
 "#;
 
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1469,7 +2999,7 @@ modules:
           - ANOTHER_ERR
 "#;
     assert_eq!(
-        YamlParser::parse_str(s).unwrap_err().kind(),
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
         NON_UNIQUE_NAME
     );
 
@@ -1490,7 +3020,7 @@ modules:
         errors:
           - ANOTHER_ERR
 "#;
-    assert!(YamlParser::parse_str(s).is_ok());
+    assert!(YamlParser::parse_str(s, false).is_ok());
 }
 
 #[test]
@@ -1514,7 +3044,7 @@ errors:
         ..Default::default()
     };
     let spec = spec_from_err(err);
-    let res = YamlParser::parse_str(s).unwrap();
+    let res = YamlParser::parse_str(s, false).unwrap();
     assert_eq!(res, spec);
 
     for val in [true, false] {
@@ -1528,7 +3058,7 @@ errors:
             ..Default::default()
         };
         let spec = spec_from_err(err);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 }
@@ -1545,6 +3075,7 @@ fn test_error_variant_type_bad_name() {
         ("CustomErr", NAME_COLLISION),
         ("CustomErrCat", NAME_COLLISION),
         ("CustomErrKind", NAME_COLLISION),
+        ("CustomErrCatKinds", NAME_COLLISION),
         ("ExistingErr", NON_UNIQUE_NAME),
     ];
 
@@ -1562,7 +3093,7 @@ errors:
 
     for tc in test_cases {
         let s = format!("{pfx}    - name: MY_ERR\n      variant_type: {}", tc.0);
-        assert_eq!(YamlParser::parse_str(&s).unwrap_err().kind(), tc.1);
+        assert_eq!(YamlParser::parse_str(&s, false).unwrap_err().kind(), tc.1);
     }
 }
 
@@ -1581,13 +3112,13 @@ fn test_category_variant_type() {
             ..Default::default()
         };
         let spec = spec_from_category(cat);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
     for bad in BAD_BOOLEANS {
         let s = format!("---\ncategory:\n  variant_type: {bad}\nerrors:\n  - DUMMY_ERR");
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
     }
 }
@@ -1606,13 +3137,13 @@ fn test_module_variant_type() {
             ..Default::default()
         };
         let spec = spec_from_module(module);
-        let res = YamlParser::parse_str(&s).unwrap();
+        let res = YamlParser::parse_str(&s, false).unwrap();
         assert_eq!(res, spec);
     }
 
     for bad in BAD_BOOLEANS {
         let s = format!("---\nmodule:\n  variant_type: {bad}\nerrors:\n  - DUMMY_ERR");
-        let res = YamlParser::parse_str(&s);
+        let res = YamlParser::parse_str(&s, false);
         assert_eq!(res.unwrap_err().kind(), BAD_VALUE_TYPE);
     }
 }
@@ -1640,6 +3171,620 @@ categories:
       - name: CAT_TWO_ERR_TWO
 "#;
 
-    let res = YamlParser::parse_str(s);
+    let res = YamlParser::parse_str(s, false);
     assert_eq!(res.unwrap_err().kind(), NON_UNIQUE_NAME);
+
+    let unique = s.replacen("ErrOne", "ErrThree", 1);
+    assert!(YamlParser::parse_str(&unique, false).is_ok());
+
+    let not_flat = s.replace("    flat_kinds: true\n", "");
+    assert!(YamlParser::parse_str(&not_flat, false).is_ok());
+
+    // the implicit `ErrOne` of an error without a variant type doesn't collide
+    let implicit = s.replace("    variant_type: true\n", "").replace(
+        "      - name: CAT_ONE_ERR_ONE\n        variant_type: ErrOne\n",
+        "      - name: ERR_ONE\n",
+    );
+    assert!(YamlParser::parse_str(&implicit, false).is_ok());
+}
+
+#[test]
+fn test_module_groups() {
+    log_init();
+
+    let spec_str = |groups: &str| {
+        format!(
+            "
+---
+module:
+  groups:
+    {groups}
+
+categories:
+  - name: Cat1
+    errors:
+      - ERR1
+      - ERR2
+  - name: Cat2
+    errors:
+      - ERR1
+      - ERR3
+"
+        )
+    };
+
+    let s = spec_str("retryable: [ERR2, Cat2::ERR1, ERR3]\n    fatal: [Cat1::ERR1]");
+    let res = YamlParser::parse_str(&s, false).unwrap();
+    let groups = &res.modules[0].groups;
+    assert_eq!(
+        groups,
+        &[
+            GroupSpec {
+                name: "retryable".into(),
+                errors: vec!["ERR2".into(), "Cat2::ERR1".into(), "ERR3".into()],
+            },
+            GroupSpec {
+                name: "fatal".into(),
+                errors: vec!["Cat1::ERR1".into()],
+            },
+        ]
+    );
+    let members = res.modules[0].group_members(&groups[0]);
+    assert_eq!(
+        members
+            .iter()
+            .map(|(c, e)| (c.name.as_str(), e.name.as_str()))
+            .collect::<Vec<_>>(),
+        [("Cat1", "ERR2"), ("Cat2", "ERR1"), ("Cat2", "ERR3")]
+    );
+
+    for (bad, kind) in [
+        ("retryable: [ERR4]", BAD_REFERENCE),
+        ("retryable: [Cat3::ERR1]", BAD_REFERENCE),
+        ("retryable: [Cat1::ERR3]", BAD_REFERENCE),
+        ("retryable: [ERR1]", BAD_REFERENCE),
+        ("retryable: [ERR2, Cat1::ERR2]", NON_UNIQUE_NAME),
+        ("retryable: []", EMPTY_LIST),
+        ("retryable: ERR2", BAD_VALUE_TYPE),
+        ("retryable: [1]", BAD_VALUE_TYPE),
+        ("Retryable: [ERR2]", BAD_IDENTIFIER_CASE),
+        ("retry-able: [ERR2]", BAD_IDENTIFIER_CHARACTERS),
+        ("in: [ERR2]", NAME_COLLISION),
+        ("index: [ERR2]", BAD_NAME),
+        ("error: [ERR2]", NAME_COLLISION),
+        ("{}", EMPTY_LIST),
+    ] {
+        let s = spec_str(bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_bridge_enum() {
+    log_init();
+
+    let spec_str = |bridge_enum: &str| {
+        format!(
+            "
+---
+module:
+  bridge_enum:
+    {bridge_enum}
+
+categories:
+  - name: Cat1
+    errors:
+      - ERR1
+      - ERR2
+  - name: Cat2
+    errors:
+      - ERR1
+"
+        )
+    };
+    let variants = "variants: {ERR2: Two, Cat1::ERR1: One, Cat2::ERR1: One}";
+
+    let s = spec_str(&format!("path: crate::legacy::Error\n    {variants}"));
+    let res = YamlParser::parse_str(&s, false).unwrap();
+    let bridge_enum = res.modules[0].bridge_enum.as_ref().unwrap();
+    assert_eq!(
+        bridge_enum,
+        &BridgeEnumSpec {
+            path: "crate::legacy::Error".into(),
+            variants: vec![
+                ("ERR2".into(), "Two".into()),
+                ("Cat1::ERR1".into(), "One".into()),
+                ("Cat2::ERR1".into(), "One".into()),
+            ],
+        }
+    );
+    assert_eq!(
+        res.modules[0]
+            .bridge_enum_variants(bridge_enum)
+            .iter()
+            .map(|(c, e, v)| (c.name.as_str(), e.name.as_str(), *v))
+            .collect::<Vec<_>>(),
+        [
+            ("Cat1", "ERR2", "Two"),
+            ("Cat1", "ERR1", "One"),
+            ("Cat2", "ERR1", "One")
+        ]
+    );
+
+    let with_path = |v: String| format!("path: Error\n    {v}");
+    for (bad, kind) in [
+        (variants.to_owned(), MISSING_ATTRIBUTE),
+        ("path: Error".into(), MISSING_ATTRIBUTE),
+        (format!("path: not a path\n    {variants}"), BAD_VALUE),
+        (format!("path: [Error]\n    {variants}"), BAD_VALUE_TYPE),
+        (
+            format!("{}\n    name: Bad", with_path(variants.into())),
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (with_path("variants: {}".into()), EMPTY_LIST),
+        (with_path("variants: [ERR2]".into()), BAD_VALUE_TYPE),
+        (with_path("variants: {ERR2: Two}".into()), MISSING_ATTRIBUTE),
+        (with_path(variants.replace("ERR2:", "ERR3:")), BAD_REFERENCE),
+        (
+            with_path(variants.replace("Cat1::ERR1", "ERR1")),
+            BAD_REFERENCE,
+        ),
+        (
+            with_path(variants.replace("ERR2:", "Cat1::ERR2: Dup, ERR2:")),
+            NON_UNIQUE_NAME,
+        ),
+        (
+            with_path(variants.replace("Two", "two")),
+            BAD_IDENTIFIER_CASE,
+        ),
+        ("Error".into(), BAD_VALUE_TYPE),
+    ] {
+        let s = spec_str(&bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_display_period() {
+    log_init();
+
+    let spec_str = |attrs: &str| {
+        format!(
+            "
+---
+module:
+  {attrs}
+
+errors:
+  - PERIOD: Display with a period.
+  - QUESTION: Is it a display?
+  - EXCLAMATION: A display!
+  - name: NO_DISPLAY
+  - NO_PERIOD: Display without a period
+"
+        )
+    };
+    let displays = |s: &str| {
+        let spec = YamlParser::parse_str(s, false).unwrap();
+        spec.modules[0]
+            .errors_iter()
+            .map(|e| e.display.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let s = spec_str("enforce_display_period: true");
+    assert_eq!(
+        YamlParser::parse_str(&s, false).unwrap_err().kind(),
+        BAD_DISPLAY_STRING
+    );
+
+    let s = spec_str("enforce_display_period: false");
+    assert_eq!(displays(&s)[4].as_deref(), Some("Display without a period"));
+
+    let expected = [
+        Some("Display with a period."),
+        Some("Is it a display?"),
+        Some("A display!"),
+        None,
+        Some("Display without a period."),
+    ];
+    for attrs in [
+        "normalize_display_period: true",
+        "normalize_display_period: true\n  enforce_display_period: true",
+    ] {
+        let s = spec_str(attrs);
+        assert_eq!(displays(&s), expected.map(|d| d.map(String::from)));
+    }
+
+    let s = spec_str("enforce_display_period: true").replace(" without a period", ".");
+    assert!(YamlParser::parse_str(&s, false).is_ok());
+}
+
+#[test]
+fn test_module_display_case_keeps_displays() {
+    log_init();
+
+    let spec_str = |attrs: &str| {
+        format!(
+            "
+---
+module:
+  {attrs}
+
+errors:
+  - LOWER: a lowercase display
+  - UPPER: An uppercase display
+  - ACRONYM: I/O failed
+  - name: NO_DISPLAY
+  - NUMERIC: 42 is not an answer
+"
+        )
+    };
+    let displays = |s: &str| {
+        let spec = YamlParser::parse_str(s, false).unwrap();
+        spec.modules[0]
+            .errors_iter()
+            .map(|e| e.display.clone())
+            .collect::<Vec<_>>()
+    };
+
+    let expected = [
+        Some("a lowercase display"),
+        Some("An uppercase display"),
+        Some("I/O failed"),
+        None,
+        Some("42 is not an answer"),
+    ];
+    for attrs in [
+        "display_case: sentence",
+        "display_case: lower",
+        "display_case: none",
+        "display_case: sentence\n  display_is_message_id: true\n  display_resolver: crate::msg",
+    ] {
+        let s = spec_str(attrs);
+        assert_eq!(displays(&s), expected.map(|d| d.map(String::from)));
+    }
+
+    let s = spec_str("display_case: sentence\n  normalize_display_period: true");
+    assert_eq!(displays(&s)[0].as_deref(), Some("a lowercase display."));
+}
+
+#[test]
+fn test_display_category() {
+    log_init();
+
+    let s = r#"
+---
+categories:
+  - name: Parser
+    errors:
+      - BAD_TOKEN: "{category} failed."
+      - QUEUE_FULL: "{{category}} is {{literal}}, {category}}} and {{{category}."
+      - TIMEOUT: "{unknown} {category"
+  - name: Processor
+    errors:
+      - BAD_TOKEN: "{category}: {category}."
+"#;
+    let spec = YamlParser::parse_str(s, false).unwrap();
+    let displays = spec.modules[0]
+        .errors_iter()
+        .map(|e| e.display.clone().unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(
+        displays,
+        [
+            "Parser failed.",
+            "{category} is {literal}, Parser} and {Parser.",
+            "{unknown} {category",
+            "Processor: Processor.",
+        ]
+    );
+
+    let s = "---\nmodule:\n  enforce_display_period: true\nerrors:\n  - BAD_ARG: \"{category}\"";
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        BAD_DISPLAY_STRING
+    );
+}
+
+#[test]
+fn test_module_allows() {
+    log_init();
+
+    for (good, allows) in ["[]", "[dead_code]", "[clippy::all, dead_code]"]
+        .into_iter()
+        .zip([vec![], vec!["dead_code"], vec!["clippy::all", "dead_code"]])
+    {
+        let s = format!("---\nmodule:\n  allows: {}\n\nerrors:\n  - DUMMY_ERR", good);
+        let module = ModuleSpec {
+            allows: allows.into_iter().map(String::from).collect(),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("[Clippy::All]", BAD_VALUE),
+        ("[\"clippy::\"]", BAD_VALUE),
+        ("[\"clippy::a::b\"]", BAD_VALUE),
+        ("[\"dead code\"]", BAD_VALUE),
+        ("[dead_code, dead_code]", NON_UNIQUE_NAME),
+        ("dead_code", BAD_VALUE_TYPE),
+        ("[1]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  allows: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_map_by_name() {
+    log_init();
+    let spec_str = |map: &str, errors: &str| {
+        format!(
+            "
+---
+modules:
+  - name: errors
+    categories:
+      - name: Parser
+        errors: [BAD_TOKEN, QUEUE_FULL]
+      - name: General
+        errors: [QUEUE_FULL, TIMEOUT]
+  - name: internal_errors
+    map_by_name: {map}
+    categories:
+      - name: Parser
+        errors: [QUEUE_FULL]
+      - name: Io
+        errors: [{errors}]
+"
+        )
+    };
+
+    let spec = YamlParser::parse_str(&spec_str("[errors]", "TIMEOUT"), false).unwrap();
+    assert_eq!(spec.modules[1].map_by_name, ["errors"]);
+
+    for (map, errors, kind) in [
+        ("[errors]", "QUEUE_FULL", BAD_REFERENCE),
+        ("[errors]", "BAD_FILE", BAD_REFERENCE),
+        ("[unknown_errors]", "TIMEOUT", BAD_REFERENCE),
+        ("[internal_errors]", "TIMEOUT", BAD_REFERENCE),
+        ("[errors, errors]", "TIMEOUT", NON_UNIQUE_NAME),
+        ("errors", "TIMEOUT", BAD_VALUE_TYPE),
+    ] {
+        let s = spec_str(map, errors);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{map} {errors}"
+        );
+    }
+}
+
+#[test]
+fn test_module_extends() {
+    log_init();
+    let spec_str = |base: &str, mid: &str| {
+        format!(
+            "
+---
+modules:
+  - name: leaf_errors
+    extends: mid_errors
+    categories:
+      - name: General
+        errors: [TIMEOUT]
+  - name: mid_errors
+    extends: {mid}
+    error_trait: true
+    categories:
+      - name: General
+        errors: [QUEUE_FULL]
+  - name: base_errors{base}
+    doc: Base errors.
+    flat_kinds: true
+    error_trait: false
+    allows: [dead_code]
+    categories:
+      - name: General
+        errors: [BAD_TOKEN]
+"
+        )
+    };
+
+    let spec = YamlParser::parse_str(&spec_str("", "base_errors"), false).unwrap();
+    let leaf = &spec.modules[0];
+    assert_eq!(leaf.extends.as_deref(), Some("mid_errors"));
+    assert_eq!(leaf.flat_kinds, Some(true));
+    assert_eq!(leaf.error_trait, Some(true));
+    assert_eq!(leaf.allows, ["dead_code"]);
+    assert_eq!(leaf.doc, None);
+    assert_eq!(leaf.categories[0].errors[0].name, "TIMEOUT");
+    assert_eq!(spec.modules[1].error_trait, Some(true));
+    assert_eq!(spec.modules[2].error_trait, Some(false));
+
+    for (base, mid, kind) in [
+        ("", "unknown_errors", BAD_REFERENCE),
+        ("", "mid_errors", EXTENDS_CYCLE),
+        ("\n    extends: leaf_errors", "base_errors", EXTENDS_CYCLE),
+        ("", "BadErrors", BAD_NAME),
+        ("", "[base_errors]", BAD_VALUE_TYPE),
+    ] {
+        let s = spec_str(base, mid);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{base:?} {mid}"
+        );
+    }
+
+    let s = "---\nmodule:\n  extends: errors\n\nerrors:\n  - DUMMY_ERR";
+    assert_eq!(
+        YamlParser::parse_str(s, false).unwrap_err().kind(),
+        BAD_OBJECT_ATTRIBUTE
+    );
+}
+
+#[test]
+fn test_module_value_widths() {
+    log_init();
+
+    for (good, widths) in ["[]", "[8]", "[64, 16]"]
+        .into_iter()
+        .zip([vec![], vec![8], vec![64, 16]])
+    {
+        let s = format!(
+            "---\nmodule:\n  value_widths: {}\n\nerrors:\n  - DUMMY_ERR",
+            good
+        );
+        let module = ModuleSpec {
+            value_widths: widths,
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("[0]", BAD_VALUE),
+        ("[12]", BAD_VALUE),
+        ("[128]", BAD_VALUE),
+        ("[-8]", BAD_VALUE),
+        ("[8, 8]", BAD_VALUE),
+        ("8", BAD_VALUE_TYPE),
+        ("[u8]", BAD_VALUE_TYPE),
+    ] {
+        let s = format!(
+            "---\nmodule:\n  value_widths: {}\n\nerrors:\n  - DUMMY_ERR",
+            bad
+        );
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_module_feature() {
+    log_init();
+
+    for good in ["std", "serde_json", "tokio-1", "v1.2", "a+b", "2d"] {
+        let s = format!(
+            "---\nmodule:\n  feature: {}\n\nerrors:\n  - DUMMY_ERR",
+            good
+        );
+        let module = ModuleSpec {
+            feature: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"\"", BAD_VALUE),
+        ("\"-std\"", BAD_VALUE),
+        ("\"my feature\"", BAD_VALUE),
+        ("\"dep:serde\"", BAD_VALUE),
+        ("[std]", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  feature: {}\n\nerrors:\n  - DUMMY_ERR", bad);
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
+}
+
+#[test]
+fn test_lenient() {
+    log_init();
+
+    let good = YamlParser::parse_str("---\nerrors:\n  - DUMMY_ERR", false).unwrap();
+    for (s, kind) in [
+        (
+            "---\nfoo: bar\nerrors:\n  - DUMMY_ERR",
+            BAD_ROOT_LEVEL_KEYWORD,
+        ),
+        (
+            "---\nmain:\n  foo: bar\nerrors:\n  - DUMMY_ERR",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "---\nmodule:\n  foo: bar\nerrors:\n  - DUMMY_ERR",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "---\ncategory:\n  foo: bar\nerrors:\n  - DUMMY_ERR",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+        (
+            "---\nerrors:\n  - name: DUMMY_ERR\n    foo: bar",
+            BAD_OBJECT_ATTRIBUTE,
+        ),
+    ] {
+        assert_eq!(
+            YamlParser::parse_str(s, false).unwrap_err().kind(),
+            kind,
+            "{s}"
+        );
+        assert_eq!(YamlParser::parse_str(s, true).unwrap(), good);
+    }
+}
+
+#[test]
+fn test_module_runtime_path() {
+    log_init();
+
+    for good in [
+        "tighterror",
+        "::tighterror",
+        "crate::rt",
+        "my_errors::tighterror",
+    ] {
+        let s = format!("---\nmodule:\n  runtime_path: \"{good}\"\n\nerrors:\n  - DUMMY_ERR");
+        let module = ModuleSpec {
+            runtime_path: Some(good.into()),
+            ..Default::default()
+        };
+        let spec = spec_from_module(module);
+        let res = YamlParser::parse_str(&s, false).unwrap();
+        assert_eq!(spec, res);
+    }
+
+    for (bad, kind) in [
+        ("\"\"", BAD_VALUE),
+        ("\"not a path\"", BAD_VALUE),
+        ("\"tighterror::\"", BAD_VALUE),
+        ("[tighterror]", BAD_VALUE_TYPE),
+        ("true", BAD_VALUE_TYPE),
+    ] {
+        let s = format!("---\nmodule:\n  runtime_path: {bad}\n\nerrors:\n  - DUMMY_ERR");
+        assert_eq!(
+            YamlParser::parse_str(&s, false).unwrap_err().kind(),
+            kind,
+            "{bad}"
+        );
+    }
 }
@@ -1,12 +1,18 @@
 use crate::coder::idents;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 mod error;
 pub use error::*;
 
+mod bridge;
+pub use bridge::*;
+
 mod category;
 pub use category::*;
 
+mod group;
+pub use group::*;
+
 pub mod definitions;
 
 mod main;
@@ -16,11 +22,51 @@ mod module;
 pub use module::*;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct Spec {
     /// The specification file path
     pub path: PathBuf,
+    /// The SHA-256 hash of the specification source, hex-encoded
+    pub hash: String,
     /// The main spec
     pub main: MainSpec,
     /// A list of tighterror module specs
     pub modules: Vec<ModuleSpec>,
 }
+
+#[cfg(feature = "serde")]
+impl Spec {
+    /// Sets the attributes missing in the specification to the values
+    /// used by the code generator.
+    pub fn resolve_defaults(&mut self) {
+        let no_std = self.main.no_std;
+        for m in &mut self.modules {
+            m.resolve_defaults(no_std);
+        }
+        self.main.no_std = Some(self.main.no_std());
+    }
+}
+
+/// Resolves a path relative to the specification file.
+///
+/// An absolute path is returned as is.
+pub fn spec_relative_path(spec_path: &Path, path: &Path) -> PathBuf {
+    if path.is_absolute() {
+        return path.to_owned();
+    }
+    if let Some(sp) = spec_path.parent() {
+        return sp.join(path);
+    }
+    path.to_owned()
+}
+
+/// Checks if a generated unit-test is selected by a test selector.
+///
+/// A selector selects the test with the same name, and all the tests
+/// whose name ends with `_<selector>`, e.g., `uniqueness` selects both
+/// `category_uniqueness` and `err_kind_uniqueness`.
+pub fn test_selector_matches(selector: &str, test_name: &str) -> bool {
+    test_name
+        .strip_suffix(selector)
+        .is_some_and(|p| p.is_empty() || p.ends_with('_'))
+}
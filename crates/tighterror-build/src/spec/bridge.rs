@@ -0,0 +1,10 @@
+/// A conversion of error kinds into variants of an external enum
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct BridgeEnumSpec {
+    /// The Rust path of the enum, e.g., `crate::legacy::Error`
+    pub path: String,
+    /// Pairs of an error reference, optionally qualified by category name,
+    /// and an enum variant name
+    pub variants: Vec<(String, String)>,
+}
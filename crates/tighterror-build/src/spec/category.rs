@@ -3,15 +3,25 @@ use crate::{
     spec::{ErrorSpec, OverridableErrorSpec},
 };
 use convert_case::Case::{Snake, UpperCamel, UpperSnake};
+use std::path::PathBuf;
 
 pub const IMPLICIT_CATEGORY_NAME: &str = "General";
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct CategorySpec {
     pub name: String,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub oes: OverridableErrorSpec,
     /// Category constant's documentation
     pub doc: Option<String>,
+    /// A file to read `doc` from, relative to the specification file
+    pub doc_file: Option<PathBuf>,
+    /// Category ordinal pinned regardless of declaration order
+    pub index: Option<usize>,
+    /// Sub-categories the category's errors may be nested under
+    pub subcategories: Vec<SubcategorySpec>,
+    /// Category errors, including the errors of its sub-categories
     pub errors: Vec<ErrorSpec>,
 }
 
@@ -24,6 +34,16 @@ impl CategorySpec {
         casing::convert_case(&self.name, UpperCamel, Snake)
     }
 
+    /// Returns the errors nested under a sub-category, in declaration order.
+    pub fn subcategory_errors<'a>(
+        &'a self,
+        s: &'a SubcategorySpec,
+    ) -> impl Iterator<Item = &'a ErrorSpec> {
+        self.errors
+            .iter()
+            .filter(|e| e.subcategory.as_deref() == Some(s.name.as_str()))
+    }
+
     pub fn implicit_with_errors(errors: Vec<ErrorSpec>) -> Self {
         Self {
             name: IMPLICIT_CATEGORY_NAME.into(),
@@ -32,3 +52,17 @@ impl CategorySpec {
         }
     }
 }
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct SubcategorySpec {
+    pub name: String,
+    /// Sub-category module's documentation
+    pub doc: Option<String>,
+}
+
+impl SubcategorySpec {
+    pub fn module_name(&self) -> String {
+        casing::convert_case(&self.name, UpperCamel, Snake)
+    }
+}
@@ -1,5 +1,9 @@
+use super::{DisplayCase, DisplayCodeRadix, OrderBy, SerdeRepr};
+use crate::coder::{Edition, OutputMode};
+
 pub const STDOUT_PATH: &str = "-";
-pub const IMPLICIT_FILENAME: &str = "tighterror.rs";
+pub const STDIN_PATH: &str = "-";
+pub const IMPLICIT_FILE_STEM: &str = "tighterror";
 pub const DEFAULT_MODULE_DOC: &str = "";
 pub const DEFAULT_ERROR_STRUCT_DOC: &str =
     "Error type.\n\nSee the [kinds] module for error kind constants.";
@@ -14,8 +18,107 @@ pub const DEFAULT_TEST: bool = false;
 pub const DEFAULT_ERR_INTO_RESULT: bool = true;
 pub const DEFAULT_ERR_KIND_INTO_RESULT: bool = true;
 pub const DEFAULT_ERROR_TRAIT: bool = true;
+pub const DEFAULT_CORE_ERROR: bool = false;
 pub const DEFAULT_UPDATE_MODE: bool = false;
+pub const DEFAULT_HEADER: bool = false;
+pub const DEFAULT_MODULE_FILENAME: bool = false;
 pub const DEFAULT_NO_STD: bool = false;
 pub const DEFAULT_FLAT_KINDS: bool = false;
+pub const DEFAULT_PRELUDE: bool = false;
 pub const DEFAULT_SEPARATE_FILES: bool = false;
+pub const DEFAULT_STRICT: bool = false;
+pub const DEFAULT_STRICT_DOCS: bool = false;
+pub const DEFAULT_REQUIRE_DISPLAY: bool = false;
+pub const DEFAULT_SPLIT_TABLES: bool = false;
+pub const DEFAULT_LENIENT: bool = false;
+pub const DEFAULT_OUTPUT_EXT: &str = "rs";
+pub const OUTPUT_PATTERN_PLACEHOLDER: &str = "{module}";
+pub const TABLES_FILE_SUFFIX: &str = "_tables";
+/// Names of the generated unit-tests, without the `test_` prefix
+pub const GENERATED_TESTS: &[&str] = &[
+    "category_name",
+    "category_display",
+    "category_uniqueness",
+    "category_values",
+    "category_from_value",
+    "category_from_name",
+    "category_from_str",
+    "category_kind_count",
+    "category_sets",
+    "category_kinds",
+    "category_slices",
+    "category_default",
+    "err_kind_name",
+    "err_kind_qualified_name",
+    "err_kind_display",
+    "catalog",
+    "err_kind_uniqueness",
+    "err_kind_value_uniqueness",
+    "err_kind_hash",
+    "err_kind_matches",
+    "err_kind_category",
+    "err_kind_is_in",
+    "map_by_name",
+    "err_kind_assoc_consts",
+    "prelude",
+    "err_kind_iter",
+    "err_kind_from_value",
+    "err_kind_try_from_value",
+    "err_kind_variant",
+    "err_kind_into_value",
+    "err_kind_debug_stable",
+    "err_kind_from_str",
+    "err_kind_from_name",
+    "err_kind_arbitrary",
+    "err_kind_proptest",
+    "err_kind_serde",
+    "err_kind_ord",
+    "err_kind_index",
+    "err_kind_category_index",
+    "err_kind_from_value_unchecked",
+    "err_kind_default",
+    "value_widths",
+    "err_kind_groups",
+    "err_display",
+    "err_message",
+    "err_boxed",
+    "display_padding",
+    "display_unpadded",
+    "err_debug_location",
+    "bail",
+    "err_hash",
+    "err_from_kind_ref",
+    "err_kind_error",
+    "result_from_err",
+    "result_from_err_kind",
+    "variant_types_display",
+    "variant_types_to_kind",
+    "variant_types_to_error",
+    "variant_types_to_result",
+];
+pub const DEFAULT_OUTPUT_MODE: OutputMode = OutputMode::Overwrite;
+pub const DEFAULT_EDITION: Edition = Edition::Edition2021;
 pub const DEFAULT_VARIANT_TYPE: bool = false;
+pub const DEFAULT_LOCATION: bool = false;
+pub const DEFAULT_ARBITRARY: bool = false;
+pub const DEFAULT_PROPTEST: bool = false;
+pub const DEFAULT_KIND_TO_CAT: bool = false;
+pub const DEFAULT_KIND_ITER: bool = false;
+pub const DEFAULT_CATEGORY_SETS: bool = false;
+pub const DEFAULT_CATEGORY_DEFAULT: bool = false;
+pub const DEFAULT_DOC_CFG: bool = false;
+pub const DEFAULT_CATALOG: bool = false;
+pub const DEFAULT_ASSOC_CONSTS: bool = false;
+pub const DEFAULT_DEBUG_STABLE: bool = false;
+pub const DEFAULT_ALLOW_UNSAFE: bool = false;
+pub const DEFAULT_ERROR_DEFAULT: bool = false;
+pub const DEFAULT_ENFORCE_DISPLAY_PERIOD: bool = false;
+pub const DEFAULT_NORMALIZE_DISPLAY_PERIOD: bool = false;
+pub const DEFAULT_DISPLAY_CASE: DisplayCase = DisplayCase::None;
+pub const DEFAULT_DISPLAY_WITH_CODE: bool = false;
+pub const DEFAULT_DISPLAY_CODE_RADIX: DisplayCodeRadix = DisplayCodeRadix::Hex;
+pub const DEFAULT_DISPLAY_IS_MESSAGE_ID: bool = false;
+pub const DEFAULT_RUNTIME_PATH: &str = "tighterror";
+pub const DEFAULT_ORDER_BY: OrderBy = OrderBy::Value;
+pub const DEFAULT_SERDE: bool = false;
+pub const DEFAULT_SERDE_REPR: SerdeRepr = SerdeRepr::Name;
@@ -1,22 +1,44 @@
-use crate::common::casing;
+use crate::{common::casing, spec::definitions::DEFAULT_ERROR_DEFAULT};
 use convert_case::Case;
+use std::path::PathBuf;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct OverridableErrorSpec {
     pub doc_from_display: Option<bool>,
     pub variant_type: Option<bool>,
 }
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ErrorSpec {
     pub name: String,
     pub display: Option<String>,
     pub doc: Option<String>,
+    /// A file to read `doc` from, relative to the specification file
+    pub doc_file: Option<PathBuf>,
     pub variant_type_name: Option<String>,
+    /// The error kind returned by `impl Default for ErrorKind`
+    pub default: Option<bool>,
+    /// Variant ordinal pinned regardless of declaration order
+    pub index: Option<usize>,
+    /// The name of the sub-category the error is nested under
+    pub subcategory: Option<String>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub oes: OverridableErrorSpec,
 }
 
 impl ErrorSpec {
+    pub fn is_default(&self) -> bool {
+        self.default.unwrap_or(DEFAULT_ERROR_DEFAULT)
+    }
+
+    pub fn subcategory_module_name(&self) -> Option<String> {
+        self.subcategory
+            .as_deref()
+            .map(|s| casing::convert_case(s, Case::UpperCamel, Case::Snake))
+    }
+
     pub fn variant_type_name(&self) -> String {
         if let Some(ref vtn) = self.variant_type_name {
             vtn.clone()
@@ -0,0 +1,38 @@
+use crate::spec::{CategorySpec, ErrorSpec};
+
+/// The separator of a category-qualified error reference, e.g., `Parser::BAD_NAME`
+pub const GROUP_REF_SEPARATOR: &str = "::";
+
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct GroupSpec {
+    /// The name of the group in lower_snake_case
+    pub name: String,
+    /// References to group member errors, optionally qualified by category name
+    pub errors: Vec<String>,
+}
+
+impl GroupSpec {
+    pub fn method_name(&self) -> String {
+        format!("is_{}", self.name)
+    }
+}
+
+/// Resolves an error reference against a list of categories.
+///
+/// Returns all the matching errors. A valid reference matches exactly one.
+pub fn resolve_error_ref<'a>(
+    categories: &'a [CategorySpec],
+    r: &str,
+) -> Vec<(&'a CategorySpec, &'a ErrorSpec)> {
+    let (cat_name, err_name) = match r.split_once(GROUP_REF_SEPARATOR) {
+        Some((c, e)) => (Some(c), e),
+        None => (None, r),
+    };
+    categories
+        .iter()
+        .filter(|c| cat_name.map_or(true, |n| c.name == n))
+        .flat_map(|c| c.errors.iter().map(move |e| (c, e)))
+        .filter(|(_, e)| e.name == err_name)
+        .collect()
+}
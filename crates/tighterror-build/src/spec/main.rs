@@ -1,8 +1,12 @@
-use super::definitions::{DEFAULT_NO_STD, STDOUT_PATH};
+use super::{
+    definitions::{DEFAULT_NO_STD, STDOUT_PATH},
+    spec_relative_path,
+};
 use crate::errors::TbError;
 use std::path::{Path, PathBuf};
 
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct MainSpec {
     /// Output file path: relative to the specification file, or an
     /// absolute path.
@@ -11,6 +15,20 @@ pub struct MainSpec {
     pub no_std: Option<bool>,
     /// Write every module in a separate file
     pub separate_files: Option<bool>,
+    /// The module file path pattern in separate-files mode, relative to
+    /// the output directory
+    pub output_pattern: Option<String>,
+    /// Include the module unit-tests, overridden by the `test` option
+    pub test: Option<bool>,
+    /// Selectors of the generated unit-tests to include, overridden by the
+    /// `tests` option
+    pub tests: Option<Vec<String>>,
+    /// Require an explicit display string of every error, overridden by
+    /// the `require_display` option
+    pub require_display: Option<bool>,
+    /// Write the private name, display and doc tables of every module in
+    /// a separate file, overridden by the `split_tables` option
+    pub split_tables: Option<bool>,
 }
 
 impl MainSpec {
@@ -31,11 +49,5 @@ impl MainSpec {
 }
 
 fn output_path(spec_path: &Path, output: &Path) -> Result<PathBuf, TbError> {
-    if output.is_absolute() {
-        return Ok(output.to_owned());
-    }
-    if let Some(sp) = spec_path.parent() {
-        return Ok(sp.join(output));
-    }
-    Ok(output.to_owned())
+    Ok(spec_relative_path(spec_path, output))
 }
@@ -1,13 +1,70 @@
-use super::{definitions::*, idents, CategorySpec, ErrorSpec, OverridableErrorSpec};
+use super::{
+    definitions::*, idents, resolve_error_ref, BridgeEnumSpec, CategorySpec, ErrorSpec, GroupSpec,
+    OverridableErrorSpec, GROUP_REF_SEPARATOR,
+};
+use crate::common::casing;
+use convert_case::Case;
+use std::path::PathBuf;
 
 pub const IMPLICIT_MODULE_NAME: &str = "errors";
 
+/// The ordering policy of the ErrorKind struct
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum OrderBy {
+    /// Order by the error kind name
+    Name,
+    /// Order by the error kind value
+    Value,
+}
+
+/// The serialization format of the ErrorKind struct
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum SerdeRepr {
+    /// Serialize the error kind name
+    Name,
+    /// Serialize the error kind value
+    Value,
+}
+
+/// The casing normalization of display strings
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum DisplayCase {
+    /// Capitalize the first letter
+    Sentence,
+    /// Lowercase the first letter, unless it starts an acronym
+    Lower,
+    /// Keep display strings as is
+    None,
+}
+
+/// The radix of the error kind value appended to display strings
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum DisplayCodeRadix {
+    /// Hexadecimal with the `0x` prefix
+    Hex,
+    /// Decimal
+    Decimal,
+}
+
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct ModuleSpec {
     /// The name of the module
     pub name: Option<String>,
+    /// The name of a module to inherit unset attributes from
+    pub extends: Option<String>,
     /// Module documentation
     pub doc: Option<String>,
+    /// A file to read `doc` from, relative to the specification file
+    pub doc_file: Option<PathBuf>,
     /// Error struct's documentation
     pub err_doc: Option<String>,
     /// ErrorKind struct's documentation
@@ -20,17 +77,87 @@ pub struct ModuleSpec {
     pub result_from_err_kind: Option<bool>,
     /// Add `impl std::error::Error for Error`
     pub error_trait: Option<bool>,
+    /// Implement `core::error::Error` instead of `std::error::Error`
+    pub core_error: Option<bool>,
     /// A custom name for the Error struct
     pub err_name: Option<String>,
     /// A custom name for the ErrorKind struct
     pub err_kind_name: Option<String>,
     /// A custom name for the ErrorCategory struct
     pub err_cat_name: Option<String>,
+    /// A custom name for the error kind constants module
+    pub kinds_mod_name: Option<String>,
+    /// A custom name for the error category constants module
+    pub categories_mod_name: Option<String>,
+    #[cfg_attr(feature = "serde", serde(flatten))]
     pub oes: OverridableErrorSpec,
     /// Place the error kind consts under `mod kind`
     /// and not under `mod kind::<category_mod>`.
     /// Requires all error names to be unique per module.
     pub flat_kinds: Option<bool>,
+    /// Add `mod prelude` re-exporting all the error kind consts.
+    /// Requires all error names to be unique per module.
+    pub prelude: Option<bool>,
+    /// Capture the source location of an error when it is created
+    pub location: Option<bool>,
+    /// Add `impl arbitrary::Arbitrary for ErrorKind` behind `arbitrary` feature
+    pub arbitrary: Option<bool>,
+    /// Add a `proptest` strategy for ErrorKind behind `proptest` feature
+    pub proptest: Option<bool>,
+    /// The ordering policy of `impl Ord for ErrorKind`
+    pub order_by: Option<OrderBy>,
+    /// Add `impl Serialize/Deserialize for ErrorKind` behind `serde` feature
+    pub serde: Option<bool>,
+    /// The serialization format of `impl Serialize for ErrorKind`
+    pub serde_repr: Option<SerdeRepr>,
+    /// Add a kind-to-category lookup table and `ErrorKind::category_index`
+    pub kind_to_cat: Option<bool>,
+    /// Add `ErrorKind::VARIANTS` and `ErrorKind::iter`
+    pub kind_iter: Option<bool>,
+    /// Add a category set type built with `impl BitOr for ErrorCategory`
+    pub category_sets: Option<bool>,
+    /// Add `impl Default for ErrorCategory` returning the first category
+    pub category_default: Option<bool>,
+    /// Annotate feature-gated items with `#[cfg_attr(docsrs, doc(cfg(...)))]`
+    pub doc_cfg: Option<bool>,
+    /// Add a static `CATALOG` table of the errors' `(name, display, doc)` tuples
+    pub catalog: Option<bool>,
+    /// Add the error kind constants as associated constants of `ErrorKind`
+    pub assoc_consts: Option<bool>,
+    /// Omit the error kind value from `impl Debug for ErrorKind`
+    pub debug_stable: Option<bool>,
+    /// Allow generation of `unsafe` code, e.g., `ErrorKind::from_value_unchecked`
+    pub allow_unsafe: Option<bool>,
+    /// Require every display string to end with a terminal punctuation mark
+    pub enforce_display_period: Option<bool>,
+    /// Append a period to display strings without a terminal punctuation mark
+    pub normalize_display_period: Option<bool>,
+    /// The casing normalization of display strings
+    pub display_case: Option<DisplayCase>,
+    /// Append the error kind value to `impl Display for Error/ErrorKind`
+    pub display_with_code: Option<bool>,
+    /// The radix of the error kind value appended by `display_with_code`
+    pub display_code_radix: Option<DisplayCodeRadix>,
+    /// Treat display strings as message ids resolved at runtime
+    pub display_is_message_id: Option<bool>,
+    /// Path of the function resolving a message id into a display value
+    pub display_resolver: Option<String>,
+    /// Path of the `tighterror` runtime crate in the generated code
+    pub runtime_path: Option<String>,
+    /// Include the module unit-test, overrides the global `test` option
+    pub test: Option<bool>,
+    /// Lints to allow in the generated module
+    pub allows: Vec<String>,
+    /// Fixed widths, in bits, of additional `value_u<N>` accessors
+    pub value_widths: Vec<usize>,
+    /// Crate feature the module is conditionally compiled on
+    pub feature: Option<String>,
+    /// Named groups of errors with membership test methods
+    pub groups: Vec<GroupSpec>,
+    /// Conversion of error kinds into variants of an external enum
+    pub bridge_enum: Option<BridgeEnumSpec>,
+    /// Modules the error kinds are converted into by matching names
+    pub map_by_name: Vec<String>,
     /// Module categories
     pub categories: Vec<CategorySpec>,
 }
@@ -43,13 +170,143 @@ impl ModuleSpec {
         }
     }
 
-    pub fn errors_iter(&self) -> ModuleSpecErrorIter {
+    /// Copies the attributes not set in this module from `base`.
+    ///
+    /// The module identity, i.e., its name and documentation, and
+    /// attributes referencing the module's own errors aren't inherited.
+    pub fn inherit(&mut self, base: &ModuleSpec) {
+        macro_rules! inherit {
+            ($($($f:ident).+),+ $(,)?) => {
+                $(
+                    if self.$($f).+.is_none() {
+                        self.$($f).+.clone_from(&base.$($f).+);
+                    }
+                )+
+            };
+        }
+
+        inherit!(
+            err_doc,
+            err_kind_doc,
+            err_cat_doc,
+            result_from_err,
+            result_from_err_kind,
+            error_trait,
+            core_error,
+            err_name,
+            err_kind_name,
+            err_cat_name,
+            kinds_mod_name,
+            categories_mod_name,
+            oes.doc_from_display,
+            oes.variant_type,
+            flat_kinds,
+            prelude,
+            location,
+            arbitrary,
+            proptest,
+            order_by,
+            serde,
+            serde_repr,
+            kind_to_cat,
+            kind_iter,
+            category_sets,
+            category_default,
+            doc_cfg,
+            catalog,
+            assoc_consts,
+            debug_stable,
+            allow_unsafe,
+            enforce_display_period,
+            normalize_display_period,
+            display_case,
+            display_with_code,
+            display_code_radix,
+            display_is_message_id,
+            display_resolver,
+            runtime_path,
+            test,
+            feature,
+        );
+
+        if self.allows.is_empty() {
+            self.allows.clone_from(&base.allows);
+        }
+        if self.value_widths.is_empty() {
+            self.value_widths.clone_from(&base.value_widths);
+        }
+    }
+
+    pub fn errors_iter(&self) -> ModuleSpecErrorIter<'_> {
         ModuleSpecErrorIter {
             categories: self.categories.iter(),
             errors: [].iter(),
         }
     }
 
+    pub fn default_error(&self) -> Option<(&CategorySpec, &ErrorSpec)> {
+        self.categories
+            .iter()
+            .flat_map(|c| c.errors.iter().map(move |e| (c, e)))
+            .find(|(_, e)| e.is_default())
+    }
+
+    /// Resolves group member references into `(category, error)` pairs.
+    ///
+    /// Must be called on a validated specification only.
+    pub fn group_members<'a>(&'a self, g: &'a GroupSpec) -> Vec<(&'a CategorySpec, &'a ErrorSpec)> {
+        g.errors
+            .iter()
+            .map(|r| {
+                let mut found = resolve_error_ref(&self.categories, r);
+                debug_assert_eq!(found.len(), 1);
+                found.swap_remove(0)
+            })
+            .collect()
+    }
+
+    /// Resolves bridge enum variant references into `(category, error, variant)`
+    /// tuples.
+    ///
+    /// Must be called on a validated specification only.
+    pub fn bridge_enum_variants<'a>(
+        &'a self,
+        b: &'a BridgeEnumSpec,
+    ) -> Vec<(&'a CategorySpec, &'a ErrorSpec, &'a str)> {
+        b.variants
+            .iter()
+            .map(|(r, v)| {
+                let mut found = resolve_error_ref(&self.categories, r);
+                debug_assert_eq!(found.len(), 1);
+                let (c, e) = found.swap_remove(0);
+                (c, e, v.as_str())
+            })
+            .collect()
+    }
+
+    /// Resolves the same-named error of the `target` module for every error
+    /// of this module, as `(category, error, target category, target error)`.
+    pub fn map_by_name_errors<'a>(
+        &'a self,
+        target: &'a ModuleSpec,
+    ) -> Vec<(
+        &'a CategorySpec,
+        &'a ErrorSpec,
+        &'a CategorySpec,
+        &'a ErrorSpec,
+    )> {
+        self.categories
+            .iter()
+            .flat_map(|c| c.errors.iter().map(move |e| (c, e)))
+            .map(|(c, e)| {
+                let mut found = target.resolve_same_name(c, e);
+                debug_assert_eq!(found.len(), 1);
+                let (tc, te) = found.swap_remove(0);
+                (c, e, tc, te)
+            })
+            .collect()
+    }
+
     pub fn n_errors_in_largest_category(&self) -> Option<usize> {
         self.categories.iter().map(|c| c.errors.len()).max()
     }
@@ -78,20 +335,21 @@ impl ModuleSpec {
             return doc;
         }
 
-        let dfd = e
-            .oes
-            .doc_from_display
-            .or(c.oes.doc_from_display)
-            .or(self.oes.doc_from_display)
-            .unwrap_or(DEFAULT_DOC_FROM_DISPLAY);
-
-        if dfd {
+        if self.err_doc_from_display(c, e) {
             e.display.as_deref().unwrap_or(DEFAULT_ERROR_KIND_CONST_DOC)
         } else {
             DEFAULT_ERROR_KIND_CONST_DOC
         }
     }
 
+    pub fn err_doc_from_display(&self, c: &CategorySpec, e: &ErrorSpec) -> bool {
+        e.oes
+            .doc_from_display
+            .or(c.oes.doc_from_display)
+            .or(self.oes.doc_from_display)
+            .unwrap_or(DEFAULT_DOC_FROM_DISPLAY)
+    }
+
     pub fn err_kind_doc(&self) -> &str {
         self.err_kind_doc
             .as_deref()
@@ -121,12 +379,19 @@ impl ModuleSpec {
     }
 
     pub fn error_trait(&self, no_std: Option<bool>) -> bool {
+        if self.core_error() {
+            return self.error_trait.unwrap_or(DEFAULT_ERROR_TRAIT);
+        }
         no_std
             .map(|v| !v)
             .or(self.error_trait)
             .unwrap_or(DEFAULT_ERROR_TRAIT)
     }
 
+    pub fn core_error(&self) -> bool {
+        self.core_error.unwrap_or(DEFAULT_CORE_ERROR)
+    }
+
     pub fn err_name(&self) -> &str {
         self.err_name.as_deref().unwrap_or(idents::ERROR)
     }
@@ -141,10 +406,172 @@ impl ModuleSpec {
             .unwrap_or(idents::ERROR_CATEGORY)
     }
 
+    pub fn kinds_mod_name(&self) -> &str {
+        self.kinds_mod_name
+            .as_deref()
+            .unwrap_or(idents::ERROR_KINDS_MOD)
+    }
+
+    pub fn categories_mod_name(&self) -> &str {
+        self.categories_mod_name
+            .as_deref()
+            .unwrap_or(idents::CATEGORY_CONSTS_MOD)
+    }
+
     pub fn flat_kinds(&self) -> bool {
         self.flat_kinds.unwrap_or(DEFAULT_FLAT_KINDS)
     }
 
+    pub fn prelude(&self) -> bool {
+        self.prelude.unwrap_or(DEFAULT_PRELUDE)
+    }
+
+    pub fn location(&self) -> bool {
+        self.location.unwrap_or(DEFAULT_LOCATION)
+    }
+
+    pub fn arbitrary(&self) -> bool {
+        self.arbitrary.unwrap_or(DEFAULT_ARBITRARY)
+    }
+
+    pub fn proptest(&self) -> bool {
+        self.proptest.unwrap_or(DEFAULT_PROPTEST)
+    }
+
+    pub fn proptest_fn_name(&self) -> String {
+        format!(
+            "any_{}",
+            casing::convert_case(self.err_kind_name(), Case::UpperCamel, Case::Snake)
+        )
+    }
+
+    pub fn order_by(&self) -> OrderBy {
+        self.order_by.unwrap_or(DEFAULT_ORDER_BY)
+    }
+
+    pub fn serde(&self) -> bool {
+        self.serde.unwrap_or(DEFAULT_SERDE)
+    }
+
+    pub fn serde_repr(&self) -> SerdeRepr {
+        self.serde_repr.unwrap_or(DEFAULT_SERDE_REPR)
+    }
+
+    pub fn kind_to_cat(&self) -> bool {
+        self.kind_to_cat.unwrap_or(DEFAULT_KIND_TO_CAT)
+    }
+
+    pub fn kind_iter(&self) -> bool {
+        self.kind_iter.unwrap_or(DEFAULT_KIND_ITER)
+    }
+
+    pub fn category_sets(&self) -> bool {
+        self.category_sets.unwrap_or(DEFAULT_CATEGORY_SETS)
+    }
+
+    pub fn category_default(&self) -> bool {
+        self.category_default.unwrap_or(DEFAULT_CATEGORY_DEFAULT)
+    }
+
+    pub fn doc_cfg(&self) -> bool {
+        self.doc_cfg.unwrap_or(DEFAULT_DOC_CFG)
+    }
+
+    pub fn catalog(&self) -> bool {
+        self.catalog.unwrap_or(DEFAULT_CATALOG)
+    }
+
+    pub fn err_cat_set_name(&self) -> String {
+        format!("{}Set", self.err_cat_name())
+    }
+
+    pub fn err_cat_kinds_name(&self) -> String {
+        format!("{}Kinds", self.err_cat_name())
+    }
+
+    pub fn assoc_consts(&self) -> bool {
+        self.assoc_consts.unwrap_or(DEFAULT_ASSOC_CONSTS)
+    }
+
+    pub fn debug_stable(&self) -> bool {
+        self.debug_stable.unwrap_or(DEFAULT_DEBUG_STABLE)
+    }
+
+    /// Returns the name of the error kind's associated constant.
+    ///
+    /// The name is prefixed with the category name unless `flat_kinds` is
+    /// enabled, mirroring the namespacing of the error kind constants module.
+    pub fn err_kind_assoc_const_name(&self, c: &CategorySpec, e: &ErrorSpec) -> String {
+        if self.flat_kinds() {
+            e.name.clone()
+        } else {
+            format!("{}_{}", c.ident_name(), e.name)
+        }
+    }
+
+    /// Resolves the error of this module with the same name as the error `e`
+    /// of category `c` of another module.
+    ///
+    /// An error in the same-named category is preferred, otherwise all the
+    /// errors with the same name are returned.
+    pub fn resolve_same_name(
+        &self,
+        c: &CategorySpec,
+        e: &ErrorSpec,
+    ) -> Vec<(&CategorySpec, &ErrorSpec)> {
+        let qualified = format!("{}{}{}", c.name, GROUP_REF_SEPARATOR, e.name);
+        let resolved = resolve_error_ref(&self.categories, &qualified);
+        if resolved.is_empty() {
+            resolve_error_ref(&self.categories, &e.name)
+        } else {
+            resolved
+        }
+    }
+
+    pub fn enforce_display_period(&self) -> bool {
+        self.enforce_display_period
+            .unwrap_or(DEFAULT_ENFORCE_DISPLAY_PERIOD)
+    }
+
+    pub fn normalize_display_period(&self) -> bool {
+        self.normalize_display_period
+            .unwrap_or(DEFAULT_NORMALIZE_DISPLAY_PERIOD)
+    }
+
+    pub fn display_case(&self) -> DisplayCase {
+        self.display_case.unwrap_or(DEFAULT_DISPLAY_CASE)
+    }
+
+    pub fn display_with_code(&self) -> bool {
+        self.display_with_code.unwrap_or(DEFAULT_DISPLAY_WITH_CODE)
+    }
+
+    pub fn display_code_radix(&self) -> DisplayCodeRadix {
+        self.display_code_radix
+            .unwrap_or(DEFAULT_DISPLAY_CODE_RADIX)
+    }
+
+    pub fn display_is_message_id(&self) -> bool {
+        self.display_is_message_id
+            .unwrap_or(DEFAULT_DISPLAY_IS_MESSAGE_ID)
+    }
+
+    pub fn display_resolver(&self) -> Option<&str> {
+        self.display_resolver.as_deref()
+    }
+
+    pub fn runtime_path(&self) -> &str {
+        self.runtime_path.as_deref().unwrap_or(DEFAULT_RUNTIME_PATH)
+    }
+
+    pub fn test(&self, test: bool) -> bool {
+        self.test.unwrap_or(test)
+    }
+
+    pub fn allow_unsafe(&self) -> bool {
+        self.allow_unsafe.unwrap_or(DEFAULT_ALLOW_UNSAFE)
+    }
+
     pub fn has_variant_types(&self) -> bool {
         self.categories
             .iter()
@@ -174,6 +601,113 @@ impl ModuleSpec {
             .or(self.oes.variant_type)
             .unwrap_or(DEFAULT_VARIANT_TYPE)
     }
+
+    /// Sets the attributes missing in the specification to the values
+    /// used by the code generator.
+    ///
+    /// Attributes without a default value, i.e., `doc_file`,
+    /// `display_resolver`, `feature` and `bridge_enum`, stay as is. So does
+    /// `test`, which defaults to the global option. `extends` is cleared,
+    /// because the inherited attributes are copied into the module.
+    #[cfg(feature = "serde")]
+    pub fn resolve_defaults(&mut self, no_std: Option<bool>) {
+        let cat_docs: Vec<String> = self
+            .categories
+            .iter()
+            .map(|c| self.cat_const_doc(c).to_owned())
+            .collect();
+        let err_attrs: Vec<Vec<_>> = self
+            .categories
+            .iter()
+            .map(|c| {
+                c.errors
+                    .iter()
+                    .map(|e| {
+                        (
+                            self.err_kind_const_doc(c, e).to_owned(),
+                            self.err_doc_from_display(c, e),
+                            self.err_has_variant_type(c, e),
+                        )
+                    })
+                    .collect()
+            })
+            .collect();
+        for ((c, doc), attrs) in self.categories.iter_mut().zip(cat_docs).zip(err_attrs) {
+            c.doc = Some(doc);
+            for (e, (doc, doc_from_display, variant_type)) in c.errors.iter_mut().zip(attrs) {
+                e.doc = Some(doc);
+                e.default = Some(e.is_default());
+                e.oes.doc_from_display = Some(doc_from_display);
+                e.oes.variant_type = Some(variant_type);
+            }
+        }
+
+        // a struct literal, so a new attribute doesn't compile until resolved
+        *self = Self {
+            name: Some(self.name().to_owned()),
+            // the inherited attributes are copied already
+            extends: None,
+            doc: Some(self.doc().to_owned()),
+            // the file content is in `doc` already
+            doc_file: self.doc_file.take(),
+            err_doc: Some(self.err_doc().to_owned()),
+            err_kind_doc: Some(self.err_kind_doc().to_owned()),
+            err_cat_doc: Some(self.err_cat_doc().to_owned()),
+            result_from_err: Some(self.result_from_err()),
+            result_from_err_kind: Some(self.result_from_err_kind()),
+            error_trait: Some(self.error_trait(no_std)),
+            core_error: Some(self.core_error()),
+            err_name: Some(self.err_name().to_owned()),
+            err_kind_name: Some(self.err_kind_name().to_owned()),
+            err_cat_name: Some(self.err_cat_name().to_owned()),
+            kinds_mod_name: Some(self.kinds_mod_name().to_owned()),
+            categories_mod_name: Some(self.categories_mod_name().to_owned()),
+            oes: OverridableErrorSpec {
+                doc_from_display: Some(
+                    self.oes
+                        .doc_from_display
+                        .unwrap_or(DEFAULT_DOC_FROM_DISPLAY),
+                ),
+                variant_type: Some(self.oes.variant_type.unwrap_or(DEFAULT_VARIANT_TYPE)),
+            },
+            flat_kinds: Some(self.flat_kinds()),
+            prelude: Some(self.prelude()),
+            location: Some(self.location()),
+            arbitrary: Some(self.arbitrary()),
+            proptest: Some(self.proptest()),
+            order_by: Some(self.order_by()),
+            serde: Some(self.serde()),
+            serde_repr: Some(self.serde_repr()),
+            kind_to_cat: Some(self.kind_to_cat()),
+            kind_iter: Some(self.kind_iter()),
+            category_sets: Some(self.category_sets()),
+            category_default: Some(self.category_default()),
+            doc_cfg: Some(self.doc_cfg()),
+            catalog: Some(self.catalog()),
+            assoc_consts: Some(self.assoc_consts()),
+            debug_stable: Some(self.debug_stable()),
+            allow_unsafe: Some(self.allow_unsafe()),
+            enforce_display_period: Some(self.enforce_display_period()),
+            normalize_display_period: Some(self.normalize_display_period()),
+            display_case: Some(self.display_case()),
+            display_with_code: Some(self.display_with_code()),
+            display_code_radix: Some(self.display_code_radix()),
+            display_is_message_id: Some(self.display_is_message_id()),
+            // no resolver by default
+            display_resolver: self.display_resolver.take(),
+            runtime_path: Some(self.runtime_path().to_owned()),
+            // unset means the global `test` option
+            test: self.test,
+            allows: std::mem::take(&mut self.allows),
+            value_widths: std::mem::take(&mut self.value_widths),
+            // unset means an unconditional module
+            feature: self.feature.take(),
+            groups: std::mem::take(&mut self.groups),
+            bridge_enum: self.bridge_enum.take(),
+            map_by_name: std::mem::take(&mut self.map_by_name),
+            categories: std::mem::take(&mut self.categories),
+        };
+    }
 }
 
 pub struct ModuleSpecErrorIter<'a> {
@@ -0,0 +1,10 @@
+extern crate test_compilation;
+
+use test_compilation::errors::{kind::general::BAD_FILE, ErrorKind};
+
+fn main() {
+    let _kind = ErrorKind(0);
+    //~^ ERROR cannot initialize a tuple struct which contains private fields
+    let _value = BAD_FILE.0;
+    //~^ ERROR field `0` of struct `test_compilation::errors::ErrorKind` is private
+}
@@ -0,0 +1,36 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `arbitrary` module attribute.
+
+#![deny(warnings)]
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors::*, single_errors};
+    use arbitrary::{Arbitrary, Unstructured};
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_arbitrary_covers_all_kinds() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        let mut kinds = HashSet::new();
+        while !u.is_empty() {
+            kinds.insert(ErrorKind::arbitrary(&mut u).unwrap());
+        }
+        assert_eq!(kinds.len(), 5);
+        assert!(kinds.contains(&kind::parser::UNEXPECTED_EOF));
+        assert!(kinds.contains(&kind::processor::TIMEOUT));
+    }
+
+    #[test]
+    fn test_arbitrary_single_kind() {
+        let data: Vec<u8> = (0..=255).collect();
+        let mut u = Unstructured::new(&data);
+        for _ in 0..16 {
+            let k = single_errors::ErrorKind::arbitrary(&mut u).unwrap();
+            assert_eq!(k, single_errors::kind::general::BAD_TOKEN);
+        }
+    }
+}
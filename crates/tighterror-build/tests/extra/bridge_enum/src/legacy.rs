@@ -0,0 +1,17 @@
+//! The hand-rolled error type preserved as the public API.
+
+/// The legacy error enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    BadToken,
+    Timeout,
+    OutOfMemory,
+}
+
+/// A legacy public function returning the legacy error.
+///
+/// The implementation is migrated to tighterror, while the
+/// conversion keeps the signature intact.
+pub fn parse(input: &str) -> Result<(), Error> {
+    Ok(crate::parser::parse(input)?)
+}
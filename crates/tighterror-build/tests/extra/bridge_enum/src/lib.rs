@@ -0,0 +1,36 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `bridge_enum` module attribute.
+
+#![deny(warnings)]
+
+pub mod errors;
+pub mod legacy;
+mod parser;
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        errors::{kind::*, Error},
+        legacy,
+    };
+
+    #[test]
+    fn test_bridge_enum_from_kind() {
+        for (kind, variant) in [
+            (parser::BAD_TOKEN, legacy::Error::BadToken),
+            (parser::TIMEOUT, legacy::Error::Timeout),
+            (processor::OUT_OF_MEMORY, legacy::Error::OutOfMemory),
+            (processor::TIMEOUT, legacy::Error::Timeout),
+        ] {
+            assert_eq!(legacy::Error::from(kind), variant);
+            assert_eq!(legacy::Error::from(Error::from(kind)), variant);
+        }
+    }
+
+    #[test]
+    fn test_bridge_enum_question_mark() {
+        assert_eq!(legacy::parse("abc"), Ok(()));
+        assert_eq!(legacy::parse("a!c"), Err(legacy::Error::BadToken));
+        assert_eq!(legacy::parse(""), Err(legacy::Error::Timeout));
+    }
+}
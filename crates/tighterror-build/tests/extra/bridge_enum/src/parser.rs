@@ -0,0 +1,11 @@
+//! The migrated implementation using tighterror errors.
+
+use crate::errors::{kind::parser::*, Error};
+
+pub fn parse(input: &str) -> Result<(), Error> {
+    match input {
+        "" => TIMEOUT.into(),
+        s if s.contains('!') => BAD_TOKEN.into(),
+        _ => Ok(()),
+    }
+}
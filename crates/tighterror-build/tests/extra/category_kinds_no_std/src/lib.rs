@@ -0,0 +1,51 @@
+//! This is a test crate to check the error category kinds iterator
+//! in no_std environment.
+
+#![deny(warnings)]
+#![no_std]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+use errors::{ErrorCategoryKinds, ErrorKind};
+
+/// Returns the error kind at position `n` of the iterator.
+pub fn nth_kind(mut kinds: ErrorCategoryKinds, n: usize) -> Option<ErrorKind> {
+    kinds.nth(n)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::*;
+    use super::nth_kind;
+
+    #[test]
+    fn test_kinds() {
+        let mut kinds = category::PARSER.kinds();
+        assert_eq!(kinds.len(), 2);
+        assert_eq!(kinds.next(), Some(kind::parser::BAD_TOKEN));
+        assert_eq!(kinds.next(), Some(kind::parser::UNEXPECTED_EOF));
+        assert_eq!(kinds.next(), None);
+        assert_eq!(kinds.next(), None);
+    }
+
+    #[test]
+    fn test_kinds_into_iter() {
+        let mut n = 0;
+        for k in category::PROCESSOR {
+            assert_eq!(k, kind::processor::QUEUE_FULL);
+            n += 1;
+        }
+        assert_eq!(n, 1);
+    }
+
+    #[test]
+    fn test_kinds_named_type() {
+        assert_eq!(
+            nth_kind(category::PARSER.kinds(), 1),
+            Some(kind::parser::UNEXPECTED_EOF)
+        );
+    }
+}
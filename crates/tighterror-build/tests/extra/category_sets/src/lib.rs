@@ -0,0 +1,33 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `category_sets` and `category_default` module attributes.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{category::*, ErrorCategory, ErrorCategorySet};
+
+    #[test]
+    fn test_category_sets() {
+        let set = PARSER | PROCESSOR;
+        assert!(set.contains(PARSER));
+        assert!(set.contains(PROCESSOR));
+        assert!(!set.contains(GENERAL));
+        assert_eq!(set | GENERAL, PARSER | PROCESSOR | GENERAL);
+        assert!(ErrorCategorySet::default().is_empty());
+        assert_eq!(
+            format!("{set:?}"),
+            "{ErrorCategory(PARSER), ErrorCategory(PROCESSOR)}"
+        );
+    }
+
+    #[test]
+    fn test_category_default() {
+        assert_eq!(ErrorCategory::default(), PARSER);
+    }
+}
@@ -0,0 +1,35 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `core_error` module attribute in no_std environment.
+
+#![deny(warnings)]
+#![no_std]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{kind::general::*, variant::types::general::*, Error};
+    use core::error::Error as CoreError;
+
+    fn as_core_error<E: CoreError + 'static>(e: E) -> E {
+        e
+    }
+
+    #[test]
+    fn test_core_error() {
+        let err = as_core_error(Error::from(BAD_FILE));
+        let dyn_err: &dyn CoreError = &err;
+        assert!(dyn_err.source().is_none());
+        assert!(dyn_err.downcast_ref::<Error>().is_some());
+    }
+
+    #[test]
+    fn test_core_error_variant_type() {
+        let err = as_core_error(BadArg);
+        let dyn_err: &dyn CoreError = &err;
+        assert!(dyn_err.downcast_ref::<BadArg>().is_some());
+    }
+}
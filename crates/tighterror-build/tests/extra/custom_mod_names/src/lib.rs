@@ -0,0 +1,21 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with custom names of the error kinds and categories modules.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{domains, kinds, variant::types::lexer::BadChar, Error};
+
+    #[test]
+    fn test_custom_mod_names() {
+        assert_eq!(kinds::parser::BAD_TOKEN.category(), domains::PARSER);
+        assert_eq!(kinds::lexer::BAD_CHAR.category(), domains::LEXER);
+        assert_eq!(Error::from(BadChar).kind(), kinds::lexer::BAD_CHAR);
+    }
+}
@@ -0,0 +1,26 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `debug_stable` module attribute.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{kind, Error};
+
+    #[test]
+    fn test_debug_stable() {
+        assert_eq!(
+            format!("{:?}", kind::parser::BAD_TOKEN),
+            "ErrorKind { cat: PARSER, var: BAD_TOKEN }"
+        );
+        assert_eq!(
+            format!("{:?}", Error::from(kind::general::BAD_ARG)),
+            "Error(ErrorKind { cat: GENERAL, var: BAD_ARG })"
+        );
+    }
+}
@@ -0,0 +1,63 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with display strings resolved from message ids.
+
+#![deny(warnings)]
+
+/// A stub of an i18n layer.
+mod i18n {
+    pub fn message(id: &'static str) -> String {
+        match id {
+            "parser-bad-token" => "Jeton invalide.".into(),
+            "processor-queue-full" => "File pleine.".into(),
+            _ => format!("<{id}>"),
+        }
+    }
+}
+
+/// Crate errors.
+pub mod errors {
+    use super::i18n::message;
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::*;
+
+    #[test]
+    fn test_display_resolved() {
+        assert_eq!(
+            Error::from(kind::parser::BAD_TOKEN).to_string(),
+            "Jeton invalide."
+        );
+        assert_eq!(
+            Error::from(kind::processor::QUEUE_FULL).to_string(),
+            "File pleine."
+        );
+        assert_eq!(
+            Error::from(kind::parser::UNEXPECTED_EOF).to_string(),
+            "<UNEXPECTED_EOF>"
+        );
+        assert_eq!(
+            variant::types::parser::BadToken.to_string(),
+            "Jeton invalide."
+        );
+    }
+
+    #[test]
+    fn test_message_is_id() {
+        assert_eq!(kind::parser::BAD_TOKEN.message(), "parser-bad-token");
+        assert_eq!(
+            Error::from(kind::processor::QUEUE_FULL).message(),
+            "processor-queue-full"
+        );
+    }
+
+    #[test]
+    fn test_display_padding() {
+        assert_eq!(
+            format!("{:>16}", Error::from(kind::processor::QUEUE_FULL)),
+            "    File pleine."
+        );
+    }
+}
@@ -0,0 +1,39 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `display_with_code` module attribute.
+
+#![deny(warnings)]
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{decimal_errors, errors};
+
+    #[test]
+    fn test_display_with_hex_code() {
+        use errors::{kind::processor::*, Error};
+        assert_eq!(QUEUE_FULL.to_string(), "QUEUE_FULL [0x2]");
+        assert_eq!(QUEUE_FULL.name(), "QUEUE_FULL");
+        assert_eq!(
+            Error::from(QUEUE_FULL).to_string(),
+            "Processor queue is full. [0x2]"
+        );
+        assert_eq!(Error::from(TIMEOUT).to_string(), "TIMEOUT [0x3]");
+        assert_eq!(Error::from(TIMEOUT).message(), "TIMEOUT");
+    }
+
+    #[test]
+    fn test_display_with_decimal_code() {
+        use decimal_errors::{kind::processor::*, Error};
+        assert_eq!(QUEUE_FULL.to_string(), "QUEUE_FULL [2]");
+        assert_eq!(
+            Error::from(QUEUE_FULL).to_string(),
+            "Processor queue is full. [2]"
+        );
+        assert_eq!(format!("{:>16}", TIMEOUT), "     TIMEOUT [3]");
+        assert_eq!(
+            format!("{:<16}|", Error::from(TIMEOUT)),
+            "TIMEOUT [3]     |"
+        );
+    }
+}
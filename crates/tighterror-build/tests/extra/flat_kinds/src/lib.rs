@@ -17,4 +17,19 @@ mod tests {
     fn test_error_kinds_are_under_kinds_module() {
         assert_ne!(errors::kind::TIMEOUT, errors::kind::CONNECTION_REFUSED);
     }
+
+    #[test]
+    fn test_assoc_consts() {
+        assert_eq!(errors::ErrorKind::TIMEOUT, errors::kind::TIMEOUT);
+    }
+
+    #[test]
+    fn test_qualified_name() {
+        assert_eq!(errors::kind::TIMEOUT.name(), "TIMEOUT");
+        assert_eq!(errors::kind::TIMEOUT.qualified_name(), "GENERAL::TIMEOUT");
+        assert_eq!(
+            errors::kind::CONNECTION_REFUSED.qualified_name(),
+            "IO::CONNECTION_REFUSED"
+        );
+    }
 }
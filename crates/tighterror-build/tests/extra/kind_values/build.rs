@@ -0,0 +1,28 @@
+use std::fmt::Write;
+use tighterror_build::CodegenOptions;
+
+fn main() {
+    println!("cargo:rerun-if-changed=tighterror.yaml");
+    env_logger::builder().init();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    let out_path = format!("{out_dir}/errors.rs");
+    let mut opts = CodegenOptions::new();
+    if let Err(e) = opts.output(out_path).test(true).codegen() {
+        panic!("codegen failed: out_dir: {out_dir}; {e}");
+    }
+
+    let values = match opts.kind_values() {
+        Ok(v) => v,
+        Err(e) => panic!("kind values failed: {e}"),
+    };
+    let kinds = &values["errors"];
+    let mut code = format!(
+        "pub static KIND_VALUES: [(&str, u64); {}] = [\n",
+        kinds.len()
+    );
+    for (name, value) in kinds {
+        writeln!(code, "    ({name:?}, {value}),").unwrap();
+    }
+    code.push_str("];\n");
+    std::fs::write(format!("{out_dir}/kind_values.rs"), code).unwrap();
+}
@@ -0,0 +1,47 @@
+//! This is a test crate to check that the error kind values computed by
+//! `CodegenOptions::kind_values` match the generated code.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+include!(concat!(env!("OUT_DIR"), "/kind_values.rs"));
+
+#[cfg(test)]
+mod tests {
+    use super::{errors::*, KIND_VALUES};
+
+    #[test]
+    fn test_kind_values() {
+        assert_eq!(KIND_VALUES.len(), CATALOG.len());
+        for (name, value) in KIND_VALUES {
+            let kind = ErrorKind::from_value(value.try_into().unwrap()).unwrap();
+            assert_eq!(kind.qualified_name(), name);
+            assert_eq!(u64::from(kind.value()), value);
+        }
+    }
+
+    #[test]
+    fn test_kind_values_of_constants() {
+        let value = |k: ErrorKind| {
+            KIND_VALUES
+                .iter()
+                .find(|(n, _)| *n == k.qualified_name())
+                .map(|(_, v)| *v)
+                .unwrap()
+        };
+        for kind in [
+            kind::parser::BAD_TOKEN,
+            kind::parser::QUEUE_FULL,
+            kind::processor::TIMEOUT,
+            kind::processor::QUEUE_FULL,
+            kind::processor::OUT_OF_MEMORY,
+            kind::general::BAD_ARG,
+        ] {
+            assert_eq!(u64::from(kind.value()), value(kind));
+        }
+    }
+}
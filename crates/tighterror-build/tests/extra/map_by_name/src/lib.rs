@@ -0,0 +1,26 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `map_by_name` module attribute.
+
+#![deny(warnings)]
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors, internal_errors};
+
+    #[test]
+    fn test_map_by_name() {
+        let kind = errors::ErrorKind::from(internal_errors::kind::QUEUE_FULL);
+        assert_eq!(kind, errors::kind::parser::QUEUE_FULL);
+        let kind = errors::ErrorKind::from(internal_errors::kind::TIMEOUT);
+        assert_eq!(kind, errors::kind::general::TIMEOUT);
+    }
+
+    #[test]
+    fn test_map_by_name_error() {
+        let err: errors::Error =
+            internal_errors::InternalError::from(internal_errors::kind::TIMEOUT).into();
+        assert_eq!(err.kind(), errors::kind::general::TIMEOUT);
+    }
+}
@@ -36,4 +36,16 @@ mod tests {
         let e: anyhow::Error = Error::from(kind::general::BAD_FILE).into();
         assert_eq!(format!("{e}"), "BAD_FILE");
     }
+
+    #[test]
+    fn test_into_boxed_error() {
+        fn foo() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Err(kind::general::BAD_FILE)?
+        }
+        fn bar() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Err(Error::from(kind::general::BAD_FILE))?
+        }
+        assert_eq!(format!("{}", foo().unwrap_err()), "BAD_FILE");
+        assert_eq!(format!("{}", bar().unwrap_err()), "BAD_FILE");
+    }
 }
@@ -1,5 +1,5 @@
 //! This is a test crate to check `tighterror.yaml`
-//! with minimal configuration.
+//! with minimal configuration and display strings.
 
 #![deny(warnings)]
 
@@ -7,3 +7,21 @@
 pub mod errors {
     include!(concat!(env!("OUT_DIR"), "/errors.rs"));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{kind::general::*, CATALOG};
+
+    #[test]
+    fn test_display_case() {
+        assert_eq!(BAD_CONF_FILE.message(), "configuration file is malformed.");
+        assert_eq!(IO_ERROR.message(), "an IO error occurred.");
+        assert_eq!(IO_FAILED.message(), "I/O failed.");
+        for (kind, (_, display, _)) in [BAD_CONF_FILE, BAD_ARG, IO_ERROR, IO_FAILED]
+            .iter()
+            .zip(CATALOG)
+        {
+            assert_eq!(kind.message(), display);
+        }
+    }
+}
@@ -20,4 +20,44 @@ mod tests {
             errors::kind::processing::QUEUE_FULL
         );
     }
+
+    #[test]
+    fn test_category_kind_count() {
+        let cat = errors::kind::general::TIMEOUT.category();
+        assert_eq!(
+            format!("{} ({} kinds)", cat, cat.kind_count()),
+            "GENERAL (2 kinds)"
+        );
+    }
+
+    #[test]
+    fn test_kind_iter() {
+        use errors::McErrorKind;
+        assert_eq!(McErrorKind::iter().count(), McErrorKind::VARIANTS.len());
+        assert_eq!(
+            McErrorKind::VARIANTS,
+            [
+                "BAD_TOKEN",
+                "QUEUE_FULL",
+                "OUT_OF_MEMORY",
+                "QUEUE_FULL",
+                "BAD_ARG",
+                "TIMEOUT"
+            ]
+        );
+        assert_eq!(
+            McErrorKind::iter().last(),
+            Some(errors::kind::general::TIMEOUT)
+        );
+    }
+
+    #[test]
+    fn test_assoc_consts() {
+        use errors::{kind, McErrorKind};
+        assert_eq!(McErrorKind::PARSING_QUEUE_FULL, kind::parsing::QUEUE_FULL);
+        assert_eq!(
+            McErrorKind::PROCESSING_QUEUE_FULL,
+            kind::processing::QUEUE_FULL
+        );
+    }
 }
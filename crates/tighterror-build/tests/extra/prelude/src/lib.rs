@@ -0,0 +1,20 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `prelude` module attribute.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{category::*, prelude::*};
+
+    #[test]
+    fn test_prelude() {
+        assert_eq!(BAD_TOKEN.category(), PARSER);
+        assert_eq!(TIMEOUT.category(), PROCESSOR);
+    }
+}
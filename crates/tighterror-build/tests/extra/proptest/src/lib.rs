@@ -0,0 +1,27 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `proptest` module attribute.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::*;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn test_any_error_kind(k in any_error_kind()) {
+            prop_assert!(k.category() == category::PARSER || k.category() == category::PROCESSOR);
+        }
+
+        #[test]
+        fn test_arbitrary(k in any::<ErrorKind>()) {
+            prop_assert_eq!(ErrorKind::from_name(k.name()), Some(k));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `runtime_path` module attribute and a renamed
+//! `tighterror` dependency.
+
+#![deny(warnings)]
+
+pub use tight as rt;
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors, reexported_errors};
+    use tight::VariantType;
+
+    #[test]
+    fn test_renamed_dependency() {
+        use errors::{kind::parser::*, variant::types::parser::BadChar, Error};
+        let line = line!() + 1;
+        let err = Error::from(BAD_TOKEN);
+        assert_eq!(err.kind(), BAD_TOKEN);
+        assert_eq!(err.to_string(), "Bad token.");
+        assert_eq!(tight::Kind::name(&BAD_TOKEN), "BAD_TOKEN");
+        assert_eq!(tight::Error::location(&err).line(), line);
+        assert_eq!(<BadChar as VariantType>::KIND, BAD_CHAR);
+        assert!("NO_SUCH_KIND".parse::<errors::ErrorKind>().is_err());
+    }
+
+    #[test]
+    fn test_reexported_runtime() {
+        use reexported_errors::{kind::general::*, variant::types::general::QueueFull, Error};
+        assert_eq!(Error::from(QueueFull).kind(), QUEUE_FULL);
+        assert_eq!(<QueueFull as crate::rt::VariantType>::NAME, "QueueFull");
+        assert_eq!(QUEUE_FULL.name(), "QUEUE_FULL");
+    }
+}
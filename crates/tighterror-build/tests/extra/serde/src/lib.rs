@@ -0,0 +1,54 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `serde` module attribute.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{by_name, by_value};
+
+    #[test]
+    fn test_serde_name() {
+        use by_name::{kind::*, ErrorKind};
+        for (kind, json) in [
+            (parser::BAD_TOKEN, r#""PARSER::BAD_TOKEN""#),
+            (parser::TIMEOUT, r#""PARSER::TIMEOUT""#),
+            (processor::OUT_OF_MEMORY, r#""PROCESSOR::OUT_OF_MEMORY""#),
+            (processor::TIMEOUT, r#""PROCESSOR::TIMEOUT""#),
+        ] {
+            assert_eq!(serde_json::to_string(&kind).unwrap(), json);
+            assert_eq!(serde_json::from_str::<ErrorKind>(json).unwrap(), kind);
+        }
+        for (json, kind) in [
+            (r#""BAD_TOKEN""#, parser::BAD_TOKEN),
+            (r#""Parser::BAD_TOKEN""#, parser::BAD_TOKEN),
+            (r#""Processor::TIMEOUT""#, processor::TIMEOUT),
+        ] {
+            assert_eq!(serde_json::from_str::<ErrorKind>(json).unwrap(), kind);
+        }
+        assert!(serde_json::from_str::<ErrorKind>(r#""TIMEOUT""#).is_err());
+        assert!(serde_json::from_str::<ErrorKind>("0").is_err());
+    }
+
+    #[test]
+    fn test_serde_value() {
+        use by_value::{kind::*, ErrorKind};
+        for kind in [
+            parser::BAD_TOKEN,
+            parser::TIMEOUT,
+            processor::OUT_OF_MEMORY,
+            processor::TIMEOUT,
+        ] {
+            let json = serde_json::to_string(&kind).unwrap();
+            assert_eq!(json, kind.value().to_string());
+            assert_eq!(serde_json::from_str::<ErrorKind>(&json).unwrap(), kind);
+        }
+        assert!(serde_json::from_str::<ErrorKind>("256").is_err());
+        assert!(serde_json::from_str::<ErrorKind>(r#""BAD_TOKEN""#).is_err());
+    }
+}
@@ -0,0 +1,20 @@
+use tighterror_build::CodegenOptions;
+
+fn main() {
+    println!("cargo:rerun-if-changed=tighterror.yaml");
+    env_logger::builder().init();
+    let out_dir = std::env::var("OUT_DIR").unwrap();
+    for (dir, split_tables) in [("split", true), ("whole", false)] {
+        let out_path = format!("{out_dir}/{dir}");
+        std::fs::create_dir_all(&out_path).unwrap();
+        if let Err(e) = CodegenOptions::new()
+            .output(out_path)
+            .separate_files(true)
+            .split_tables(split_tables)
+            .test(true)
+            .codegen()
+        {
+            panic!("codegen failed: out_dir: {out_dir}; {e}");
+        }
+    }
+}
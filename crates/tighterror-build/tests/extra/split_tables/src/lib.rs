@@ -0,0 +1,43 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with the private tables split into a separate file.
+
+#![deny(warnings)]
+
+/// Errors with the tables in `errors_tables.rs`.
+pub mod split {
+    include!(concat!(env!("OUT_DIR"), "/split/errors.rs"));
+}
+
+/// Errors with the tables in `errors.rs`.
+pub mod whole {
+    include!(concat!(env!("OUT_DIR"), "/whole/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split, whole};
+    use std::path::Path;
+
+    #[test]
+    fn test_tables_file() {
+        let out_dir = Path::new(env!("OUT_DIR"));
+        assert!(out_dir.join("split/errors_tables.rs").is_file());
+        assert!(!out_dir.join("whole/errors_tables.rs").exists());
+    }
+
+    #[test]
+    fn test_split_tables_behave_identically() {
+        assert_eq!(split::ErrorKind::VARIANTS, whole::ErrorKind::VARIANTS);
+        assert_eq!(
+            split::ErrorKind::iter().count(),
+            whole::ErrorKind::iter().count()
+        );
+        for (s, w) in split::ErrorKind::iter().zip(whole::ErrorKind::iter()) {
+            assert_eq!(s.value(), w.value());
+            assert_eq!(s.name(), w.name());
+            assert_eq!(s.to_string(), w.to_string());
+            assert_eq!(s.category().name(), w.category().name());
+        }
+        assert_eq!(split::CATALOG, whole::CATALOG);
+    }
+}
@@ -0,0 +1,58 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with sub-categories.
+
+#![deny(warnings)]
+
+include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+
+#[cfg(test)]
+mod tests {
+    use crate::{errors, flat_errors};
+
+    #[test]
+    fn test_subcategory_paths() {
+        use errors::{
+            category::{IO, PARSER},
+            kind::{io, parser},
+            Error, ErrorKind,
+        };
+        assert_eq!(parser::BAD_INPUT.category(), PARSER);
+        assert_eq!(parser::lexer::BAD_TOKEN.category(), PARSER);
+        assert_eq!(parser::lexer::BAD_CHAR.category(), PARSER);
+        assert_eq!(parser::grammar::UNEXPECTED_TOKEN.category(), PARSER);
+        assert_eq!(io::net::TIMEOUT.category(), IO);
+        assert_eq!(ErrorKind::default(), io::net::TIMEOUT);
+        assert_eq!(parser::lexer::BAD_CHAR.name(), "BAD_CHAR");
+        assert_eq!(
+            Error::from(parser::lexer::BAD_CHAR).to_string(),
+            "Decoded an invalid character."
+        );
+    }
+
+    #[test]
+    fn test_subcategory_values() {
+        use errors::kind::{io, parser};
+        assert_eq!(parser::BAD_INPUT.value(), 0);
+        assert_eq!(parser::lexer::BAD_TOKEN.value(), 1);
+        assert_eq!(parser::lexer::BAD_CHAR.value(), 2);
+        assert_eq!(parser::grammar::UNEXPECTED_TOKEN.value(), 3);
+        assert_eq!(io::net::TIMEOUT.value(), 4);
+    }
+
+    #[test]
+    fn test_subcategory_prelude_and_groups() {
+        use errors::{prelude::*, variant::types::parser::BadToken};
+        assert!(BAD_TOKEN.is_lexical());
+        assert!(BAD_CHAR.is_lexical());
+        assert!(!UNEXPECTED_TOKEN.is_lexical());
+        assert_eq!(BadToken.kind(), BAD_TOKEN);
+    }
+
+    #[test]
+    fn test_subcategory_flat_kinds() {
+        use flat_errors::{category::PARSER, kind::*};
+        assert_eq!(BAD_TOKEN.category(), PARSER);
+        assert_eq!(BAD_TOKEN.value(), 1);
+        assert_eq!(TIMEOUT.value(), 2);
+    }
+}
@@ -0,0 +1,25 @@
+//! This is a test crate to check `tighterror.yaml`
+//! with `value_widths` module attribute.
+
+#![deny(warnings)]
+
+/// Crate errors.
+pub mod errors {
+    include!(concat!(env!("OUT_DIR"), "/errors.rs"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::errors::{category::*, kind::processor::*};
+
+    #[test]
+    fn test_value_widths() {
+        let value: u8 = TIMEOUT.value();
+        assert_eq!(TIMEOUT.value_u8(), value);
+        assert_eq!(TIMEOUT.value_u16(), u16::from(value));
+        assert_eq!(TIMEOUT.value_u64(), u64::from(value));
+        assert_eq!(PROCESSOR.value_u8(), 1);
+        assert_eq!(PROCESSOR.value_u16(), 1);
+        assert_eq!(PROCESSOR.value_u64(), 1);
+    }
+}
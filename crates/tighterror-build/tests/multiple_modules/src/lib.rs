@@ -27,9 +27,14 @@ pub mod flat_kinds_mod_without_display {
     ));
 }
 
+/// Module with error location.
+pub mod location_errors {
+    include!(concat!(env!("OUT_DIR"), "/location_errors.rs"));
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::{errors, flat_kinds_mod, internal_errors};
+    use crate::{errors, flat_kinds_mod, internal_errors, location_errors};
 
     #[test]
     fn test_kind_constants_are_placed_in_different_modules() {
@@ -58,4 +63,114 @@ mod tests {
             "CatTwoSpecial"
         );
     }
+
+    #[test]
+    fn test_location() {
+        fn timeout() -> Result<(), location_errors::Error> {
+            location_errors::variant::types::general::Timeout.into()
+        }
+
+        fn propagate() -> Result<(), location_errors::Error> {
+            timeout()?;
+            Ok(())
+        }
+
+        let err = timeout().unwrap_err();
+        assert_eq!(err.kind(), location_errors::kind::general::TIMEOUT);
+        assert_eq!(err.location().file(), file!());
+        assert!(err.location().is_defined());
+        assert_eq!(err, propagate().unwrap_err());
+        assert_eq!(err.location(), propagate().unwrap_err().location());
+    }
+
+    #[test]
+    fn test_bail() {
+        fn timeout(fail: bool) -> Result<u32, location_errors::Error> {
+            if fail {
+                location_errors::bail!(location_errors::kind::general::TIMEOUT);
+            }
+            Ok(1)
+        }
+
+        assert_eq!(timeout(false), Ok(1));
+        let err = timeout(true).unwrap_err();
+        assert_eq!(err.kind(), location_errors::kind::general::TIMEOUT);
+        assert_eq!(err.location().file(), file!());
+    }
+
+    #[test]
+    fn test_order_by_name() {
+        use internal_errors::kind::{parser, processor};
+        let mut kinds = [processor::BAD_STATE, processor::BAD_FILE, parser::BAD_FILE];
+        kinds.sort();
+        assert_eq!(
+            kinds,
+            [parser::BAD_FILE, processor::BAD_FILE, processor::BAD_STATE]
+        );
+        assert!(processor::BAD_STATE > parser::BAD_FILE);
+        assert!(errors::kind::parsing::BAD_TOKEN < errors::kind::general::BAD_ARG);
+    }
+
+    #[test]
+    fn test_category_index() {
+        assert_eq!(errors::kind::parsing::QUEUE_FULL.category_index(), 0);
+        assert_eq!(errors::kind::processing::QUEUE_FULL.category_index(), 1);
+        assert_eq!(errors::kind::general::TIMEOUT.category_index(), 2);
+        assert_eq!(flat_kinds_mod::kind::CAT_ONE_ERR_TWO.category_index(), 0);
+        assert_eq!(location_errors::kind::general::TIMEOUT.category_index(), 0);
+    }
+
+    #[test]
+    fn test_default() {
+        assert_eq!(
+            errors::McErrorKind::default(),
+            errors::kind::general::TIMEOUT
+        );
+        let err = location_errors::Error::default();
+        assert_eq!(err.kind(), location_errors::kind::general::BAD_FILE);
+        assert_eq!(err.location().file(), file!());
+    }
+
+    #[test]
+    fn test_variant() {
+        use errors::kind::processing::*;
+        use tighterror::Kind;
+        const V: u8 = WITHOUT_DOC.variant();
+        assert_eq!(V, 3);
+        assert_eq!(Kind::variant(&OUT_OF_MEMORY), 0);
+        assert_eq!(QUEUE_FULL.variant(), 1);
+    }
+
+    #[test]
+    fn test_from_str() {
+        use internal_errors::{kind::*, ErrorKind};
+        use tighterror::ParseKindError;
+        assert_eq!("BAD_STATE".parse(), Ok(processor::BAD_STATE));
+        assert_eq!("Processor::BAD_STATE".parse(), Ok(processor::BAD_STATE));
+        assert_eq!(
+            ErrorKind::try_from("Parser::BAD_FILE"),
+            Ok(parser::BAD_FILE)
+        );
+        assert_eq!("BAD_FILE".parse::<ErrorKind>(), Err(ParseKindError));
+        assert_eq!(
+            ErrorKind::try_from("Parser::BAD_STATE"),
+            Err(ParseKindError)
+        );
+    }
+
+    #[test]
+    fn test_groups() {
+        use errors::kind::{general, parsing, processing};
+        assert!(parsing::QUEUE_FULL.is_retryable());
+        assert!(processing::QUEUE_FULL.is_retryable());
+        assert!(general::TIMEOUT.is_retryable());
+        assert!(!general::TIMEOUT.is_fatal());
+        assert!(!parsing::BAD_TOKEN.is_retryable());
+        assert!(processing::OUT_OF_MEMORY.is_fatal());
+
+        use flat_kinds_mod::kind::*;
+        assert!(CAT_ONE_ERR_ONE.is_odd());
+        assert!(CAT_TWO_ERR_ONE.is_odd());
+        assert!(!CAT_ONE_ERR_TWO.is_odd());
+    }
 }
@@ -17,4 +17,15 @@ pub trait Category:
 
     /// Returns the category name.
     fn name(&self) -> &'static str;
+
+    /// Creates an error category from a value of the underlying Rust type.
+    ///
+    /// The function returns `None` if `value` doesn't denote a valid error
+    /// category **in its current definition**.
+    ///
+    /// Persisting the raw values, and/or using them between different invocations
+    /// of a program (possibly compiled with another version of error's origin crate)
+    /// may lead to bugs because the mapping between an error category and its
+    /// underlying raw value may change.
+    fn from_value(value: Self::R) -> Option<Self>;
 }
@@ -0,0 +1,55 @@
+use core::fmt::{Debug, Display};
+
+/// An error returned when converting a raw value to an error kind fails.
+///
+/// This is the error type of the `TryFrom<R>` implementation of the generated
+/// error kind type, where `R` is the underlying Rust type of the error kind.
+/// It is returned when the value doesn't denote an error kind, and carries
+/// the offending value.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct InvalidValueError<R>(R);
+
+impl<R: Copy> InvalidValueError<R> {
+    /// Creates a new error from the offending value.
+    #[inline]
+    pub const fn new(value: R) -> Self {
+        Self(value)
+    }
+
+    /// Returns the value that doesn't denote an error kind.
+    #[inline]
+    pub const fn value(&self) -> R {
+        self.0
+    }
+}
+
+impl<R: Display> Display for InvalidValueError<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "invalid error kind value: {}", self.0)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl<R: Debug + Display> std::error::Error for InvalidValueError<R> {}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_display() {
+        let e = InvalidValueError::new(42u8);
+        assert_eq!(e.value(), 42);
+        assert_eq!(format!("{e}"), "invalid error kind value: 42");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_trait() {
+        let e: &dyn std::error::Error = &InvalidValueError::new(42u8);
+        assert!(e.source().is_none());
+    }
+}
@@ -36,6 +36,13 @@ pub trait Kind: Copy + Clone + Eq + PartialEq + Ord + PartialOrd + Debug + Displ
     /// underlying raw value may change.
     fn value(&self) -> Self::R;
 
+    /// Returns the error kind variant as the underlying Rust type.
+    ///
+    /// The variant is the index of the error kind within its category, i.e.,
+    /// the error kind value without the category bits. It can be used to
+    /// index arrays parallel to the list of errors in a category.
+    fn variant(&self) -> Self::R;
+
     /// Creates an error kind from a value of the underlying Rust type.
     ///
     /// The function returns `None` if `value` doesn't denote a valid error
@@ -21,6 +21,7 @@
 //!
 //! 1. [High-Level Overview](#high-level-overview)
 //!     * [Variant Types](#variant-types)
+//!     * [Parsing Error Kinds](#parsing-error-kinds)
 //! 1. [Implementation Details](#implementation-details)
 //! 1. [Specification File Reference](#specification-file-reference)
 //!     * [Filename](#filename)
@@ -130,17 +131,60 @@
 //! # impl From<ErrorKind> for Error {
 //! #     fn from(v: ErrorKind) -> Self { Self(v) }
 //! # }
+//! # impl From<&ErrorKind> for Error {
+//! #     fn from(v: &ErrorKind) -> Self { Self(*v) }
+//! # }
 //! # impl Error {
 //! #     pub fn kind(&self) -> ErrorKind { self.0 }
 //! #     pub fn category(&self) -> ErrorCategory { self.kind().category() }
 //! # }
 //! # impl ErrorKind {
 //! #     pub fn category(&self) -> ErrorCategory { ErrorCategory(self.0 >> 2) }
+//! #     pub const fn error(self) -> Error { Error(self) }
 //! # }
 //! # fn foo() {
 //! let e: Error = BAD_FILE.into();
 //! assert!(matches!(e.kind(), BAD_FILE));
 //! assert!(matches!(e.category(), GENERAL));
+//! let e: Error = (&BAD_FILE).into();
+//! assert!(matches!(e.kind(), BAD_FILE));
+//! let e = BAD_FILE.error();
+//! assert!(matches!(e.kind(), BAD_FILE));
+//! # }
+//! ```
+//!
+//! `ErrorKind::error` is the method form of `Error::from`. It is `const`
+//! unless the `location` module attribute is enabled.
+//!
+//! `ErrorKind::category` and `ErrorKind::is_in` are `const`, so the category
+//! of an *error kind* can be tested at compile time, e.g.,
+//! `const IS_GENERAL: bool = BAD_FILE.is_in(GENERAL);`.
+//!
+//! The *error kind* constants derive `PartialEq` and `Eq`, so they can be
+//! used as patterns in `match` expressions and the `matches!` macro:
+//!
+//! ```rust
+//! # #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+//! # #[repr(transparent)]
+//! # pub struct ErrorKind(u8);
+//! # pub mod kind {
+//! #     pub mod general {
+//! #         use super::super::ErrorKind;
+//! #         pub const BAD_FILE: ErrorKind = ErrorKind(0);
+//! #         pub const BAD_ARG: ErrorKind = ErrorKind(2);
+//! #         pub const TIMEOUT: ErrorKind = ErrorKind(4);
+//! #     }
+//! # }
+//! fn is_retryable(k: ErrorKind) -> bool {
+//!     match k {
+//!         kind::general::BAD_FILE | kind::general::BAD_ARG => false,
+//!         kind::general::TIMEOUT => true,
+//!         _ => false,
+//!     }
+//! }
+//! # fn main() {
+//! assert!(is_retryable(kind::general::TIMEOUT));
+//! assert!(matches!(kind::general::BAD_ARG, kind::general::BAD_ARG));
 //! # }
 //! ```
 //!
@@ -197,6 +241,55 @@
 //! }
 //! ```
 //!
+//! ### Parsing Error Kinds
+//!
+//! An *error kind* can be parsed from its name using the `FromStr` and
+//! `TryFrom<&str>` implementations. Both share the same lookup, and
+//! fail with [ParseKindError] if the string doesn't denote an error kind
+//! of the module.
+//! [ParseKindError] implements `std::error::Error` when the `std` feature
+//! of *tighterror* is enabled.
+//!
+//! A name qualified by the category name, e.g., `General::BAD_FILE`, or by
+//! the category constant name as returned by `ErrorKind::qualified_name`,
+//! e.g., `GENERAL::BAD_FILE`, is always accepted. A plain name, e.g.,
+//! `BAD_FILE`, is accepted only if it is unique in the module.
+//!
+//! The same lookup is available as `ErrorKind::from_name`, which returns
+//! an `Option` instead of a `Result`. Similarly, `ErrorCategory::from_name`
+//! creates an *error category* from its name, and the *error category* also
+//! implements `FromStr` and `TryFrom<&str>` failing with [ParseKindError].
+//!
+//! An *error kind* can also be converted from a raw value of its underlying
+//! Rust type using `TryFrom`, e.g., `ErrorKind::try_from(42u8)`. The
+//! conversion fails with [InvalidValueError] that carries the offending value,
+//! available with [InvalidValueError::value], and displays as
+//! `invalid error kind value: 42`. Like [ParseKindError], it implements
+//! `std::error::Error` when the `std` feature is enabled.
+//!
+//! ### Error Catalog
+//!
+//! With the `catalog` module attribute a module contains a static `CATALOG`
+//! table of `(name, display, doc)` tuples, one per *error kind* in the order of their
+//! values. This is handy for generating documentation of the errors, e.g.,
+//! a table of error codes, without parsing the specification file.
+//!
+//! The name is qualified by the category name, e.g., `GENERAL::BAD_FILE`,
+//! unless `flat_kinds` is enabled. The doc is an empty string if the error
+//! has no documentation.
+//!
+//! ```rust
+//! pub static CATALOG: [(&str, &str, &str); 1] = [
+//!     ("GENERAL::BAD_FILE", "BAD_FILE", "The file is not valid."),
+//! ];
+//! ```
+//!
+//! The position of an *error kind* in the catalog is returned by
+//! `ErrorKind::index()`. The indices are dense, in the range `0..N` where `N`
+//! is the number of *error kinds* in the module, and unlike the values don't
+//! depend on the bit layout. This allows indexing arrays sized by the number
+//! of *error kinds*, e.g., `CATALOG[kind.index()]`.
+//!
 //! [newtype]: https://doc.rust-lang.org/rust-by-example/generics/new_types.html
 //!
 //!
@@ -251,7 +344,7 @@
 //!
 //! When specification filename isn't explicitly provided *tighterror-build*
 //! searches the current working directory for a file with one of the default
-//! names `tighterror.yaml` or `tighterror.toml`.
+//! names `tighterror.yaml`, `tighterror.yml` or `tighterror.toml`.
 //!
 //! Custom filenames, or files outside the current working directory,
 //! must be explicitly specified. A custom filename must have one of the
@@ -271,10 +364,20 @@
 //!   Defines the *error kind* name. Must be a string in UPPER_SNAKE_CASE.
 //!   This string becomes the *error kind* constant.<br><br>
 //!
+//! * `default` - bool (optional)
+//!
+//!   When enabled the [Default] trait is implemented for the *error kind* and
+//!   *error* structs, returning this *error kind*.
+//!   At most one error in a module can be marked as default.<br>
+//!   Default: `false`<br><br>
+//!
 //! * `display` - string (optional)
 //!
 //!   Defines the *error kind's* display string. This string is used in
 //!   `std::fmt::Display` implementation to display the *error kind*.<br>
+//!   The `{category}` token is replaced at code generation time with the
+//!   name of the error's category, e.g., `{category} failed` becomes
+//!   `Parser failed`. Literal braces are escaped as `{{` and `}}`.<br>
 //!   When undefined the `name` is used as display string.<br><br>
 //!
 //! * `doc` - string (optional)
@@ -283,6 +386,16 @@
 //!   doc comment of the *error kind's* constant.
 //!   When undefined the constant doesn't receive a doc comment.<br><br>
 //!
+//! * `doc_file` - string (optional)<a name="err-obj-doc-file"></a>
+//!
+//!   Defines a path to a file whose contents are used as the `doc` attribute.
+//!   This allows keeping long documentation, e.g., in Markdown files, out of
+//!   the specification file. The file is read at code generation time.
+//!
+//!   A relative path is relative to the location of the specification file.
+//!   Code generation fails if the file cannot be read.
+//!   This attribute is mutually exclusive with `doc`.<br><br>
+//!
 //! * `doc_from_display` - bool (optional)<a name="err-obj-doc-from-display"></a>
 //!
 //!   When enabled the `display` string is also used as the doc comment,
@@ -296,6 +409,19 @@
 //!   Values defined on lower levels win.<br>
 //!   Default: `false`<br><br>
 //!
+//! * `index` - integer (optional)
+//!
+//!   Pins the error's variant ordinal, and hence the value of its *error
+//!   kind*, regardless of the error's position in the *error list*. This
+//!   allows to reorder the errors of a category without changing the
+//!   generated values.
+//!
+//!   If defined, the attribute must be defined by all errors in the
+//!   category, and the indices must be unique and contiguous, i.e., cover
+//!   the range `0..N` where `N` is the number of errors in the
+//!   category. The attribute isn't supported in a category with
+//!   `subcategories`, whose ranges of variants must stay contiguous.<br><br>
+//!
 //! * `variant_type` - bool|string (optional)<a name="err-obj-variant-type"></a>
 //!
 //!   Enables creation of a Variant Type for this error.
@@ -315,6 +441,9 @@
 //!   [module object](#module-variant-type).
 //!   Values defined on lower levels win.
 //!
+//!   The Variant Type name must differ from the error, error kind and
+//!   error category type names of all the modules in the specification.
+//!
 //!   Default: `false`<br><br>
 //!
 //! ### Error Object Examples
@@ -479,6 +608,11 @@
 //!   This string becomes a *category* constant after transition to
 //!   UPPER_SNAKE_CASE.
 //!
+//!   The name in snake_case is used as the name of category-specific
+//!   sub-modules, e.g., `kind::<category-module-name>`. Hence, names that
+//!   collide with names of generated modules, e.g., `Kind` or `Category`,
+//!   are not allowed.
+//!
 //!   The name is a mandatory attribute when *category object* is defined
 //!   as an item in a [*category list*](#category-list).
 //!   Otherwise it is optional with the default value `General`.<br><br>
@@ -489,6 +623,11 @@
 //!
 //!   This becomes the doc comment of the category constant.<br><br>
 //!
+//! * `doc_file` - string (optional)
+//!
+//!   Defines a path to a file whose contents are used as the `doc` attribute.
+//!   See the [error object](#err-obj-doc-file) for more information.<br><br>
+//!
 //! * `doc_from_display` - bool (optional)<a name="category-doc-from-display"></a>
 //!
 //!   Sets a default value for the [`doc_from_display`](#err-obj-doc-from-display)
@@ -504,10 +643,40 @@
 //!   Defines the [list of errors](#error-list) belonging to this category.<br>
 //!
 //!   This is a mandatory attribute when *category object* is defined
-//!   as an item in a [*category list*](#category-list). Conversely, when
-//!   a *category object* is defined as a standalone root-level attribute
-//!   (see below) this attribute is forbidden, and the error list must be
-//!   defined as a root-level attribute.<br><br>
+//!   as an item in a [*category list*](#category-list), unless the category
+//!   defines `subcategories`. Conversely, when a *category object* is
+//!   defined as a standalone root-level attribute (see below) this attribute
+//!   is forbidden, and the error list must be defined as a root-level
+//!   attribute.<br><br>
+//!
+//! * `index` - integer (optional)
+//!
+//!   Pins the category's ordinal, and hence the values of the category and
+//!   its error kinds, regardless of the category's position in the
+//!   *category list*. This allows, for example, to sort the categories
+//!   alphabetically without changing the generated values.
+//!
+//!   If defined, the attribute must be defined by all categories in the
+//!   module, and the indices must be unique and contiguous, i.e., cover
+//!   the range `0..N` where `N` is the number of categories.<br><br>
+//!
+//! * `subcategories` - list of objects (optional)
+//!
+//!   Nests some of the category's errors under sub-categories, a single
+//!   level deep. Every sub-category object has the following attributes:
+//!   a mandatory UpperCamelCase `name`, an optional `doc` and a mandatory
+//!   `errors` [list](#error-list).
+//!
+//!   The error kind constants of a sub-category are placed in the
+//!   `kind::<category-module-name>::<subcategory-module-name>` module,
+//!   unless `flat_kinds` is enabled. The errors still belong to the
+//!   category, and their names must be unique in the category, including
+//!   all its sub-categories. The sub-category errors follow the errors
+//!   defined directly in the category, so every sub-category occupies
+//!   a contiguous range of the category's variant bits.
+//!
+//!   Like `errors`, this attribute is forbidden when the *category object*
+//!   is defined as a standalone root-level attribute.<br><br>
 //!
 //! * `variant_type` - bool (optional)<a name="category-variant-type"></a>
 //!
@@ -564,6 +733,26 @@
 //! display = "Operation timed out"
 //! ```
 //!
+//! A category with sub-categories. The error kind constants are
+//! `kind::parser::BAD_INPUT`, `kind::parser::lexer::BAD_TOKEN` and
+//! `kind::parser::grammar::UNEXPECTED_TOKEN`.
+//!
+//! ```yaml
+//! ---
+//! categories:
+//!   - name: Parser
+//!     errors:
+//!       - BAD_INPUT: Input is malformed.
+//!     subcategories:
+//!       - name: Lexer
+//!         doc: Lexer error kind constants.
+//!         errors:
+//!           - BAD_TOKEN: Decoded an invalid token.
+//!       - name: Grammar
+//!         errors:
+//!           - UNEXPECTED_TOKEN: Token is out of place.
+//! ```
+//!
 //! ## Category List
 //!
 //! *Category list* is an ordered list of [*category objects*](#category-object)
@@ -653,7 +842,7 @@
 //!
 //! When *module object* appears as an item in a *module list* its
 //! `name` and `categories` attributes are mandatory. Conversely, when defined
-//! under the `module` attribute, `categories` is forbidden and `name`
+//! under the `module` attribute, both attributes are optional and `name`
 //! has a default value. Hence, the whole `module` section is optional.
 //!
 //! When no *module object* is explicitly defined *tighterror* creates an
@@ -663,20 +852,265 @@
 //!
 //! A *module object* comprises the following attributes:
 //!
+//! * `allow_unsafe` - bool (optional)
+//!
+//!   When enabled `unsafe` code may be generated. Currently this adds
+//!   a `const unsafe fn from_value_unchecked()` method to the *error kind*
+//!   struct. The method creates an *error kind* from a raw value without
+//!   checking its validity, and using an invalid value is undefined behavior.
+//!
+//!   This attribute is disabled by default, so the generated code can be used
+//!   in crates that forbid `unsafe` code.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `allows` - list of strings (optional)
+//!
+//!   Defines a list of lints to allow in the generated code, e.g.,
+//!   `[clippy::all, dead_code]`. This is useful when a crate denies lints
+//!   the generated code trips. Every item must be a lint path, i.e., a
+//!   lint name optionally prefixed by a tool name.
+//!
+//!   When the module is written at the top of a file, i.e., in
+//!   [*separate files*](#separate-files-mode) mode or when the specification
+//!   has a single module, the lints are emitted as an inner attribute
+//!   `#![allow(...)]`. Note that inner attributes are not permitted in files
+//!   included with the `include!` macro. When the module is enclosed in a
+//!   *module block* the lints are emitted as an `#[allow(...)]` attribute of
+//!   the block. In *append* output mode the attribute is not emitted.<br>
+//!   By default no lints are allowed.<br><br>
+//!
+//! * `arbitrary` - bool (optional)
+//!
+//!   When enabled an implementation of the [`arbitrary::Arbitrary`] trait is
+//!   added to the *error kind* struct. The arbitrary value is chosen from the
+//!   error kinds defined in the module.
+//!
+//!   The implementation is gated behind an `arbitrary` feature of the user
+//!   crate. Hence, the crate must declare the feature and an optional
+//!   dependency on the [arbitrary] crate, e.g.,
+//!   `arbitrary = ["dep:arbitrary"]`.<br>
+//!   Default: `false`<br><br>
+//!
+//! [`arbitrary::Arbitrary`]: https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html
+//! [arbitrary]: https://crates.io/crates/arbitrary
+//!
+//! * `assoc_consts` - bool (optional)
+//!
+//!   When enabled the *error kind* constants are also added as associated
+//!   constants of the *error kind* struct, e.g., `ErrorKind::PARSER_BAD_TOKEN`
+//!   in addition to `kind::parser::BAD_TOKEN`. The constant names are prefixed
+//!   with the category name in `UPPER_SNAKE_CASE`, unless `flat_kinds` is
+//!   enabled, in which case the error names are used as is, e.g.,
+//!   `ErrorKind::BAD_TOKEN`. The names must be unique in the module.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `bridge_enum` - BridgeEnumObject (optional)
+//!
+//!   Adds implementations of the [From] trait converting the *error kind*
+//!   and the *error* structs into variants of an external enum. This allows
+//!   adopting tighterror gradually, while keeping a hand-rolled error enum
+//!   as the public API.
+//!
+//!   The object has two required attributes:
+//!
+//!   * `path` - the Rust path of the enum, e.g., `crate::legacy::Error`.
+//!     The path is resolved in the scope of the generated module, hence an
+//!     absolute path is recommended.
+//!   * `variants` - a mapping from error references to enum variant names.
+//!     A reference is an error name, or an error name qualified by its
+//!     category name, as in `groups`. Every error of the module must be
+//!     mapped to a unit variant. Multiple errors may map to the same variant.
+//!
+//!   ```yaml
+//!   module:
+//!     bridge_enum:
+//!       path: crate::legacy::Error
+//!       variants:
+//!         BAD_TOKEN: BadToken
+//!         Parser::TIMEOUT: Timeout
+//!         Processor::TIMEOUT: Timeout
+//!
+//!   categories:
+//!     - name: Parser
+//!       errors:
+//!         - BAD_TOKEN
+//!         - TIMEOUT
+//!     - name: Processor
+//!       errors:
+//!         - TIMEOUT
+//!   ```
+//!
+//!   The above specification adds `From<ErrorKind>` and `From<Error>`
+//!   implementations for `crate::legacy::Error`.<br>
+//!   By default no bridge enum is defined.<br><br>
+//!
+//! * `catalog` - bool (optional)
+//!
+//!   When enabled the module contains a static `CATALOG` table of the
+//!   errors, see [Error Catalog](#error-catalog).
+//!   The table and the error docs it refers to are public and linked into
+//!   the binary, hence the attribute is opt-in.<br>
+//!   Default: `false`<br><br>
+//!
 //! * `categories` - CategoryList (optional)
 //!
 //!   Defines the [list of categories](#category-list) of this module.
 //!
 //!   This attribute is required when a *module object* is specified as an item
 //!   in a *module list*. When specified under the root-level `module` attribute
-//!   `categories` must be specified as root-level attribute too (see [category
-//!   list](#category-list)).<br><br>
+//!   it is equivalent to the root-level `categories` attribute (see [category
+//!   list](#category-list)), and is mutually exclusive with the root-level
+//!   `errors`, `category` and `categories` attributes.
+//!
+//!   ```yaml
+//!   ---
+//!   module:
+//!     name: my_errors
+//!     categories:
+//!       - name: Parser
+//!         errors:
+//!           - BAD_TOKEN
+//!   ```
+//!   <br>
+//!
+//! * `categories_mod_name` - string (optional)
+//!
+//!   Defines a custom name of the sub-module holding the *error category*
+//!   constants. This is useful when the default name clashes with a domain
+//!   term. The name must be specified in `snake_case`, and cannot be a Rust
+//!   keyword or another reserved identifier, e.g., `variant`.<br>
+//!   Default: `category`<br><br>
+//!
+//! * `category_default` - bool (optional)
+//!
+//!   When enabled an implementation of the [Default] trait is added to
+//!   the *error category* struct. The default category is the first category
+//!   of the module, i.e., the first declared category or the category pinned
+//!   to index `0`. In a module with an implicit category it is the
+//!   implicit `General` category.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `category_sets` - bool (optional)
+//!
+//!   When enabled an *error category set* struct, named after the *error
+//!   category* struct with the `Set` suffix, e.g., `ErrorCategorySet`, is
+//!   added to the generated code. Error categories are combined into a set
+//!   with the `|` operator, e.g., `PARSER | PROCESSOR`, and the set's
+//!   `contains()` method checks membership of a category. The set stores one
+//!   bit per category, therefore at most 64 categories are supported.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `core_error` - bool (optional)
+//!
+//!   When enabled the error trait, see `error_trait`, is implemented as
+//!   [`core::error::Error`] instead of `std::error::Error`. Since the trait
+//!   doesn't require `std`, `error_trait` is not implicitly disabled in a
+//!   `no_std` environment.
+//!
+//!   `core::error::Error` is stable since Rust 1.81. The generated code
+//!   doesn't compile with older compilers when this attribute is enabled.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `debug_stable` - bool (optional)
+//!
+//!   When enabled the generated `impl Debug for ErrorKind` omits the
+//!   numeric error kind value, and prints the category and error kind
+//!   names only, e.g., `ErrorKind { cat: GENERAL, var: BAD_FILE }`.
+//!   The `Debug` output of `Error` follows, except for the error location
+//!   when `location` is enabled. This keeps the `Debug` output, e.g.,
+//!   in snapshot tests, stable when the error kind values change.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `display_case` - string (optional)
+//!
+//!   The casing normalization of the `display` strings of the module,
+//!   applied to the generated code only. One of:
+//!   * `sentence` - the first letter is capitalized, e.g.,
+//!     `bad file` becomes `Bad file`.
+//!   * `lower` - the first letter is lowercased, e.g., `Bad file` becomes
+//!     `bad file`. A display string starting with an acronym, e.g.,
+//!     `IO failure` or `I/O failed`, is kept as is.
+//!   * `none` - the `display` strings are kept as is.
+//!
+//!   The casing isn't normalized if `display_is_message_id` is enabled.<br>
+//!   Default: `none`<br><br>
+//!
+//! * `display_code_radix` - string (optional)
+//!
+//!   The radix of the error kind value appended by `display_with_code`.
+//!   One of:
+//!   * `hex` - hexadecimal with the `0x` prefix, e.g., `[0x23]`.
+//!   * `decimal` - decimal, e.g., `[35]`.
+//!
+//!   Default: `hex`<br><br>
+//!
+//! * `display_is_message_id` - bool (optional)
+//!
+//!   When enabled the `display` strings of the module are message ids,
+//!   e.g., gettext-style ids, resolved at runtime by the `display_resolver`
+//!   function. The `Display` implementations of the *error* struct and of
+//!   the variant types call the resolver with the message id instead of
+//!   writing the string directly, while the `message()` methods return the
+//!   message id. An error without a `display` string uses its name as the
+//!   message id.
+//!
+//!   The `display_case`, `enforce_display_period` and
+//!   `normalize_display_period` attributes don't apply to message ids.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `display_resolver` - string (optional)
+//!
+//!   The path of the function resolving a message id, e.g.,
+//!   `crate::i18n::message`. The function is called with the message id,
+//!   a `&'static str`, and returns a value implementing
+//!   [Display](core::fmt::Display). The formatter flags, e.g., width and
+//!   alignment, are applied by the `Display` implementation of the returned
+//!   value.
+//!
+//!   The resolver must be in scope of the generated module. Relative paths
+//!   are resolved in the generated module, so an `include!`-ed module may
+//!   bring the resolver into scope with a `use` declaration.
+//!
+//!   Required if `display_is_message_id` is enabled, and not allowed
+//!   otherwise.<br><br>
+//!
+//! * `display_with_code` - bool (optional)
+//!
+//!   When enabled the generated `Display` implementations of the *error*
+//!   and *error kind* structs append the error kind value to the display
+//!   string, e.g., `Queue is full. [0x23]`, see `display_code_radix`.
+//!   This is handy to identify an error in support tickets.
+//!
+//!   The `name()` and `message()` methods aren't affected. The formatter
+//!   flags, e.g., width and alignment, apply to the whole string including
+//!   the value, e.g., `{:>20}` right-aligns `BAD_FILE [0x0]`. The strings
+//!   with the value are computed at generation time, except with
+//!   `display_resolver`, where the flags apply to the resolved message only.<br>
+//!   Default: `false`<br><br>
 //!
 //! * `doc` - string (optional)
 //!
 //!   Defines the doc comment of the generated module.<br>
 //!   By default module doc comment is not defined.<br><br>
 //!
+//! * `doc_cfg` - bool (optional)
+//!
+//!   When enabled the feature-gated items of the module, e.g., the `serde`
+//!   implementations or the module itself when `feature` is set, are also
+//!   annotated with `#[cfg_attr(docsrs, doc(cfg(feature = "...")))]`.
+//!   This shows the feature requirement in documentation built with
+//!   `--cfg docsrs`, e.g., on docs.rs.
+//!
+//!   The `doc_cfg` feature is unstable, so the crate must enable it with
+//!   `#![cfg_attr(docsrs, feature(doc_cfg))]` in its root, otherwise the
+//!   documentation build with `--cfg docsrs` fails.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `doc_file` - string (optional)
+//!
+//!   Defines a path to a file whose contents are used as the `doc` attribute.
+//!   See the [error object](#err-obj-doc-file) for more information.<br><br>
+//!
 //! * `doc_from_display` - bool (optional)<a name="module-doc-from-display"></a>
 //!
 //!   Sets a default value for the [`doc_from_display`](#err-obj-doc-from-display)
@@ -697,6 +1131,14 @@
 //!   ```
 //!   <br>
 //!
+//! * `enforce_display_period` - bool (optional)
+//!
+//!   When enabled every `display` string in the module must end with a
+//!   terminal punctuation mark, i.e., a period `.`, a question mark `?` or
+//!   an exclamation mark `!`. Otherwise, parsing of the specification file
+//!   fails.<br>
+//!   Default: `false`<br><br>
+//!
 //! * `err_cat_doc` - string (optional)
 //!
 //!   Defines the doc comment of the *error category* struct.
@@ -778,10 +1220,59 @@
 //! * `error_trait` - bool (optional)
 //!
 //!   When enabled implements `std::error::Error` trait on the *error*
-//!   struct.<br>
-//!   This attribute is ignored when `no_std` is enabled.<br>
+//!   struct. In `std` environment an implementation of [From] trait is added
+//!   too, to create a `Box<dyn std::error::Error + Send + Sync>` from
+//!   `ErrorKind`, so the `?` operator works on both the *error* and the
+//!   *error kind* in functions returning boxed errors.<br>
+//!   This attribute is ignored when `no_std` is enabled, unless `core_error`
+//!   is enabled too.<br>
 //!   Default: `true`<br><br>
 //!
+//! * `extends` - string (optional)
+//!
+//!   Names a module of the [module list](#module-list) to inherit attributes
+//!   from. Every attribute not set in the module is copied from the named base
+//!   module, so shared options, e.g., `error_trait` or `doc_from_display`, can
+//!   be specified once. A base module may extend another module, and the
+//!   attributes are resolved along the chain before the specification is
+//!   validated. The parsing fails if the named module doesn't exist or if
+//!   the modules extend each other in a cycle.
+//!
+//!   The module's `name`, `doc`, `doc_file`, `categories`, `groups`,
+//!   `bridge_enum` and `map_by_name` are never inherited.
+//!
+//!   ```yaml
+//!   modules:
+//!     - name: errors
+//!       error_trait: false
+//!       flat_kinds: true
+//!       categories:
+//!         - name: General
+//!           errors: [TIMEOUT]
+//!     - name: internal_errors
+//!       extends: errors
+//!       categories:
+//!         - name: Io
+//!           errors: [BAD_FILE]
+//!   ```
+//!
+//!   This attribute is allowed only in a *module list*.<br>
+//!   By default no attributes are inherited.<br><br>
+//!
+//! * `feature` - string (optional)
+//!
+//!   Compiles the module only when the given crate feature is enabled.
+//!   When the module is enclosed in a *module block* the block is annotated
+//!   with `#[cfg(feature = "...")]`. When the module is written at the top of
+//!   a file, i.e., in [*separate files*](#separate-files-mode) mode or when
+//!   the specification has a single module, an inner attribute
+//!   `#![cfg(feature = "...")]` is emitted instead. Inner attributes are not
+//!   permitted in files included with the `include!` macro, so in this case
+//!   the `include!` should be wrapped with the `cfg` attribute manually.
+//!   In *append* output mode the attribute is not emitted.
+//!   See `doc_cfg` for marking the requirement in the documentation.<br>
+//!   By default the module is compiled unconditionally.<br><br>
+//!
 //! * `flat_kinds` - bool (optional)
 //!
 //!   Puts the error kind constants directly under the `kind` sub-module
@@ -826,8 +1317,176 @@
 //!         - ANOTHER_ERR
 //!   ```
 //!
+//!   Every category module also contains a `pub static ALL` array of the
+//!   category's error kinds, ordered by value, e.g., `kind::foo::ALL`.
+//!   Hence, `ALL` cannot be used as an error name unless `flat_kinds` is
+//!   enabled. In flat mode the arrays are placed in the `kind::by_category`
+//!   sub-module and are named after the category in `UPPER_SNAKE_CASE`,
+//!   e.g., `kind::by_category::FOO`.
+//!
 //!   Default: `false`<br><br>
 //!
+//! * `groups` - mapping (optional)
+//!
+//!   Defines named groups of errors. Every group adds an `is_<group-name>()`
+//!   method to the *error kind* struct that returns `true` if the error kind
+//!   is a member of the group. Membership is tested using a lookup table.
+//!
+//!   The mapping keys are the group names, specified in lower_snake_case.
+//!   The names `in` and `error` are reserved, because their methods would
+//!   collide with, or be confused with, the *error kind* methods `is_in`
+//!   and `error`.
+//!   The values are non-empty lists of references to errors defined in the
+//!   module. A reference is an error name, e.g., `TIMEOUT`, or an error name
+//!   qualified by its category name, e.g., `Parser::QUEUE_FULL`. A qualified
+//!   reference is required when an error name isn't unique in the module.
+//!
+//!   ```yaml
+//!   module:
+//!     groups:
+//!       retryable: [Parser::QUEUE_FULL, Processor::QUEUE_FULL, TIMEOUT]
+//!
+//!   categories:
+//!     - name: Parser
+//!       errors:
+//!         - QUEUE_FULL
+//!     - name: Processor
+//!       errors:
+//!         - QUEUE_FULL
+//!         - TIMEOUT
+//!   ```
+//!
+//!   The above specification adds `ErrorKind::is_retryable()`.<br>
+//!   By default no groups are defined.<br><br>
+//!
+//! * `kind_iter` - bool (optional)
+//!
+//!   When enabled the *error kind* struct receives the `VARIANTS` associated
+//!   constant, a slice of all error kind names, and the `iter()` associated
+//!   function returning an iterator over all error kinds, ordered by value.
+//!   The names in `VARIANTS` follow the order of `iter()`. This covers the
+//!   common use cases of the [strum](https://docs.rs/strum) crate without
+//!   depending on it.
+//!
+//!   With `assoc_consts` the name `VARIANTS` is reserved, i.e., an error
+//!   whose associated constant is named `VARIANTS` is rejected.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `kind_to_cat` - bool (optional)
+//!
+//!   When enabled a lookup table mapping every *error kind*, by its ordinal
+//!   in the module, to its category ordinal is added to the generated code.
+//!   The *error kind* struct receives a `category_index()` method that
+//!   returns the category ordinal using the table, without relying on the
+//!   bit layout of the *error kind* value. This is useful for building FFI
+//!   bridges.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `kinds_mod_name` - string (optional)
+//!
+//!   Defines a custom name of the sub-module holding the *error kind*
+//!   constants. The same rules as in `categories_mod_name` apply, and the two
+//!   names must be different.<br>
+//!   Default: `kind`<br><br>
+//!
+//! * `location` - bool (optional)
+//!
+//!   When enabled the *error* struct captures the source [Location] where it
+//!   was created from an *error kind* or a *variant type*, and
+//!   [Error::location] returns it instead of an *undefined* location.
+//!
+//!   The location is captured using `#[track_caller]`, hence an error created
+//!   with the `?` operator reports the location of the operator.
+//!   The captured location is included in the `Debug` output of the *error*
+//!   struct.
+//!
+//!   The *error* struct receives a `from_kind_at(kind, location)`
+//!   constructor, and the module receives a `bail!(kind)` macro that expands
+//!   to `return Err(Error::from_kind_at(kind, Location::caller()))`,
+//!   returning an error with the location of the macro invocation.
+//!   The macro refers to the *error* struct by its path from the crate root,
+//!   e.g., `crate::errors::Error`, hence the module must be declared at the
+//!   crate root under its module name. The macro is usable within the crate,
+//!   e.g., `errors::bail!(kind::general::TIMEOUT)`.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `map_by_name` - list of strings (optional)
+//!
+//!   Lists modules of the specification the module's errors are converted
+//!   into. For every listed module an implementation of `From` converting the
+//!   *error kind* and the *error* into the other module's types is added.
+//!   Every *error kind* is converted into the *error kind* with the same name
+//!   in the other module, preferring an error in the same-named category.
+//!   The code generation fails if an error has no same-named error in the
+//!   other module, or if the name is ambiguous.
+//!
+//!   ```yaml
+//!   modules:
+//!     - name: errors
+//!       categories:
+//!         - name: General
+//!           errors: [TIMEOUT, BAD_ARG]
+//!     - name: internal_errors
+//!       map_by_name: [errors]
+//!       categories:
+//!         - name: Io
+//!           errors: [TIMEOUT]
+//!   ```
+//!
+//!   The above specification adds `From<internal_errors::ErrorKind>` for
+//!   `errors::ErrorKind`. The modules are referenced as sibling modules,
+//!   which is always the case when the modules are written to a single file.
+//!   In [*separate files*](#separate-files-mode) mode the files must be
+//!   included as sibling modules with the modules' names.<br>
+//!   By default no conversions are added.<br><br>
+//!
+//! * `normalize_display_period` - bool (optional)
+//!
+//!   When enabled a period `.` is appended to every `display` string in the
+//!   module that doesn't end with a terminal punctuation mark.
+//!   See `enforce_display_period` for the list of terminal punctuation marks.
+//!   <br>
+//!   Default: `false`<br><br>
+//!
+//! * `order_by` - string (optional)
+//!
+//!   Defines the ordering of the *error kind* struct, i.e., its [Ord] and
+//!   [PartialOrd] implementations. Supported values are:
+//!
+//!   * `value` - error kinds are compared by their [value](Kind::value)
+//!   * `name` - error kinds are compared by their [name](Kind::name).
+//!     Error kinds with equal names, i.e., in different categories,
+//!     are compared by value.
+//!
+//!   Default: `value`<br><br>
+//!
+//! * `prelude` - bool (optional)
+//!
+//!   When enabled a `prelude` submodule re-exporting all the *error kind*
+//!   constants of the module is added. This allows bringing all the
+//!   constants into scope with a single glob import, e.g.,
+//!   `use errors::prelude::*;`, instead of importing every per-category
+//!   submodule of the error kind constants module.
+//!
+//!   Requires all error names to be unique per module.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `proptest` - bool (optional)
+//!
+//!   When enabled a [proptest] strategy function choosing among the error
+//!   kinds defined in the module is added, together with an implementation
+//!   of the [`proptest::arbitrary::Arbitrary`] trait for the *error kind*
+//!   struct. The function is named after the *error kind* struct, e.g.,
+//!   `any_error_kind` for `ErrorKind`.
+//!
+//!   The additions are gated behind a `proptest` feature of the user crate.
+//!   Hence, the crate must declare the feature and an optional dependency
+//!   on the [proptest] crate, e.g., `proptest = ["dep:proptest"]`.<br>
+//!   Default: `false`<br><br>
+//!
+//! [`proptest::arbitrary::Arbitrary`]: https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html
+//! [proptest]: https://crates.io/crates/proptest
+//!
 //! * `result_from_err` - bool (optional)
 //!
 //!   When enabled an implementation of [From] trait is added
@@ -836,9 +1495,70 @@
 //!
 //! * `result_from_err_kind` - bool (optional)
 //!
-//!    When enabled an implementation of [From] trait is added
-//!    to create a `Result<T, Error>` from `ErrorKind`.<br>
-//!    Default: `true`<br><br>
+//!   When enabled an implementation of [From] trait is added
+//!   to create a `Result<T, Error>` from `ErrorKind`.<br>
+//!   Default: `true`<br><br>
+//!
+//! * `runtime_path` - string (optional)
+//!
+//!   The path of the *tighterror* crate used by the generated code, e.g.,
+//!   in `impl tighterror::Kind for ErrorKind`. This is required if the
+//!   dependency is renamed in `Cargo.toml`, e.g., `tight`, or if the crate
+//!   is re-exported under a different path, e.g., `crate::rt`.
+//!   The value must be a Rust path.<br>
+//!   Default: `tighterror`<br><br>
+//!
+//! * `serde` - bool (optional)
+//!
+//!   When enabled implementations of the [`serde::Serialize`] and
+//!   [`serde::Deserialize`] traits are added to the *error kind* struct.
+//!   The representation is defined by the `serde_repr` attribute.
+//!
+//!   The implementations are gated behind a `serde` feature of the user
+//!   crate. Hence, the crate must declare the feature and an optional
+//!   dependency on the [serde] crate, e.g., `serde = ["dep:serde"]`.<br>
+//!   Default: `false`<br><br>
+//!
+//! [`serde::Serialize`]: https://docs.rs/serde/latest/serde/trait.Serialize.html
+//! [`serde::Deserialize`]: https://docs.rs/serde/latest/serde/trait.Deserialize.html
+//! [serde]: https://crates.io/crates/serde
+//!
+//! * `serde_repr` - string (optional)
+//!
+//!   Defines the serialized representation of the *error kind* struct.
+//!   It requires `serde: true`. Supported values are:
+//!
+//!   * `name` - an error kind is represented by its name qualified by the
+//!     category name, as returned by `ErrorKind::qualified_name`, e.g.,
+//!     `GENERAL::TIMEOUT`. Deserialization accepts the same names as
+//!     `ErrorKind::from_name`.
+//!   * `value` - an error kind is represented by its [value](Kind::value).
+//!     This representation is compact but not stable: the values change
+//!     when errors or categories are added, removed or reordered.
+//!
+//!   Default: `name`<br><br>
+//!
+//! * `test` - bool (optional)
+//!
+//!   Includes or excludes the module unit-test in the generated code.
+//!   The module-level value takes precedence over the `-t, --test`
+//!   command-line argument and [`CodegenOptions::test`]. This allows
+//!   excluding the unit-test of a specific module while enabling it
+//!   for the rest, and vice versa.<br>
+//!   By default the global option is used, see
+//!   [*main object* `test`](#main-object-test).<br><br>
+//!
+//! [`CodegenOptions::test`]: https://docs.rs/tighterror-build/latest/tighterror_build/struct.CodegenOptions.html#method.test
+//!
+//! * `value_widths` - list of integers (optional)
+//!
+//!   Adds fixed-width value accessors to the *error kind* and *error category*
+//!   structs, e.g., `[16, 64]` adds `value_u16()` and `value_u64()`. This
+//!   keeps user code stable when the underlying Rust type of a module changes
+//!   as errors are added. Every item must be one of `8`, `16`, `32` or `64`.
+//!   A width narrower than the underlying Rust type is a generation-time
+//!   error.<br>
+//!   By default no accessors are added.<br><br>
 //!
 //! * `variant_type` - bool (optional)<a name="module-variant-type"></a>
 //!
@@ -960,8 +1680,16 @@
 //!
 //!   Generates code suitable for Rust `no_std` environment.
 //!
-//!   When enabled this attribute implicitly disables `error_trait` and
-//!   skips unit tests that require `std`.<br>
+//!   When enabled this attribute implicitly disables `error_trait`, unless
+//!   `core_error` is enabled, and skips unit tests that require `std`.
+//!
+//!   If the attribute isn't set, *tighterror* detects a `no_std` target
+//!   when the code is generated from a build script: if the
+//!   `CARGO_CFG_TARGET_OS` environment variable, set by `cargo`, equals
+//!   `none` (e.g., `thumbv7em-none-eabihf`), the target has no `std` and
+//!   `no_std` is enabled. The `--no-std` command line flag and the
+//!   `CodegenOptions::no_std` option override both the attribute and the
+//!   detection.<br>
 //!   Default: `false`<br><br>
 //!
 //! * `output` - string (optional)<a name="main-object-output"></a>
@@ -980,12 +1708,113 @@
 //!     of the `.rs` extension
 //!
 //!   - when *separate files* mode is disabled the output is written to file
-//!     `tighterror.rs` under the directory
+//!     `tighterror.rs` under the directory, or to a file named after the
+//!     module if the specification has a single module and the
+//!     `--module-filename` command-line option of *cargo-tighterror*, or
+//!     `CodegenOptions::module_filename`, is enabled
 //!
 //!   When undefined the output is written to `stdout`.
 //!
 //!   This attribute is overridden by the `-o, --output` command-line
-//!   argument in *cargo-tighterror*.<br><br>
+//!   argument and the `TIGHTERROR_OUTPUT` environment variable in
+//!   *cargo-tighterror*.<br><br>
+//!
+//! * `output_pattern` - string (optional)
+//!
+//!   Defines the module file path pattern of the
+//!   [*separate files* mode](#separate-files-mode).
+//!
+//!   The pattern must contain the `{module}` placeholder, which is replaced
+//!   with the module name. The resulting path is relative to the output
+//!   directory, and missing intermediate directories are created.
+//!   For example, `gen_{module}.rs` writes module `errors` to
+//!   `gen_errors.rs`, while `{module}/mod.rs` writes it to `errors/mod.rs`.
+//!
+//!   This attribute is overridden by the `--output-pattern` command-line
+//!   argument in *cargo-tighterror*.<br>
+//!   Default: `{module}.rs`<br><br>
+//!
+//! * `require_display` - bool (optional)<a name="main-object-require-display"></a>
+//!
+//!   Requires an explicit `display` attribute of every error. An error
+//!   without a display string is displayed as its name, which is rarely
+//!   appropriate in a public API. When enabled, code generation fails
+//!   with an error naming the first error lacking a display string.
+//!
+//!   This attribute is overridden by the `--require-display` command-line
+//!   argument and [`CodegenOptions::require_display`].<br>
+//!   Default: `false`<br><br>
+//!
+//! [`CodegenOptions::require_display`]: https://docs.rs/tighterror-build/latest/tighterror_build/struct.CodegenOptions.html#method.require_display
+//!
+//! * `split_tables` - bool (optional)
+//!
+//!   Writes the private name, display and doc tables of every module to a
+//!   separate file in [*separate files* mode](#separate-files-mode). With
+//!   large error sets these tables dominate the module file, which slows
+//!   down tools like `rust-analyzer`.
+//!
+//!   The tables of a module are written to the file `<module>_tables.rs`
+//!   next to the module file, and the module file includes it using
+//!   `include!("<module>_tables.rs")`. The tables file must be kept next to
+//!   the module file and must not be declared as a module with `mod`. The
+//!   generated code behaves identically with or without this attribute.
+//!   A tables file mustn't be the file of another module, e.g., the tables
+//!   of a module `errors` collide with a module `errors_tables`.
+//!
+//!   This attribute is ignored outside of *separate files* mode. It is
+//!   overridden by the `--split-tables` command-line argument and
+//!   `CodegenOptions::split_tables`.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `test` - bool (optional)<a name="main-object-test"></a>
+//!
+//!   Includes the unit-tests of all modules in the generated code. This
+//!   allows a specification to request the unit-tests itself, so that
+//!   *cargo-tighterror* and build scripts generate the same code.
+//!
+//!   The precedence, from highest to lowest, is: the module-level `test`
+//!   attribute, the `-t, --test` command-line argument or
+//!   [`CodegenOptions::test`], and this attribute.<br>
+//!   Default: `false`<br><br>
+//!
+//! * `tests` - list of strings (optional)
+//!
+//!   Selects the generated unit-tests to include, in the modules that
+//!   include the unit-tests. A name selects the test with the same name
+//!   and all the tests whose name ends with `_<name>`, e.g., `uniqueness`
+//!   selects the category, error kind and error kind value uniqueness
+//!   tests, while `from_value` selects `category_from_value` and
+//!   `err_kind_from_value`, but not `err_kind_from_value_unchecked`.
+//!
+//!   The test names are: `category_name`, `category_display`,
+//!   `category_uniqueness`, `category_values`, `category_from_value`,
+//!   `category_from_name`, `category_from_str`, `category_kind_count`,
+//!   `category_sets`, `category_kinds`, `category_slices`, `category_default`,
+//!   `err_kind_name`, `err_kind_qualified_name`, `err_kind_display`,
+//!   `catalog`, `err_kind_uniqueness`, `err_kind_value_uniqueness`,
+//!   `err_kind_hash`, `err_kind_matches`,
+//!   `err_kind_category`, `err_kind_is_in`, `map_by_name`, `err_kind_assoc_consts`,
+//!   `prelude`, `err_kind_iter`, `err_kind_from_value`,
+//!   `err_kind_try_from_value`, `err_kind_variant`,
+//!   `err_kind_into_value`, `err_kind_debug_stable`, `err_kind_from_str`,
+//!   `err_kind_from_name`, `err_kind_arbitrary`, `err_kind_proptest`,
+//!   `err_kind_serde`, `err_kind_ord`, `err_kind_index`, `err_kind_category_index`,
+//!   `err_kind_from_value_unchecked`, `err_kind_default`, `value_widths`,
+//!   `err_kind_groups`, `err_display`, `err_message`, `err_boxed`,
+//!   `display_padding`, `display_unpadded`, `err_debug_location`, `bail`, `err_hash`,
+//!   `err_from_kind_ref`, `err_kind_error`, `result_from_err`, `result_from_err_kind`,
+//!   `variant_types_display`,
+//!   `variant_types_to_kind`, `variant_types_to_error` and
+//!   `variant_types_to_result`.
+//!   A test is generated only if the module has the tested feature.
+//!
+//!   The list must not be empty and must not contain unknown names.
+//!
+//!   This attribute is overridden by [`CodegenOptions::tests`].<br>
+//!   Default: all the tests<br><br>
+//!
+//! [`CodegenOptions::tests`]: https://docs.rs/tighterror-build/latest/tighterror_build/struct.CodegenOptions.html#method.tests
 //!
 //! ### Main Object Examples
 //!
@@ -1049,16 +1878,40 @@
 //! $> cargo help tighterror
 //! The cargo plugin of the tighterror framework.
 //!
-//! Usage: cargo tighterror [OPTIONS]
+//! Usage: cargo tighterror [OPTIONS] [COMMAND]
+//!
+//! Commands:
+//!   emit-build-rs  Print a build script that generates the code from the specification file
+//!   list           Print the error kinds and their values without generating code
+//!   help           Print this message or the help of the given subcommand(s)
 //!
 //! Options:
-//!   -s, --spec <PATH>     The specification file path
-//!   -o, --output <PATH>   The output path
-//!   -t, --test            Include a unit-test in the generated code
-//!   -u, --update          Do not overwrite the output file if data is unchanged
-//!   -S, --separate-files  Write modules in separate files
-//!   -h, --help            Print help
-//!   -V, --version         Print version
+//!   -s, --spec <PATH>               The specification file path
+//!       --lang <LANG>               The specification markup language [possible values: yaml, toml]
+//!   -o, --output <PATH>             The output path [env: TIGHTERROR_OUTPUT]
+//!   -t, --test[=<BOOL>]             Include a unit-test in the generated code
+//!       --no-std[=<BOOL>]           Generate code for a no_std environment, overriding the specification
+//!       --header[=<BOOL>]           Start the generated files with a generated-by comment including the spec hash
+//!   -u, --update[=<BOOL>]           Do not overwrite the output file if data is unchanged
+//!       --diff                      Print a unified diff of the changes instead of writing the output
+//!       --print-layout              Print the bit layout of the error kinds instead of writing the output
+//!   -S, --separate-files[=<BOOL>]   Write modules in separate files
+//!       --output-pattern <PATTERN>  The module file path pattern in separate-files mode, e.g., gen_{module}.rs
+//!       --output-ext <EXT>          The extension of the output files in an output directory, e.g., gen.rs
+//!       --module-filename[=<BOOL>]  Name the file after the single module when the output is a directory
+//!       --split-tables[=<BOOL>]     Write the private tables of every module to a <module>_tables.rs file
+//!       --strict[=<BOOL>]           Report specification warnings as errors
+//!       --fail-on-warnings          Exit with a failure status if any warning was logged
+//!       --strict-docs[=<BOOL>]      Reject documentation strings containing tab characters
+//!       --require-display[=<BOOL>]  Require an explicit display string of every error
+//!       --lenient[=<BOOL>]          Ignore unknown specification attributes with a warning
+//!       --output-mode <MODE>        The output file writing mode [possible values: overwrite, append]
+//!       --edition <EDITION>         The Rust edition used to format the generated code [possible values: 2015, 2018, 2021, 2024]
+//!       --rustfmt-config <PATH>     The rustfmt configuration file used to format the generated code
+//!       --catalog <PATH>            Write a JSON catalog of the errors to the given path
+//!       --threads <N>               The number of threads generating the modules in parallel
+//!   -h, --help                      Print help (see more with '--help')
+//!   -V, --version                   Print version
 //! ```
 //!
 //! * `-s, --spec <PATH>` (optional)
@@ -1067,10 +1920,24 @@
 //!
 //!   It is required if a specification file has a custom name.
 //!   It can be omitted if the specification file uses the default
-//!   name `tighterror.yaml` or `tighterror.toml` and is present in the
-//!   current working directory.
+//!   name `tighterror.yaml`, `tighterror.yml` or `tighterror.toml` and is
+//!   present in the current working directory.
 //!
-//!   The file extension `.yaml` or `.toml` is mandatory in custom filenames.
+//!   The file extension `.yaml`, `.yml` or `.toml` is mandatory in custom
+//!   filenames, unless the language is set with `--lang`.
+//!
+//!   Use hyphen `-` to read the specification from `stdin`. In this case
+//!   `--lang` is mandatory, and relative paths in the specification are
+//!   relative to the current working directory.
+//!   <br><br>
+//!
+//! * `--lang <LANG>` (optional)
+//!
+//!   Defines the specification markup language: `yaml` or `toml`.
+//!
+//!   By default the language is detected from the specification filename
+//!   extension. This argument overrides the detection, and is mandatory
+//!   when the specification is read from `stdin`.
 //!   <br><br>
 //!
 //! * `-o, --output <PATH>` (optional)
@@ -1080,10 +1947,16 @@
 //!   This can be either an absolute path, a relative path, or a hyphen `-`.
 //!   A relative path is relative to the location of the specification file.
 //!
-//!   This argument overrides the [`MainObject::output`](#main-object-output)
-//!   attribute defined in the specification file.
-//!   If this argument is omitted and output path isn't
-//!   defined in the specification file the output is written to `stdout`.
+//!   If this argument is omitted the output path is taken from the
+//!   `TIGHTERROR_OUTPUT` environment variable, e.g., to redirect the output
+//!   in CI without editing the specification file. An empty value is
+//!   ignored. The output path is resolved in the following order:
+//!
+//!   1. the `-o, --output` argument
+//!   2. the `TIGHTERROR_OUTPUT` environment variable
+//!   3. the [`MainObject::output`](#main-object-output) attribute defined
+//!      in the specification file
+//!   4. `stdout`
 //!
 //!   Use hyphen `-` to force the output to be written to `stdout`.<br><br>
 //!
@@ -1091,7 +1964,27 @@
 //!
 //!   Includes a unit-test in the generated Rust code.
 //!
-//!   In `no_std` environments test cases that require `std` are excluded.<br><br>
+//!   In `no_std` environments test cases that require `std` are excluded.
+//!   This argument overrides the *main object* `test` attribute, and
+//!   the module-level `test` attribute overrides this argument.
+//!   Use `--test=false` to exclude a test enabled in the specification.<br><br>
+//!
+//! * `--no-std` (optional)
+//!
+//!   Generates code for a `no_std` environment.
+//!
+//!   This argument overrides the *main object* `no_std` attribute and
+//!   the `no_std` target detection. Use `--no-std=false` to generate
+//!   `std` code regardless of both.<br><br>
+//!
+//! * `--header` (optional)
+//!
+//!   Starts every generated file with a comment noting the
+//!   *tighterror-build* version, the specification file name and the
+//!   SHA-256 hash of the specification and its `doc_file` files, e.g.,
+//!   `// @generated by tighterror-build v0.0.22 from tighterror.yaml (sha256: 5f1e...)`.
+//!   This allows reviewers to verify that the generated code matches the
+//!   specification.<br><br>
 //!
 //! * `-u, --update` (optional)
 //!
@@ -1106,6 +1999,24 @@
 //!   has changed, i.e., a rebuild is indeed required.
 //!   <br><br>
 //!
+//! * `--diff` (optional)
+//!
+//!   Prints a unified diff between the existing output and the generated
+//!   code to `stdout`, instead of writing the output. This allows previewing
+//!   the changes before regenerating the code. In *separate files* mode a
+//!   diff is printed per file. Nothing is printed if the output is up to
+//!   date.<br><br>
+//!
+//! * `--print-layout` (optional)
+//!
+//!   Prints the bit layout of the error kinds to `stdout`, instead of
+//!   writing the output. For every module the underlying Rust type, the
+//!   number of kind, category and variant bits, and the number of variants
+//!   of every category are printed. This helps to debug the binary
+//!   representation of the error kinds. The same layout is logged at
+//!   *info* level during code generation, e.g., with `RUST_LOG=info`.
+//!   <br><br>
+//!
 //! * `-S, --separate-files` (optional)<a name="separate-files-mode"></a>
 //!
 //!   Enables the *separate files* mode.
@@ -1118,6 +2029,7 @@
 //!   The `-o, --output` argument must point to an existing directory under
 //!   which the separate files are written. The filenames are derived from
 //!   the corresponding module names with the addition of `.rs` suffix.
+//!   See `--output-pattern` to customize the filenames.
 //!
 //!   If the output is written to `stdout` the *separate files* mode is
 //!   implicitly disabled.
@@ -1144,6 +2056,159 @@
 //!   ```
 //!   <br><br>
 //!
+//! * `--output-pattern <PATTERN>` (optional)
+//!
+//!   Defines the module file path pattern of the *separate files* mode.
+//!   See the `output_pattern` attribute of the [main object](#main-object)
+//!   for details. This argument overrides the attribute.
+//!   <br><br>
+//!
+//! * `--output-ext <EXT>` (optional)
+//!
+//!   Defines the extension of the output files whose names are derived by
+//!   *cargo-tighterror*, i.e., `tighterror.rs` and the module files when the
+//!   output path is a directory. The extension is given without the leading
+//!   dot, e.g., `gen.rs` writes module `errors` to `errors.gen.rs`. An
+//!   explicit `--output-pattern` takes precedence.<br>
+//!   Default: `rs`<br><br>
+//!
+//! * `--module-filename` (optional)
+//!
+//!   When the output path is an existing directory and *separate files* mode
+//!   is disabled, names the output file after the module, e.g., `errors.rs`,
+//!   instead of `tighterror.rs`. This applies only if the specification has
+//!   a single module.
+//!   <br><br>
+//!
+//! * `--split-tables` (optional)
+//!
+//!   Writes the private tables of every module to a separate file in
+//!   *separate files* mode. See the `split_tables` attribute of the
+//!   [main object](#main-object) for details. This argument overrides the
+//!   attribute.
+//!   <br><br>
+//!
+//! * `--strict` (optional)
+//!
+//!   Enables the *strict* mode.
+//!
+//!   By default suspicious, but valid, specification definitions are reported
+//!   as warnings and code generation proceeds. For example, two errors in the
+//!   same module that share a `display` string, two categories in the same
+//!   module that define the same error names, or a module that gains its
+//!   second category compared to the existing output file, which adds
+//!   category bits to the error kind layout. In *strict* mode such warnings
+//!   are reported as errors and code generation fails.
+//!
+//!   The latter is detected using the `// tighterror:cat_bits <module> <bits>`
+//!   comments the generated code starts with. These comments record the number
+//!   of category bits of every module in the file, and should be kept intact.
+//!   <br><br>
+//!
+//! * `--fail-on-warnings` (optional)
+//!
+//!   Makes *cargo-tighterror* exit with a failure status if any warning was
+//!   logged, e.g., in CI. Unlike the *strict* mode, every warning is
+//!   reported before failing. The code is generated without writing the
+//!   output first, so the output isn't written if there are warnings.
+//!   A specification read from `stdin` can't be read twice, hence its
+//!   output is written before the warnings are checked. The warnings are
+//!   printed to `stderr` unless `RUST_LOG` filters them out.
+//!   <br><br>
+//!
+//! * `--strict-docs` (optional)
+//!
+//!   Rejects documentation and display strings that contain a tab character.
+//!
+//!   YAML doesn't allow tabs in the indentation of block scalars, e.g.,
+//!   multi-line `doc` strings. Depending on their position such tabs may end
+//!   the string early and the rest of the text is silently lost. Because
+//!   the truncated text is still a valid string, the mistake can't be
+//!   detected after parsing. This option catches the common cause.
+//!   <br><br>
+//!
+//! * `--require-display` (optional)
+//!
+//!   Fails code generation if an error lacks an explicit display string,
+//!   naming the offending error. Overrides the
+//!   [`MainObject::require_display`](#main-object-require-display)
+//!   attribute.
+//!   <br><br>
+//!
+//! * `--lenient` (optional)
+//!
+//!   Logs unknown attributes and root-level keywords in the specification
+//!   file as warnings and ignores them, instead of failing. This allows
+//!   using a specification written for a newer version of *tighterror*
+//!   with an older *cargo-tighterror*. Combine with `--fail-on-warnings`
+//!   to still notice them in CI.
+//!   <br><br>
+//!
+//! * `--output-mode <MODE>` (optional)
+//!
+//!   Defines the output file writing mode: `overwrite` (default) or `append`.
+//!
+//!   In `append` mode the generated code is placed between the
+//!   `// tighterror:begin` and `// tighterror:end` marker lines. Only the
+//!   region between the markers is regenerated, while the content outside of
+//!   it is preserved. This allows adding hand-written items, e.g., custom
+//!   `impl` blocks, to a single generated file. If the markers are not found
+//!   the region is appended to the end of the file. The output file is
+//!   written only if its content changes, and module doc comments are not
+//!   generated.
+//!   <br><br>
+//!
+//! * `--edition <EDITION>` (optional)
+//!
+//!   Defines the Rust edition used to format the generated code:
+//!   `2015`, `2018`, `2021` (default) or `2024`. The edition is passed
+//!   to `rustfmt` as is.
+//!   <br><br>
+//!
+//! * `--rustfmt-config <PATH>` (optional)
+//!
+//!   Defines the `rustfmt` configuration file used to format the generated
+//!   code. By default `rustfmt` runs from the directory of the output file,
+//!   so the project `rustfmt.toml`, e.g., with a custom `tab_spaces`, is
+//!   discovered regardless of the current working directory. This argument
+//!   passes the given file to `rustfmt` with `--config-path` instead.
+//!   <br><br>
+//!
+//! * `--catalog <PATH>` (optional)
+//!
+//!   Writes a machine-readable JSON catalog of the errors to the given path,
+//!   in addition to the generated code. The catalog lists every module,
+//!   category and error kind with its name, display string, documentation
+//!   and numeric value, as assigned in the generated code. This is useful,
+//!   e.g., for documentation sites.
+//!   <br><br>
+//!
+//! * `--threads <N>` (optional)
+//!
+//!   Defines the number of threads generating the modules of the
+//!   specification in parallel. By default, a thread per CPU core is used.
+//!   The generated code doesn't depend on the number of threads.
+//!   <br><br>
+//!
+//! * `emit-build-rs` (command)
+//!
+//!   Prints a [build script](#tighterror-build) that generates the code from the
+//!   specification file during the crate build. The specification file
+//!   path is defined by the `-s, --spec` argument, or detected as described
+//!   above. The path is relative to the current working directory, hence the
+//!   command should be invoked in the crate root directory, e.g.,
+//!   `cargo tighterror emit-build-rs > build.rs`.
+//!   <br><br>
+//!
+//! * `list [--json]` (command)
+//!
+//!   Prints a table of the category name, the error kind name, the error
+//!   kind value and the display string of every error kind, without
+//!   generating code. The values are assigned exactly as in the generated
+//!   code. With `--json` the listing is printed in the format of the
+//!   `--catalog` file, e.g., `cargo tighterror list --json | jq`.
+//!   <br><br>
+//!
 //! # Motivation
 //!
 //! Error handling in general and error representation/reporting in particular
@@ -1412,6 +2477,9 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "std")]
+extern crate std;
+
 mod category;
 pub use category::*;
 
@@ -1423,6 +2491,10 @@ pub use error::*;
 
 mod location;
 pub use location::*;
+mod invalid_value_error;
+pub use invalid_value_error::*;
+mod parse_kind_error;
+pub use parse_kind_error::*;
 
 mod variant_type;
 pub use variant_type::*;
@@ -11,6 +11,7 @@ const UNDEFINED_LOCATION: &str = "<undefined location>";
 /// - it can be created as *undefined*
 ///
 /// A `Location` is *undefined* when the file name is an empty string.
+/// Otherwise it is *defined*.
 ///
 /// See [`Error::location`] for more information.
 ///
@@ -23,6 +24,8 @@ pub struct Location {
     pub file: &'static str,
     /// The line number.
     pub line: u32,
+    /// The column number.
+    pub column: u32,
 }
 
 impl Location {
@@ -36,6 +39,7 @@ impl Location {
         Self {
             file: loc.file(),
             line: loc.line(),
+            column: loc.column(),
         }
     }
 
@@ -43,7 +47,31 @@ impl Location {
     ///
     /// A location is *undefined* when the file name is an empty string.
     pub fn undefined() -> Location {
-        Self { file: "", line: 0 }
+        Self {
+            file: "",
+            line: 0,
+            column: 0,
+        }
+    }
+
+    /// Returns the file name.
+    ///
+    /// An empty string is returned for an *undefined* Location.
+    #[inline]
+    pub fn file(&self) -> &'static str {
+        self.file
+    }
+
+    /// Returns the line number.
+    #[inline]
+    pub fn line(&self) -> u32 {
+        self.line
+    }
+
+    /// Returns the column number.
+    #[inline]
+    pub fn column(&self) -> u32 {
+        self.column
     }
 
     /// Checks if the Location is *undefined*.
@@ -51,17 +79,61 @@ impl Location {
     pub fn is_undefined(&self) -> bool {
         self.file.is_empty()
     }
+
+    /// Checks if the Location is *defined*.
+    #[inline]
+    pub fn is_defined(&self) -> bool {
+        !self.is_undefined()
+    }
 }
 
 impl Display for Location {
-    /// Formats the Location.
+    /// Formats the Location as `file:line:column`.
     ///
     /// *Undefined* Locations are displayed as `<undefined location>`.
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         if self.is_undefined() {
             write!(f, "{}", UNDEFINED_LOCATION)
         } else {
-            write!(f, "{}:{}", self.file, self.line)
+            write!(f, "{}:{}:{}", self.file, self.line, self.column)
         }
     }
 }
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_caller() {
+        let (line, column, loc) = (line!(), column!(), Location::caller());
+        assert!(loc.is_defined());
+        assert!(!loc.is_undefined());
+        assert_eq!(loc.file(), file!());
+        assert_eq!(loc.line(), line);
+        assert_eq!(loc.column(), column + "column!(), ".len() as u32);
+    }
+
+    #[test]
+    fn test_undefined() {
+        let loc = Location::undefined();
+        assert!(loc.is_undefined());
+        assert!(!loc.is_defined());
+        assert_eq!(loc.file(), "");
+        assert_eq!(loc.line(), 0);
+        assert_eq!(loc.column(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let loc = Location {
+            file: "src/lib.rs",
+            line: 12,
+            column: 5,
+        };
+        assert_eq!(format!("{}", loc), "src/lib.rs:12:5");
+        assert_eq!(format!("{}", Location::undefined()), UNDEFINED_LOCATION);
+    }
+}
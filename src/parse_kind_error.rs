@@ -0,0 +1,39 @@
+use core::fmt::Display;
+
+/// An error returned when parsing an error kind from a string fails.
+///
+/// This is the error type of the `FromStr` and `TryFrom<&str>`
+/// implementations of the generated error kind and error category types.
+/// It is returned when the string doesn't denote an error kind or an error
+/// category name.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct ParseKindError;
+
+impl Display for ParseKindError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.pad("unknown error kind name")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for ParseKindError {}
+
+#[cfg(test)]
+mod test {
+    extern crate std;
+    use super::*;
+    use std::format;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", ParseKindError), "unknown error kind name");
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_error_trait() {
+        let e: &dyn std::error::Error = &ParseKindError;
+        assert!(e.source().is_none());
+    }
+}